@@ -0,0 +1,173 @@
+// Benchmarks for the board work done on every drop: finding 21s, letting cards fall, and a
+// whole game of drops played by a simple AI without a window. Record a baseline before
+// changing the board code and compare against it afterwards:
+//
+//     cargo bench --bench core_loop -- --save-baseline before
+//     cargo bench --bench core_loop -- --baseline before
+//
+// Criterion writes its report to target/criterion/report/index.html.
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use dropjack::game::board::Board;
+use dropjack::models::{Card, Deck, Difficulty};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use std::hint::black_box;
+
+// The game's default board
+const BOARD_WIDTH: i32 = 10;
+const BOARD_HEIGHT: i32 = 15;
+const CELL_SIZE: i32 = 50;
+
+const SEED: u64 = 21;
+const SIMULATED_DROPS: usize = 1000;
+
+// A shuffled deck that comes out the same on every run
+fn seeded_deck(rng: &mut StdRng) -> Deck {
+    let mut cards = Deck::new().cards().to_vec();
+    cards.shuffle(rng);
+    Deck::from_cards(cards)
+}
+
+fn draw(deck: &mut Deck, rng: &mut StdRng) -> Card {
+    deck.draw().unwrap_or_else(|| {
+        *deck = seeded_deck(rng);
+        deck.draw().expect("A fresh deck has cards")
+    })
+}
+
+// Lowest empty row in column `x`, where a dropped card would come to rest
+fn landing_row(board: &Board, x: i32) -> Option<i32> {
+    (0..board.height).rev().find(|&y| board.is_cell_empty(x, y))
+}
+
+// The bottom `rows` rows filled from the deck, as a board looks a while into a run
+fn filled_board(rows: i32) -> Board {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut deck = seeded_deck(&mut rng);
+    let mut board = Board::new(BOARD_WIDTH, BOARD_HEIGHT, CELL_SIZE);
+    for y in (BOARD_HEIGHT - rows)..BOARD_HEIGHT {
+        for x in 0..BOARD_WIDTH {
+            let card = draw(&mut deck, &mut rng);
+            board.place_card(x, y, card);
+        }
+    }
+    board
+}
+
+// A filled board with every other row cleared, so everything above the bottom row falls
+fn board_with_gaps() -> Board {
+    let mut board = filled_board(BOARD_HEIGHT - 1);
+    for y in (0..BOARD_HEIGHT).rev().step_by(2).skip(1) {
+        for x in 0..BOARD_WIDTH {
+            board.remove_card(x, y);
+        }
+    }
+    board
+}
+
+#[derive(Debug, Default)]
+struct SimulationResult {
+    cards_cleared: usize,
+    games_lost: usize,
+}
+
+// Play `drops` cards with an AI that puts each one where it clears the most cards, or on
+// the lowest stack when nothing clears. Clears and cascades resolve at once instead of
+// animating. A full board starts a new game.
+fn simulate_game(drops: usize, difficulty: Difficulty) -> SimulationResult {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut deck = seeded_deck(&mut rng);
+    let mut board = Board::new(BOARD_WIDTH, BOARD_HEIGHT, CELL_SIZE);
+    let mut result = SimulationResult::default();
+
+    for _ in 0..drops {
+        let card = draw(&mut deck, &mut rng);
+
+        // (cards cleared, landing row) for each column the card fits in; the best is the
+        // most cleared, then the lowest landing
+        let best = (0..BOARD_WIDTH)
+            .filter_map(|x| {
+                let y = landing_row(&board, x)?;
+                board.place_card(x, y, card);
+                let cleared =
+                    Board::combination_positions(&board.check_combinations(difficulty)).len();
+                board.remove_card(x, y);
+                Some(((cleared, y), x))
+            })
+            .max_by_key(|&(score, x)| (score, -x));
+
+        let Some(((_, y), x)) = best else {
+            result.games_lost += 1;
+            board = Board::new(BOARD_WIDTH, BOARD_HEIGHT, CELL_SIZE);
+            continue;
+        };
+        board.place_card(x, y, card);
+
+        loop {
+            let positions = Board::combination_positions(&board.check_combinations(difficulty));
+            if positions.is_empty() {
+                break;
+            }
+            for &(x, y) in &positions {
+                board.remove_card(x, y);
+            }
+            result.cards_cleared += positions.len();
+            while board.apply_gravity() {}
+            board.falling_cards.clear(); // Nothing animates here
+        }
+    }
+
+    result
+}
+
+fn bench_check_combinations(c: &mut Criterion) {
+    let half_full = filled_board(BOARD_HEIGHT / 2);
+    let full = filled_board(BOARD_HEIGHT);
+    c.bench_function("check_combinations/half_full_easy", |b| {
+        b.iter(|| black_box(&half_full).check_combinations(Difficulty::Easy))
+    });
+    c.bench_function("check_combinations/half_full_hard", |b| {
+        b.iter(|| black_box(&half_full).check_combinations(Difficulty::Hard))
+    });
+    c.bench_function("check_combinations/full_easy", |b| {
+        b.iter(|| black_box(&full).check_combinations(Difficulty::Easy))
+    });
+}
+
+fn bench_apply_gravity(c: &mut Criterion) {
+    c.bench_function("apply_gravity/settle_gaps", |b| {
+        b.iter_batched(
+            board_with_gaps,
+            |mut board| while board.apply_gravity() {},
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_simulated_game(c: &mut Criterion) {
+    let result = simulate_game(SIMULATED_DROPS, Difficulty::Easy);
+    println!(
+        "Simulated {} drops: {} cards cleared, {} games lost",
+        SIMULATED_DROPS, result.cards_cleared, result.games_lost
+    );
+
+    let mut group = c.benchmark_group("simulated_game");
+    group.sample_size(10); // Each sample is a whole game
+    group.bench_function("1000_drops_easy", |b| {
+        b.iter(|| simulate_game(black_box(SIMULATED_DROPS), Difficulty::Easy))
+    });
+    group.bench_function("1000_drops_hard", |b| {
+        b.iter(|| simulate_game(black_box(SIMULATED_DROPS), Difficulty::Hard))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_check_combinations,
+    bench_apply_gravity,
+    bench_simulated_game
+);
+criterion_main!(benches);
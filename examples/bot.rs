@@ -0,0 +1,94 @@
+// A bot that plays DropJack with no window, through the library API: each card goes to the
+// column whose stack it brings closest to 21 without going over. Pass a seed code to play
+// that run, or leave it off for a random one:
+//
+//     cargo run --example bot -- SEEDCD
+
+use dropjack::board::Board;
+use dropjack::game::Game;
+use dropjack::game::balance::Balance;
+use dropjack::models::{Card, Difficulty, RunSeed};
+use dropjack::store::{MemorySavedRunStore, MemoryScoreStore, MemorySettingsStore};
+use std::error::Error;
+
+const MAX_DROPS: usize = 500;
+const MAX_TICKS_PER_DROP: usize = 2000; // Frames to wait for a card to land and the next to appear
+
+// How good a column is for this card: a 21 beats everything, then the highest total under
+// 21. A bust is a last resort and a full column isn't a choice at all.
+fn column_value(board: &Board, x: i32, card: Card, difficulty: Difficulty) -> i32 {
+    match board.contiguous_sum_preview(x, card, difficulty) {
+        Some(21) => 100,
+        Some(sum) if sum < 21 => sum,
+        Some(_) => -1,
+        None => i32::MIN,
+    }
+}
+
+fn best_column(board: &Board, card: Card, difficulty: Difficulty) -> i32 {
+    (0..board.width)
+        .max_by_key(|&x| column_value(board, x, card, difficulty))
+        .unwrap_or(0)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let seed = match std::env::args().nth(1) {
+        Some(code) => RunSeed::parse(&code).ok_or(format!("{:?} isn't a seed code", code))?,
+        None => RunSeed::random(),
+    };
+
+    // Nothing on disk, and clears go off straight away since there's nobody to watch them
+    let mut game = Game::builder()
+        .score_store(Box::new(MemoryScoreStore::new()))
+        .settings_store(Box::new(MemorySettingsStore::new()))
+        .saved_run_store(Box::new(MemorySavedRunStore::new()))
+        .balance(Balance {
+            combination_delay_ms: 0,
+            ..Balance::default()
+        })
+        .build()?;
+    game.start_seeded_game(Difficulty::Easy, seed);
+
+    let mut drops = 0;
+    while drops < MAX_DROPS && !game.is_game_over() {
+        let Some(card) = game.current_card.as_ref().map(|playing| playing.card) else {
+            game.update();
+            continue;
+        };
+        let column = best_column(&game.board, card, game.difficulty);
+
+        // Slide over a column at a time, then drop
+        for _ in 0..MAX_TICKS_PER_DROP {
+            let Some(playing) = &game.current_card else {
+                break;
+            };
+            let x = playing.target.x;
+            if x == column && playing.position.x == column {
+                game.hard_drop();
+                break;
+            }
+            if column < x {
+                game.move_current_card_left();
+            } else if column > x {
+                game.move_current_card_right();
+            }
+            game.update();
+        }
+        drops += 1;
+
+        // Let the drop land and settle before the next card
+        for _ in 0..MAX_TICKS_PER_DROP {
+            game.update();
+            if game.is_game_over() || game.current_card.is_some() && game.board.is_settled() {
+                break;
+            }
+        }
+    }
+
+    println!("Seed {}: {} points in {} drops", seed, game.score, drops);
+    match game.score_submission() {
+        Ok(_) => println!("The run's log replays to the same score"),
+        Err(message) => println!("The run's log doesn't check out: {}", message),
+    }
+    Ok(())
+}
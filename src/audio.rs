@@ -1,77 +1,333 @@
 use crate::game::AudioEvent;
-use rodio::{Decoder, OutputStream, OutputStreamHandle, Source};
+use rodio::cpal::traits::{DeviceTrait, HostTrait};
+use rodio::source::Buffered;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
 use std::collections::HashMap;
+use std::io::Cursor;
+#[cfg(not(target_os = "emscripten"))]
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+#[cfg(target_os = "emscripten")]
+use std::time::Instant;
+
+const FALLBACK_SOUND_PATH: &str = "assets/audio/click.ogg";
+const MUSIC_BASE_PATH: &str = "assets/audio/music_base.ogg";
+const MUSIC_INTENSITY_PATH: &str = "assets/audio/music_intensity.ogg";
+const INTENSITY_FADE_PER_SEC: f32 = 0.5; // How fast the stem crossfade follows the target
+const CHAIN_INTENSITY_BOOST: f32 = 0.35; // Added to the intensity target per cascade step
+const CHAIN_BOOST_DECAY_PER_SEC: f32 = 0.1;
+const BASE_DUCK: f32 = 0.3; // How much the base loop dips when the stem is at full intensity
+const EVENT_DUCK_GAIN: f32 = 0.3; // Music level under a big event's sound
+const DUCK_ATTACK_PER_SEC: f32 = 8.0; // Ducking is quick so the event sound lands clearly
+const DUCK_RELEASE_PER_SEC: f32 = 1.5; // Recovery is a short fade
+// A sound decoded to PCM once at load. Clones share the decoded frames, so each play
+// only creates a new cursor into them.
+type DecodedSound = Buffered<Decoder<Cursor<Vec<u8>>>>;
+
+const DEVICE_CHECK_INTERVAL_SECS: u64 = 2; // How often to look for unplugged or new devices
 
 /// Audio system for the DropJack game using rodio
 ///
 /// Supports individual sound files for each of the 12 audio events.
 /// Falls back to click.ogg if specific event sounds are missing.
-/// Also includes music playback capabilities (stubbed for now).
+/// Background music is a base loop with an intensity stem layered on top; the stem
+/// fades in as the board fills up or chains go off.
 pub struct AudioSystem {
-    _stream: OutputStream, // Keep alive for the entire program duration
-    stream_handle: OutputStreamHandle,
-    sound_data: HashMap<AudioEvent, Vec<u8>>, // Event-specific audio data
-    fallback_sound: Option<Vec<u8>>,          // Fallback click.ogg for missing sounds
-    music_playing: bool,                      // Track if music is currently playing
-    current_music_volume: f32,                // Current music volume
+    output: Option<AudioOutput>, // None when no output device could be opened
+    requested_device: Option<String>, // Device picked in settings; None follows the system default
+    devices: Vec<String>,        // Output devices seen at the last check
+    device_watcher: DeviceWatcher,
+    sound_data: HashMap<AudioEvent, DecodedSound>, // Event-specific sounds
+    fallback_sound: Option<DecodedSound>,          // Fallback click.ogg for missing sounds
+    music_playing: bool,                           // Track if music is currently playing
+    current_music_volume: f32,                     // Current music volume
+    music: Option<MusicLayers>,                    // Playing stems, when the music files loaded
+    intensity: f32,                                // Current stem mix, eased toward the target
+    chain_boost: f32,                              // Extra intensity from recent chains, decays
+    duck_hold: f32,                                // Seconds left at the ducked level
+    duck_gain: f32,                                // Master music gain, 1.0 when not ducked
+}
+
+// An open output stream; dropping it closes the device
+struct AudioOutput {
+    _stream: OutputStream,
+    handle: OutputStreamHandle,
+    device_name: Option<String>, // The device actually opened, when its name is known
+}
+
+// The two looping stems, started together so they stay in sync
+struct MusicLayers {
+    base: Sink,
+    intensity: Sink,
+}
+
+// What a device check found
+struct DeviceScan {
+    devices: Vec<String>,
+    default_device: Option<String>,
+}
+
+impl DeviceScan {
+    fn now() -> Self {
+        DeviceScan {
+            devices: AudioSystem::output_devices(),
+            default_device: AudioSystem::default_device_name(),
+        }
+    }
+}
+
+// Checks the output devices every DEVICE_CHECK_INTERVAL_SECS. Asking the system can take
+// long enough to drop frames, so the checks run on a thread of their own and the audio
+// system picks up the latest result each frame, during play as much as anywhere else.
+#[cfg(not(target_os = "emscripten"))]
+struct DeviceWatcher {
+    scans: Receiver<DeviceScan>,
+}
+
+#[cfg(not(target_os = "emscripten"))]
+impl DeviceWatcher {
+    fn spawn() -> Self {
+        let (sender, scans) = mpsc::channel();
+        std::thread::spawn(move || {
+            loop {
+                std::thread::sleep(Duration::from_secs(DEVICE_CHECK_INTERVAL_SECS));
+                // Stops once the audio system is gone and nobody is listening
+                if sender.send(DeviceScan::now()).is_err() {
+                    break;
+                }
+            }
+        });
+        DeviceWatcher { scans }
+    }
+
+    /// The newest scan since the last call, without waiting for one
+    fn latest(&mut self) -> Option<DeviceScan> {
+        self.scans.try_iter().last()
+    }
+}
+
+// Browser builds have no threads, and the browser picks the device anyway, so the checks
+// run inline on the same interval
+#[cfg(target_os = "emscripten")]
+struct DeviceWatcher {
+    last_check: Instant,
+}
+
+#[cfg(target_os = "emscripten")]
+impl DeviceWatcher {
+    fn spawn() -> Self {
+        DeviceWatcher {
+            last_check: Instant::now(),
+        }
+    }
+
+    fn latest(&mut self) -> Option<DeviceScan> {
+        if self.last_check.elapsed() < Duration::from_secs(DEVICE_CHECK_INTERVAL_SECS) {
+            return None;
+        }
+        self.last_check = Instant::now();
+        Some(DeviceScan::now())
+    }
 }
 
 impl AudioSystem {
-    /// Initialize the audio system using rodio
+    /// Initialize the audio system on the default output device with every sound loaded
+    #[allow(dead_code)]
     pub fn new() -> Self {
-        // Initialize rodio output stream
-        let (stream, stream_handle) = match OutputStream::try_default() {
-            Ok((stream, handle)) => {
-                println!("Audio system initialized successfully with rodio");
-                (stream, handle)
+        let mut audio_system = Self::without_sounds();
+        audio_system.load_fallback_sound();
+        for (event, _) in Self::sound_files() {
+            audio_system.load_event_sound(event);
+        }
+        audio_system
+    }
+
+    /// Open the default output device without loading any sounds yet, so they can be
+    /// loaded one at a time with `load_fallback_sound` and `load_event_sound`
+    pub fn without_sounds() -> Self {
+        let output = Self::open_output(None);
+        if output.is_some() {
+            println!("Audio system initialized successfully with rodio");
+        }
+
+        AudioSystem {
+            output,
+            requested_device: None,
+            devices: Self::output_devices(),
+            device_watcher: DeviceWatcher::spawn(),
+            sound_data: HashMap::new(),
+            fallback_sound: None,
+            music_playing: false,
+            current_music_volume: 0.7,
+            music: None,
+            intensity: 0.0,
+            chain_boost: 0.0,
+            duck_hold: 0.0,
+            duck_gain: 1.0,
+        }
+    }
+
+    /// Load the click sound used for events without their own. Returns whether it loaded.
+    pub fn load_fallback_sound(&mut self) -> bool {
+        self.fallback_sound = Self::load_decoded_sound(FALLBACK_SOUND_PATH);
+        if self.fallback_sound.is_none() {
+            eprintln!(
+                "Warning: Could not load fallback audio file {}",
+                FALLBACK_SOUND_PATH
+            );
+        }
+        self.fallback_sound.is_some()
+    }
+
+    /// Load one event's own sound. Returns whether it loaded; without it the event plays
+    /// the fallback click.
+    pub fn load_event_sound(&mut self, event: AudioEvent) -> bool {
+        let Some(file_path) = Self::get_audio_config().remove(&event) else {
+            return false;
+        };
+        if let Some(sound) = Self::load_decoded_sound(&file_path) {
+            self.sound_data.insert(event, sound);
+            println!("Loaded audio for {:?}: {}", event, file_path);
+            true
+        } else {
+            println!(
+                "Using fallback sound for {:?} (missing: {})",
+                event, file_path
+            );
+            false
+        }
+    }
+
+    /// Every event with a sound file of its own, sorted by path
+    pub fn sound_files() -> Vec<(AudioEvent, String)> {
+        let mut files: Vec<(AudioEvent, String)> = Self::get_audio_config().into_iter().collect();
+        files.sort_by(|a, b| a.1.cmp(&b.1));
+        files
+    }
+
+    /// Names of the output devices currently available
+    pub fn output_devices() -> Vec<String> {
+        match rodio::cpal::default_host().output_devices() {
+            Ok(devices) => devices.filter_map(|device| device.name().ok()).collect(),
+            Err(e) => {
+                eprintln!("Could not list audio devices: {}", e);
+                Vec::new()
             }
+        }
+    }
+
+    fn default_device_name() -> Option<String> {
+        rodio::cpal::default_host()
+            .default_output_device()
+            .and_then(|device| device.name().ok())
+    }
+
+    // Open the named device, falling back to the system default if it's missing or fails
+    fn open_output(device_name: Option<&str>) -> Option<AudioOutput> {
+        if let Some(name) = device_name {
+            let device =
+                rodio::cpal::default_host()
+                    .output_devices()
+                    .ok()
+                    .and_then(|mut devices| {
+                        devices.find(|device| device.name().is_ok_and(|device| device == name))
+                    });
+            match device.map(|device| OutputStream::try_from_device(&device)) {
+                Some(Ok((stream, handle))) => {
+                    return Some(AudioOutput {
+                        _stream: stream,
+                        handle,
+                        device_name: Some(name.to_string()),
+                    });
+                }
+                Some(Err(e)) => eprintln!("Could not open audio device {}: {}", name, e),
+                None => eprintln!("Audio device {} not found, using the default", name),
+            }
+        }
+
+        match OutputStream::try_default() {
+            Ok((stream, handle)) => Some(AudioOutput {
+                _stream: stream,
+                handle,
+                device_name: Self::default_device_name(),
+            }),
             Err(e) => {
                 eprintln!("Warning: Could not initialize audio: {}", e);
-                return AudioSystem {
-                    _stream: OutputStream::try_default()
-                        .unwrap_or_else(|_| panic!("Failed to create fallback audio stream"))
-                        .0,
-                    stream_handle: OutputStream::try_default().unwrap().1,
-                    sound_data: HashMap::new(),
-                    fallback_sound: None,
-                    music_playing: false,
-                    current_music_volume: 0.7,
-                };
+                None
+            }
+        }
+    }
+
+    /// Follow the device picked in settings, and reopen the output when the device in use
+    /// goes away, the system default changes, or a device shows up after starting without one.
+    /// Devices are checked in the background, so this never waits on the system.
+    pub fn update_output_device(&mut self, wanted: Option<&str>) {
+        if self.requested_device.as_deref() != wanted {
+            self.requested_device = wanted.map(str::to_string);
+            self.reopen_output();
+            return;
+        }
+        let Some(scan) = self.device_watcher.latest() else {
+            return;
+        };
+        self.devices = scan.devices;
+
+        let current = self
+            .output
+            .as_ref()
+            .and_then(|output| output.device_name.clone());
+        let lost = match (&self.output, &current) {
+            (Some(_), Some(name)) => !self.devices.contains(name),
+            (Some(_), None) => false,
+            (None, _) => !self.devices.is_empty(),
+        };
+        let moved = match wanted {
+            // The system default moved, e.g. headphones were plugged in
+            None => self.output.is_some() && current != scan.default_device,
+            // The picked device is back after falling back to the default
+            Some(name) => {
+                current.as_deref() != Some(name) && self.devices.iter().any(|d| d == name)
             }
         };
 
-        // Load the fallback click sound
-        let fallback_sound = Self::load_sound_file("assets/audio/click.ogg");
-        if fallback_sound.is_none() {
-            eprintln!("Warning: Could not load fallback audio file assets/audio/click.ogg");
+        if lost || moved {
+            self.reopen_output();
         }
+    }
 
-        // Try to load event-specific audio files
-        let mut sound_data = HashMap::new();
-        let audio_config = Self::get_audio_config();
+    fn reopen_output(&mut self) {
+        if let Some(music) = self.music.take() {
+            music.base.stop();
+            music.intensity.stop();
+        }
 
-        for (event, file_path) in audio_config {
-            if let Some(data) = Self::load_sound_file(&file_path) {
-                sound_data.insert(event, data);
-                println!("Loaded audio for {:?}: {}", event, file_path);
-            } else {
-                println!(
-                    "Using fallback sound for {:?} (missing: {})",
-                    event, file_path
-                );
-            }
+        // Close the old stream before opening the new one
+        self.output = None;
+        self.output = Self::open_output(self.requested_device.as_deref());
+        match self.output_device_name() {
+            Some(name) => println!("Audio output: {}", name),
+            None if self.output.is_some() => println!("Audio output: system default"),
+            None => eprintln!("Audio output: none available"),
         }
 
-        AudioSystem {
-            _stream: stream,
-            stream_handle,
-            sound_data,
-            fallback_sound,
-            music_playing: false,
-            current_music_volume: 0.7,
+        if self.music_playing {
+            self.music = self.load_music_layers();
+            self.apply_music_mix();
         }
     }
 
+    /// Output devices seen at the last device check
+    pub fn known_devices(&self) -> &[String] {
+        &self.devices
+    }
+
+    /// Name of the device sound is going to, when known
+    pub fn output_device_name(&self) -> Option<&str> {
+        self.output
+            .as_ref()
+            .and_then(|output| output.device_name.as_deref())
+    }
+
     /// Play sound for a specific audio event with volume control
     pub fn play_event(
         &self,
@@ -85,27 +341,20 @@ impl AudioSystem {
             return;
         }
 
-        // Try to get event-specific sound, fall back to click.ogg if not found
-        let sound_data = self.sound_data.get(&event).or(self.fallback_sound.as_ref());
-
-        if let Some(data) = sound_data {
-            // Create a new decoder from the sound data for each play
-            let cursor = std::io::Cursor::new(data.clone());
-
-            match Decoder::new(cursor) {
-                Ok(source) => {
-                    // Apply volume adjustment and play the sound
-                    let source_with_volume = source.amplify(volume);
-                    if let Err(e) = self
-                        .stream_handle
-                        .play_raw(source_with_volume.convert_samples())
-                    {
-                        eprintln!("Failed to play sound for {:?}: {}", event, e);
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Failed to decode sound for {:?}: {}", event, e);
-                }
+        // Try to get event-specific sound, then its stand-in, then click.ogg
+        let sound = self
+            .sound_data
+            .get(&event)
+            .or_else(|| Self::stand_in_event(event).and_then(|e| self.sound_data.get(&e)))
+            .or(self.fallback_sound.as_ref());
+
+        if let Some(sound) = sound {
+            // Apply volume adjustment and play the sound
+            let source_with_volume = sound.clone().amplify(volume);
+            if let Some(output) = &self.output
+                && let Err(e) = output.handle.play_raw(source_with_volume.convert_samples())
+            {
+                eprintln!("Failed to play sound for {:?}: {}", event, e);
             }
         } else {
             eprintln!("No audio available for {:?}", event);
@@ -176,15 +425,85 @@ impl AudioSystem {
                 AudioEvent::HardDrop,
                 "assets/audio/hard_drop.ogg".to_string(),
             ),
+            (
+                AudioEvent::NewRecord,
+                "assets/audio/new_record.ogg".to_string(),
+            ),
+            (
+                AudioEvent::InitialsRejected,
+                "assets/audio/initials_rejected.ogg".to_string(),
+            ),
+            (
+                AudioEvent::NewPersonalBest,
+                "assets/audio/new_personal_best.ogg".to_string(),
+            ),
+            (
+                AudioEvent::NaturalBlackjack,
+                "assets/audio/natural_blackjack.ogg".to_string(),
+            ),
         ])
     }
 
+    /// Events whose own sound file hasn't been made yet, and the sound that plays for them
+    /// until it is. A pack can still supply the real file under the event's own name.
+    fn stand_in_event(event: AudioEvent) -> Option<AudioEvent> {
+        match event {
+            AudioEvent::NewRecord => Some(AudioEvent::StartGame),
+            AudioEvent::InitialsRejected => Some(AudioEvent::ForfeitGame),
+            AudioEvent::NewPersonalBest => Some(AudioEvent::ResumeGame),
+            AudioEvent::NaturalBlackjack => Some(AudioEvent::DifficultyChange),
+            _ => None,
+        }
+    }
+
+    /// Replace sounds with the ones an audio pack provides. Pack files are matched to
+    /// events by file name, so a pack only needs the sounds it changes.
+    pub fn load_sound_pack(&mut self, dir: &std::path::Path) {
+        let fallback_path = dir.join("click.ogg");
+        if fallback_path.is_file()
+            && let Some(sound) = Self::load_decoded_sound(&fallback_path.to_string_lossy())
+        {
+            self.fallback_sound = Some(sound);
+        }
+
+        for (event, file_path) in Self::get_audio_config() {
+            let Some(file_name) = std::path::Path::new(&file_path).file_name() else {
+                continue;
+            };
+            let pack_path = dir.join(file_name);
+            if !pack_path.is_file() {
+                continue;
+            }
+            if let Some(sound) = Self::load_decoded_sound(&pack_path.to_string_lossy()) {
+                self.sound_data.insert(event, sound);
+                println!("Loaded audio for {:?}: {}", event, pack_path.display());
+            }
+        }
+    }
+
     /// Load a sound file into memory
     /// Returns the raw bytes that can be decoded multiple times
     fn load_sound_file(path: &str) -> Option<Vec<u8>> {
         std::fs::read(path).ok()
     }
 
+    /// Load and fully decode a sound file, so playing it later never touches the decoder
+    fn load_decoded_sound(path: &str) -> Option<DecodedSound> {
+        let data = Self::load_sound_file(path)?;
+        match Decoder::new(Cursor::new(data)) {
+            Ok(decoder) => {
+                let sound = decoder.buffered();
+                // Run through a clone once; the decoded frames stay shared with `sound`
+                sound.clone().for_each(drop);
+                Some(sound)
+            }
+            Err(e) => {
+                eprintln!("Failed to decode sound {}: {}", path, e);
+                None
+            }
+        }
+    }
+
     /// Get statistics about loaded sounds
     pub fn get_audio_stats(&self) -> (usize, usize) {
         let specific_sounds = self.sound_data.len();
@@ -192,25 +511,115 @@ impl AudioSystem {
         (specific_sounds, total_possible)
     }
 
-    /// Start playing background music (stubbed implementation)
+    /// Whether an audio output device is open
+    pub fn has_output_device(&self) -> bool {
+        self.output.is_some()
+    }
+
+    /// Whether the fallback click sound loaded
+    pub fn has_fallback_sound(&self) -> bool {
+        self.fallback_sound.is_some()
+    }
+
+    /// Every event-specific sound file, sorted by path, with whether it loaded
+    pub fn sound_file_status(&self) -> Vec<(String, bool)> {
+        let mut status: Vec<(String, bool)> = Self::get_audio_config()
+            .into_iter()
+            .map(|(event, path)| (path, self.sound_data.contains_key(&event)))
+            .collect();
+        status.sort();
+        status
+    }
+
+    /// Start the music stems looping. Without the music files only the state is tracked.
     pub fn start_music(&mut self, volume: f32, _muted: bool) {
-        // In a real implementation, this would load and start playing a music file
-        // For now, we just track the state
         self.music_playing = true;
         self.current_music_volume = volume;
-        // TODO: Load and play actual music file (e.g., "assets/music/background.ogg")
+        self.music = self.load_music_layers();
+        self.apply_music_mix();
+    }
+
+    fn load_music_layers(&self) -> Option<MusicLayers> {
+        let output = self.output.as_ref()?;
+        let base = Self::start_loop(&output.handle, MUSIC_BASE_PATH)?;
+        let intensity = Self::start_loop(&output.handle, MUSIC_INTENSITY_PATH)?;
+        Some(MusicLayers { base, intensity })
+    }
+
+    fn start_loop(stream_handle: &OutputStreamHandle, path: &str) -> Option<Sink> {
+        let data = Self::load_sound_file(path)?;
+        let source = match Decoder::new(Cursor::new(data)) {
+            Ok(source) => source,
+            Err(e) => {
+                eprintln!("Failed to decode music {}: {}", path, e);
+                return None;
+            }
+        };
+        match Sink::try_new(stream_handle) {
+            Ok(sink) => {
+                sink.set_volume(0.0);
+                sink.append(source.repeat_infinite());
+                Some(sink)
+            }
+            Err(e) => {
+                eprintln!("Failed to start music {}: {}", path, e);
+                None
+            }
+        }
     }
 
     /// Stop playing background music
     pub fn stop_music(&mut self) {
         self.music_playing = false;
-        // TODO: Stop the actual music playback
+        if let Some(music) = self.music.take() {
+            music.base.stop();
+            music.intensity.stop();
+        }
     }
 
     /// Set music volume
     pub fn set_music_volume(&mut self, volume: f32) {
         self.current_music_volume = volume.clamp(0.0, 1.0);
-        // TODO: Apply volume to the currently playing music
+        self.apply_music_mix();
+    }
+
+    /// Ease the stem mix toward the board's danger level (0.0 calm to 1.0 about to top out)
+    pub fn update_music(&mut self, delta_time: f32, danger: f32) {
+        self.chain_boost = (self.chain_boost - CHAIN_BOOST_DECAY_PER_SEC * delta_time).max(0.0);
+        let target = (danger + self.chain_boost).clamp(0.0, 1.0);
+        self.intensity = ease_toward(self.intensity, target, INTENSITY_FADE_PER_SEC * delta_time);
+
+        if self.duck_hold > 0.0 {
+            self.duck_hold -= delta_time;
+            self.duck_gain = ease_toward(
+                self.duck_gain,
+                EVENT_DUCK_GAIN,
+                DUCK_ATTACK_PER_SEC * delta_time,
+            );
+        } else {
+            self.duck_gain = ease_toward(self.duck_gain, 1.0, DUCK_RELEASE_PER_SEC * delta_time);
+        }
+
+        self.apply_music_mix();
+    }
+
+    /// Dip the music under a big event's sound for `hold`, then fade it back up
+    pub fn duck_music(&mut self, hold: Duration) {
+        self.duck_hold = self.duck_hold.max(hold.as_secs_f32());
+    }
+
+    /// Push the intensity up for a chain; it drifts back down over the next few seconds
+    pub fn boost_music_intensity(&mut self) {
+        self.chain_boost = (self.chain_boost + CHAIN_INTENSITY_BOOST).min(1.0);
+    }
+
+    fn apply_music_mix(&self) {
+        if let Some(music) = &self.music {
+            let volume = self.current_music_volume * self.duck_gain;
+            let (base, intensity) = stem_volumes(volume, self.intensity);
+            music.base.set_volume(base);
+            music.intensity.set_volume(intensity);
+        }
     }
 
     /// Check if music is currently playing
@@ -247,6 +656,21 @@ impl AudioSystem {
     }
 }
 
+/// Volumes for the base loop and intensity stem at a given intensity
+fn stem_volumes(volume: f32, intensity: f32) -> (f32, f32) {
+    let intensity = intensity.clamp(0.0, 1.0);
+    (volume * (1.0 - BASE_DUCK * intensity), volume * intensity)
+}
+
+/// Move `current` toward `target` by at most `step`
+fn ease_toward(current: f32, target: f32, step: f32) -> f32 {
+    if current < target {
+        (current + step).min(target)
+    } else {
+        (current - step).max(target)
+    }
+}
+
 impl Drop for AudioSystem {
     fn drop(&mut self) {
         // Nothing to clean up in the placeholder implementation
@@ -288,6 +712,10 @@ mod tests {
                 AudioEvent::MoveRight,
                 AudioEvent::SoftDrop,
                 AudioEvent::HardDrop,
+                AudioEvent::NewRecord,
+                AudioEvent::InitialsRejected,
+                AudioEvent::NewPersonalBest,
+                AudioEvent::NaturalBlackjack,
             ]
         }
     }
@@ -308,8 +736,8 @@ mod tests {
 
         // Verify configuration is complete
         assert!(
-            config.len() >= 16,
-            "Audio configuration should have at least 16 events"
+            config.len() >= 20,
+            "Audio configuration should have at least 20 events"
         );
     }
 
@@ -349,12 +777,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_stand_in_sounds_have_files() {
+        let config = AudioSystem::get_audio_config();
+        for event in test_fixtures::create_all_audio_events() {
+            if let Some(stand_in) = AudioSystem::stand_in_event(event) {
+                assert!(std::path::Path::new(&config[&stand_in]).is_file());
+                assert_eq!(AudioSystem::stand_in_event(stand_in), None);
+            }
+        }
+    }
+
     #[test]
     fn test_load_sound_file_nonexistent() {
         let result = AudioSystem::load_sound_file("nonexistent_file.ogg");
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_load_decoded_sound_missing_or_invalid() {
+        assert!(AudioSystem::load_decoded_sound("nonexistent_file.ogg").is_none());
+
+        // Files that exist but aren't audio fail to decode rather than panicking
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("not_audio.ogg");
+        std::fs::write(&path, b"definitely not ogg").unwrap();
+        let _ = AudioSystem::load_decoded_sound(path.to_str().unwrap());
+    }
+
     #[test]
     fn test_load_sound_file_invalid_path() {
         let result = AudioSystem::load_sound_file("");
@@ -372,7 +822,7 @@ mod tests {
 
         // Should have the correct number of configured events
         let (loaded, total) = audio_system.get_audio_stats();
-        assert_eq!(total, 16); // Should match the number of events in config
+        assert_eq!(total, 20); // Should match the number of events in config
         assert!(loaded <= total); // Loaded count should not exceed total
     }
 
@@ -440,6 +890,26 @@ mod tests {
         // If we reach here, drop succeeded
     }
 
+    #[test]
+    fn test_stem_volumes_crossfade() {
+        assert_eq!(stem_volumes(0.8, 0.0), (0.8, 0.0));
+
+        let (base, stem) = stem_volumes(0.8, 1.0);
+        assert!((base - 0.8 * (1.0 - BASE_DUCK)).abs() < f32::EPSILON);
+        assert_eq!(stem, 0.8);
+
+        // Out of range intensity is clamped
+        assert_eq!(stem_volumes(0.5, 2.0), stem_volumes(0.5, 1.0));
+    }
+
+    #[test]
+    fn test_ease_toward_does_not_overshoot() {
+        assert_eq!(ease_toward(0.0, 1.0, 0.25), 0.25);
+        assert_eq!(ease_toward(0.9, 1.0, 0.25), 1.0);
+        assert_eq!(ease_toward(0.5, 0.0, 0.25), 0.25);
+        assert_eq!(ease_toward(0.1, 0.0, 0.25), 0.0);
+    }
+
     mod integration_tests {
         use super::*;
 
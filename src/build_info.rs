@@ -0,0 +1,84 @@
+// Which build of the game this is, stamped in by build.rs, so bug reports can say exactly
+// what was running. Also installs the panic hook that writes crash reports.
+
+use crate::storage::platform_storage;
+use serde::Serialize;
+use std::fmt;
+
+pub const CRASH_REPORT_KEY: &str = "crash_report.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub git_hash: &'static str,
+    pub build_date: &'static str,
+}
+
+impl BuildInfo {
+    pub const CURRENT: BuildInfo = BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        git_hash: env!("DROPJACK_GIT_HASH"),
+        build_date: env!("DROPJACK_BUILD_DATE"),
+    };
+}
+
+// e.g. "v0.1.0 (46f0ef3, 2026-10-16)"
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "v{} ({}, {})",
+            self.version, self.git_hash, self.build_date
+        )
+    }
+}
+
+/// On a panic, write what happened and which build it happened in to the app data
+/// directory before the usual message is printed. The previous report is replaced.
+pub fn install_crash_reporter() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = crash_report(
+            BuildInfo::CURRENT,
+            &info.to_string(),
+            &std::backtrace::Backtrace::force_capture().to_string(),
+        );
+        match platform_storage().and_then(|storage| storage.write(CRASH_REPORT_KEY, &report)) {
+            Ok(()) => eprintln!("Crash report saved as {}", CRASH_REPORT_KEY),
+            Err(e) => eprintln!("Failed to save crash report: {}", e),
+        }
+        default_hook(info);
+    }));
+}
+
+fn crash_report(build: BuildInfo, panic: &str, backtrace: &str) -> String {
+    format!(
+        "DropJack {}\nCrashed: {}\nOS: {} {}\n\n{}\n\nBacktrace:\n{}\n",
+        build,
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        panic,
+        backtrace
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crash_report_names_the_build() {
+        let build = BuildInfo {
+            version: "1.2.3",
+            git_hash: "abc1234",
+            build_date: "2026-01-02",
+        };
+        assert_eq!(build.to_string(), "v1.2.3 (abc1234, 2026-01-02)");
+
+        let report = crash_report(build, "panicked at src/main.rs:1:1", "0: main");
+        assert!(report.starts_with("DropJack v1.2.3 (abc1234, 2026-01-02)\n"));
+        assert!(report.contains("panicked at src/main.rs:1:1"));
+        assert!(report.contains("Backtrace:\n0: main"));
+    }
+}
@@ -1,13 +1,37 @@
-use crate::models::HighScore;
+use crate::error::DropJackError;
+use crate::integrity::Signer;
+use crate::models::{
+    COLUMN_DROPS_PREFIX, DeckTemplate, Difficulty, HighScore, RunOutcome, Statistics,
+    column_drops_stat_name, parse_legacy_date, score_percentile,
+};
+use crate::store::ScoreStore;
+use chrono::{DateTime, Utc};
+use rusqlite::types::ValueRef;
 use rusqlite::{Connection, Result, params};
+use std::collections::HashMap;
 use std::path::Path;
 
 pub struct Database {
     conn: Connection,
+    signer: Option<Signer>, // Signs each score row; None leaves rows unsigned and unchecked
 }
 
 impl Database {
-    pub fn new(db_path: &Path) -> Result<Self> {
+    pub fn new(db_path: &Path) -> std::result::Result<Self, DropJackError> {
+        Self::with_signer(db_path, Signer::for_this_machine())
+    }
+
+    pub fn with_signer(
+        db_path: &Path,
+        signer: Option<Signer>,
+    ) -> std::result::Result<Self, DropJackError> {
+        Self::open(db_path, signer).map_err(|source| DropJackError::DatabaseOpen {
+            path: db_path.to_path_buf(),
+            source,
+        })
+    }
+
+    fn open(db_path: &Path, signer: Option<Signer>) -> Result<Self> {
         let conn = Connection::open(db_path)?;
 
         // Create a high scores table if it doesn't exist
@@ -16,46 +40,540 @@ impl Database {
                 id INTEGER PRIMARY KEY,
                 player_initials TEXT NOT NULL,
                 score INTEGER NOT NULL,
-                difficulty TEXT NOT NULL,
-                date TEXT NOT NULL
+                difficulty INTEGER NOT NULL,
+                date INTEGER NOT NULL
+            )",
+            [],
+        )?;
+
+        // Databases from before mods don't have the modded column yet
+        let has_modded = conn
+            .prepare("SELECT 1 FROM pragma_table_info('high_scores') WHERE name = 'modded'")?
+            .exists([])?;
+        if !has_modded {
+            conn.execute(
+                "ALTER TABLE high_scores ADD COLUMN modded INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+        }
+
+        // Nor from before deck templates the deck_template column
+        let has_deck_template = conn
+            .prepare("SELECT 1 FROM pragma_table_info('high_scores') WHERE name = 'deck_template'")?
+            .exists([])?;
+        if !has_deck_template {
+            conn.execute(
+                "ALTER TABLE high_scores ADD COLUMN deck_template TEXT NOT NULL DEFAULT 'Standard'",
+                [],
+            )?;
+        }
+
+        // Nor from before relax mode the relaxed column
+        let has_relaxed = conn
+            .prepare("SELECT 1 FROM pragma_table_info('high_scores') WHERE name = 'relaxed'")?
+            .exists([])?;
+        if !has_relaxed {
+            conn.execute(
+                "ALTER TABLE high_scores ADD COLUMN relaxed INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+        }
+
+        // Nor from before signing the signature and modified columns. Scores already saved are
+        // signed as they stand, since there's no telling whether they were edited before.
+        let has_signature = conn
+            .prepare("SELECT 1 FROM pragma_table_info('high_scores') WHERE name = 'signature'")?
+            .exists([])?;
+        if !has_signature {
+            conn.execute("ALTER TABLE high_scores ADD COLUMN signature TEXT", [])?;
+            conn.execute(
+                "ALTER TABLE high_scores ADD COLUMN modified INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+        }
+
+        // Nor from before difficulties were stored as their level rather than their name.
+        // SQLite can't change a column's type, so the scores are copied into a new table.
+        let difficulty_is_text = conn
+            .prepare(
+                "SELECT 1 FROM pragma_table_info('high_scores') WHERE name = 'difficulty' AND type = 'TEXT'",
+            )?
+            .exists([])?;
+        if difficulty_is_text {
+            migrate_difficulty_to_level(&conn)?;
+        }
+
+        // Nor from before dates were stored as a timestamp rather than written out in local
+        // time. The scores are copied into a new table again, signed over the new date. Text
+        // dates can also turn up in an INTEGER column the difficulty migration just built.
+        let date_is_text = conn
+            .prepare(
+                "SELECT 1 FROM pragma_table_info('high_scores') WHERE name = 'date' AND type = 'TEXT'",
+            )?
+            .exists([])?
+            || conn
+                .prepare("SELECT 1 FROM high_scores WHERE typeof(date) = 'text'")?
+                .exists([])?;
+        if date_is_text {
+            migrate_date_to_timestamp(&conn, signer.as_ref())?;
+        }
+
+        // Lifetime counters, one row per statistic
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS stats (
+                name TEXT PRIMARY KEY,
+                value INTEGER NOT NULL
             )",
             [],
         )?;
 
-        Ok(Database { conn })
+        // Best star rating per puzzle level
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS puzzle_stars (
+                level_id TEXT PRIMARY KEY,
+                stars INTEGER NOT NULL
+            )",
+            [],
+        )?;
+
+        let database = Database { conn, signer };
+        if !has_signature {
+            database.sign_unsigned_scores()?;
+        }
+        Ok(database)
     }
 
     pub fn add_high_score(&self, high_score: &HighScore) -> Result<i64> {
+        let signature = self
+            .signer
+            .as_ref()
+            .map(|signer| signer.sign(&signed_fields(high_score)));
         self.conn.execute(
-            "INSERT INTO high_scores (player_initials, score, difficulty, date) VALUES (?1, ?2, ?3, ?4)",
+            "INSERT INTO high_scores (player_initials, score, difficulty, date, modded, deck_template, relaxed, modified, signature) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
             params![
                 high_score.player_initials,
                 high_score.score,
-                high_score.difficulty,
-                high_score.date
+                high_score.difficulty.level(),
+                high_score.date.timestamp(),
+                high_score.modded,
+                high_score.deck_template.name(),
+                high_score.relaxed,
+                high_score.modified,
+                signature
             ],
         )?;
 
         Ok(self.conn.last_insert_rowid())
     }
 
+    // Scores whose signature is missing or doesn't match their row come back marked modified
     pub fn get_high_scores(&self, limit: usize) -> Result<Vec<HighScore>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, player_initials, score, difficulty, date FROM high_scores ORDER BY score DESC LIMIT ?1"
-        )?;
+        self.query_high_scores(i64::MIN, limit)
+    }
+
+    // Best scores set at or after `since`, for the leaderboard's Today and This Week tabs
+    pub fn get_high_scores_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<HighScore>> {
+        self.query_high_scores(since.timestamp(), limit)
+    }
+
+    fn query_high_scores(&self, since: i64, limit: usize) -> Result<Vec<HighScore>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM high_scores WHERE date >= ?1 ORDER BY score DESC LIMIT ?2",
+            HIGH_SCORE_COLUMNS
+        ))?;
 
-        let high_scores = stmt.query_map(params![limit as i64], |row| {
-            Ok(HighScore {
-                id: Some(row.get(0)?),
-                player_initials: row.get(1)?,
-                score: row.get(2)?,
-                difficulty: row.get(3)?,
-                date: row.get(4)?,
-            })
+        let high_scores = stmt.query_map(params![since, limit as i64], |row| {
+            let (mut high_score, signature) = read_high_score(row)?;
+            if let Some(signer) = &self.signer {
+                let fields = signed_fields(&high_score);
+                high_score.modified |=
+                    !signature.is_some_and(|signature| signer.verify(&fields, &signature));
+            }
+            Ok(high_score)
         })?;
 
         high_scores.collect()
     }
+
+    fn sign_unsigned_scores(&self) -> Result<()> {
+        let Some(signer) = &self.signer else {
+            return Ok(());
+        };
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM high_scores WHERE signature IS NULL",
+            HIGH_SCORE_COLUMNS
+        ))?;
+        let unsigned = stmt
+            .query_map([], read_high_score)?
+            .collect::<Result<Vec<_>>>()?;
+        for (high_score, _) in unsigned {
+            self.conn.execute(
+                "UPDATE high_scores SET signature = ?1 WHERE id = ?2",
+                params![signer.sign(&signed_fields(&high_score)), high_score.id],
+            )?;
+        }
+        Ok(())
+    }
+
+    // Rank a score would take among saved scores for the same difficulty and mode (1 = best)
+    pub fn get_score_rank(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<usize> {
+        let better_scores: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM high_scores WHERE difficulty = ?1 AND relaxed = ?2 AND score > ?3",
+            params![difficulty.level(), relaxed, score],
+            |row| row.get(0),
+        )?;
+
+        Ok(better_scores as usize + 1)
+    }
+
+    // Share of the saved scores for the difficulty and mode that `score` beats, as a
+    // percentage; None before the first one
+    pub fn get_score_percentile(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<Option<u8>> {
+        let (below, total): (i64, i64) = self.conn.query_row(
+            "SELECT COALESCE(SUM(score < ?3), 0), COUNT(*) FROM high_scores
+             WHERE difficulty = ?1 AND relaxed = ?2",
+            params![difficulty.level(), relaxed, score],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+
+        Ok(score_percentile(below as usize, total as usize))
+    }
+
+    // Best saved score for the difficulty and mode, or None before the first one
+    pub fn get_personal_best(&self, difficulty: Difficulty, relaxed: bool) -> Result<Option<i32>> {
+        self.conn.query_row(
+            "SELECT MAX(score) FROM high_scores WHERE difficulty = ?1 AND relaxed = ?2",
+            params![difficulty.level(), relaxed],
+            |row| row.get(0),
+        )
+    }
+
+    pub fn record_run(&self, outcome: RunOutcome) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO stats (name, value) VALUES (?1, 1)
+             ON CONFLICT(name) DO UPDATE SET value = value + 1",
+            params![outcome.stat_name()],
+        )?;
+        Ok(())
+    }
+
+    // Each column's drops are their own counter row, named by column_drops_stat_name
+    pub fn record_column_drops(&self, drops: &[u32]) -> Result<()> {
+        for (column, &count) in drops.iter().enumerate().filter(|(_, count)| **count > 0) {
+            self.conn.execute(
+                "INSERT INTO stats (name, value) VALUES (?1, ?2)
+                 ON CONFLICT(name) DO UPDATE SET value = value + ?2",
+                params![column_drops_stat_name(column), count],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn get_statistics(&self) -> Result<Statistics> {
+        let count = |outcome: RunOutcome| -> Result<u32> {
+            self.conn.query_row(
+                "SELECT COALESCE(MAX(value), 0) FROM stats WHERE name = ?1",
+                params![outcome.stat_name()],
+                |row| row.get(0),
+            )
+        };
+
+        let mut column_drops = Vec::new();
+        let mut stmt = self
+            .conn
+            .prepare("SELECT name, value FROM stats WHERE name LIKE ?1")?;
+        let rows = stmt.query_map(params![format!("{}%", COLUMN_DROPS_PREFIX)], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, u32>(1)?))
+        })?;
+        for row in rows {
+            let (name, value) = row?;
+            let Some(column) = name
+                .strip_prefix(COLUMN_DROPS_PREFIX)
+                .and_then(|column| column.parse::<usize>().ok())
+            else {
+                continue;
+            };
+            if column_drops.len() <= column {
+                column_drops.resize(column + 1, 0);
+            }
+            column_drops[column] = value;
+        }
+
+        Ok(Statistics {
+            runs_finished: count(RunOutcome::Finished)?,
+            runs_abandoned: count(RunOutcome::Abandoned)?,
+            runs_saved: count(RunOutcome::Saved)?,
+            column_drops,
+        })
+    }
+
+    // A lower rating than the level's best leaves the best in place
+    pub fn record_puzzle_stars(&self, level_id: &str, stars: u8) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO puzzle_stars (level_id, stars) VALUES (?1, ?2)
+             ON CONFLICT(level_id) DO UPDATE SET stars = MAX(stars, ?2)",
+            params![level_id, stars],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_puzzle_stars(&self) -> Result<HashMap<String, u8>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT level_id, stars FROM puzzle_stars")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect()
+    }
+}
+
+const HIGH_SCORE_COLUMNS: &str = "id, player_initials, score, difficulty, date, modded, deck_template, relaxed, modified, signature";
+
+// A row selected with HIGH_SCORE_COLUMNS, and the signature saved with it
+fn read_high_score(row: &rusqlite::Row) -> Result<(HighScore, Option<String>)> {
+    let level: i64 = row.get(3)?;
+    let high_score = HighScore {
+        id: Some(row.get(0)?),
+        player_initials: row.get(1)?,
+        score: row.get(2)?,
+        difficulty: Difficulty::from_level(level)
+            .ok_or(rusqlite::Error::IntegralValueOutOfRange(3, level))?,
+        date: read_date(row, 4)?,
+        modded: row.get(5)?,
+        deck_template: DeckTemplate::from_name(&row.get::<_, String>(6)?).unwrap_or_default(),
+        relaxed: row.get(7)?,
+        modified: row.get(8)?,
+    };
+    Ok((high_score, row.get(9)?))
+}
+
+// Rebuild high_scores with an integer difficulty column. Names other than the ones the game
+// writes become Easy, and no longer match their signature.
+fn migrate_difficulty_to_level(conn: &Connection) -> Result<()> {
+    let transaction = conn.unchecked_transaction()?;
+    transaction.execute(
+        "CREATE TABLE high_scores_migrated (
+            id INTEGER PRIMARY KEY,
+            player_initials TEXT NOT NULL,
+            score INTEGER NOT NULL,
+            difficulty INTEGER NOT NULL,
+            date INTEGER NOT NULL,
+            modded INTEGER NOT NULL DEFAULT 0,
+            deck_template TEXT NOT NULL DEFAULT 'Standard',
+            relaxed INTEGER NOT NULL DEFAULT 0,
+            signature TEXT,
+            modified INTEGER NOT NULL DEFAULT 0
+        )",
+        [],
+    )?;
+    transaction.execute(
+        "INSERT INTO high_scores_migrated
+             (id, player_initials, score, difficulty, date, modded, deck_template, relaxed, signature, modified)
+         SELECT id, player_initials, score, CASE difficulty WHEN ?1 THEN ?2 ELSE ?3 END,
+             date, modded, deck_template, relaxed, signature, modified
+         FROM high_scores",
+        params![
+            Difficulty::Hard.to_string(),
+            Difficulty::Hard.level(),
+            Difficulty::Easy.level()
+        ],
+    )?;
+    transaction.execute("DROP TABLE high_scores", [])?;
+    transaction.execute("ALTER TABLE high_scores_migrated RENAME TO high_scores", [])?;
+    transaction.commit()
+}
+
+// The date column holds a timestamp, or text in LEGACY_DATE_FORMAT until it has been
+// migrated. A date that can't be read comes back as the epoch.
+fn read_date(row: &rusqlite::Row, index: usize) -> Result<DateTime<Utc>> {
+    let date = match row.get_ref(index)? {
+        ValueRef::Integer(seconds) => DateTime::from_timestamp(seconds, 0),
+        ValueRef::Text(text) => std::str::from_utf8(text).ok().and_then(parse_legacy_date),
+        _ => None,
+    };
+    Ok(date.unwrap_or_default())
+}
+
+// Rebuild high_scores with the date as seconds since the epoch. Scores whose signature held
+// over their text date are signed again over the new one; the rest lose their signature and
+// stay flagged as modified. Dates that were already timestamps keep theirs.
+fn migrate_date_to_timestamp(conn: &Connection, signer: Option<&Signer>) -> Result<()> {
+    let transaction = conn.unchecked_transaction()?;
+    let scores = transaction
+        .prepare(&format!("SELECT {} FROM high_scores", HIGH_SCORE_COLUMNS))?
+        .query_map([], |row| {
+            let (high_score, signature) = read_high_score(row)?;
+            let date_text = match row.get_ref(4)? {
+                ValueRef::Text(text) => Some(String::from_utf8_lossy(text).into_owned()),
+                _ => None,
+            };
+            Ok((high_score, signature, date_text))
+        })?
+        .collect::<Result<Vec<_>>>()?;
+
+    transaction.execute(
+        "CREATE TABLE high_scores_migrated (
+            id INTEGER PRIMARY KEY,
+            player_initials TEXT NOT NULL,
+            score INTEGER NOT NULL,
+            difficulty INTEGER NOT NULL,
+            date INTEGER NOT NULL,
+            modded INTEGER NOT NULL DEFAULT 0,
+            deck_template TEXT NOT NULL DEFAULT 'Standard',
+            relaxed INTEGER NOT NULL DEFAULT 0,
+            signature TEXT,
+            modified INTEGER NOT NULL DEFAULT 0
+        )",
+        [],
+    )?;
+    for (high_score, signature, date_text) in scores {
+        let signature = match (signer, date_text) {
+            (Some(signer), Some(date_text)) => signature
+                .filter(|signature| {
+                    signer.verify(&legacy_signed_fields(&high_score, &date_text), signature)
+                })
+                .map(|_| signer.sign(&signed_fields(&high_score))),
+            _ => signature,
+        };
+        transaction.execute(
+            "INSERT INTO high_scores_migrated
+                 (id, player_initials, score, difficulty, date, modded, deck_template, relaxed, signature, modified)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![
+                high_score.id,
+                high_score.player_initials,
+                high_score.score,
+                high_score.difficulty.level(),
+                high_score.date.timestamp(),
+                high_score.modded,
+                high_score.deck_template.name(),
+                high_score.relaxed,
+                signature,
+                high_score.modified
+            ],
+        )?;
+    }
+    transaction.execute("DROP TABLE high_scores", [])?;
+    transaction.execute("ALTER TABLE high_scores_migrated RENAME TO high_scores", [])?;
+    transaction.commit()
+}
+
+// Everything about a score that a hand edit could change, in a fixed order for signing
+fn signed_fields(high_score: &HighScore) -> String {
+    format!(
+        "v2|{}|{}|{}|{}|{}|{}|{}|{}",
+        high_score.player_initials,
+        high_score.score,
+        high_score.difficulty,
+        high_score.date.timestamp(),
+        high_score.modded,
+        high_score.deck_template.name(),
+        high_score.relaxed,
+        high_score.modified
+    )
+}
+
+// What scores were signed over while the date was kept as text
+fn legacy_signed_fields(high_score: &HighScore, date_text: &str) -> String {
+    format!(
+        "v1|{}|{}|{}|{}|{}|{}|{}|{}",
+        high_score.player_initials,
+        high_score.score,
+        high_score.difficulty,
+        date_text,
+        high_score.modded,
+        high_score.deck_template.name(),
+        high_score.relaxed,
+        high_score.modified
+    )
+}
+
+// SQLite backend for the game's score store
+impl ScoreStore for Database {
+    fn add_high_score(
+        &mut self,
+        high_score: &HighScore,
+    ) -> Result<i64, Box<dyn std::error::Error>> {
+        Ok(Database::add_high_score(self, high_score)?)
+    }
+
+    fn get_high_scores(&self, limit: usize) -> Result<Vec<HighScore>, Box<dyn std::error::Error>> {
+        Ok(Database::get_high_scores(self, limit)?)
+    }
+
+    fn get_high_scores_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<HighScore>, Box<dyn std::error::Error>> {
+        Ok(Database::get_high_scores_since(self, since, limit)?)
+    }
+
+    fn get_score_rank(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<usize, Box<dyn std::error::Error>> {
+        Ok(Database::get_score_rank(self, score, difficulty, relaxed)?)
+    }
+
+    fn get_score_percentile(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<Option<u8>, Box<dyn std::error::Error>> {
+        Ok(Database::get_score_percentile(
+            self, score, difficulty, relaxed,
+        )?)
+    }
+
+    fn get_personal_best(
+        &self,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error>> {
+        Ok(Database::get_personal_best(self, difficulty, relaxed)?)
+    }
+
+    fn record_run(
+        &mut self,
+        outcome: RunOutcome,
+        column_drops: &[u32],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Database::record_run(self, outcome)?;
+        Ok(Database::record_column_drops(self, column_drops)?)
+    }
+
+    fn get_statistics(&self) -> Result<Statistics, Box<dyn std::error::Error>> {
+        Ok(Database::get_statistics(self)?)
+    }
+
+    fn get_puzzle_stars(&self) -> Result<HashMap<String, u8>, Box<dyn std::error::Error>> {
+        Ok(Database::get_puzzle_stars(self)?)
+    }
+
+    fn record_puzzle_stars(
+        &mut self,
+        level_id: &str,
+        stars: u8,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(Database::record_puzzle_stars(self, level_id, stars)?)
+    }
 }
 
 #[cfg(test)]
@@ -67,7 +585,7 @@ mod tests {
     // Test fixtures for creating temporary databases and test data
     mod test_fixtures {
         use super::*;
-        use chrono::Utc;
+        use chrono::SubsecRound;
 
         pub fn create_temp_database() -> (Database, TempDir) {
             let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
@@ -76,23 +594,31 @@ mod tests {
             (db, temp_dir)
         }
 
-        pub fn create_sample_high_score(initials: &str, score: i32, difficulty: &str) -> HighScore {
+        pub fn create_sample_high_score(
+            initials: &str,
+            score: i32,
+            difficulty: Difficulty,
+        ) -> HighScore {
             HighScore {
                 id: None,
                 player_initials: initials.to_string(),
                 score,
-                difficulty: difficulty.to_string(),
-                date: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                difficulty,
+                date: Utc::now().trunc_subsecs(0),
+                modded: false,
+                deck_template: DeckTemplate::Standard,
+                relaxed: false,
+                modified: false,
             }
         }
 
         pub fn create_multiple_high_scores() -> Vec<HighScore> {
             vec![
-                create_sample_high_score("AAA", 1000, "Easy"),
-                create_sample_high_score("BBB", 1500, "Medium"),
-                create_sample_high_score("CCC", 2000, "Hard"),
-                create_sample_high_score("DDD", 500, "Easy"),
-                create_sample_high_score("EEE", 1200, "Medium"),
+                create_sample_high_score("AAA", 1000, Difficulty::Easy),
+                create_sample_high_score("BBB", 1500, Difficulty::Hard),
+                create_sample_high_score("CCC", 2000, Difficulty::Hard),
+                create_sample_high_score("DDD", 500, Difficulty::Easy),
+                create_sample_high_score("EEE", 1200, Difficulty::Hard),
             ]
         }
     }
@@ -120,7 +646,7 @@ mod tests {
     #[test]
     fn test_add_high_score() {
         let (db, _temp_dir) = test_fixtures::create_temp_database();
-        let high_score = test_fixtures::create_sample_high_score("TST", 1000, "Easy");
+        let high_score = test_fixtures::create_sample_high_score("TST", 1000, Difficulty::Easy);
 
         let result = db.add_high_score(&high_score);
         assert!(result.is_ok());
@@ -158,9 +684,9 @@ mod tests {
 
         // Add scores in random order
         let high_scores = vec![
-            test_fixtures::create_sample_high_score("LOW", 100, "Easy"),
-            test_fixtures::create_sample_high_score("HIGH", 2000, "Hard"),
-            test_fixtures::create_sample_high_score("MID", 1000, "Medium"),
+            test_fixtures::create_sample_high_score("LOW", 100, Difficulty::Easy),
+            test_fixtures::create_sample_high_score("HIGH", 2000, Difficulty::Hard),
+            test_fixtures::create_sample_high_score("MID", 1000, Difficulty::Hard),
         ];
 
         for high_score in &high_scores {
@@ -176,6 +702,89 @@ mod tests {
         assert_eq!(retrieved_scores[2].score, 100); // Lowest last
     }
 
+    #[test]
+    fn test_get_score_rank() {
+        let (db, _temp_dir) = test_fixtures::create_temp_database();
+        let high_scores = test_fixtures::create_multiple_high_scores();
+
+        for high_score in &high_scores {
+            db.add_high_score(high_score)
+                .expect("Failed to add high score");
+        }
+
+        // Easy has 1000 and 500 on the board
+        assert_eq!(db.get_score_rank(1200, Difficulty::Easy, false).unwrap(), 1);
+        assert_eq!(db.get_score_rank(700, Difficulty::Easy, false).unwrap(), 2);
+        assert_eq!(db.get_score_rank(100, Difficulty::Easy, false).unwrap(), 3);
+
+        // Other difficulties don't affect the rank
+        assert_eq!(db.get_score_rank(1900, Difficulty::Hard, false).unwrap(), 2);
+        assert_eq!(db.get_score_rank(1300, Difficulty::Hard, false).unwrap(), 3);
+
+        // Relax mode runs are ranked on their own
+        assert_eq!(db.get_score_rank(10, Difficulty::Easy, true).unwrap(), 1);
+        let mut relaxed = test_fixtures::create_sample_high_score("REL", 5000, Difficulty::Easy);
+        relaxed.relaxed = true;
+        db.add_high_score(&relaxed).unwrap();
+        assert_eq!(db.get_score_rank(1200, Difficulty::Easy, false).unwrap(), 1);
+        assert_eq!(db.get_score_rank(1200, Difficulty::Easy, true).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_get_score_percentile() {
+        let (db, _temp_dir) = test_fixtures::create_temp_database();
+        assert_eq!(
+            db.get_score_percentile(700, Difficulty::Easy, false)
+                .unwrap(),
+            None
+        );
+
+        for high_score in &test_fixtures::create_multiple_high_scores() {
+            db.add_high_score(high_score)
+                .expect("Failed to add high score");
+        }
+
+        // Easy has 1000 and 500; ties don't count as beaten
+        assert_eq!(
+            db.get_score_percentile(700, Difficulty::Easy, false)
+                .unwrap(),
+            Some(50)
+        );
+        assert_eq!(
+            db.get_score_percentile(500, Difficulty::Easy, false)
+                .unwrap(),
+            Some(0)
+        );
+        assert_eq!(
+            db.get_score_percentile(1800, Difficulty::Hard, false)
+                .unwrap(),
+            Some(66)
+        );
+        assert_eq!(
+            db.get_score_percentile(1800, Difficulty::Hard, true)
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_personal_best() {
+        let (db, _temp_dir) = test_fixtures::create_temp_database();
+        assert_eq!(db.get_personal_best(Difficulty::Easy, false).unwrap(), None);
+
+        for high_score in &test_fixtures::create_multiple_high_scores() {
+            db.add_high_score(high_score)
+                .expect("Failed to add high score");
+        }
+        assert_eq!(
+            db.get_personal_best(Difficulty::Easy, false).unwrap(),
+            Some(1000)
+        );
+
+        // Relax mode keeps a best of its own
+        assert_eq!(db.get_personal_best(Difficulty::Easy, true).unwrap(), None);
+    }
+
     #[test]
     fn test_get_high_scores_limit() {
         let (db, _temp_dir) = test_fixtures::create_temp_database();
@@ -204,7 +813,7 @@ mod tests {
     #[test]
     fn test_high_score_data_integrity() {
         let (db, _temp_dir) = test_fixtures::create_temp_database();
-        let original_score = test_fixtures::create_sample_high_score("XYZ", 1500, "Medium");
+        let original_score = test_fixtures::create_sample_high_score("XYZ", 1500, Difficulty::Hard);
 
         let row_id = db
             .add_high_score(&original_score)
@@ -234,7 +843,7 @@ mod tests {
         // Create database and add a score
         {
             let db = Database::new(&db_path).expect("Failed to create database");
-            let high_score = test_fixtures::create_sample_high_score("PER", 999, "Test");
+            let high_score = test_fixtures::create_sample_high_score("PER", 999, Difficulty::Easy);
             db.add_high_score(&high_score)
                 .expect("Failed to add high score");
         }
@@ -249,11 +858,300 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_record_run_statistics() {
+        let (db, _temp_dir) = test_fixtures::create_temp_database();
+        assert_eq!(db.get_statistics().unwrap(), Statistics::default());
+
+        db.record_run(RunOutcome::Abandoned).unwrap();
+        db.record_run(RunOutcome::Abandoned).unwrap();
+        db.record_run(RunOutcome::Saved).unwrap();
+
+        let statistics = db.get_statistics().unwrap();
+        assert_eq!(statistics.runs_abandoned, 2);
+        assert_eq!(statistics.runs_saved, 1);
+        assert_eq!(statistics.runs_finished, 0);
+    }
+
+    #[test]
+    fn test_new_columns_added_to_old_database() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().join("old.db");
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            conn.execute(
+                "CREATE TABLE high_scores (
+                    id INTEGER PRIMARY KEY,
+                    player_initials TEXT NOT NULL,
+                    score INTEGER NOT NULL,
+                    difficulty TEXT NOT NULL,
+                    date TEXT NOT NULL
+                )",
+                [],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO high_scores (player_initials, score, difficulty, date)
+                 VALUES ('OLD', 100, 'Easy', '2024-01-01 10:00:00')",
+                [],
+            )
+            .unwrap();
+        }
+
+        let db = Database::new(&db_path).unwrap();
+        let mut modded = test_fixtures::create_sample_high_score("MOD", 200, Difficulty::Easy);
+        modded.modded = true;
+        db.add_high_score(&modded).unwrap();
+
+        let mut frenzy = test_fixtures::create_sample_high_score("FCF", 150, Difficulty::Easy);
+        frenzy.deck_template = DeckTemplate::FaceCardFrenzy;
+        db.add_high_score(&frenzy).unwrap();
+
+        let scores = db.get_high_scores(10).unwrap();
+        assert!(scores[0].modded);
+        assert_eq!(scores[1].deck_template, DeckTemplate::FaceCardFrenzy);
+        assert!(!scores[2].modded);
+        assert_eq!(scores[2].deck_template, DeckTemplate::Standard);
+        assert_eq!(scores[2].difficulty, Difficulty::Easy);
+        assert_eq!(
+            scores[2].date,
+            parse_legacy_date("2024-01-01 10:00:00").unwrap()
+        );
+
+        // Opening it again leaves the migrated table alone
+        assert!(Database::new(&db_path).is_ok());
+    }
+
+    #[test]
+    fn test_edited_scores_are_flagged_modified() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().join("signed.db");
+        let signer = || Some(Signer::new(b"test-machine"));
+
+        let db = Database::with_signer(&db_path, signer()).unwrap();
+        db.add_high_score(&test_fixtures::create_sample_high_score(
+            "AAA",
+            100,
+            Difficulty::Easy,
+        ))
+        .unwrap();
+        db.add_high_score(&test_fixtures::create_sample_high_score(
+            "BBB",
+            50,
+            Difficulty::Easy,
+        ))
+        .unwrap();
+        assert!(db.get_high_scores(10).unwrap().iter().all(|s| !s.modified));
+
+        db.conn
+            .execute(
+                "UPDATE high_scores SET score = 9000 WHERE player_initials = 'BBB'",
+                [],
+            )
+            .unwrap();
+        drop(db);
+
+        let db = Database::with_signer(&db_path, signer()).unwrap();
+        let scores = db.get_high_scores(10).unwrap();
+        assert_eq!(scores[0].player_initials, "BBB");
+        assert!(scores[0].modified);
+        assert!(!scores[1].modified);
+
+        // Clearing the signature doesn't get an edit past either
+        db.conn
+            .execute("UPDATE high_scores SET signature = NULL", [])
+            .unwrap();
+        assert!(db.get_high_scores(10).unwrap()[1].modified);
+
+        // Without a key nothing can be checked, so nothing is flagged
+        let unsigned = Database::with_signer(&db_path, None).unwrap();
+        assert!(
+            unsigned
+                .get_high_scores(10)
+                .unwrap()
+                .iter()
+                .all(|s| !s.modified)
+        );
+    }
+
+    #[test]
+    fn test_scores_from_before_signing_are_trusted() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().join("unsigned.db");
+        {
+            let db = Database::with_signer(&db_path, None).unwrap();
+            db.add_high_score(&test_fixtures::create_sample_high_score(
+                "OLD",
+                100,
+                Difficulty::Easy,
+            ))
+            .unwrap();
+            db.conn
+                .execute_batch(
+                    "ALTER TABLE high_scores DROP COLUMN signature;
+                     ALTER TABLE high_scores DROP COLUMN modified;",
+                )
+                .unwrap();
+        }
+
+        let db = Database::with_signer(&db_path, Some(Signer::new(b"test-machine"))).unwrap();
+        let scores = db.get_high_scores(10).unwrap();
+        assert_eq!(scores.len(), 1);
+        assert!(!scores[0].modified);
+    }
+
+    #[test]
+    fn test_difficulty_names_become_levels() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().join("named.db");
+        let signer = || Some(Signer::new(b"test-machine"));
+        {
+            let db = Database::with_signer(&db_path, signer()).unwrap();
+            db.add_high_score(&test_fixtures::create_sample_high_score(
+                "HRD",
+                300,
+                Difficulty::Hard,
+            ))
+            .unwrap();
+            db.add_high_score(&test_fixtures::create_sample_high_score(
+                "EZY",
+                200,
+                Difficulty::Easy,
+            ))
+            .unwrap();
+            // Put the names back the way older versions saved them
+            db.conn
+                .execute_batch(
+                    "ALTER TABLE high_scores RENAME COLUMN difficulty TO level;
+                     ALTER TABLE high_scores ADD COLUMN difficulty TEXT NOT NULL DEFAULT '';
+                     UPDATE high_scores SET difficulty = CASE level WHEN 1 THEN 'Hard' ELSE 'Easy' END;
+                     ALTER TABLE high_scores DROP COLUMN level;",
+                )
+                .unwrap();
+        }
+
+        let db = Database::with_signer(&db_path, signer()).unwrap();
+        let scores = db.get_high_scores(10).unwrap();
+        assert_eq!(scores[0].difficulty, Difficulty::Hard);
+        assert_eq!(scores[1].difficulty, Difficulty::Easy);
+        assert!(scores.iter().all(|score| !score.modified));
+        assert_eq!(db.get_score_rank(250, Difficulty::Hard, false).unwrap(), 2);
+        assert_eq!(db.get_score_rank(250, Difficulty::Easy, false).unwrap(), 1);
+
+        let level_type: String = db
+            .conn
+            .query_row(
+                "SELECT type FROM pragma_table_info('high_scores') WHERE name = 'difficulty'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(level_type, "INTEGER");
+    }
+
+    #[test]
+    fn test_text_dates_become_timestamps() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().join("dated.db");
+        let signer = Signer::new(b"test-machine");
+        {
+            let db = Database::with_signer(&db_path, None).unwrap();
+            db.conn
+                .execute_batch(
+                    "ALTER TABLE high_scores DROP COLUMN date;
+                     ALTER TABLE high_scores ADD COLUMN date TEXT NOT NULL DEFAULT '';",
+                )
+                .unwrap();
+            // Signed the way older versions signed, with the date as text
+            for (initials, score, signed_score) in [("OLD", 300, 300), ("EDT", 200, 100)] {
+                let high_score = test_fixtures::create_sample_high_score(
+                    initials,
+                    signed_score,
+                    Difficulty::Easy,
+                );
+                let signature =
+                    signer.sign(&legacy_signed_fields(&high_score, "2024-01-15 14:30:00"));
+                db.conn
+                    .execute(
+                        "INSERT INTO high_scores (player_initials, score, difficulty, date, signature)
+                         VALUES (?1, ?2, 0, '2024-01-15 14:30:00', ?3)",
+                        params![initials, score, signature],
+                    )
+                    .unwrap();
+            }
+        }
+
+        let db = Database::with_signer(&db_path, Some(signer)).unwrap();
+        let scores = db.get_high_scores(10).unwrap();
+        let date = parse_legacy_date("2024-01-15 14:30:00").unwrap();
+        assert!(scores.iter().all(|score| score.date == date));
+        assert!(!scores[0].modified);
+        assert!(scores[1].modified);
+
+        let date_type: String = db
+            .conn
+            .query_row(
+                "SELECT type FROM pragma_table_info('high_scores') WHERE name = 'date'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(date_type, "INTEGER");
+    }
+
+    #[test]
+    fn test_high_scores_since() {
+        let (db, _temp_dir) = test_fixtures::create_temp_database();
+        let now = Utc::now();
+        for (initials, score, days_ago) in [("OLD", 900, 30), ("WEK", 500, 3), ("NOW", 100, 0)] {
+            let mut high_score =
+                test_fixtures::create_sample_high_score(initials, score, Difficulty::Easy);
+            high_score.date = now - chrono::Days::new(days_ago);
+            db.add_high_score(&high_score).unwrap();
+        }
+
+        let initials = |since| -> Vec<String> {
+            db.get_high_scores_since(since, 10)
+                .unwrap()
+                .into_iter()
+                .map(|score| score.player_initials)
+                .collect()
+        };
+        assert_eq!(initials(now - chrono::Days::new(1)), ["NOW"]);
+        assert_eq!(initials(now - chrono::Days::new(7)), ["WEK", "NOW"]);
+        assert_eq!(db.get_high_scores(10).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_record_column_drops() {
+        let (db, _temp_dir) = test_fixtures::create_temp_database();
+        db.record_column_drops(&[2, 0, 1]).unwrap();
+        db.record_column_drops(&[1, 0, 0, 5]).unwrap();
+
+        let statistics = db.get_statistics().unwrap();
+        assert_eq!(statistics.column_drops, vec![3, 0, 1, 5]);
+    }
+
+    #[test]
+    fn test_puzzle_stars_keep_the_best() {
+        let (db, _temp_dir) = test_fixtures::create_temp_database();
+        db.record_puzzle_stars("tower", 2).unwrap();
+        db.record_puzzle_stars("tower", 1).unwrap();
+        db.record_puzzle_stars("first_steps", 3).unwrap();
+
+        let stars = db.get_puzzle_stars().unwrap();
+        assert_eq!(stars.get("tower"), Some(&2));
+        assert_eq!(stars.get("first_steps"), Some(&3));
+
+        db.record_puzzle_stars("tower", 3).unwrap();
+        assert_eq!(db.get_puzzle_stars().unwrap().get("tower"), Some(&3));
+    }
+
     #[test]
     fn test_database_error_handling() {
         // Test with invalid path (should fail gracefully)
         let invalid_path = Path::new("/invalid/path/that/does/not/exist/test.db");
         let result = Database::new(invalid_path);
-        assert!(result.is_err());
+        assert!(matches!(result, Err(DropJackError::DatabaseOpen { .. })));
     }
 }
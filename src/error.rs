@@ -0,0 +1,60 @@
+// Errors from setting a game up: building it, opening the score database, finding the assets
+// the window needs and reading saved settings back. Everything past setup reports through the
+// game's own events and toasts instead.
+
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum DropJackError {
+    InvalidBoardSize(String), // What was asked for and what the limits are
+    DatabaseOpen {
+        path: PathBuf,
+        source: rusqlite::Error,
+    },
+    NoScoreStore,            // Neither a score store nor a database path was given
+    Storage(String),         // The settings, balance or saved-run storage couldn't be opened
+    AssetMissing(PathBuf),   // A file the front end can't run without
+    SettingsCorrupt(String), // The saved settings are there but don't parse
+}
+
+impl fmt::Display for DropJackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DropJackError::InvalidBoardSize(message) => {
+                write!(f, "Invalid board size: {}", message)
+            }
+            DropJackError::DatabaseOpen { path, source } => {
+                write!(
+                    f,
+                    "Could not open the score database {}: {}",
+                    path.display(),
+                    source
+                )
+            }
+            DropJackError::NoScoreStore => {
+                write!(f, "A database path or a score store must be provided")
+            }
+            DropJackError::Storage(message) => write!(f, "Could not open storage: {}", message),
+            DropJackError::AssetMissing(path) => {
+                write!(
+                    f,
+                    "Missing asset {} (run from the game's folder)",
+                    path.display()
+                )
+            }
+            DropJackError::SettingsCorrupt(message) => {
+                write!(f, "The saved settings are corrupt: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DropJackError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DropJackError::DatabaseOpen { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,192 @@
+// Gameplay numbers that designers may want to tune without recompiling. They are read
+// from balance.toml in the app data directory at startup; anything left out of the file
+// keeps its default, and a file that fails validation is ignored as a whole.
+
+use crate::storage::Storage;
+use serde::Deserialize;
+use std::error::Error;
+use std::time::Duration;
+
+const BALANCE_KEY: &str = "balance.toml";
+const MAX_COMBINATION_DELAY_MS: u64 = 5000; // Longer than this and a cascade looks frozen
+
+// Which 21s to clear when the cards on the board could make more than one set of them.
+// Combinations never share a card; the strategy decides which set is best.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CombinationStrategy {
+    // One long combination over several short ones
+    #[default]
+    Longest,
+    // The most points, natural bonuses included
+    HighestScore,
+    // The most cards off the board
+    MostCards,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Balance {
+    pub combination_delay_ms: u64, // Between one combination exploding and the next
+    pub length_bonus: f32,         // Each card past two multiplies a combination's points by this
+    pub cascade_bonus: i32,        // Extra score for each clear that chains from another
+    pub natural_bonus: i32,        // Extra score for a two-card ace and ten-value 21
+    pub combination_strategy: CombinationStrategy, // Which 21s clear when they compete for cards
+    pub move_speed: f32,           // Pixels per frame the falling card slides sideways
+    pub fall_speed: f32,           // Pixels per frame the falling card drops between rows
+    pub hard_drop_speed: f32,      // Pixels per frame while hard dropping
+}
+
+impl Default for Balance {
+    fn default() -> Self {
+        Balance {
+            combination_delay_ms: 300,
+            length_bonus: 2.0,
+            cascade_bonus: 50,
+            natural_bonus: 100,
+            combination_strategy: CombinationStrategy::default(),
+            move_speed: 12.0,
+            fall_speed: 8.0,
+            hard_drop_speed: 20.0,
+        }
+    }
+}
+
+impl Balance {
+    /// Parse and validate a balance file
+    pub fn from_toml(contents: &str) -> Result<Self, Box<dyn Error>> {
+        let balance: Balance = toml::from_str(contents)?;
+        balance.validate()?;
+        Ok(balance)
+    }
+
+    /// Balance from storage, or the defaults when there is no file or it can't be used
+    pub fn load_from(storage: &dyn Storage) -> Self {
+        let contents = match storage.read(BALANCE_KEY) {
+            Ok(Some(contents)) => contents,
+            Ok(None) => return Self::default(),
+            Err(e) => {
+                eprintln!("Failed to read {}, using defaults: {}", BALANCE_KEY, e);
+                return Self::default();
+            }
+        };
+
+        match Self::from_toml(&contents) {
+            Ok(balance) => {
+                println!("Loaded game balance from {}", BALANCE_KEY);
+                balance
+            }
+            Err(e) => {
+                eprintln!("Invalid {}, using defaults: {}", BALANCE_KEY, e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.combination_delay_ms > MAX_COMBINATION_DELAY_MS {
+            return Err(format!(
+                "combination_delay_ms must be at most {}",
+                MAX_COMBINATION_DELAY_MS
+            ));
+        }
+        if !self.length_bonus.is_finite() || self.length_bonus < 1.0 {
+            return Err("length_bonus must be at least 1.0".to_string());
+        }
+        if self.cascade_bonus < 0 {
+            return Err("cascade_bonus can't be negative".to_string());
+        }
+        if self.natural_bonus < 0 {
+            return Err("natural_bonus can't be negative".to_string());
+        }
+        for (name, speed) in [
+            ("move_speed", self.move_speed),
+            ("fall_speed", self.fall_speed),
+            ("hard_drop_speed", self.hard_drop_speed),
+        ] {
+            if !speed.is_finite() || speed <= 0.0 {
+                return Err(format!("{} must be greater than zero", name));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn combination_delay(&self) -> Duration {
+        Duration::from_millis(self.combination_delay_ms)
+    }
+
+    /// What a combination of `length` cards multiplies its card values by: 1 for two
+    /// cards, then `length_bonus` more for each card after that
+    pub fn length_multiplier(&self, length: usize) -> i32 {
+        let extra_cards = length.saturating_sub(2).min(i32::MAX as usize) as i32;
+        self.length_bonus
+            .powi(extra_cards)
+            .round()
+            .min(i32::MAX as f32) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::FileStorage;
+
+    #[test]
+    fn test_partial_file_keeps_defaults() {
+        let balance = Balance::from_toml("cascade_bonus = 80\nmove_speed = 16.0\n").unwrap();
+        assert_eq!(balance.cascade_bonus, 80);
+        assert_eq!(balance.move_speed, 16.0);
+        assert_eq!(balance.length_bonus, 2.0);
+        assert_eq!(balance.combination_delay(), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_combination_strategy_names() {
+        assert_eq!(
+            Balance::default().combination_strategy,
+            CombinationStrategy::Longest
+        );
+        let balance = Balance::from_toml("combination_strategy = \"most_cards\"").unwrap();
+        assert_eq!(balance.combination_strategy, CombinationStrategy::MostCards);
+        assert!(Balance::from_toml("combination_strategy = \"fewest\"").is_err());
+    }
+
+    #[test]
+    fn test_invalid_files_are_rejected() {
+        assert!(Balance::from_toml("fall_speed = 0.0").is_err());
+        assert!(Balance::from_toml("length_bonus = 0.5").is_err());
+        assert!(Balance::from_toml("card_clear_points = 21").is_err());
+        assert!(Balance::from_toml("combination_delay_ms = 60000").is_err());
+        assert!(Balance::from_toml("cascade_bonsu = 10").is_err());
+        assert!(Balance::from_toml("natural_bonus = -5").is_err());
+        assert!(Balance::from_toml("cascade_bonus = \"lots\"").is_err());
+    }
+
+    #[test]
+    fn test_length_multiplier() {
+        let balance = Balance::default();
+        assert_eq!(balance.length_multiplier(2), 1);
+        assert_eq!(balance.length_multiplier(3), 2);
+        assert_eq!(balance.length_multiplier(5), 8);
+
+        let flat = Balance::from_toml("length_bonus = 1.0").unwrap();
+        assert_eq!(flat.length_multiplier(6), 1);
+    }
+
+    #[test]
+    fn test_load_from_falls_back_to_defaults() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let storage = FileStorage::new(temp_dir.path()).unwrap();
+        assert_eq!(Balance::load_from(&storage), Balance::default());
+
+        storage
+            .write(BALANCE_KEY, "hard_drop_speed = 30.0")
+            .unwrap();
+        assert_eq!(Balance::load_from(&storage).hard_drop_speed, 30.0);
+
+        storage
+            .write(BALANCE_KEY, "hard_drop_speed = -1.0")
+            .unwrap();
+        assert_eq!(Balance::load_from(&storage), Balance::default());
+    }
+}
@@ -0,0 +1,129 @@
+// The last stretch of a run's boards, so the game over screen can step back through
+// what led to the top-out. A frame is recorded whenever the board or the falling card moves.
+
+use crate::models::{Card, Position};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardFrame {
+    pub at: Instant,
+    pub grid: Vec<Vec<Option<Card>>>,
+    pub current_card: Option<(Card, Position)>, // The falling card, at its grid position
+}
+
+pub struct BoardHistory {
+    frames: VecDeque<BoardFrame>, // Oldest first
+    window: Duration,             // Frames older than this behind the newest are dropped
+}
+
+impl BoardHistory {
+    pub fn new(window: Duration) -> Self {
+        BoardHistory {
+            frames: VecDeque::new(),
+            window,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Add a frame if anything changed since the last one, and drop frames past the window
+    pub fn record(
+        &mut self,
+        grid: &[Vec<Option<Card>>],
+        current_card: Option<(Card, Position)>,
+        now: Instant,
+    ) {
+        let unchanged = self
+            .frames
+            .back()
+            .is_some_and(|last| last.grid == grid && last.current_card == current_card);
+        if unchanged {
+            return;
+        }
+
+        self.frames.push_back(BoardFrame {
+            at: now,
+            grid: grid.to_vec(),
+            current_card,
+        });
+        while self
+            .frames
+            .front()
+            .is_some_and(|first| now.duration_since(first.at) > self.window)
+        {
+            self.frames.pop_front();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The frame `steps_back` before the newest (0 is the newest)
+    pub fn frame(&self, steps_back: usize) -> Option<&BoardFrame> {
+        let index = self.frames.len().checked_sub(steps_back + 1)?;
+        self.frames.get(index)
+    }
+
+    /// How long before the newest frame the frame `steps_back` was recorded
+    pub fn age(&self, steps_back: usize) -> Duration {
+        match (self.frame(steps_back), self.frames.back()) {
+            (Some(frame), Some(newest)) => newest.at.duration_since(frame.at),
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Suit, Value};
+
+    fn grid_with(card: Option<Card>) -> Vec<Vec<Option<Card>>> {
+        vec![vec![card, None], vec![None, None]]
+    }
+
+    #[test]
+    fn test_records_only_changes() {
+        let mut history = BoardHistory::new(Duration::from_secs(30));
+        let now = Instant::now();
+        let king = Card::new(Suit::Spades, Value::King);
+
+        history.record(&grid_with(None), None, now);
+        history.record(&grid_with(None), None, now + Duration::from_millis(16));
+        assert_eq!(history.len(), 1);
+
+        history.record(&grid_with(Some(king)), None, now + Duration::from_secs(1));
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.frame(0).unwrap().grid[0][0], Some(king));
+        assert_eq!(history.frame(1).unwrap().grid[0][0], None);
+        assert_eq!(history.age(1), Duration::from_secs(1));
+        assert!(history.frame(2).is_none());
+    }
+
+    #[test]
+    fn test_drops_frames_past_the_window() {
+        let mut history = BoardHistory::new(Duration::from_secs(30));
+        let now = Instant::now();
+        let card = Card::new(Suit::Hearts, Value::Five);
+
+        history.record(&grid_with(None), None, now);
+        history.record(
+            &grid_with(None),
+            Some((card, Position { x: 1, y: 0 })),
+            now + Duration::from_secs(10),
+        );
+        history.record(&grid_with(Some(card)), None, now + Duration::from_secs(35));
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.age(1), Duration::from_secs(25));
+    }
+}
@@ -0,0 +1,224 @@
+// All card motion on the board goes through here: the player's card sliding between
+// columns and dropping, hard drops that keep falling after the next card is in play, and
+// cards settling under gravity. Each card's visual position (in pixels from the top left
+// of the board) moves towards its target cell at a fixed speed per frame and snaps onto
+// it once it's close enough. Game logic decides where cards go; this only moves them there
+// and reports which ones arrived.
+
+use super::balance::Balance;
+use crate::models::{Card, FallingCard, PlayingCard};
+
+const GRAVITY_SPEED: f32 = 6.0; // Pixels per frame at full speed
+const SNAP_DISTANCE: f32 = 0.1; // Close enough to count as there
+
+// What finished moving this frame
+#[derive(Debug, Default)]
+pub struct Arrivals {
+    pub placed: Vec<PlayingCard>, // Hard drops that reached their cell, oldest first
+    pub landed: Vec<(i32, i32, Card)>, // Cards moved by gravity, with the cell they landed in
+}
+
+pub struct CardAnimator {
+    cell_size: f32,
+    move_speed: f32,
+    fall_speed: f32,
+    hard_drop_speed: f32,
+    gravity_speed: f32,
+}
+
+impl CardAnimator {
+    /// Speeds for one frame, scaled by the game clock
+    pub fn new(cell_size: i32, balance: &Balance, time_scale: f32) -> Self {
+        CardAnimator {
+            cell_size: cell_size as f32,
+            move_speed: balance.move_speed * time_scale,
+            fall_speed: balance.fall_speed * time_scale,
+            hard_drop_speed: balance.hard_drop_speed * time_scale,
+            gravity_speed: GRAVITY_SPEED * time_scale,
+        }
+    }
+
+    /// Move every card one frame. Hard drops that land are taken out of `hard_drops` and
+    /// handed back for the game to place.
+    pub fn update(
+        &self,
+        current_card: Option<&mut PlayingCard>,
+        hard_drops: &mut Vec<PlayingCard>,
+        falling_cards: &mut [FallingCard],
+    ) -> Arrivals {
+        let landed = self.update_gravity(falling_cards);
+
+        if let Some(card) = current_card {
+            self.move_playing_card(card);
+        }
+
+        let mut placed = Vec::new();
+        let mut index = 0;
+        while index < hard_drops.len() {
+            if self.move_playing_card(&mut hard_drops[index]) {
+                placed.push(hard_drops.remove(index));
+            } else {
+                index += 1;
+            }
+        }
+
+        Arrivals { placed, landed }
+    }
+
+    /// Move the cards gravity is settling, returning the ones that landed this frame
+    pub fn update_gravity(&self, falling_cards: &mut [FallingCard]) -> Vec<(i32, i32, Card)> {
+        let mut landed = Vec::new();
+        for falling_card in falling_cards.iter_mut().filter(|fc| fc.is_animating) {
+            let target_y = falling_card.to_y as f32 * self.cell_size;
+            if approach(&mut falling_card.visual_y, target_y, self.gravity_speed) {
+                falling_card.is_animating = false;
+                landed.push((falling_card.x, falling_card.to_y, falling_card.card));
+            }
+        }
+        landed
+    }
+
+    // Slide towards the target column, and drop towards the target row while falling.
+    // Returns true when the card finished falling this frame.
+    fn move_playing_card(&self, card: &mut PlayingCard) -> bool {
+        let target_x = card.target.x as f32 * self.cell_size;
+        if approach(&mut card.visual_position.x, target_x, self.move_speed) {
+            card.position.x = card.target.x;
+        }
+
+        if !card.is_falling {
+            return false;
+        }
+        let fall_speed = if card.is_hard_dropping {
+            self.hard_drop_speed
+        } else {
+            self.fall_speed
+        };
+        let target_y = card.target.y as f32 * self.cell_size;
+        if !approach(&mut card.visual_position.y, target_y, fall_speed) {
+            return false;
+        }
+        card.position.y = card.target.y;
+        card.is_falling = false;
+        card.is_hard_dropping = false;
+        true
+    }
+}
+
+// Step `value` towards `target` by at most `speed`, snapping onto it when close. Returns
+// whether it's there.
+fn approach(value: &mut f32, target: f32, speed: f32) -> bool {
+    let diff = target - *value;
+    if diff.abs() <= speed.max(SNAP_DISTANCE) {
+        *value = target;
+        true
+    } else {
+        *value += speed * diff.signum();
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Position, Suit, Value};
+
+    mod test_fixtures {
+        use super::*;
+
+        pub fn create_animator() -> CardAnimator {
+            CardAnimator::new(48, &Balance::default(), 1.0)
+        }
+
+        pub fn create_playing_card(x: i32, y: i32) -> PlayingCard {
+            PlayingCard::builder(Card::new(Suit::Hearts, Value::Ace), Position { x, y })
+                .cell_size(48)
+                .build()
+        }
+    }
+
+    #[test]
+    fn test_gravity_cards_land_once() {
+        let animator = test_fixtures::create_animator();
+        let card = Card::new(Suit::Hearts, Value::Ace);
+        let mut falling_cards = vec![FallingCard {
+            card,
+            to_y: 7,
+            x: 2,
+            visual_y: 100.0,
+            is_animating: true,
+        }];
+
+        assert!(animator.update_gravity(&mut falling_cards).is_empty());
+        assert!(falling_cards[0].visual_y > 100.0);
+
+        let mut landed = Vec::new();
+        for _ in 0..100 {
+            landed = animator.update_gravity(&mut falling_cards);
+            if !landed.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(landed, vec![(2, 7, card)]);
+        assert_eq!(falling_cards[0].visual_y, 7.0 * 48.0);
+        assert!(!falling_cards[0].is_animating);
+        assert!(animator.update_gravity(&mut falling_cards).is_empty());
+    }
+
+    #[test]
+    fn test_player_card_slides_and_drops() {
+        let animator = test_fixtures::create_animator();
+        let mut card = test_fixtures::create_playing_card(2, 0);
+        card.target = Position { x: 3, y: 1 };
+        card.is_falling = true;
+
+        for _ in 0..100 {
+            animator.update(Some(&mut card), &mut Vec::new(), &mut []);
+            if !card.is_falling && card.position.x == 3 {
+                break;
+            }
+        }
+        assert_eq!(card.position, Position { x: 3, y: 1 });
+        assert_eq!(card.visual_position.x, 3.0 * 48.0);
+        assert_eq!(card.visual_position.y, 48.0);
+    }
+
+    #[test]
+    fn test_hard_drops_are_handed_back_when_they_land() {
+        let animator = test_fixtures::create_animator();
+        let mut near = test_fixtures::create_playing_card(0, 0);
+        near.target = Position { x: 0, y: 1 };
+        near.is_falling = true;
+        near.is_hard_dropping = true;
+        let mut far = test_fixtures::create_playing_card(1, 0);
+        far.target = Position { x: 1, y: 10 };
+        far.is_falling = true;
+        far.is_hard_dropping = true;
+        let mut hard_drops = vec![far, near];
+
+        // The hard drop speed covers a short drop in a few frames
+        let mut placed = Vec::new();
+        for _ in 0..3 {
+            placed.extend(animator.update(None, &mut hard_drops, &mut []).placed);
+        }
+        assert_eq!(placed.len(), 1);
+        assert_eq!(placed[0].position, Position { x: 0, y: 1 });
+        assert!(!placed[0].is_hard_dropping);
+        assert_eq!(hard_drops.len(), 1);
+        assert_eq!(hard_drops[0].target.y, 10);
+    }
+
+    #[test]
+    fn test_approach_snaps_when_close() {
+        let mut value = 10.0;
+        assert!(!approach(&mut value, 20.0, 4.0));
+        assert_eq!(value, 14.0);
+        assert!(!approach(&mut value, 20.0, 4.0));
+        assert!(approach(&mut value, 20.0, 4.0));
+        assert_eq!(value, 20.0);
+
+        let mut value = 20.05;
+        assert!(approach(&mut value, 20.0, 0.0));
+        assert_eq!(value, 20.0);
+    }
+}
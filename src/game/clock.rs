@@ -0,0 +1,77 @@
+// How fast play runs relative to real time. The fall timer and every animation update
+// scale by time_scale, which is 1.0 except for a moment of slow motion during long cascades.
+
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+pub struct GameClock {
+    pub time_scale: f32,
+    slow_until: Option<Instant>, // When the current slow motion ends
+}
+
+impl GameClock {
+    pub fn new() -> Self {
+        GameClock {
+            time_scale: 1.0,
+            slow_until: None,
+        }
+    }
+
+    /// Run at `scale` speed for `duration` from `now`
+    pub fn slow_down(&mut self, scale: f32, duration: Duration, now: Instant) {
+        self.time_scale = scale;
+        self.slow_until = Some(now + duration);
+    }
+
+    /// Called once per update; returns to full speed once the slow motion runs out
+    pub fn tick(&mut self, now: Instant) {
+        if self.slow_until.is_some_and(|until| now >= until) {
+            self.time_scale = 1.0;
+            self.slow_until = None;
+        }
+    }
+
+    /// Push the end of any slow motion back by time spent paused
+    pub fn delay(&mut self, away: Duration) {
+        if let Some(until) = self.slow_until.as_mut() {
+            *until += away;
+        }
+    }
+
+    /// A real-time interval stretched to match the time scale
+    pub fn stretch(&self, interval: Duration) -> Duration {
+        interval.div_f32(self.time_scale)
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slow_motion_runs_out() {
+        let mut clock = GameClock::new();
+        let start = Instant::now();
+        clock.slow_down(0.5, Duration::from_millis(400), start);
+        assert_eq!(clock.time_scale, 0.5);
+        assert_eq!(clock.stretch(Duration::from_millis(100)).as_millis(), 200);
+
+        clock.tick(start + Duration::from_millis(300));
+        assert_eq!(clock.time_scale, 0.5);
+
+        // A pause pushes the end back
+        clock.delay(Duration::from_millis(200));
+        clock.tick(start + Duration::from_millis(500));
+        assert_eq!(clock.time_scale, 0.5);
+
+        clock.tick(start + Duration::from_millis(600));
+        assert_eq!(clock.time_scale, 1.0);
+        assert_eq!(clock.stretch(Duration::from_millis(100)).as_millis(), 100);
+    }
+}
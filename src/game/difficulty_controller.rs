@@ -0,0 +1,158 @@
+// Adaptive speed: watches the run's events and nudges the fall speed a few levels either
+// way of the difficulty's curve. Cards that keep settling near the top ease it off; clears
+// that keep coming within a few drops of each other push it on.
+
+use super::events::GameEvent;
+
+const DANGER_ROWS: i32 = 3; // A card settling in the top rows is a close call
+const DANGER_STREAK: u32 = 3; // Close calls in a row before easing off
+const FAST_CLEAR_DROPS: u32 = 3; // A clear within this many drops of the last one is fast
+const FAST_CLEAR_STREAK: u32 = 3; // Fast clears in a row before speeding up
+pub const MAX_ADJUSTMENT: i32 = 3; // Most speed levels either way of the curve
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DifficultyController {
+    enabled: bool,
+    adjustment: i32,    // Speed levels added to the curve's, negative when eased off
+    danger_streak: u32, // Close calls since the last card that settled lower down
+    fast_clears: u32,   // Fast clears since the last slow one
+    drops_since_clear: u32, // Cards placed since the last clear
+}
+
+impl DifficultyController {
+    /// A fresh controller for a new run; one that isn't enabled never adjusts
+    pub fn new(enabled: bool) -> Self {
+        DifficultyController {
+            enabled,
+            ..Default::default()
+        }
+    }
+
+    /// Speed levels to add to the curve's level, between -MAX_ADJUSTMENT and MAX_ADJUSTMENT
+    pub fn adjustment(&self) -> i32 {
+        self.adjustment
+    }
+
+    /// Take in an event from the run. True when the adjustment changed, so the fall speed
+    /// needs working out again.
+    pub fn observe(&mut self, event: &GameEvent) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        match event {
+            GameEvent::CardPlaced { y, .. } => {
+                self.drops_since_clear = self.drops_since_clear.saturating_add(1);
+                if *y >= DANGER_ROWS {
+                    self.danger_streak = 0;
+                    return false;
+                }
+                self.danger_streak += 1;
+                if self.danger_streak < DANGER_STREAK {
+                    return false;
+                }
+                self.danger_streak = 0;
+                self.fast_clears = 0;
+                self.shift(-1)
+            }
+            // Cascades off the same drop come with no drops in between and don't count again
+            GameEvent::CombinationCleared { .. } if self.drops_since_clear > 0 => {
+                let fast = self.drops_since_clear <= FAST_CLEAR_DROPS;
+                self.drops_since_clear = 0;
+                self.danger_streak = 0;
+                if !fast {
+                    self.fast_clears = 0;
+                    return false;
+                }
+                self.fast_clears += 1;
+                if self.fast_clears < FAST_CLEAR_STREAK {
+                    return false;
+                }
+                self.fast_clears = 0;
+                self.shift(1)
+            }
+            _ => false,
+        }
+    }
+
+    fn shift(&mut self, step: i32) -> bool {
+        let adjusted = (self.adjustment + step).clamp(-MAX_ADJUSTMENT, MAX_ADJUSTMENT);
+        let changed = adjusted != self.adjustment;
+        self.adjustment = adjusted;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Card, Suit, Value};
+
+    fn placed(y: i32) -> GameEvent {
+        GameEvent::CardPlaced {
+            x: 0,
+            y,
+            card: Card::new(Suit::Spades, Value::Two),
+        }
+    }
+
+    fn cleared() -> GameEvent {
+        GameEvent::CombinationCleared {
+            positions: Vec::new(),
+            points: 21,
+            combinations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_close_calls_ease_off() {
+        let mut controller = DifficultyController::new(true);
+        assert!(!controller.observe(&placed(1)));
+        assert!(
+            !controller.observe(&placed(10)),
+            "A low card breaks the streak"
+        );
+        assert!(!controller.observe(&placed(0)));
+        assert!(!controller.observe(&placed(2)));
+        assert!(controller.observe(&placed(1)));
+        assert_eq!(controller.adjustment(), -1);
+
+        for _ in 0..20 {
+            controller.observe(&placed(0));
+        }
+        assert_eq!(controller.adjustment(), -MAX_ADJUSTMENT);
+    }
+
+    #[test]
+    fn test_fast_clears_speed_up() {
+        let mut controller = DifficultyController::new(true);
+        for _ in 0..FAST_CLEAR_STREAK - 1 {
+            controller.observe(&placed(12));
+            assert!(!controller.observe(&cleared()));
+            assert!(
+                !controller.observe(&cleared()),
+                "A cascade isn't another clear"
+            );
+        }
+        controller.observe(&placed(12));
+        assert!(controller.observe(&cleared()));
+        assert_eq!(controller.adjustment(), 1);
+
+        // A slow clear starts the count again
+        for _ in 0..=FAST_CLEAR_DROPS {
+            controller.observe(&placed(12));
+        }
+        controller.observe(&cleared());
+        controller.observe(&placed(12));
+        controller.observe(&cleared());
+        assert_eq!(controller.adjustment(), 1);
+    }
+
+    #[test]
+    fn test_disabled_controller_never_adjusts() {
+        let mut controller = DifficultyController::new(false);
+        for _ in 0..10 {
+            assert!(!controller.observe(&placed(0)));
+        }
+        assert_eq!(controller.adjustment(), 0);
+    }
+}
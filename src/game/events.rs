@@ -0,0 +1,373 @@
+use crate::models::{Card, Value};
+use std::time::Duration;
+
+const BIG_CASCADE_CHAIN: i32 = 3; // Cascades from this step on duck the music
+const CASCADE_DUCK_MS: u64 = 800;
+const GAME_OVER_DUCK_MS: u64 = 2000;
+const NEW_RECORD_DUCK_MS: u64 = 2500; // Matches the new record celebration
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum AudioEvent {
+    DifficultyChange,
+    StartGame,
+    DropCard,
+    MakeMatch,
+    ExplodeCard,
+    PauseGame,
+    ResumeGame,
+    ForfeitGame,
+    GameOver,
+    OpenQuitConfirmation,
+    ReturnToGame,
+    QuitGame,
+    // Card movement events
+    MoveLeft,
+    MoveRight,
+    SoftDrop,
+    HardDrop,
+    // High score events
+    NewRecord,
+    InitialsRejected,
+    NewPersonalBest,
+    // An ace and a ten-value card cleared on their own
+    NaturalBlackjack,
+}
+
+/// The cards of one combination that made 21, in the order the path ran through them
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClearedCombination {
+    pub cards: Vec<Card>,
+    pub multiplier: i32, // Length bonus the card values were multiplied by
+    pub points: i32,     // Clear points for these cards (cascade bonuses are counted separately)
+    pub chain: i32,      // 1 for the drop itself, 2+ for each cascade that followed
+    pub natural: bool,   // Tagged by the board when the path is an ace and a ten-value card
+}
+
+impl ClearedCombination {
+    pub fn new(cards: Vec<Card>, multiplier: i32, chain: i32) -> Self {
+        let mut combination = ClearedCombination {
+            cards,
+            multiplier,
+            points: 0,
+            chain,
+            natural: false,
+        };
+        combination.points = combination.card_points().iter().sum();
+        combination
+    }
+
+    /// What each card scores: its blackjack value as counted towards the total, times the
+    /// length multiplier
+    pub fn card_points(&self) -> Vec<i32> {
+        let mut values: Vec<i32> = self
+            .cards
+            .iter()
+            .map(|card| card.value.value() as i32)
+            .collect();
+        let total: i32 = values.iter().sum();
+        if total + 10 <= 21
+            && let Some(ace) = self.cards.iter().position(|card| card.value == Value::Ace)
+        {
+            values[ace] += 10;
+        }
+        values
+            .into_iter()
+            .map(|value| value.saturating_mul(self.multiplier))
+            .collect()
+    }
+
+    /// How the points were reached, e.g. "21 x4"
+    pub fn breakdown(&self) -> String {
+        format!("{} x{}", self.total(), self.multiplier)
+    }
+
+    /// Sum of the cards, counting one ace as 11 when that doesn't go over 21
+    pub fn total(&self) -> i32 {
+        let total: i32 = self
+            .cards
+            .iter()
+            .map(|card| card.value.value() as i32)
+            .sum();
+        let has_ace = self.cards.iter().any(|card| card.value == Value::Ace);
+        if has_ace && total + 10 <= 21 {
+            total + 10
+        } else {
+            total
+        }
+    }
+
+    /// The sum written out, e.g. "K + 6 + 5 = 21"
+    pub fn equation(&self) -> String {
+        let symbols: Vec<&str> = self.cards.iter().map(|card| card.value.symbol()).collect();
+        format!("{} = {}", symbols.join(" + "), self.total())
+    }
+}
+
+// Everything the game wants the outside world (audio, particles, UI) to know about.
+// The game only pushes these; each consumer picks out the events it cares about.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    // Feedback cue with no gameplay meaning of its own (menu navigation, pausing, moves)
+    Sound(AudioEvent),
+    CardPlaced {
+        x: i32,
+        y: i32,
+        card: Card,
+    },
+    // A card moved by gravity reached the cell it was falling to
+    CardLanded {
+        x: i32,
+        y: i32,
+        card: Card,
+    },
+    CombinationCleared {
+        positions: Vec<(i32, i32)>,
+        points: i32,
+        combinations: Vec<ClearedCombination>,
+    },
+    CardExploded {
+        x: i32,
+        y: i32,
+        card: Card,
+    },
+    ChainAdvanced {
+        chain: i32,
+        bonus: i32,
+    },
+    NaturalBlackjack {
+        positions: Vec<(i32, i32)>,
+        bonus: i32,
+    },
+    LevelUp {
+        fall_speed: Duration,
+    },
+    GameOver {
+        score: i32,
+    },
+    NewRecord {
+        rank: usize,
+    },
+    // The live score just passed the best saved score for the run's difficulty
+    NewPersonalBest {
+        score: i32,
+    },
+    ScoreSaveFailed {
+        message: String,
+    },
+    RunSaveFailed {
+        message: String,
+    },
+    StatisticsExported {
+        key: String, // Where the export was written in the app data directory
+    },
+    StatisticsExportFailed {
+        message: String,
+    },
+    SnapshotSaved {
+        key: String, // Where the debug snapshot went in the app data directory
+    },
+    SnapshotRestored {
+        key: String,
+    },
+    SnapshotFailed {
+        message: String,
+    },
+    PuzzleSolved {
+        name: String,
+        stars: u8,
+    },
+    PuzzleFailed {
+        name: String,
+    },
+    PuzzleUnavailable {
+        message: String, // Why the level couldn't be set up
+    },
+    ColumnCleared {
+        column: i32,  // Cleared in relax mode because the board filled up
+        penalty: i32, // Points taken off the score for it
+    },
+    LevelSaved {
+        path: String, // Where the level editor wrote the level
+    },
+    LevelSaveFailed {
+        message: String,
+    },
+    SeedCopied {
+        code: String, // The run's seed, for the front end to put on the clipboard
+    },
+}
+
+impl GameEvent {
+    // Sound to play for this event, if any
+    pub fn audio_event(&self) -> Option<AudioEvent> {
+        match self {
+            GameEvent::Sound(event) => Some(*event),
+            GameEvent::CardPlaced { .. } | GameEvent::CardLanded { .. } => {
+                Some(AudioEvent::DropCard)
+            }
+            GameEvent::CombinationCleared { .. } => Some(AudioEvent::MakeMatch),
+            GameEvent::CardExploded { .. } => Some(AudioEvent::ExplodeCard),
+            GameEvent::GameOver { .. } => Some(AudioEvent::GameOver),
+            GameEvent::NewRecord { .. } => Some(AudioEvent::NewRecord),
+            GameEvent::NewPersonalBest { .. } => Some(AudioEvent::NewPersonalBest),
+            GameEvent::NaturalBlackjack { .. } => Some(AudioEvent::NaturalBlackjack),
+            GameEvent::PuzzleSolved { .. } => Some(AudioEvent::NewRecord),
+            GameEvent::PuzzleFailed { .. } => Some(AudioEvent::GameOver),
+            GameEvent::ChainAdvanced { .. }
+            | GameEvent::LevelUp { .. }
+            | GameEvent::ScoreSaveFailed { .. }
+            | GameEvent::RunSaveFailed { .. }
+            | GameEvent::StatisticsExported { .. }
+            | GameEvent::StatisticsExportFailed { .. }
+            | GameEvent::SnapshotSaved { .. }
+            | GameEvent::SnapshotRestored { .. }
+            | GameEvent::SnapshotFailed { .. }
+            | GameEvent::PuzzleUnavailable { .. }
+            | GameEvent::ColumnCleared { .. } // Each card's explosion is heard already
+            | GameEvent::LevelSaved { .. }
+            | GameEvent::LevelSaveFailed { .. }
+            | GameEvent::SeedCopied { .. } => None,
+        }
+    }
+
+    // How long the music should stay ducked under this event's sound, if at all
+    pub fn music_duck(&self) -> Option<Duration> {
+        match self {
+            GameEvent::GameOver { .. } => Some(Duration::from_millis(GAME_OVER_DUCK_MS)),
+            GameEvent::NewRecord { .. } => Some(Duration::from_millis(NEW_RECORD_DUCK_MS)),
+            GameEvent::ChainAdvanced { chain, .. } if *chain >= BIG_CASCADE_CHAIN => {
+                Some(Duration::from_millis(CASCADE_DUCK_MS))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Suit;
+
+    #[test]
+    fn test_sound_event_passes_through() {
+        let event = GameEvent::Sound(AudioEvent::MoveLeft);
+        assert_eq!(event.audio_event(), Some(AudioEvent::MoveLeft));
+    }
+
+    #[test]
+    fn test_gameplay_events_map_to_sounds() {
+        let card = Card::new(Suit::Hearts, Value::Ace);
+
+        assert_eq!(
+            GameEvent::CardPlaced { x: 0, y: 0, card }.audio_event(),
+            Some(AudioEvent::DropCard)
+        );
+        assert_eq!(
+            GameEvent::CardLanded { x: 0, y: 1, card }.audio_event(),
+            Some(AudioEvent::DropCard)
+        );
+        assert_eq!(
+            GameEvent::CombinationCleared {
+                positions: vec![(0, 0), (0, 1)],
+                points: 42,
+                combinations: Vec::new(),
+            }
+            .audio_event(),
+            Some(AudioEvent::MakeMatch)
+        );
+        assert_eq!(
+            GameEvent::CardExploded { x: 0, y: 0, card }.audio_event(),
+            Some(AudioEvent::ExplodeCard)
+        );
+        assert_eq!(
+            GameEvent::GameOver { score: 10 }.audio_event(),
+            Some(AudioEvent::GameOver)
+        );
+        assert_eq!(
+            GameEvent::NewRecord { rank: 1 }.audio_event(),
+            Some(AudioEvent::NewRecord)
+        );
+        assert_eq!(
+            GameEvent::NaturalBlackjack {
+                positions: vec![(0, 0), (0, 1)],
+                bonus: 100,
+            }
+            .audio_event(),
+            Some(AudioEvent::NaturalBlackjack)
+        );
+    }
+
+    #[test]
+    fn test_silent_events() {
+        assert_eq!(
+            GameEvent::ChainAdvanced {
+                chain: 2,
+                bonus: 50
+            }
+            .audio_event(),
+            None
+        );
+        assert_eq!(
+            GameEvent::LevelUp {
+                fall_speed: Duration::from_millis(900)
+            }
+            .audio_event(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cleared_combination_equation() {
+        let combination = ClearedCombination::new(
+            vec![
+                Card::new(Suit::Clubs, Value::King),
+                Card::new(Suit::Clubs, Value::Six),
+                Card::new(Suit::Clubs, Value::Five),
+            ],
+            2,
+            1,
+        );
+        assert_eq!(combination.total(), 21);
+        assert_eq!(combination.equation(), "K + 6 + 5 = 21");
+        assert_eq!(combination.card_points(), vec![20, 12, 10]);
+        assert_eq!(combination.points, 42);
+        assert_eq!(combination.breakdown(), "21 x2");
+
+        // The ace only counts as 11 when it fits
+        let soft = ClearedCombination::new(
+            vec![
+                Card::new(Suit::Hearts, Value::Ace),
+                Card::new(Suit::Hearts, Value::King),
+            ],
+            1,
+            1,
+        );
+        assert_eq!(soft.equation(), "A + K = 21");
+        assert_eq!(soft.card_points(), vec![11, 10]);
+
+        let hard = ClearedCombination::new(
+            vec![
+                Card::new(Suit::Hearts, Value::Ace),
+                Card::new(Suit::Hearts, Value::King),
+                Card::new(Suit::Hearts, Value::Queen),
+            ],
+            2,
+            1,
+        );
+        assert_eq!(hard.total(), 21);
+        assert_eq!(hard.card_points(), vec![2, 20, 20]);
+    }
+
+    #[test]
+    fn test_big_events_duck_music() {
+        assert!(GameEvent::GameOver { score: 10 }.music_duck().is_some());
+        assert!(GameEvent::NewRecord { rank: 1 }.music_duck().is_some());
+
+        // Only the deeper steps of a cascade count as big
+        let chain = |chain| GameEvent::ChainAdvanced { chain, bonus: 50 };
+        assert_eq!(chain(2).music_duck(), None);
+        assert!(chain(BIG_CASCADE_CHAIN).music_duck().is_some());
+
+        assert_eq!(GameEvent::Sound(AudioEvent::MoveLeft).music_duck(), None);
+    }
+}
@@ -0,0 +1,145 @@
+// Plays thousands of random input sequences through a game with no window, checking after
+// every tick that the board stays consistent and the score never goes down within a run.
+// Slow next to the unit tests, so it only builds with `cargo test --features fuzz`.
+
+use super::Game;
+use crate::models::Difficulty;
+use crate::store::{MemorySavedRunStore, MemorySettingsStore};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+const SEQUENCES: u64 = 2000;
+const INPUTS_PER_SEQUENCE: usize = 150;
+const MAX_WAIT_TICKS: u32 = 20;
+
+#[derive(Debug, Clone, Copy)]
+enum Input {
+    Left,
+    Right,
+    Down,
+    HardDrop,
+    Pause,
+    Resume,
+    Wait(u32), // Ticks with no input, letting cards fall and animations finish
+}
+
+impl Input {
+    fn random(rng: &mut StdRng) -> Self {
+        match rng.random_range(0..10) {
+            0 | 1 => Input::Left,
+            2 | 3 => Input::Right,
+            4 => Input::Down,
+            5 | 6 => Input::HardDrop,
+            7 => Input::Pause,
+            8 => Input::Resume,
+            _ => Input::Wait(rng.random_range(1..=MAX_WAIT_TICKS)),
+        }
+    }
+
+    // Moves only reach the game during play, as they do from the input handler
+    fn apply(self, game: &mut Game) {
+        game.note_input();
+        if !game.is_playing() && !matches!(self, Input::Resume) {
+            return;
+        }
+        match self {
+            Input::Left => game.move_current_card_left(),
+            Input::Right => game.move_current_card_right(),
+            Input::Down => game.move_current_card_down(),
+            Input::HardDrop => game.hard_drop(),
+            Input::Pause => game.transition_to_paused(),
+            Input::Resume if game.is_paused() => game.transition_to_playing(),
+            Input::Resume | Input::Wait(_) => {}
+        }
+    }
+
+    fn ticks(self) -> u32 {
+        match self {
+            Input::Wait(ticks) => ticks,
+            _ => 1,
+        }
+    }
+}
+
+// No two cards claim the same cell: the card in play and the hard drops in flight are
+// headed for cells nothing else holds, and no two hard drops share a landing cell
+fn assert_no_overlaps(game: &Game, history: &[Input]) {
+    let board = &game.board;
+    let mut claimed = HashSet::new();
+
+    if let Some(card) = &game.current_card {
+        let position = card.position;
+        assert!(
+            board.is_cell_empty(position.x, position.y),
+            "Card in play overlaps a placed card at {:?} after {:?}",
+            position,
+            history
+        );
+        claimed.insert((position.x, position.y));
+    }
+
+    for card in &game.hard_dropping_cards {
+        let target = (card.target.x, card.target.y);
+        assert!(
+            board.is_cell_empty(target.0, target.1),
+            "Hard drop is landing on a placed card at {:?} after {:?}",
+            target,
+            history
+        );
+        assert!(
+            claimed.insert(target),
+            "Two cards are headed for {:?} after {:?}",
+            target,
+            history
+        );
+    }
+}
+
+#[test]
+fn test_random_inputs_keep_the_game_consistent() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let mut game = Game::builder()
+        .database_path(temp_dir.path().join("fuzz.db"))
+        .saved_run_store(Box::new(MemorySavedRunStore::new()))
+        .settings_store(Box::new(MemorySettingsStore::new())) // Not whatever other tests saved
+        .build()
+        .expect("Failed to create game");
+
+    for seed in 0..SEQUENCES {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let difficulty = if seed % 2 == 0 {
+            Difficulty::Easy
+        } else {
+            Difficulty::Hard
+        };
+        game.settings.handicap_rows = (seed % 4) as u32; // Some runs start on a handicap
+        game.start_game(difficulty);
+
+        let mut history = Vec::with_capacity(INPUTS_PER_SEQUENCE);
+        let mut last_score = game.score;
+        for _ in 0..INPUTS_PER_SEQUENCE {
+            let input = Input::random(&mut rng);
+            history.push(input);
+            input.apply(&mut game);
+
+            for _ in 0..input.ticks() {
+                game.update();
+                assert_no_overlaps(&game, &history);
+                assert!(
+                    game.score >= last_score,
+                    "Score fell from {} to {} after {:?} (seed {})",
+                    last_score,
+                    game.score,
+                    history,
+                    seed
+                );
+                last_score = game.score;
+            }
+
+            if game.is_game_over() {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+// What the player did in one frame, in the game's own terms rather than any window library's.
+// The front end reads its devices into an InputSnapshot once a frame and each state handles
+// it from there, so the same snapshots can come from a recording, a network peer, a bot or a
+// test instead of a keyboard.
+
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+// Keys any screen listens for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Key {
+    Letter(char), // 'A' to 'Z'
+    Up,
+    Down,
+    Left,
+    Right,
+    Space,
+    Enter,
+    Escape,
+    Backspace,
+    Tab,
+    Minus,
+    Equal,
+    F5,
+    F7,
+    F9,
+}
+
+// Controller buttons, named for where they sit on an Xbox-style pad
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PadButton {
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    A, // Bottom face button
+    B, // Right
+    X, // Left
+    Y, // Top
+    LeftShoulder,
+    RightShoulder,
+    Select,
+    Start,
+}
+
+// A finished touch, when touch controls are on. Positions are in screen pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TouchGesture {
+    Tap { x: f32, y: f32 },
+    SwipeLeft,
+    SwipeRight,
+    SwipeDown,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputSnapshot {
+    #[serde(skip, default = "Instant::now")]
+    pub now: Instant, // When the frame was read; replayed frames take the time they're played
+    pub keys: Vec<Key>,                // Pressed this frame
+    pub buttons: Vec<PadButton>,       // Pressed this frame; empty without a controller
+    pub modifier_held: bool,           // Ctrl or Cmd, for clipboard shortcuts
+    pub any_held: bool,                // Any key or button above is down, pressed this frame or not
+    pub up: bool,                      // Up pressed, or the stick pushed up
+    pub down: bool,                    // Down pressed, or the stick pushed down
+    pub left_held: bool,               // Key, D-Pad or stick
+    pub right_held: bool,              // Key, D-Pad or stick
+    pub soft_drop: f32,                // 0.0 (not at all) to 1.0 (full speed)
+    pub mouse: (f32, f32),             // Cursor position in screen pixels
+    pub mouse_moved: bool,             // The cursor moved since last frame
+    pub mouse_clicked: bool,           // Left button pressed this frame
+    pub gesture: Option<TouchGesture>, // Finished touch, when touch controls are on
+    pub pasted: Option<String>,        // Clipboard text, read when Ctrl+V is pressed
+}
+
+impl InputSnapshot {
+    /// A frame where nothing was pressed
+    pub fn new(now: Instant) -> Self {
+        InputSnapshot {
+            now,
+            keys: Vec::new(),
+            buttons: Vec::new(),
+            modifier_held: false,
+            any_held: false,
+            up: false,
+            down: false,
+            left_held: false,
+            right_held: false,
+            soft_drop: 0.0,
+            mouse: (0.0, 0.0),
+            mouse_moved: false,
+            mouse_clicked: false,
+            gesture: None,
+            pasted: None,
+        }
+    }
+
+    /// A frame where only `keys` were pressed
+    pub fn pressing(now: Instant, keys: &[Key]) -> Self {
+        InputSnapshot {
+            keys: keys.to_vec(),
+            any_held: !keys.is_empty(),
+            ..InputSnapshot::new(now)
+        }
+    }
+
+    /// Whether `key` was pressed this frame
+    pub fn key(&self, key: Key) -> bool {
+        self.keys.contains(&key)
+    }
+
+    /// Whether controller `button` was pressed this frame
+    pub fn button(&self, button: PadButton) -> bool {
+        self.buttons.contains(&button)
+    }
+
+    /// Ctrl (or Cmd) held with `key`
+    pub fn shortcut(&self, key: Key) -> bool {
+        self.modifier_held && self.key(key)
+    }
+
+    /// Left pressed on the keys or D-Pad; the stick only counts for held movement
+    pub fn left(&self) -> bool {
+        self.key(Key::Left) || self.button(PadButton::DPadLeft)
+    }
+
+    /// Right pressed on the keys or D-Pad
+    pub fn right(&self) -> bool {
+        self.key(Key::Right) || self.button(PadButton::DPadRight)
+    }
+
+    /// SPACE, ENTER or A
+    pub fn action(&self) -> bool {
+        self.key(Key::Space) || self.key(Key::Enter) || self.button(PadButton::A)
+    }
+
+    /// ESC or the controller's Select/Back button
+    pub fn escape(&self) -> bool {
+        self.key(Key::Escape) || self.button(PadButton::Select)
+    }
+
+    /// ESC or B, for screens that go back a step
+    pub fn back(&self) -> bool {
+        self.key(Key::Escape) || self.button(PadButton::B)
+    }
+
+    /// The first letter typed this frame
+    pub fn typed(&self) -> Option<char> {
+        self.keys.iter().find_map(|key| match key {
+            Key::Letter(c) => Some(*c),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_reads_keys_and_buttons() {
+        let input = InputSnapshot {
+            buttons: vec![PadButton::B],
+            ..InputSnapshot::pressing(Instant::now(), &[Key::Left, Key::Letter('V')])
+        };
+        assert!(input.left());
+        assert!(input.back());
+        assert!(!input.escape());
+        assert_eq!(input.typed(), Some('V'));
+
+        // Without Ctrl the V is typed, with it the V pastes
+        assert!(!input.shortcut(Key::Letter('V')));
+        let input = InputSnapshot {
+            modifier_held: true,
+            ..input
+        };
+        assert!(input.shortcut(Key::Letter('V')));
+    }
+
+    #[test]
+    fn test_snapshot_round_trips_through_json() {
+        let input = InputSnapshot {
+            buttons: vec![PadButton::Start],
+            soft_drop: 0.5,
+            gesture: Some(TouchGesture::Tap { x: 10.0, y: 20.0 }),
+            ..InputSnapshot::pressing(Instant::now(), &[Key::Letter('R'), Key::Escape])
+        };
+
+        let json = serde_json::to_string(&input).unwrap();
+        let replayed: InputSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(replayed.keys, input.keys);
+        assert_eq!(replayed.buttons, input.buttons);
+        assert_eq!(replayed.soft_drop, input.soft_drop);
+        assert_eq!(replayed.gesture, input.gesture);
+    }
+}
@@ -0,0 +1,190 @@
+// The puzzle level being built in the level editor. Cards are stacked onto columns and taken
+// off the top, so a draft board never has a card hanging in the air; the same column edits
+// suit any mode that lets the player lay out a board by hand.
+
+use super::board::Board;
+use crate::models::puzzle::card_code;
+use crate::models::{Card, Difficulty, PuzzleLevel};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelDraft {
+    pub id: String,
+    pub name: String,
+    pub difficulty: Difficulty,
+    pub par: usize,
+    pub grid: Vec<Vec<Option<Card>>>, // Rows top to bottom, like Board::grid
+    pub cards: Vec<Card>, // The queue, dealt first card first; its length is the move limit
+}
+
+impl LevelDraft {
+    /// An empty board of this size with nothing queued
+    pub fn new(id: String, name: String, width: i32, height: i32) -> Self {
+        LevelDraft {
+            id,
+            name,
+            difficulty: Difficulty::Easy,
+            par: 1,
+            grid: vec![vec![None; width.max(1) as usize]; height.max(1) as usize],
+            cards: Vec::new(),
+        }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.grid.first().map_or(0, |row| row.len() as i32)
+    }
+
+    pub fn height(&self) -> i32 {
+        self.grid.len() as i32
+    }
+
+    /// Put a card on top of column `x`'s stack. False when the column is full.
+    pub fn stack_card(&mut self, x: i32, card: Card) -> bool {
+        let Some(y) = (0..self.height())
+            .rev()
+            .find(|&y| self.cell(x, y).is_some_and(|cell| cell.is_none()))
+        else {
+            return false;
+        };
+        self.grid[y as usize][x as usize] = Some(card);
+        true
+    }
+
+    /// Take the top card off column `x`'s stack
+    pub fn unstack_card(&mut self, x: i32) -> Option<Card> {
+        let y = (0..self.height()).find(|&y| self.cell(x, y).is_some_and(|cell| cell.is_some()))?;
+        self.grid[y as usize][x as usize].take()
+    }
+
+    /// Add a card to the end of the queue
+    pub fn queue_card(&mut self, card: Card) {
+        self.cards.push(card);
+    }
+
+    /// Drop the last card of the queue, keeping par within what's left
+    pub fn unqueue_card(&mut self) -> Option<Card> {
+        let card = self.cards.pop();
+        self.par = self.par.min(self.cards.len()).max(1);
+        card
+    }
+
+    /// Move par by `steps`, between one card and the whole queue
+    pub fn adjust_par(&mut self, steps: i32) {
+        let most = self.cards.len().max(1);
+        self.par = self
+            .par
+            .saturating_add_signed(steps as isize)
+            .clamp(1, most);
+    }
+
+    /// Whether the draft makes a level that can be played: something on the board, a card
+    /// queued, and no 21 already waiting to clear before the first card drops
+    pub fn check(&self) -> Result<(), String> {
+        if self.grid.iter().flatten().all(Option::is_none) {
+            return Err("the board is empty".to_string());
+        }
+        if self.cards.is_empty() {
+            return Err("no cards are queued".to_string());
+        }
+
+        let mut board = Board::new(self.width(), self.height(), 1);
+        board.grid = self.grid.clone();
+        if !board.check_combinations(self.difficulty).is_empty() {
+            return Err("the board already has a 21 on it".to_string());
+        }
+        Ok(())
+    }
+
+    /// The draft as a level file would hold it. Empty rows above the highest card are left
+    /// out, since a level's board rests on the bottom row.
+    pub fn level(&self) -> PuzzleLevel {
+        let first_row = self
+            .grid
+            .iter()
+            .position(|row| row.iter().any(Option::is_some))
+            .unwrap_or(self.grid.len());
+        let board = self.grid[first_row..]
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.map_or(".".to_string(), card_code))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+
+        PuzzleLevel {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            difficulty: self.difficulty,
+            par: self.par,
+            board,
+            cards: self.cards.iter().map(|&card| card_code(card)).collect(),
+            solution: Vec::new(),
+        }
+    }
+
+    fn cell(&self, x: i32, y: i32) -> Option<Option<Card>> {
+        let row = self.grid.get(usize::try_from(y).ok()?)?;
+        row.get(usize::try_from(x).ok()?).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Suit, Value};
+
+    fn draft() -> LevelDraft {
+        LevelDraft::new("custom_1".to_string(), "Custom 1".to_string(), 3, 4)
+    }
+
+    #[test]
+    fn test_cards_stack_from_the_bottom() {
+        let mut draft = draft();
+        let king = Card::new(Suit::Spades, Value::King);
+        let two = Card::new(Suit::Hearts, Value::Two);
+
+        assert!(draft.stack_card(1, king));
+        assert!(draft.stack_card(1, two));
+        assert_eq!(draft.grid[3][1], Some(king));
+        assert_eq!(draft.grid[2][1], Some(two));
+
+        assert_eq!(draft.unstack_card(1), Some(two));
+        assert_eq!(draft.unstack_card(0), None);
+        assert!(!draft.stack_card(5, king));
+
+        for _ in 0..3 {
+            assert!(draft.stack_card(2, two));
+        }
+        assert!(draft.stack_card(2, two));
+        assert!(!draft.stack_card(2, two), "The column is full");
+    }
+
+    #[test]
+    fn test_check_and_level() {
+        let mut draft = draft();
+        assert!(draft.check().is_err());
+
+        draft.stack_card(0, Card::new(Suit::Spades, Value::King));
+        draft.stack_card(2, Card::new(Suit::Hearts, Value::Nine));
+        assert!(draft.check().is_err(), "Nothing queued yet");
+
+        draft.queue_card(Card::new(Suit::Spades, Value::Ace));
+        draft.queue_card(Card::new(Suit::Hearts, Value::Two));
+        draft.adjust_par(5);
+        assert_eq!(draft.par, 2);
+        assert_eq!(draft.check(), Ok(()));
+
+        let level = draft.level();
+        assert_eq!(level.board, vec!["KS . 9H".to_string()]);
+        assert_eq!(level.cards, vec!["AS".to_string(), "2H".to_string()]);
+        assert_eq!(level.grid(3, 4).unwrap(), draft.grid);
+
+        // An ace on the king is 21 before anything has dropped
+        draft.stack_card(0, Card::new(Suit::Clubs, Value::Ace));
+        assert!(draft.check().is_err());
+
+        draft.unqueue_card();
+        assert_eq!(draft.par, 1);
+    }
+}
@@ -1,54 +1,184 @@
 // Sub-modules
+pub mod balance;
 pub mod board;
+pub mod board_history;
+pub mod card_animator;
+pub mod clock;
+pub mod difficulty_controller;
+pub mod events;
+pub mod input;
+pub mod level_editor;
+pub mod name_filter;
+pub mod odds;
+pub mod puzzle;
+pub mod run_log;
+pub mod score_worker;
+pub mod search_trace;
 pub mod states;
 
+use self::balance::{Balance, CombinationStrategy};
 use self::board::Board;
+use self::board_history::{BoardFrame, BoardHistory};
+use self::card_animator::CardAnimator;
+use self::clock::GameClock;
+use self::difficulty_controller::DifficultyController;
+use self::input::InputSnapshot;
+use self::level_editor::LevelDraft;
+use self::name_filter::NameFilter;
+use self::puzzle::{PuzzleOrigin, PuzzleRun};
+use self::run_log::{RunLog, RunStep, ScoreSubmission};
+use self::score_worker::{ScoreRequest, ScoreResponse, ScoreWorker};
+use self::search_trace::SearchTrace;
+use crate::build_info::BuildInfo;
 use crate::database::Database;
+use crate::error::DropJackError;
 use crate::models::{
-    Card, Deck, DelayedDestruction, Difficulty, GameSettings, HighScore, PlayingCard, Position,
-    VisualPosition,
+    Card, DEBUG_SNAPSHOT_KEY, DebugSnapshot, Deck, DelayedDestruction, Difficulty,
+    DifficultyParams, DisplayProfile, GameSettings, HighScore, LeaderboardPeriod, MAX_NAME_LEN,
+    PlayingCard, Position, PuzzleLevel, RunOutcome, RunSeed, STATISTICS_EXPORT_KEY, SavedRun,
+    Season, Statistics, StatisticsExport, Suit, Value, VirtualKeyboard, VisualPosition,
 };
-use std::path::Path;
+use crate::mods::ModScripts;
+use crate::mods::catalog::{self, ModItem};
+use crate::storage::platform_storage;
+use crate::store::{
+    JsonSavedRunStore, JsonSettingsStore, SavedRunStore, ScoreStore, SettingsStore,
+};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
-pub use self::states::{GameOver, GameState, Paused, Playing, QuitConfirm, Settings, StartScreen};
+pub use self::events::{AudioEvent, ClearedCombination, GameEvent};
+pub use self::states::{
+    Accessibility, AccessibilityOption, Appearance, AppearanceOption, CombinationLog, Diagnostics,
+    ForfeitConfirm, ForfeitOption, GameOver, GameSetup, GameSetupOption, GameState, HowToPlay,
+    LevelEditor, MainMenuOption, MenuModel, ModsScreen, PauseMenuOption, PauseReason, Paused,
+    Playing, PuzzleSelect, QuitConfirm, RestartConfirm, SeedEntry, Settings, SettingsOrigin,
+    SetupStep, SetupWizard, StartScreen, StatisticsScreen,
+};
 
-const COMBINATION_DELAY: u64 = 300;
+const NEW_RECORD_RANK_LIMIT: usize = 3; // Top 3 per difficulty, matching the start screen board
+const RELAX_COLUMN_PENALTY: i32 = 100; // Points a relax mode run loses for each column it clears
+pub const NEW_RECORD_CELEBRATION_MS: u64 = 2500;
+pub const GAME_OVER_ZOOM_MS: u64 = 900; // Camera push toward the losing column before the overlay
+const GAME_OVER_INPUT_DEBOUNCE_MS: u64 = 300; // Game over ignores every key for this long
+pub const INITIALS_REJECTED_MS: u64 = 500; // The initials box shakes for this long when turned away
+pub const PERSONAL_BEST_BANNER_MS: u64 = 2000; // "New PB!" flashes this long once the best is passed
+pub const NEXT_CARD_REVEAL_MS: u64 = 350; // The next card preview flips face up over this long
+const SETTINGS_SAVE_DEBOUNCE_MS: u64 = 500; // Quiet time after the last change before writing settings
+pub const IDLE_PAUSE_SECS: u64 = 30; // How long the game's window waits for input before pausing play
+const SLOW_MOTION_CHAIN: i32 = 3; // Cascades reaching this step slow play down briefly
+const SLOW_MOTION_SCALE: f32 = 0.5;
+const SLOW_MOTION_MS: u64 = 400;
+const HARD_DROP_STEER_MS: u64 = 150; // How long after a hard drop left/right still steers it
+const LOCK_DELAY_MS: u64 = 400; // How long a landed card beside a gap waits for a slide before locking
+const BUST_LOCK_MS: u64 = 10_000; // How long a busted run stays locked under the bust rule
+const BOARD_HISTORY_SECS: u64 = 30; // How far back the game over replay reaches
+pub const COMBINATION_HISTORY_LIMIT: usize = 200; // Oldest combinations drop off the log past this
+pub const MIN_BOARD_WIDTH: i32 = 4; // Narrower boards leave no room to steer around a bust
+pub const MIN_BOARD_HEIGHT: i32 = 6; // The spawn row plus enough stack to build a 21
+pub const MAX_BOARD_WIDTH: i32 = 40; // Past this cards shrink too small to read on screen
+pub const MAX_BOARD_HEIGHT: i32 = 40;
 
 // Main game struct
 pub struct Game {
-    pub state: Box<dyn GameState>,
+    pub state: GameState,
     pub board: Board,
     pub deck: Deck,
     pub current_card: Option<PlayingCard>,
     pub next_card: Option<Card>,
+    pub next_card_dealt: Option<Instant>, // When the next card preview last changed, for its flip
     pub score: i32,
     pub difficulty: Difficulty,
     pub fall_speed: Duration,
+    pub fall_speed_override: Option<Duration>, // Builder override for the difficulty's starting speed
+    pub min_fall_speed_override: Option<Duration>, // Builder override for the speed floor
+    pub speed_level: u32, // Speed-ups so far this run (shown as "Speed: N" from 1)
     pub last_fall_time: Instant,
     pub speed_increase_interval: Duration,
     pub last_speed_increase: Instant,
-    pub database: Database,
-    pub high_scores: Vec<HighScore>,
+    pub last_input_time: Instant, // Last player input during play
+    pub idle_pause_after: Option<Duration>, // None disables the idle pause
+    pub suspended_at: Option<Instant>, // When play stopped updating (paused or in settings)
+    pub score_worker: ScoreWorker, // Score store calls run on a worker thread
+    pub database_path: Option<PathBuf>, // SQLite file, when scores are stored in one
+    pub settings_store: Box<dyn SettingsStore>,
+    pub high_scores: Vec<HighScore>, // Best scores of the leaderboard period showing
+    pub leaderboard_period: LeaderboardPeriod,
+    pub statistics: Statistics, // Lifetime run counters, refreshed by the score worker
+    pub run_column_drops: Vec<u32>, // Cards this run has placed in each column
+    pub saved_run_store: Box<dyn SavedRunStore>,
+    pub saved_run: Option<SavedRun>, // Run put aside with Save & Quit, waiting to be continued
+    pub puzzle_levels: Vec<PuzzleLevel>, // Bundled puzzles in play order, then the player's own
+    pub level_draft: Option<LevelDraft>, // The level editor's work, kept while it's test-played
+    pub puzzle: Option<PuzzleRun>,   // The level being played, when this run is a puzzle
+    pub puzzle_stars: HashMap<String, u8>, // Best rating per level id, refreshed by the score worker
     pub player_initials: String,
-    pub pending_explosions: Vec<(i32, i32, Card)>,
+    pub initials_keyboard: VirtualKeyboard, // On-screen keyboard for gamepad initials entry
+    pub new_record_rank: Option<usize>,     // Rank of the finished run if it made the top 3
+    pub celebration_started: Option<Instant>, // When the new record celebration began
+    pub run_percentile: Option<u8>, // Share of saved scores for the difficulty and mode the finished run beat
+    pub personal_best: Option<i32>, // Best saved score for the run's difficulty and mode, once the worker answers
+    pub personal_best_beaten: Option<Instant>, // When this run's score passed the personal best
+    pub losing_column: Option<i32>, // Column whose spawn cell stayed blocked, ending the run
+    pub game_over_started: Option<Instant>,
     pub delayed_destructions: Vec<DelayedDestruction>,
+    pub pending_clear_points: HashMap<(i32, i32), i32>, // What each card marked for removal scores when it explodes
+    pub clock: GameClock,        // Time scale for the fall timer and animations
+    pub balance: Balance,        // Tunable scoring and animation numbers from balance.toml
+    pub name_filter: NameFilter, // Initials the high score table turns away
+    pub mods: ModScripts,        // Rule-changing scripts; empty unless mods are enabled
+    pub run_modded: bool,        // Mods were active during this run, so its score is flagged
+    pub run_relaxed: bool,       // Relax mode run: a full board costs points instead of the run
+    pub run_modified: bool,      // Continued from a hand-edited save file or a debug snapshot
+    pub run_seed: Option<RunSeed>, // What the run's deck was shuffled from; None once it can't be replayed from it
+    pub run_log: Option<RunLog>, // The seeded run step by step, for submitting its score; None without a seed or with mods
+    pub search_overlay: bool,    // Debug overlay of the combination search, toggled with F7
+    pub search_trace: Option<SearchTrace>, // The search from the last card placed, while the overlay is on
+    pub difficulty_controller: DifficultyController, // Adaptive speed's nudges to the speed curve
+    pub mod_catalog: Vec<ModItem>, // Scripts and packs found at startup, for the Mods & Themes screen
+    pub season: Option<Season>,    // Time of year when the game started, for seasonal decorations
+    pub board_history: BoardHistory, // Recent boards for the game over replay
+    pub combination_history: VecDeque<ClearedCombination>, // This run's combinations, oldest first
     pub last_dropped_x: Option<i32>,
-    pub pending_audio_events: Vec<AudioEvent>,
+    pub events: Vec<GameEvent>, // Queued for the UI, audio and effects to consume
     pub hard_dropping_cards: Vec<PlayingCard>, // Cards that are hard dropping and still animating
-    pub settings: GameSettings,                // Global game settings
-    pub selected_main_option: usize,           // 0: Start New Game, 1: Settings, 2: Quit
-    pub game_session_active: bool,             // Track if a game session is currently active
+    pub hard_drop_started: Option<Instant>, // Latest hard drop, for the steer window
+    pub lock_started: Option<Instant>, // The falling card landed beside a gap and may still slide into it
+    pub lock_slide_used: bool,         // Each card gets one slide after landing
+    pub settings: GameSettings,        // Global game settings
+    pub settings_save_requested: Option<Instant>, // Last unsaved settings change, if any
+    pub audio_devices: Vec<String>, // Output devices the settings screen can pick (kept current by the UI)
+    pub selected_main_option: MainMenuOption, // Highlighted main menu entry
+    pub game_session_active: bool,  // Track if a game session is currently active
 }
 
 pub struct GameBuilder {
     board_width: i32,
     board_height: i32,
     cell_size: i32,
+    fit_area: Option<(i32, i32)>,
     difficulty: Difficulty,
-    fall_speed: Duration,
+    fall_speed: Option<Duration>,
+    min_fall_speed: Option<Duration>,
     speed_increase_interval: Duration,
+    idle_pause_after: Option<Duration>,
     database_path: Option<Box<Path>>,
+    score_store: Option<Box<dyn ScoreStore>>,
+    settings_store: Option<Box<dyn SettingsStore>>,
+    saved_run_store: Option<Box<dyn SavedRunStore>>,
+    balance: Option<Balance>,
+    name_filter: Option<NameFilter>,
+    mod_scripts: Option<ModScripts>,
+    first_run_setup: bool,
+}
+
+impl Default for GameBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl GameBuilder {
@@ -57,10 +187,20 @@ impl GameBuilder {
             board_width: 10,
             board_height: 15,
             cell_size: 48,
+            fit_area: None,
             difficulty: Difficulty::Easy,
-            fall_speed: Duration::from_millis(1000),
+            fall_speed: None,
+            min_fall_speed: None,
             speed_increase_interval: Duration::from_secs(30),
+            idle_pause_after: None,
             database_path: None,
+            score_store: None,
+            settings_store: None,
+            saved_run_store: None,
+            balance: None,
+            name_filter: None,
+            mod_scripts: None,
+            first_run_setup: false,
         }
     }
 
@@ -78,15 +218,29 @@ impl GameBuilder {
         self
     }
 
+    // Shrink the cell size as needed so the whole board fits in this many pixels
+    pub fn fit_board_within(mut self, width: i32, height: i32) -> Self {
+        self.fit_area = Some((width, height));
+        self
+    }
+
     #[allow(dead_code)]
     pub fn difficulty(mut self, difficulty: Difficulty) -> Self {
         self.difficulty = difficulty;
         self
     }
 
+    // Starting fall speed for every run, instead of the difficulty's own
     #[allow(dead_code)]
     pub fn fall_speed(mut self, speed: Duration) -> Self {
-        self.fall_speed = speed;
+        self.fall_speed = Some(speed);
+        self
+    }
+
+    // Fastest the fall speed can get, instead of the difficulty's (or the settings') floor
+    #[allow(dead_code)]
+    pub fn min_fall_speed(mut self, speed: Duration) -> Self {
+        self.min_fall_speed = Some(speed);
         self
     }
 
@@ -96,75 +250,258 @@ impl GameBuilder {
         self
     }
 
+    // How long play can go without input before pausing itself. Off unless set, so games
+    // with nobody at the keyboard (tests, the bot) never stop on their own.
+    pub fn idle_pause_after(mut self, timeout: Option<Duration>) -> Self {
+        self.idle_pause_after = timeout;
+        self
+    }
+
     pub fn database_path<P: AsRef<Path>>(mut self, path: P) -> Self {
         self.database_path = Some(path.as_ref().into());
         self
     }
 
-    pub fn build(self) -> Result<Game, Box<dyn std::error::Error>> {
+    // Use a specific score backend instead of opening the SQLite database at database_path
+    #[allow(dead_code)]
+    pub fn score_store(mut self, store: Box<dyn ScoreStore>) -> Self {
+        self.score_store = Some(store);
+        self
+    }
+
+    // Use a specific settings backend instead of settings.json in the app data directory
+    #[allow(dead_code)]
+    pub fn settings_store(mut self, store: Box<dyn SettingsStore>) -> Self {
+        self.settings_store = Some(store);
+        self
+    }
+
+    // Use a specific backend for Save & Quit instead of savedrun.json in the app data directory
+    #[allow(dead_code)]
+    pub fn saved_run_store(mut self, store: Box<dyn SavedRunStore>) -> Self {
+        self.saved_run_store = Some(store);
+        self
+    }
+
+    // Use these balance numbers instead of balance.toml in the app data directory
+    #[allow(dead_code)]
+    pub fn balance(mut self, balance: Balance) -> Self {
+        self.balance = Some(balance);
+        self
+    }
+
+    // Use this list instead of blocked_names.txt in the app data directory
+    #[allow(dead_code)]
+    pub fn name_filter(mut self, filter: NameFilter) -> Self {
+        self.name_filter = Some(filter);
+        self
+    }
+
+    // Use these scripts instead of loading the mods folder (when mods are enabled)
+    #[allow(dead_code)]
+    pub fn mod_scripts(mut self, mods: ModScripts) -> Self {
+        self.mod_scripts = Some(mods);
+        self
+    }
+
+    // Open the setup wizard instead of the start screen when no settings have been saved yet
+    pub fn first_run_setup(mut self, enabled: bool) -> Self {
+        self.first_run_setup = enabled;
+        self
+    }
+
+    pub fn build(self) -> Result<Game, DropJackError> {
+        check_board_size(self.board_width, self.board_height)
+            .map_err(DropJackError::InvalidBoardSize)?;
+        if self.cell_size <= 0 {
+            return Err(DropJackError::InvalidBoardSize(format!(
+                "cell size {} must be at least 1 pixel",
+                self.cell_size
+            )));
+        }
+
         let mut deck = Deck::new();
         deck.shuffle();
 
-        let board = Board::new(self.board_width, self.board_height, self.cell_size);
+        let cell_size = match self.fit_area {
+            Some((width, height)) => fit_cell_size(
+                self.cell_size,
+                self.board_width,
+                self.board_height,
+                width,
+                height,
+            ),
+            None => self.cell_size,
+        };
+        let board = Board::new(self.board_width, self.board_height, cell_size);
 
-        let database = Database::new(
-            self.database_path
-                .as_ref()
-                .ok_or("Database path must be provided")?,
-        )?;
-        let high_scores = database.get_high_scores(10).unwrap_or_default();
+        // Only remember the SQLite path when it is what actually backs the scores
+        let database_path = match &self.score_store {
+            Some(_) => None,
+            None => self.database_path.as_ref().map(|path| path.to_path_buf()),
+        };
+        let score_store: Box<dyn ScoreStore> = match self.score_store {
+            Some(store) => store,
+            None => Box::new(Database::new(
+                self.database_path
+                    .as_ref()
+                    .ok_or(DropJackError::NoScoreStore)?,
+            )?),
+        };
+        let high_scores = score_store.get_high_scores(10).unwrap_or_default();
+        let statistics = score_store.get_statistics().unwrap_or_default();
+        let puzzle_stars = score_store.get_puzzle_stars().unwrap_or_default();
 
         let next_card = deck.draw();
         let now = Instant::now();
 
-        let settings = GameSettings::load();
+        let settings_store: Box<dyn SettingsStore> = match self.settings_store {
+            Some(store) => store,
+            None => Box::new(JsonSettingsStore::new(open_storage()?)),
+        };
+        let saved_settings = GameSettings::load_from(settings_store.as_ref());
+
+        let balance = match self.balance {
+            Some(balance) => balance,
+            None => Balance::load_from(open_storage()?.as_ref()),
+        };
+        let name_filter = match self.name_filter {
+            Some(filter) => filter,
+            None => NameFilter::load_from(open_storage()?.as_ref()),
+        };
+
+        let saved_run_store: Box<dyn SavedRunStore> = match self.saved_run_store {
+            Some(store) => store,
+            None => Box::new(JsonSavedRunStore::new(open_storage()?)),
+        };
+        // A run saved on a different board size can't be put back
+        let saved_run = match saved_run_store.load() {
+            Ok(run) => run.filter(|run| run.fits(self.board_width, self.board_height)),
+            Err(e) => {
+                eprintln!("Failed to load saved run: {}", e);
+                None
+            }
+        };
+        let initial_state = if self.first_run_setup && saved_settings.is_none() {
+            GameState::SetupWizard(SetupWizard::new())
+        } else {
+            GameState::StartScreen(StartScreen)
+        };
+        let settings = saved_settings.unwrap_or_else(|| {
+            // First launch: start from the preset that suits the device (e.g. a Steam Deck)
+            let mut settings = GameSettings::default();
+            settings.apply_display_profile(DisplayProfile::detect());
+            settings
+        });
+        let mods = match self.mod_scripts {
+            Some(mods) => mods,
+            None if settings.mods_enabled => ModScripts::load_platform(&settings.disabled_mods),
+            None => ModScripts::empty(),
+        };
 
-        Ok(Game {
-            state: Box::new(StartScreen),
+        let mut game = Game {
+            state: initial_state,
             board,
             deck,
             current_card: None,
             next_card,
+            next_card_dealt: None,
             score: 0,
             difficulty: settings.difficulty, // Use difficulty from settings
-            fall_speed: self.fall_speed,
+            fall_speed: Duration::ZERO,      // Set from the speed curve below
+            fall_speed_override: self.fall_speed,
+            min_fall_speed_override: self.min_fall_speed,
+            speed_level: 0,
             last_fall_time: now,
             speed_increase_interval: self.speed_increase_interval,
             last_speed_increase: now,
-            database,
+            last_input_time: now,
+            idle_pause_after: self.idle_pause_after,
+            suspended_at: None,
+            score_worker: ScoreWorker::spawn(score_store),
+            database_path,
+            settings_store,
             high_scores,
+            leaderboard_period: LeaderboardPeriod::default(),
+            statistics,
+            run_column_drops: Vec::new(),
+            saved_run_store,
+            saved_run,
+            puzzle_levels: puzzle::load_platform_levels(),
+            level_draft: None,
+            puzzle: None,
+            puzzle_stars,
             player_initials: String::new(),
-            pending_explosions: Vec::new(),
+            initials_keyboard: VirtualKeyboard::new(),
+            new_record_rank: None,
+            celebration_started: None,
+            run_percentile: None,
+            personal_best: None,
+            personal_best_beaten: None,
+            losing_column: None,
+            game_over_started: None,
             delayed_destructions: Vec::new(),
+            pending_clear_points: HashMap::new(),
+            clock: GameClock::new(),
+            balance,
+            name_filter,
+            mods,
+            run_modded: false,
+            run_relaxed: false,
+            run_modified: false,
+            run_seed: None,
+            run_log: None,
+            search_overlay: false,
+            search_trace: None,
+            difficulty_controller: DifficultyController::default(),
+            mod_catalog: catalog::discover_platform(),
+            season: Season::current(),
+            board_history: BoardHistory::new(Duration::from_secs(BOARD_HISTORY_SECS)),
+            combination_history: VecDeque::with_capacity(COMBINATION_HISTORY_LIMIT),
             last_dropped_x: None,
-            pending_audio_events: Vec::new(),
+            events: Vec::new(),
             hard_dropping_cards: Vec::new(),
+            hard_drop_started: None,
+            lock_started: None,
+            lock_slide_used: false,
             settings,
-            selected_main_option: 0,
+            settings_save_requested: None,
+            audio_devices: Vec::new(),
+            selected_main_option: MainMenuOption::default(),
             game_session_active: false,
-        })
+        };
+        game.fall_speed = game.speed_params().fall_speed_at(0);
+        Ok(game)
+    }
+}
+
+// The platform's storage for settings, balance and saved runs
+fn open_storage() -> Result<Box<dyn crate::storage::Storage>, DropJackError> {
+    platform_storage().map_err(|e| DropJackError::Storage(e.to_string()))
+}
+
+// Whether a board this size can be played, and if not, what the limits are
+pub(crate) fn check_board_size(width: i32, height: i32) -> Result<(), String> {
+    if width < MIN_BOARD_WIDTH || height < MIN_BOARD_HEIGHT {
+        return Err(format!(
+            "{}x{} is too small, the board needs at least {} columns and {} rows",
+            width, height, MIN_BOARD_WIDTH, MIN_BOARD_HEIGHT
+        ));
+    }
+    if width > MAX_BOARD_WIDTH || height > MAX_BOARD_HEIGHT {
+        return Err(format!(
+            "{}x{} is too big, the board takes at most {} columns and {} rows",
+            width, height, MAX_BOARD_WIDTH, MAX_BOARD_HEIGHT
+        ));
     }
+    Ok(())
 }
 
-#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
-pub enum AudioEvent {
-    DifficultyChange,
-    StartGame,
-    DropCard,
-    MakeMatch,
-    ExplodeCard,
-    PauseGame,
-    ResumeGame,
-    ForfeitGame,
-    GameOver,
-    OpenQuitConfirmation,
-    ReturnToGame,
-    QuitGame,
-    // Card movement events
-    MoveLeft,
-    MoveRight,
-    SoftDrop,
-    HardDrop,
+// Largest cell size up to `preferred` that fits the whole board in the given pixel area
+fn fit_cell_size(preferred: i32, columns: i32, rows: i32, width: i32, height: i32) -> i32 {
+    let fits_width = width / columns.max(1);
+    let fits_height = height / rows.max(1);
+    preferred.min(fits_width).min(fits_height).max(1)
 }
 
 impl Game {
@@ -173,34 +510,92 @@ impl Game {
     }
 
     pub fn start_game(&mut self, difficulty: Difficulty) {
-        self.state = Box::new(Playing);
+        self.start_seeded_game(difficulty, RunSeed::random());
+    }
+
+    /// Start a run whose deck and handicap rows come from `seed`, so anyone playing the same
+    /// seed, difficulty and settings gets the same cards
+    pub fn start_seeded_game(&mut self, difficulty: Difficulty, seed: RunSeed) {
+        self.suspended_at = None; // A new run has no time away to make up
+        self.set_state(Playing::default());
         self.difficulty = difficulty;
         self.score = 0;
-        self.fall_speed = Duration::from_millis(1000);
+        self.speed_level = 0;
+        self.combination_history.clear();
+        self.pending_clear_points.clear();
+        self.fall_speed = self.speed_params().fall_speed_at(0);
         self.last_fall_time = Instant::now();
         self.last_speed_increase = Instant::now();
+        self.last_input_time = Instant::now();
         self.player_initials = String::new();
+        self.new_record_rank = None;
+        self.celebration_started = None;
+        self.run_percentile = None;
+        self.personal_best = None;
+        self.personal_best_beaten = None;
+        self.losing_column = None;
+        self.game_over_started = None;
         self.last_dropped_x = None;
         self.hard_dropping_cards.clear();
+        self.hard_drop_started = None;
+        self.clock = GameClock::new();
+        self.board_history.clear();
+        self.run_column_drops = vec![0; self.board.width.max(0) as usize];
+        self.run_modded = !self.mods.is_empty();
+        self.run_relaxed = self.settings.relax_mode;
+        self.run_modified = false;
+        self.difficulty_controller = DifficultyController::new(self.settings.adaptive_speed);
         self.game_session_active = true; // Mark game session as active
+        self.puzzle = None; // start_puzzle sets it again once the level is laid out
 
         // Reset the board
-        self.board = Board::new(self.board.width, self.board.height, 48);
-
-        // Reset the deck
-        self.deck.reset();
+        self.board = Board::new(self.board.width, self.board.height, self.board.cell_size);
+
+        // A fresh deck, built from the chosen template
+        self.run_seed = Some(seed);
+        self.deck = Deck::seeded(self.settings.deck_template, seed.value());
+        self.deck.shuffle();
+
+        // The handicap's cards come out of the deck, so none of them can be drawn again
+        if self.settings.handicap_rows > 0 {
+            let rows = self.settings.handicap_rows as i32;
+            let mut rng = StdRng::seed_from_u64(seed.value());
+            for card in self.board.fill_random_rows(rows, &mut rng) {
+                self.deck.take(card);
+            }
+        }
+        self.board.gravity_moves.clear();
+        self.search_trace = None;
+        self.run_log = (!self.run_modded).then(|| RunLog {
+            seed: seed.to_string(),
+            difficulty,
+            deck_template: self.settings.deck_template,
+            handicap_rows: self.settings.handicap_rows,
+            width: self.board.width,
+            height: self.board.height,
+            relaxed: self.run_relaxed,
+            steps: Vec::new(),
+        });
+        self.next_card = self.deck.draw(); // Not one left over from the last run's deck
+        self.log_step(RunStep::Draw);
 
         // Draw the first card
         self.spawn_new_card();
+        self.request_personal_best();
 
         // Add audio event for starting game
         self.add_audio_event(AudioEvent::StartGame);
     }
 
     pub fn spawn_new_card(&mut self) {
-        if let Some(card) = self.next_card {
-            let x = self.last_dropped_x.unwrap_or(self.board.width / 2);
+        // With the whole top row full there's nowhere to put it; once the board settles
+        // check_game_over ends the run, or a clear frees a column for the next frame
+        if let Some(card) = self.next_card
+            && let Some(x) = self.spawn_column()
+        {
             let position = Position { x, y: 0 };
+            self.lock_started = None;
+            self.lock_slide_used = false;
 
             self.current_card = Some(
                 PlayingCard::builder(card, position)
@@ -217,10 +612,16 @@ impl Game {
 
             self.next_card = self.deck.draw();
 
-            if self.next_card.is_none() {
+            // Every card of the deck is on the board, so open a new one. This is the only
+            // way the same card can be in play twice. A puzzle's cards just run out.
+            if self.next_card.is_none() && self.puzzle.is_none() {
                 self.deck.reset();
                 self.next_card = self.deck.draw();
             }
+            if self.next_card.is_some() {
+                self.next_card_dealt = Some(Instant::now());
+                self.log_step(RunStep::Draw);
+            }
         }
     }
 
@@ -231,30 +632,47 @@ impl Game {
     }
 
     pub fn update_playing_state(&mut self) {
+        if self.handle_idle_pause() {
+            return;
+        }
+
+        self.clock.tick(Instant::now());
+        self.unlock_busted_cards();
         self.process_card_removals();
         self.process_delayed_destructions();
+        self.revalidate_hard_drop_targets();
         self.update_animations();
         self.handle_card_spawning();
         self.handle_auto_speed_increase();
         self.handle_automatic_card_fall();
+        self.record_board_history();
+        self.check_puzzle_result();
+        self.check_personal_best();
         self.check_game_over();
     }
 
+    fn record_board_history(&mut self) {
+        let current_card = self
+            .current_card
+            .as_ref()
+            .map(|playing_card| (playing_card.card, playing_card.position));
+        self.board_history
+            .record(&self.board.grid, current_card, Instant::now());
+    }
+
     fn process_card_removals(&mut self) {
         let removed_cards = self.board.process_marked_removals();
         if !removed_cards.is_empty() {
-            // Add audio event for making match
-            self.add_audio_event(AudioEvent::MakeMatch);
-
             for (x, y, card) in removed_cards {
-                self.pending_explosions.push((x, y, card));
-
-                // Add audio event for exploding card
-                self.add_audio_event(AudioEvent::ExplodeCard);
+                self.log_step(RunStep::Explode { x, y });
+                self.emit(GameEvent::CardExploded { x, y, card });
+                if self.puzzle.is_none() {
+                    self.deck.discard(card); // A puzzle never deals a cleared card again
+                }
 
-                // Calculate and add the score
-                let base_score = 21;
-                self.score += base_score;
+                // Each card scores its share of the combination as it goes
+                let points = self.pending_clear_points.remove(&(x, y)).unwrap_or(0);
+                self.award_points(points);
             }
 
             // Apply gravity after removals
@@ -263,100 +681,130 @@ impl Game {
     }
 
     fn update_animations(&mut self) {
-        // Update falling card animations
-        self.board.update_falling_cards();
-
-        // Update current card position animation
-        if let Some(ref mut playing_card) = self.current_card {
-            let move_speed = 12.0; // pixels per frame - scaled up for larger cells
-
-            // Horizontal movement
-            let target_x = (playing_card.target.x * self.board.cell_size) as f32;
-            if playing_card.visual_position.x != target_x {
-                let diff_x = target_x - playing_card.visual_position.x;
-                let move_x = if diff_x.abs() <= move_speed {
-                    diff_x
-                } else {
-                    move_speed * diff_x.signum()
-                };
-                playing_card.visual_position.x += move_x;
-
-                // Snap to target when close enough
-                if (playing_card.visual_position.x - target_x).abs() < 0.1 {
-                    playing_card.visual_position.x = target_x;
-                    playing_card.position.x = playing_card.target.x;
-                }
-            }
-
-            // Vertical movement (falling)
-            let target_y = (playing_card.target.y * self.board.cell_size) as f32;
-            if playing_card.is_falling && playing_card.visual_position.y != target_y {
-                // Use faster fall speed for hard drops
-                let fall_speed = if playing_card.is_hard_dropping {
-                    20.0
-                } else {
-                    8.0
-                };
-                let diff_y = target_y - playing_card.visual_position.y;
-                let move_y = if diff_y.abs() <= fall_speed {
-                    diff_y
-                } else {
-                    fall_speed * diff_y.signum()
-                };
-                playing_card.visual_position.y += move_y;
-
-                if (playing_card.visual_position.y - target_y).abs() < 0.1 {
-                    playing_card.visual_position.y = target_y;
-                    playing_card.position.y = playing_card.target.y;
-                    playing_card.is_falling = false;
-                    playing_card.is_hard_dropping = false;
-                }
-            }
-        }
-
-        // Update hard-dropping cards that are no longer the current card
-        let mut cards_to_place = Vec::new();
-        for (index, card) in self.hard_dropping_cards.iter_mut().enumerate() {
-            let target_y = (card.target.y * self.board.cell_size) as f32;
-            if card.is_falling && card.visual_position.y != target_y {
-                let fall_speed = 20.0; // Fast fall speed for hard drops
-                let diff_y = target_y - card.visual_position.y;
-                let move_y = if diff_y.abs() <= fall_speed {
-                    diff_y
-                } else {
-                    fall_speed * diff_y.signum()
-                };
-                card.visual_position.y += move_y;
-
-                if (card.visual_position.y - target_y).abs() < 0.1 {
-                    card.visual_position.y = target_y;
-                    card.position.y = card.target.y;
-                    card.is_falling = false;
-                    card.is_hard_dropping = false;
-                    cards_to_place.push(index);
-                }
-            }
-        }
-
-        // Place cards that have finished falling and remove them from hard_dropping_cards
-        for index in cards_to_place.into_iter().rev() {
-            let finished_card = self.hard_dropping_cards.remove(index);
+        let animator =
+            CardAnimator::new(self.board.cell_size, &self.balance, self.clock.time_scale);
+        let arrivals = animator.update(
+            self.current_card.as_mut(),
+            &mut self.hard_dropping_cards,
+            &mut self.board.falling_cards,
+        );
+
+        // Place hard drops that have finished falling
+        for finished_card in arrivals.placed {
             // Don't update last_dropped_x here - that should only be set when the player places a card normally
+            self.count_column_drop(finished_card.position.x);
             self.board.place_card(
                 finished_card.position.x,
                 finished_card.position.y,
                 finished_card.card,
             );
 
-            // Add audio event for dropping card
-            self.add_audio_event(AudioEvent::DropCard);
+            self.log_step(RunStep::Place {
+                x: finished_card.position.x,
+                y: finished_card.position.y,
+                card: finished_card.card,
+            });
+            self.emit(GameEvent::CardPlaced {
+                x: finished_card.position.x,
+                y: finished_card.position.y,
+                card: finished_card.card,
+            });
+            self.mods.on_card_placed(
+                finished_card.position.x,
+                finished_card.position.y,
+                &finished_card.card,
+            );
+            self.trace_search_from(finished_card.position.x, finished_card.position.y);
 
             // Process combinations after placing the card
             self.process_combinations();
+            self.lock_busted_runs();
 
             // Apply gravity to handle any floating cards after combinations
             while self.board.apply_gravity() {}
         }
+
+        if !arrivals.landed.is_empty() {
+            for (x, y, card) in arrivals.landed {
+                self.emit(GameEvent::CardLanded { x, y, card });
+            }
+            self.match_landed_cards();
+        }
+    }
+
+    // Cards that gravity moved sit out of matching until they land, so check again once
+    // the last of them has. A cascade in progress picks them up at its next step instead.
+    fn match_landed_cards(&mut self) {
+        if self.delayed_destructions.is_empty() && !self.board.has_cards_in_transit() {
+            self.process_combinations();
+            self.lock_busted_runs();
+        }
+    }
+
+    // Cards can only ever land lower than planned: when a clear opens up the cells under a
+    // hard drop's target, it's moved down to the new bottom of the column, oldest drop first
+    fn revalidate_hard_drop_targets(&mut self) {
+        for card in self.hard_dropping_cards.iter_mut() {
+            if !card.is_falling {
+                continue;
+            }
+            let x = card.target.x;
+            while self.board.is_cell_free(x, card.target.y + 1) {
+                self.board.release_cell(x, card.target.y);
+                card.target.y += 1;
+                self.board.reserve_cell(x, card.target.y);
+            }
+        }
+    }
+
+    // The most recent hard drop, while it was started moments ago and is still falling
+    fn steerable_hard_drop(&self) -> Option<usize> {
+        let started = self.hard_drop_started?;
+        if started.elapsed()
+            > self
+                .clock
+                .stretch(Duration::from_millis(HARD_DROP_STEER_MS))
+        {
+            return None;
+        }
+        let index = self.hard_dropping_cards.len().checked_sub(1)?;
+        self.hard_dropping_cards[index].is_falling.then_some(index)
+    }
+
+    // Nudge the latest hard drop one column over, if both rows it overlaps are free there.
+    // Returns true when the input went to steering, even if the way was blocked.
+    fn steer_hard_drop(&mut self, step: i32) -> bool {
+        let Some(index) = self.steerable_hard_drop() else {
+            return false;
+        };
+        let cell_size = self.board.cell_size as f32;
+        let card = &self.hard_dropping_cards[index];
+        let new_x = card.target.x + step;
+        let top_row = (card.visual_position.y / cell_size).floor() as i32;
+        let bottom_row = (card.visual_position.y / cell_size).ceil() as i32;
+        if !self.board.is_cell_free(new_x, top_row) || !self.board.is_cell_free(new_x, bottom_row) {
+            return true;
+        }
+
+        let mut landing_y = bottom_row;
+        while self.board.is_cell_free(new_x, landing_y + 1) {
+            landing_y += 1;
+        }
+        self.board.release_cell(card.target.x, card.target.y);
+        self.board.reserve_cell(new_x, landing_y);
+
+        let card = &mut self.hard_dropping_cards[index];
+        card.position.x = new_x;
+        card.target = Position {
+            x: new_x,
+            y: landing_y,
+        };
+        self.add_audio_event(if step < 0 {
+            AudioEvent::MoveLeft
+        } else {
+            AudioEvent::MoveRight
+        });
+        true
     }
 
     fn handle_card_spawning(&mut self) {
@@ -372,34 +820,122 @@ impl Game {
         if now.duration_since(self.last_speed_increase) >= self.speed_increase_interval {
             self.increase_speed();
             self.last_speed_increase = now;
+            self.emit(GameEvent::LevelUp {
+                fall_speed: self.fall_speed,
+            });
+        }
+    }
+
+    // Pause when the player has stopped giving input, so walking away never ends a run
+    fn handle_idle_pause(&mut self) -> bool {
+        let Some(timeout) = self.idle_pause_after else {
+            return false;
+        };
+        if self.last_input_time.elapsed() < timeout {
+            return false;
         }
+        self.transition_to_paused_for(PauseReason::Idle);
+        true
     }
 
     fn handle_automatic_card_fall(&mut self) {
         let now = Instant::now();
-        if now.duration_since(self.last_fall_time) >= self.fall_speed {
+
+        // A card waiting on a slide locks when its delay runs out, not on the fall timer
+        if let Some(started) = self.lock_started {
+            if now.duration_since(started)
+                >= self.clock.stretch(Duration::from_millis(LOCK_DELAY_MS))
+            {
+                self.place_current_card();
+            }
+            return;
+        }
+
+        if now.duration_since(self.last_fall_time) >= self.clock.stretch(self.fall_speed) {
             self.move_current_card_down();
             self.last_fall_time = now;
         }
     }
 
+    // New cards appear above the column the player last dropped into
+    fn preferred_spawn_column(&self) -> i32 {
+        self.last_dropped_x.unwrap_or(self.board.width / 2)
+    }
+
+    // Nudged to the nearest open column when the preferred one is blocked
+    fn spawn_column(&self) -> Option<i32> {
+        self.board
+            .nearest_free_column(self.preferred_spawn_column())
+    }
+
+    // The run only ends once every clear, cascade and hard drop has played out and there's
+    // still nowhere to spawn
     fn check_game_over(&mut self) {
-        if self.board.is_game_over() {
+        if !self.delayed_destructions.is_empty() || !self.hard_dropping_cards.is_empty() {
+            return;
+        }
+        if let Some(column) = self.board.is_game_over(self.preferred_spawn_column()) {
+            if self.puzzle.is_some() {
+                self.finish_puzzle(false);
+                return;
+            }
+            if self.run_relaxed {
+                self.clear_blocked_column(column);
+                return;
+            }
+            self.losing_column = Some(column);
             self.transition_to_game_over();
         }
     }
 
+    // Relax mode's way out of a full board: the blocked column empties, its cards go back
+    // to the deck, and the run carries on a few points lighter
+    fn clear_blocked_column(&mut self, column: i32) {
+        for y in 0..self.board.height {
+            if let Some(card) = self.board.remove_card(column, y) {
+                self.emit(GameEvent::CardExploded { x: column, y, card });
+                self.deck.discard(card);
+            }
+        }
+        self.log_step(RunStep::ColumnCleared { column });
+        let penalty = self.score.min(RELAX_COLUMN_PENALTY);
+        self.score -= penalty;
+        self.emit(GameEvent::ColumnCleared { column, penalty });
+    }
+
+    // The difficulty's speed curve with any builder or settings overrides applied.
+    // The builder wins over settings so tests and tools get exactly what they ask for.
+    pub fn speed_params(&self) -> DifficultyParams {
+        let mut params = self.difficulty.params();
+        if let Some(speed) = self.fall_speed_override {
+            params.initial_fall_speed = speed;
+        }
+        if let Some(floor) = self.min_fall_speed_override {
+            params.min_fall_speed = floor;
+        } else if let Some(floor_ms) = self.settings.min_fall_speed_ms {
+            params.min_fall_speed = Duration::from_millis(floor_ms);
+        }
+        params
+    }
+
     fn increase_speed(&mut self) {
-        // Decrease fall time by 10% (increase speed)
-        let new_fall_time = self.fall_speed.as_millis() * 9 / 10;
-        self.fall_speed = Duration::from_millis(new_fall_time as u64);
+        // Step one level along the difficulty's speed curve
+        self.speed_level = self.speed_level.saturating_add(1);
+        self.fall_speed = self.speed_params().fall_speed_at(self.pace_level());
+        self.mods.on_level_up(self.speed_level + 1);
+    }
+
+    /// The speed level cards fall at: the curve's level with any adaptive speed nudge applied
+    pub fn pace_level(&self) -> u32 {
+        self.speed_level
+            .saturating_add_signed(self.difficulty_controller.adjustment())
     }
 
     /// Checks if a move to a new logical position is valid.
     /// This prevents a card from moving into or through an occupied space.
     fn is_move_valid(&self, current_x: i32, current_y: i32, new_x: i32, new_y: i32) -> bool {
         // Check the target cell
-        if !self.board.is_cell_empty(new_x, new_y) {
+        if !self.board.is_cell_free(new_x, new_y) {
             return false;
         }
 
@@ -407,8 +943,8 @@ impl Game {
         // ensure the corner cell is also empty to prevent clipping.
         if new_x != current_x
             && new_y != current_y
-            && (!self.board.is_cell_empty(current_x, new_y)
-                || !self.board.is_cell_empty(new_x, current_y))
+            && (!self.board.is_cell_free(current_x, new_y)
+                || !self.board.is_cell_free(new_x, current_y))
         {
             return false;
         }
@@ -417,36 +953,112 @@ impl Game {
     }
 
     pub fn move_current_card_left(&mut self) {
+        if self.steer_hard_drop(-1) {
+            return;
+        }
         if let Some(card) = &self.current_card {
-            // Check only the immediate horizontal destination.
+            // Check only the immediate horizontal destination, and the cell below it when
+            // the card is partway through a fall step.
             // Let the robust `move_current_card_down` handle fall logic.
             if card.target.x == card.position.x {
                 let new_x = card.position.x - 1;
-                if new_x >= 0 && self.board.is_cell_empty(new_x, card.position.y) {
-                    if let Some(card_mut) = self.current_card.as_mut() {
+                if new_x >= 0
+                    && self.is_move_valid(card.position.x, card.position.y, new_x, card.target.y)
+                {
+                    if self.lock_started.is_some() {
+                        self.slide_locking_card(new_x);
+                    } else if let Some(card_mut) = self.current_card.as_mut() {
                         card_mut.target.x = new_x;
-                        self.add_audio_event(AudioEvent::MoveLeft);
                     }
+                    self.add_audio_event(AudioEvent::MoveLeft);
                 }
             }
         }
     }
 
     pub fn move_current_card_right(&mut self) {
+        if self.steer_hard_drop(1) {
+            return;
+        }
         if let Some(card) = &self.current_card {
-            // Check only the immediate horizontal destination.
+            // Check only the immediate horizontal destination, and the cell below it when
+            // the card is partway through a fall step.
             if card.target.x == card.position.x {
                 let new_x = card.position.x + 1;
-                if new_x < self.board.width && self.board.is_cell_empty(new_x, card.position.y) {
-                    if let Some(card_mut) = self.current_card.as_mut() {
+                if new_x < self.board.width
+                    && self.is_move_valid(card.position.x, card.position.y, new_x, card.target.y)
+                {
+                    if self.lock_started.is_some() {
+                        self.slide_locking_card(new_x);
+                    } else if let Some(card_mut) = self.current_card.as_mut() {
                         card_mut.target.x = new_x;
-                        self.add_audio_event(AudioEvent::MoveRight);
                     }
+                    self.add_audio_event(AudioEvent::MoveRight);
                 }
             }
         }
     }
 
+    // The one slide a landed card gets while its lock delay runs. The column changes right
+    // away so the next fall step, or a lock, uses the new column while the card slides over.
+    fn slide_locking_card(&mut self, new_x: i32) {
+        if let Some(card) = self.current_card.as_mut() {
+            card.position.x = new_x;
+            card.target.x = new_x;
+        }
+        self.lock_started = None;
+        self.lock_slide_used = true;
+        self.last_fall_time = Instant::now();
+    }
+
+    // Whether a card resting at (x, y) could tuck into the cell beside it: one under an
+    // overhang, or one it would carry on falling from. A free cell on open ground isn't a gap.
+    fn has_slide_gap(&self, x: i32, y: i32) -> bool {
+        let board = &self.board;
+        let overhang =
+            |side: i32| board.is_position_valid(side, y - 1) && !board.is_cell_empty(side, y - 1);
+        [x - 1, x + 1].into_iter().any(|side| {
+            board.is_cell_free(side, y) && (overhang(side) || board.is_cell_free(side, y + 1))
+        })
+    }
+
+    // Landing beside a gap starts the lock delay instead of placing the card, once per card.
+    // Returns false when the card should lock straight away.
+    fn start_lock_delay(&mut self, x: i32, y: i32) -> bool {
+        if !self.settings.slide_delay
+            || self.lock_started.is_some()
+            || self.lock_slide_used
+            || !self.has_slide_gap(x, y)
+        {
+            return false;
+        }
+        self.lock_started = Some(Instant::now());
+        true
+    }
+
+    /// Move the falling card one column toward `column` (for tap-to-move).
+    /// Returns false once the card is in that column or can't get any closer.
+    pub fn step_current_card_toward(&mut self, column: i32) -> bool {
+        let Some(card) = &self.current_card else {
+            return false;
+        };
+        if card.target.x != card.position.x {
+            return true; // Still sliding into the last column
+        }
+
+        let x = card.position.x;
+        if column < x {
+            self.move_current_card_left();
+        } else if column > x {
+            self.move_current_card_right();
+        } else {
+            return false;
+        }
+        self.current_card
+            .as_ref()
+            .is_some_and(|card| card.target.x != x)
+    }
+
     pub fn move_current_card_down(&mut self) {
         if let Some(card) = self.current_card.as_ref() {
             let current_pos = card.position;
@@ -454,7 +1066,9 @@ impl Game {
             let next_y = current_pos.y + 1;
 
             if next_y >= self.board.height {
-                self.place_current_card();
+                if !self.start_lock_delay(current_pos.x, current_pos.y) {
+                    self.place_current_card();
+                }
                 return;
             }
 
@@ -484,8 +1098,11 @@ impl Game {
                     self.last_fall_time = Instant::now();
                     self.add_audio_event(AudioEvent::SoftDrop);
                 }
-            } else {
-                // Blocked below, even vertically. The card has landed.
+            } else if self.board.is_in_transit(current_pos.x, next_y) {
+                // The card below is still falling into place; wait for it to land
+            } else if !self.start_lock_delay(current_pos.x, current_pos.y) {
+                // Blocked below, even vertically, and either nowhere to slide or the lock
+                // delay is already over. The card has landed.
                 self.place_current_card();
             }
         } else {
@@ -495,31 +1112,28 @@ impl Game {
 
     pub fn hard_drop(&mut self) {
         if let Some(mut current_card) = self.current_card.take() {
-            // Calculate the final landing position by finding the lowest empty cell
-            // Must check both board occupancy AND hard-dropping cards targeting the same position
+            // Calculate the final landing position by finding the lowest free cell. Cells
+            // reserved by earlier hard drops count as taken, so rapid drops stack in order.
             let mut final_y = current_card.position.y;
-            let card_x = current_card.position.x;
+            // A card still sliding sideways drops down the column it's sliding into
+            let card_x = current_card.target.x;
+            current_card.position.x = card_x;
 
             for test_y in (current_card.position.y + 1)..self.board.height {
-                // Check if the board cell is empty
-                let board_empty = self.board.is_cell_empty(card_x, test_y);
-
-                // Check if any hard-dropping card is already targeting this position
-                let no_hard_drop_conflict = !self
-                    .hard_dropping_cards
-                    .iter()
-                    .any(|card| card.position.x == card_x && card.target.y == test_y);
-
-                if board_empty && no_hard_drop_conflict {
+                if self.board.is_cell_free(card_x, test_y) {
                     final_y = test_y;
                 } else {
-                    // Hit an occupied cell or conflicting hard-drop target, stop here
+                    // Hit an occupied or reserved cell, stop here
                     break;
                 }
             }
 
             // Only proceed if the card can actually fall
             if final_y > current_card.position.y {
+                // Hold the landing cell so soft drops, gravity and later hard drops stay
+                // out of it until the card arrives
+                self.board.reserve_cell(card_x, final_y);
+
                 // Store the X position where the player was positioning this card
                 // This ensures the next card spawns at the player's current position
                 self.last_dropped_x = Some(current_card.position.x);
@@ -531,6 +1145,7 @@ impl Game {
 
                 // Move the current card to the hard_dropping_cards list
                 self.hard_dropping_cards.push(current_card);
+                self.hard_drop_started = self.settings.hard_drop_steer.then(Instant::now);
 
                 // Add audio event for hard drop
                 self.add_audio_event(AudioEvent::HardDrop);
@@ -546,44 +1161,116 @@ impl Game {
     }
 
     fn place_current_card(&mut self) {
+        self.lock_started = None;
         if let Some(playing_card) = self.current_card.take() {
             // Store the X position of this dropped card for the next card
             self.last_dropped_x = Some(playing_card.position.x);
+            self.count_column_drop(playing_card.position.x);
             self.board.place_card(
                 playing_card.position.x,
                 playing_card.position.y,
                 playing_card.card,
             );
 
-            // Add audio event for dropping card
-            self.add_audio_event(AudioEvent::DropCard);
+            self.log_step(RunStep::Place {
+                x: playing_card.position.x,
+                y: playing_card.position.y,
+                card: playing_card.card,
+            });
+            self.emit(GameEvent::CardPlaced {
+                x: playing_card.position.x,
+                y: playing_card.position.y,
+                card: playing_card.card,
+            });
+            self.mods.on_card_placed(
+                playing_card.position.x,
+                playing_card.position.y,
+                &playing_card.card,
+            );
+            self.trace_search_from(playing_card.position.x, playing_card.position.y);
 
             // Immediately process combinations after a card is placed.
             self.process_combinations();
+            self.lock_busted_runs();
         }
     }
 
     pub fn save_high_score(&mut self) {
-        use chrono::Local;
+        use chrono::{SubsecRound, Utc};
 
         let high_score = HighScore {
             id: None,
             player_initials: self.player_initials.clone(),
             score: self.score,
-            difficulty: self.difficulty.to_string(),
-            date: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            difficulty: self.difficulty,
+            date: Utc::now().trunc_subsecs(0),
+            modded: self.run_modded,
+            deck_template: self.deck.template(),
+            relaxed: self.run_relaxed,
+            modified: self.run_modified,
+        };
+
+        // The worker saves, then sends back the refreshed high score list
+        let since = self.leaderboard_period.start(chrono::Local::now());
+        self.score_worker
+            .send(ScoreRequest::Save { high_score, since });
+    }
+
+    /// Switch the start screen leaderboard `step` periods along, and fetch its scores
+    pub fn cycle_leaderboard_period(&mut self, step: i32) {
+        self.leaderboard_period = self.leaderboard_period.cycle(step);
+        let since = self.leaderboard_period.start(chrono::Local::now());
+        self.score_worker
+            .send(ScoreRequest::LoadHighScores { since });
+    }
+
+    /// The finished run's score and the record behind it, checked by replaying the record
+    /// here before it goes anywhere. Err when there's no record or it doesn't add up.
+    pub fn score_submission(&self) -> Result<ScoreSubmission, String> {
+        let run = self
+            .run_log
+            .clone()
+            .ok_or("Only seeded runs without mods can be submitted")?;
+        let submission = ScoreSubmission {
+            player_initials: self.player_initials.clone(),
+            score: self.score,
+            run,
         };
+        submission.verify(&self.balance)?;
+        Ok(submission)
+    }
 
-        if self.database.add_high_score(&high_score).is_ok() {
-            // Refresh high scores
-            if let Ok(scores) = self.database.get_high_scores(10) {
-                self.high_scores = scores;
+    /// Save the high score under the initials entered and go back to the start screen.
+    /// Initials the name filter blocks are cleared for another go instead, with a shake and
+    /// an error sound.
+    pub fn submit_initials(&mut self) {
+        if self.name_filter.blocks(&self.player_initials) {
+            self.player_initials.clear();
+            if let GameState::GameOver(over) = &mut self.state {
+                over.initials_rejected = Some(Instant::now());
             }
+            self.add_audio_event(AudioEvent::InitialsRejected);
+            return;
         }
+        if !self.player_initials.is_empty() {
+            self.save_high_score();
+        }
+        self.transition_to_start_screen();
+    }
+
+    /// How far through the shake for turned away initials the game over screen is, from
+    /// 0.0 to 1.0. None when nothing has been turned away lately.
+    pub fn initials_rejected_progress(&self) -> Option<f32> {
+        let GameState::GameOver(over) = &self.state else {
+            return None;
+        };
+        let elapsed = over.initials_rejected?.elapsed();
+        let duration = Duration::from_millis(INITIALS_REJECTED_MS);
+        (elapsed < duration).then(|| elapsed.as_secs_f32() / duration.as_secs_f32())
     }
 
     pub fn add_initial(&mut self, c: char) {
-        if self.player_initials.len() < 3 && c.is_ascii_alphabetic() {
+        if self.player_initials.len() < MAX_NAME_LEN && c.is_ascii_alphabetic() {
             self.player_initials.push(c.to_ascii_uppercase());
         }
     }
@@ -592,86 +1279,231 @@ impl Game {
         self.player_initials.pop();
     }
 
+    /// Show or hide the combination search overlay
+    pub fn toggle_search_overlay(&mut self) {
+        self.search_overlay = !self.search_overlay;
+        self.search_trace = None;
+    }
+
+    // With the overlay on, follow the search for 21s from each card as it's placed
+    fn trace_search_from(&mut self, x: i32, y: i32) {
+        if self.search_overlay {
+            let steps = self.board.trace_search(x, y, self.difficulty);
+            self.search_trace = Some(SearchTrace::new(steps, Instant::now()));
+        }
+    }
+
+    // Bust rule: a one-suit run stacked past 21 turns into locked cards for a while
+    fn lock_busted_runs(&mut self) {
+        if !self.settings.bust_rule {
+            return;
+        }
+        let unlock_time = Instant::now() + Duration::from_millis(BUST_LOCK_MS);
+        for run in self.board.find_busts() {
+            self.board.lock_cards(&run, unlock_time);
+        }
+    }
+
+    // Cards coming out of a bust can make 21s again. Unlocking waits out a cascade so the
+    // freed cards join the next check instead of cutting the cascade short.
+    fn unlock_busted_cards(&mut self) {
+        if !self.delayed_destructions.is_empty() {
+            return;
+        }
+        if self.board.unlock_expired(Instant::now()) > 0 {
+            self.process_combinations();
+        }
+    }
+
     // Process combinations with delayed cascading effect for better visual appeal
     fn process_combinations(&mut self) {
         // Find all combinations
-        let all_combinations = self.board.check_combinations(self.difficulty);
+        let combinations = self.find_combinations();
+        let all_combinations = Board::combination_positions(&combinations);
         if all_combinations.is_empty() {
             return; // No combinations found
         }
 
+        self.emit_combinations_cleared(&combinations, &all_combinations, 1);
+
         // Clear any existing delayed destructions
         self.delayed_destructions.clear();
 
-        // Process each card individually with staggered timing
-        let now = Instant::now();
-        let delay_between_cards = Duration::from_millis(COMBINATION_DELAY);
-
-        for (card_index, &position) in all_combinations.iter().enumerate() {
-            let removal_time = now + delay_between_cards * card_index as u32;
-
-            // Mark this individual card for delayed removal
-            self.board
-                .mark_cards_for_removal(vec![position], removal_time);
-        }
-
-        // Schedule a check for new combinations after all cards are processed
-        let final_check_time = now + delay_between_cards * all_combinations.len() as u32;
+        // Schedule a check for new combinations after every combination has exploded
+        let final_check_time = self.mark_combinations(&combinations, Instant::now());
         self.delayed_destructions.push(DelayedDestruction {
             destruction_time: final_check_time,
             chain_multiplier: 2,
-            combination_index: 1,
         });
     }
 
-    // Get and clear pending explosions
-    pub fn take_pending_explosions(&mut self) -> Vec<(i32, i32, Card)> {
-        std::mem::take(&mut self.pending_explosions)
+    // Mark each combination to explode in turn, all of a combination's cards together, and
+    // return when the last one goes
+    fn mark_combinations(&mut self, combinations: &[Vec<(i32, i32)>], now: Instant) -> Instant {
+        let delay_between_combinations = self.balance.combination_delay();
+        for (index, path) in combinations.iter().enumerate() {
+            let removal_time = now + delay_between_combinations * index as u32;
+            self.board
+                .mark_cards_for_removal(path.clone(), removal_time);
+        }
+        now + delay_between_combinations * combinations.len() as u32
     }
 
-    // Process delayed destructions
-    fn process_delayed_destructions(&mut self) {
-        let now = Instant::now();
-        let mut processed_any = false;
-        let mut new_destructions = Vec::new();
-        let mut cascade_checks = Vec::new();
+    // The combinations to clear, picked by the balance's combination strategy
+    fn find_combinations(&self) -> Vec<Vec<(i32, i32)>> {
+        self.board
+            .find_best_combinations(self.difficulty, |path| self.combination_weight(path))
+    }
 
-        // First pass: collect what needs to be done
-        self.delayed_destructions.retain(|destruction| {
-            if now >= destruction.destruction_time {
-                cascade_checks.push((destruction.chain_multiplier, destruction.combination_index));
-                processed_any = true;
-                false // Remove this destruction from the queue
-            } else {
-                true // Keep this destruction in the queue
+    fn combination_weight(&self, path: &[(i32, i32)]) -> i64 {
+        let length = path.len() as i64;
+        match self.balance.combination_strategy {
+            CombinationStrategy::Longest => length * length,
+            CombinationStrategy::MostCards => length,
+            CombinationStrategy::HighestScore => {
+                let cards = path
+                    .iter()
+                    .filter_map(|&(x, y)| self.board.grid[y as usize][x as usize])
+                    .collect();
+                let points =
+                    ClearedCombination::new(cards, self.balance.length_multiplier(path.len()), 1)
+                        .points as i64;
+                if self.board.is_natural(path) {
+                    points + self.balance.natural_bonus as i64
+                } else {
+                    points
+                }
             }
-        });
-
+        }
+    }
+
+    // Announce cleared combinations and add them to the run's history
+    fn emit_combinations_cleared(
+        &mut self,
+        combinations: &[Vec<(i32, i32)>],
+        positions: &[(i32, i32)],
+        chain: i32,
+    ) {
+        self.log_step(RunStep::Clear {
+            paths: combinations.to_vec(),
+            chain,
+        });
+        let cleared: Vec<ClearedCombination> = combinations
+            .iter()
+            .map(|path| {
+                let mut combination = ClearedCombination::new(
+                    path.iter()
+                        .filter_map(|&(x, y)| self.board.grid[y as usize][x as usize])
+                        .collect(),
+                    self.balance.length_multiplier(path.len()),
+                    chain,
+                );
+                combination.natural = self.board.is_natural(path);
+                combination
+            })
+            .collect();
+
+        // Cards score as their combination explodes
+        for (path, combination) in combinations.iter().zip(&cleared) {
+            for (&position, points) in path.iter().zip(combination.card_points()) {
+                *self.pending_clear_points.entry(position).or_insert(0) += points;
+            }
+        }
+
+        for combination in &cleared {
+            self.record_combination(combination.clone());
+        }
+        for path in combinations {
+            self.score += self.mods.on_combination(path.len(), chain);
+        }
+
+        let naturals: Vec<Vec<(i32, i32)>> = combinations
+            .iter()
+            .zip(&cleared)
+            .filter(|(_, combination)| combination.natural)
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        self.emit(GameEvent::CombinationCleared {
+            points: cleared.iter().map(|combination| combination.points).sum(),
+            positions: positions.to_vec(),
+            combinations: cleared,
+        });
+
+        // Naturals pay their bonus straight away, on top of the points their cards score
+        for path in naturals {
+            let bonus = self.award_points(self.balance.natural_bonus);
+            self.emit(GameEvent::NaturalBlackjack {
+                positions: path,
+                bonus,
+            });
+        }
+    }
+
+    // Keep a combination in the bounded history, dropping the oldest when full
+    fn record_combination(&mut self, combination: ClearedCombination) {
+        if self.combination_history.len() == COMBINATION_HISTORY_LIMIT {
+            self.combination_history.pop_front();
+        }
+        self.combination_history.push_back(combination);
+    }
+
+    // Most recent 21, shown in the info panel
+    pub fn last_combination(&self) -> Option<&ClearedCombination> {
+        self.combination_history.back()
+    }
+
+    // Process delayed destructions
+    fn process_delayed_destructions(&mut self) {
+        let now = Instant::now();
+        let mut processed_any = false;
+        let mut new_destructions = Vec::new();
+        let mut cascade_checks = Vec::new();
+
+        // First pass: collect what needs to be done. Checks wait for cards still falling
+        // into place, since those can't be matched yet.
+        let in_transit = self.board.has_cards_in_transit();
+        self.delayed_destructions.retain(|destruction| {
+            if now >= destruction.destruction_time && !in_transit {
+                cascade_checks.push(destruction.chain_multiplier);
+                processed_any = true;
+                false // Remove this destruction from the queue
+            } else {
+                true // Keep this destruction in the queue
+            }
+        });
+
         // Second pass: handle the cascade checks
-        for (chain_multiplier, combination_index) in cascade_checks {
-            let new_combinations = self.board.check_combinations(self.difficulty);
+        for chain_multiplier in cascade_checks {
+            let combinations = self.find_combinations();
+            let new_combinations = Board::combination_positions(&combinations);
 
             if !new_combinations.is_empty() {
-                // Found new combinations! Mark them for delayed removal
-                let delay_between_cards = Duration::from_millis(COMBINATION_DELAY);
-
-                for (card_index, &position) in new_combinations.iter().enumerate() {
-                    let removal_time = now + delay_between_cards * card_index as u32;
-                    self.board
-                        .mark_cards_for_removal(vec![position], removal_time);
-                }
+                self.emit_combinations_cleared(&combinations, &new_combinations, chain_multiplier);
 
-                // Schedule next cascade check
-                let final_check_time = now + delay_between_cards * new_combinations.len() as u32;
+                // Found new combinations! Mark them for delayed removal and schedule the
+                // next cascade check
+                let final_check_time = self.mark_combinations(&combinations, now);
                 new_destructions.push(DelayedDestruction {
                     destruction_time: final_check_time,
                     chain_multiplier: chain_multiplier + 1,
-                    combination_index: combination_index + 1,
                 });
 
+                // Long cascades slow down for a moment so the player can follow them
+                if chain_multiplier >= SLOW_MOTION_CHAIN {
+                    self.clock.slow_down(
+                        SLOW_MOTION_SCALE,
+                        Duration::from_millis(SLOW_MOTION_MS),
+                        now,
+                    );
+                }
+
                 // Add cascade bonus
-                let cascade_bonus = 50;
-                self.score += cascade_bonus;
+                let bonus = self.award_points(self.balance.cascade_bonus);
+                self.emit(GameEvent::ChainAdvanced {
+                    chain: chain_multiplier,
+                    bonus,
+                });
             } else {
                 // No more combinations found - end the cascade
             }
@@ -686,220 +1518,3028 @@ impl Game {
 
     // Helper methods for state management
     pub fn is_playing(&self) -> bool {
-        self.state.state_name() == "Playing"
+        matches!(self.state, GameState::Playing(_))
     }
 
     pub fn is_paused(&self) -> bool {
-        self.state.state_name() == "Paused"
+        matches!(self.state, GameState::Paused(_))
     }
 
     pub fn is_start_screen(&self) -> bool {
-        self.state.state_name() == "StartScreen"
+        matches!(self.state, GameState::StartScreen(_))
     }
 
     pub fn is_game_over(&self) -> bool {
-        self.state.state_name() == "GameOver"
+        matches!(self.state, GameState::GameOver(_))
     }
 
     pub fn is_quit_confirm(&self) -> bool {
-        self.state.state_name() == "QuitConfirm"
+        matches!(self.state, GameState::QuitConfirm(_))
     }
 
     pub fn is_settings(&self) -> bool {
-        self.state.state_name() == "Settings"
+        matches!(self.state, GameState::Settings(_))
+    }
+
+    pub fn is_setup_wizard(&self) -> bool {
+        matches!(self.state, GameState::SetupWizard(_))
+    }
+
+    pub fn is_combination_log(&self) -> bool {
+        matches!(self.state, GameState::CombinationLog(_))
+    }
+
+    pub fn is_diagnostics(&self) -> bool {
+        matches!(self.state, GameState::Diagnostics(_))
+    }
+
+    pub fn is_accessibility(&self) -> bool {
+        matches!(self.state, GameState::Accessibility(_))
+    }
+
+    /// The season to decorate for, unless the player has turned decorations off
+    pub fn active_season(&self) -> Option<Season> {
+        self.season.filter(|_| self.settings.seasonal_events)
+    }
+
+    pub fn is_appearance(&self) -> bool {
+        matches!(self.state, GameState::Appearance(_))
+    }
+
+    pub fn is_game_setup(&self) -> bool {
+        matches!(self.state, GameState::GameSetup(_))
+    }
+
+    pub fn is_how_to_play(&self) -> bool {
+        matches!(self.state, GameState::HowToPlay(_))
+    }
+
+    pub fn is_restart_confirm(&self) -> bool {
+        matches!(self.state, GameState::RestartConfirm(_))
+    }
+
+    pub fn is_forfeit_confirm(&self) -> bool {
+        matches!(self.state, GameState::ForfeitConfirm(_))
+    }
+
+    pub fn is_statistics_screen(&self) -> bool {
+        matches!(self.state, GameState::StatisticsScreen(_))
+    }
+
+    pub fn is_mods_screen(&self) -> bool {
+        matches!(self.state, GameState::ModsScreen(_))
+    }
+
+    pub fn is_puzzle_select(&self) -> bool {
+        matches!(self.state, GameState::PuzzleSelect(_))
+    }
+
+    pub fn is_seed_entry(&self) -> bool {
+        matches!(self.state, GameState::SeedEntry(_))
+    }
+
+    pub fn is_level_editor(&self) -> bool {
+        matches!(self.state, GameState::LevelEditor(_))
+    }
+
+    /// Move to another screen, running the exit hook of the one being left and the enter
+    /// hook of the new one. Changes within a screen (moving a menu highlight, typing) edit
+    /// `self.state` in place instead, and run neither.
+    pub fn set_state(&mut self, next: impl Into<GameState>) {
+        let previous = std::mem::replace(&mut self.state, next.into());
+        self.on_exit(&previous);
+        self.on_enter();
+    }
+
+    fn on_exit(&mut self, previous: &GameState) {
+        // Whatever replaces play, the clock stops until play comes back
+        if let GameState::Playing(_) = previous {
+            self.suspended_at.get_or_insert_with(Instant::now);
+        }
+    }
+
+    fn on_enter(&mut self) {
+        match self.state {
+            GameState::Playing(_) => self.resume_play_timers(),
+            GameState::StartScreen(_) => {
+                self.puzzle = None;
+                self.game_session_active = false; // End game session when returning to start screen
+            }
+            GameState::GameOver(_) => {
+                self.game_over_started = Some(Instant::now());
+                self.initials_keyboard.reset();
+            }
+            GameState::RestartConfirm(_) => self.add_audio_event(AudioEvent::PauseGame),
+            GameState::QuitConfirm(_) | GameState::ForfeitConfirm(_) => {
+                self.add_audio_event(AudioEvent::OpenQuitConfirmation);
+            }
+            _ => {}
+        }
     }
 
     pub fn transition_to_start_screen(&mut self) {
-        self.state = Box::new(StartScreen);
-        self.game_session_active = false; // End game session when returning to start screen
+        self.set_state(StartScreen);
         self.add_audio_event(AudioEvent::ReturnToGame);
     }
 
     pub fn transition_to_playing(&mut self) {
-        self.state = Box::new(Playing);
+        self.set_state(Playing::default());
         self.add_audio_event(AudioEvent::ResumeGame);
     }
 
     pub fn transition_to_paused(&mut self) {
-        self.state = Box::new(Paused);
+        self.transition_to_paused_for(PauseReason::Manual);
+    }
+
+    pub fn transition_to_paused_for(&mut self, reason: PauseReason) {
+        self.set_state(Paused::new(reason));
         self.add_audio_event(AudioEvent::PauseGame);
     }
 
+    // Called when the window loses focus; only an active run needs protecting
+    pub fn pause_for_focus_loss(&mut self) {
+        if self.is_playing() {
+            self.transition_to_paused_for(PauseReason::FocusLost);
+        }
+    }
+
+    // Record player input during play, which holds off the idle pause
+    pub fn note_input(&mut self) {
+        self.last_input_time = Instant::now();
+    }
+
+    // Push the play timers forward by the time spent away, so the card doesn't drop
+    // (and the speed doesn't jump) to catch up on the frames that never ran
+    fn resume_play_timers(&mut self) {
+        if let Some(suspended_at) = self.suspended_at.take() {
+            let away = suspended_at.elapsed();
+            self.last_fall_time += away;
+            self.last_speed_increase += away;
+            self.clock.delay(away);
+            self.board.delay_locks(away);
+            if let Some(started) = self.lock_started.as_mut() {
+                *started += away; // A slide's lock delay doesn't run out while play is stopped
+            }
+        }
+        self.last_input_time = Instant::now();
+    }
+
     pub fn transition_to_game_over(&mut self) {
-        self.state = Box::new(GameOver);
-        self.add_audio_event(AudioEvent::GameOver);
+        self.set_state(GameOver::default());
+        self.emit(GameEvent::GameOver { score: self.score });
+        self.record_run(RunOutcome::Finished);
+
+        // Look up where this run would land before initials are entered
+        if self.score > 0 {
+            self.score_worker.send(ScoreRequest::Rank {
+                score: self.score,
+                difficulty: self.difficulty,
+                relaxed: self.run_relaxed,
+            });
+        }
+    }
+
+    // Ask for the best saved score to beat on this run's difficulty and mode
+    fn request_personal_best(&mut self) {
+        self.score_worker.send(ScoreRequest::PersonalBest {
+            difficulty: self.difficulty,
+            relaxed: self.run_relaxed,
+        });
+    }
+
+    // Flash the banner the first time this run's score passes the personal best
+    fn check_personal_best(&mut self) {
+        if self.personal_best_beaten.is_none()
+            && let Some(best) = self.personal_best
+            && self.score > best
+        {
+            self.personal_best_beaten = Some(Instant::now());
+            self.emit(GameEvent::NewPersonalBest { score: self.score });
+        }
+    }
+
+    /// The score to beat for the info panel: the saved best, or this run's score once it
+    /// has passed it. None before the first saved score.
+    pub fn best_to_show(&self) -> Option<i32> {
+        self.personal_best.map(|best| best.max(self.score))
+    }
+
+    /// How far through flipping face up the next card preview is, from 0.0 to 1.0. None
+    /// once the card is showing.
+    pub fn next_card_reveal_progress(&self) -> Option<f32> {
+        self.next_card?;
+        let elapsed = self.next_card_dealt?.elapsed();
+        let duration = Duration::from_millis(NEXT_CARD_REVEAL_MS);
+        (elapsed < duration).then(|| elapsed.as_secs_f32() / duration.as_secs_f32())
+    }
+
+    /// How far through the "New PB!" flash play is, from 0.0 to 1.0. None when the
+    /// personal best hasn't just been passed.
+    pub fn personal_best_banner_progress(&self) -> Option<f32> {
+        let elapsed = self.personal_best_beaten?.elapsed();
+        let duration = Duration::from_millis(PERSONAL_BEST_BANNER_MS);
+        (elapsed < duration).then(|| elapsed.as_secs_f32() / duration.as_secs_f32())
+    }
+
+    /// Apply any score worker results that have arrived; called once per frame
+    pub fn poll_score_worker(&mut self) {
+        for response in self.score_worker.poll() {
+            self.handle_score_response(response);
+        }
+    }
+
+    fn handle_score_response(&mut self, response: ScoreResponse) {
+        match response {
+            ScoreResponse::HighScoresUpdated(scores) => self.high_scores = scores,
+            ScoreResponse::Ranked {
+                score,
+                rank,
+                percentile,
+            } => {
+                // Ignore late answers for a run the player has already left
+                let still_on_game_over = self.is_game_over() && self.score == score;
+                if still_on_game_over {
+                    self.run_percentile = percentile;
+                }
+                if still_on_game_over && rank <= NEW_RECORD_RANK_LIMIT {
+                    self.new_record_rank = Some(rank);
+                    self.celebration_started = Some(Instant::now());
+                    self.emit(GameEvent::NewRecord { rank });
+                }
+            }
+            ScoreResponse::PersonalBest {
+                difficulty,
+                relaxed,
+                score,
+            } => {
+                // Only for the run being played, and puzzles have no leaderboard to beat
+                let this_run = self.game_session_active
+                    && self.puzzle.is_none()
+                    && self.difficulty == difficulty
+                    && self.run_relaxed == relaxed;
+                if this_run {
+                    self.personal_best = score;
+                }
+            }
+            ScoreResponse::SaveFailed(message) => {
+                eprintln!("Failed to save high score: {}", message);
+                self.emit(GameEvent::ScoreSaveFailed { message });
+            }
+            ScoreResponse::StatisticsUpdated(statistics) => self.statistics = statistics,
+            ScoreResponse::LoadFailed(message) => {
+                eprintln!("Failed to load high scores: {}", message);
+            }
+            ScoreResponse::RankFailed(message) => {
+                eprintln!("Failed to look up score rank: {}", message);
+            }
+            ScoreResponse::PersonalBestFailed(message) => {
+                eprintln!("Failed to look up personal best: {}", message);
+            }
+            ScoreResponse::RecordRunFailed(message) => {
+                eprintln!("Failed to update statistics: {}", message);
+            }
+            ScoreResponse::PuzzleStarsUpdated(puzzle_stars) => self.puzzle_stars = puzzle_stars,
+            ScoreResponse::RecordPuzzleFailed(message) => {
+                eprintln!("Failed to save puzzle stars: {}", message);
+            }
+        }
+    }
+
+    /// How many frames back the game over replay is showing; 0 when it isn't
+    pub fn replay_step(&self) -> usize {
+        match &self.state {
+            GameState::GameOver(game_over) => game_over.replay_step,
+            _ => 0,
+        }
+    }
+
+    /// Step the game over replay `steps` further back in time (negative steps forward),
+    /// staying between the final board and the oldest recorded frame
+    pub fn step_replay(&mut self, steps: i32) {
+        let oldest = self.board_history.len().saturating_sub(1);
+        if let GameState::GameOver(game_over) = &mut self.state {
+            game_over.replay_step =
+                (game_over.replay_step as i64 + steps as i64).clamp(0, oldest as i64) as usize;
+        }
+    }
+
+    /// The recorded board the replay is showing, if the player has stepped back
+    pub fn replay_frame(&self) -> Option<&BoardFrame> {
+        match self.replay_step() {
+            0 => None,
+            step => self.board_history.frame(step),
+        }
+    }
+
+    /// How far through the zoom toward the losing column the game over screen is, from 0.0
+    /// to 1.0. None once it has finished, when reduced motion is on, or when no column
+    /// reached the top.
+    pub fn game_over_zoom_progress(&self) -> Option<f32> {
+        if self.settings.reduced_motion || !self.is_game_over() {
+            return None;
+        }
+        self.losing_column?;
+        let elapsed = self.game_over_started?.elapsed();
+        let duration = Duration::from_millis(GAME_OVER_ZOOM_MS);
+        (elapsed < duration).then(|| elapsed.as_secs_f32() / duration.as_secs_f32())
+    }
+
+    /// Whether game over takes input yet. A key that was down when the run ended (a hard drop
+    /// or an Enter meant for the board) would otherwise skip straight through initials, so
+    /// nothing counts for a moment after game over starts, and then not until every key and
+    /// button from play has been let go.
+    pub fn game_over_accepts_input(&mut self, input: &InputSnapshot) -> bool {
+        let GameState::GameOver(over) = &mut self.state else {
+            return false;
+        };
+        if !over.armed {
+            let debounce = Duration::from_millis(GAME_OVER_INPUT_DEBOUNCE_MS);
+            let settled = self
+                .game_over_started
+                .is_none_or(|started| input.now.saturating_duration_since(started) >= debounce);
+            if !settled || input.any_held {
+                return false;
+            }
+            over.armed = true;
+        }
+        true
+    }
+
+    /// Whether the new record banner is still playing (initials entry waits for it)
+    pub fn is_celebrating_new_record(&self) -> bool {
+        self.celebration_started.is_some_and(|started| {
+            started.elapsed() < Duration::from_millis(NEW_RECORD_CELEBRATION_MS)
+        })
     }
 
     pub fn transition_to_quit_confirm(&mut self) {
-        self.state = Box::new(QuitConfirm);
-        self.add_audio_event(AudioEvent::OpenQuitConfirmation);
+        self.set_state(QuitConfirm);
     }
 
-    pub fn transition_to_settings(&mut self, previous_state_name: String) {
-        self.state = Box::new(Settings::new(previous_state_name));
+    pub fn transition_to_settings(&mut self, origin: SettingsOrigin) {
+        self.set_state(Settings::new(origin));
         // Settings screen uses existing audio events - no new event needed
     }
 
-    // Audio event management
-    pub fn add_audio_event(&mut self, event: AudioEvent) {
-        self.pending_audio_events.push(event);
+    /// Leave the settings screen for wherever it was opened from
+    pub fn return_from_settings(&mut self) {
+        let origin = match &self.state {
+            GameState::Settings(settings) => settings.origin.clone(),
+            _ => return,
+        };
+        match origin {
+            SettingsOrigin::StartScreen => self.transition_to_start_screen(),
+            SettingsOrigin::Playing => self.transition_to_playing(),
+            SettingsOrigin::Paused(paused) => {
+                self.set_state(paused);
+                self.add_audio_event(AudioEvent::PauseGame);
+            }
+        }
     }
 
-    pub fn take_pending_audio_events(&mut self) -> Vec<AudioEvent> {
-        std::mem::take(&mut self.pending_audio_events)
+    // First-run setup wizard. The wizard's position lives in its state.
+    fn setup_wizard(&self) -> Option<SetupWizard> {
+        match &self.state {
+            GameState::SetupWizard(wizard) => Some(wizard.clone()),
+            _ => None,
+        }
     }
 
-    /// Save current settings to disk
-    pub fn save_settings(&self) {
-        if let Err(e) = self.settings.save() {
-            eprintln!("Failed to save settings: {}", e);
+    pub fn setup_wizard_next(&mut self) {
+        let GameState::SetupWizard(wizard) = &mut self.state else {
+            return;
+        };
+        match wizard.step.next() {
+            Some(step) => wizard.step = step,
+            None => self.finish_setup_wizard(),
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use tempfile::TempDir;
+    pub fn setup_wizard_back(&mut self) {
+        if let GameState::SetupWizard(wizard) = &mut self.state
+            && let Some(step) = wizard.step.previous()
+        {
+            wizard.step = step;
+        }
+    }
 
-    // Test fixtures for game testing
-    mod test_fixtures {
-        use super::*;
-        use tempfile;
+    /// Move between the rows of the current step (only the volume step has more than one)
+    pub fn setup_wizard_select_row(&mut self, step: i32) {
+        if let GameState::SetupWizard(wizard) = &mut self.state
+            && wizard.step == SetupStep::Volume
+        {
+            wizard.volume_row = (wizard.volume_row as i32 + step).rem_euclid(2) as usize;
+        }
+    }
 
-        pub fn create_temp_database() -> (Database, TempDir) {
-            let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
-            let db_path = temp_dir.path().join("test_game.db");
-            let db = Database::new(&db_path).expect("Failed to create test database");
-            (db, temp_dir)
+    /// Change the value on the current step (left = -1, right = +1)
+    pub fn setup_wizard_adjust(&mut self, step: i32) {
+        let Some(wizard) = self.setup_wizard() else {
+            return;
+        };
+        let settings = &mut self.settings;
+        match wizard.step {
+            SetupStep::Controls => settings.control_scheme = settings.control_scheme.cycle(step),
+            SetupStep::Volume => {
+                let volume = if wizard.volume_row == 0 {
+                    &mut settings.music_volume
+                } else {
+                    &mut settings.sound_effects_volume
+                };
+                *volume = (*volume + step as f32 * 0.1).clamp(0.0, 1.0);
+            }
+            SetupStep::Difficulty => {
+                settings.difficulty = match settings.difficulty {
+                    Difficulty::Easy => Difficulty::Hard,
+                    Difficulty::Hard => Difficulty::Easy,
+                };
+                self.difficulty = settings.difficulty;
+            }
         }
+        self.add_audio_event(AudioEvent::DifficultyChange);
+    }
 
-        pub fn create_test_game() -> (Game, TempDir) {
-            let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
-            let db_path = temp_dir.path().join("test_game.db");
+    /// Save the choices made in the wizard and continue to the start screen
+    pub fn finish_setup_wizard(&mut self) {
+        self.request_settings_save();
+        self.flush_settings();
+        self.set_state(StartScreen);
+        self.add_audio_event(AudioEvent::StartGame);
+    }
 
-            let game = Game::builder()
-                .database_path(&db_path)
-                .build()
-                .expect("Failed to create test game");
+    /// The main menu entries showing right now
+    pub fn main_menu(&self) -> MenuModel {
+        let options = MainMenuOption::ALL
+            .into_iter()
+            .filter(|option| match option {
+                MainMenuOption::Continue => self.saved_run.is_some(),
+                _ => true,
+            })
+            .collect();
+        MenuModel { options }
+    }
 
-            (game, temp_dir)
+    /// Move the main menu highlight by `step` entries
+    pub fn select_main_option(&mut self, step: i32) {
+        self.selected_main_option = self.main_menu().cycle(self.selected_main_option, step);
+    }
+
+    /// Carry out the highlighted main menu entry
+    pub fn choose_main_option(&mut self) {
+        let menu = self.main_menu();
+        let Some(&option) = menu.options.get(menu.focused(self.selected_main_option)) else {
+            return;
+        };
+        match option {
+            MainMenuOption::Continue => {
+                self.continue_saved_run();
+            }
+            MainMenuOption::NewGame => self.start_game(self.settings.difficulty),
+            MainMenuOption::PlayFromSeed => self.transition_to_seed_entry(),
+            MainMenuOption::Puzzles => self.transition_to_puzzle_select(),
+            MainMenuOption::GameSetup => self.transition_to_game_setup(),
+            MainMenuOption::Settings => self.transition_to_settings(SettingsOrigin::StartScreen),
+            MainMenuOption::Statistics => self.transition_to_statistics_screen(),
+            MainMenuOption::Mods => self.transition_to_mods_screen(),
+            MainMenuOption::Quit => self.transition_to_quit_confirm(),
         }
+    }
 
-        pub fn create_test_game_with_config(
-            width: i32,
-            height: i32,
-            difficulty: Difficulty,
-        ) -> (Game, TempDir) {
-            let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
-            let db_path = temp_dir.path().join("test_game.db");
+    /// The highlighted pause menu entry, while paused
+    pub fn pause_menu_selection(&self) -> Option<PauseMenuOption> {
+        match &self.state {
+            GameState::Paused(paused) => Some(paused.selected),
+            _ => None,
+        }
+    }
 
-            let game = Game::builder()
-                .board_size(width, height)
-                .difficulty(difficulty)
-                .database_path(&db_path)
-                .build()
-                .expect("Failed to create test game");
+    pub fn select_pause_option(&mut self, step: i32) {
+        if let GameState::Paused(paused) = &mut self.state {
+            paused.selected = paused.selected.cycle(step);
+        }
+    }
 
-            (game, temp_dir)
+    /// Carry out the highlighted pause menu entry
+    pub fn choose_pause_option(&mut self) {
+        let Some(option) = self.pause_menu_selection() else {
+            return;
+        };
+        match option {
+            PauseMenuOption::Resume => self.transition_to_playing(),
+            PauseMenuOption::Restart => self.transition_to_restart_confirm(),
+            PauseMenuOption::Settings => {
+                if let GameState::Paused(paused) = &self.state {
+                    self.transition_to_settings(SettingsOrigin::Paused(paused.clone()));
+                }
+            }
+            PauseMenuOption::HowToPlay => self.transition_to_how_to_play(),
+            PauseMenuOption::QuitToMenu => self.transition_to_forfeit_confirm(),
         }
+    }
 
-        pub fn create_test_playing_card() -> PlayingCard {
-            PlayingCard::builder(
-                Card::new(crate::models::Suit::Hearts, crate::models::Value::Ace),
-                Position { x: 2, y: 1 },
-            )
-            .build()
+    /// Ask before leaving the run from the pause menu, offering to save it instead
+    pub fn transition_to_forfeit_confirm(&mut self) {
+        if let GameState::Paused(paused) = &self.state {
+            self.set_state(ForfeitConfirm {
+                return_to: paused.clone(),
+                selected: ForfeitOption::Cancel,
+            });
+        }
+    }
+
+    /// The highlighted quit-to-menu choice, while that dialog is open
+    pub fn forfeit_selection(&self) -> Option<ForfeitOption> {
+        match &self.state {
+            GameState::ForfeitConfirm(confirm) => Some(confirm.selected),
+            _ => None,
+        }
+    }
+
+    pub fn select_forfeit_option(&mut self, step: i32) {
+        if let GameState::ForfeitConfirm(confirm) = &mut self.state {
+            confirm.selected = confirm.selected.cycle(step);
+        }
+    }
+
+    pub fn choose_forfeit_option(&mut self) {
+        match self.forfeit_selection() {
+            Some(ForfeitOption::Cancel) => self.cancel_forfeit(),
+            Some(ForfeitOption::SaveAndQuit) => self.save_and_quit(),
+            Some(ForfeitOption::Forfeit) => self.forfeit_run(),
+            None => {}
+        }
+    }
+
+    pub fn cancel_forfeit(&mut self) {
+        if let GameState::ForfeitConfirm(confirm) = &self.state {
+            self.set_state(confirm.return_to.clone());
+        }
+    }
+
+    /// Throw the run away and go back to the start screen, counting it as abandoned. A
+    /// puzzle goes back to the level list instead, and doesn't count towards the statistics.
+    pub fn forfeit_run(&mut self) {
+        if !self.is_forfeit_confirm() {
+            return;
+        }
+        if let Some(run) = self.puzzle.take() {
+            self.add_audio_event(AudioEvent::ForfeitGame);
+            self.leave_puzzle(run.origin);
+            return;
+        }
+        self.record_run(RunOutcome::Abandoned);
+        self.add_audio_event(AudioEvent::ForfeitGame);
+        self.transition_to_start_screen();
+    }
+
+    /// Keep the run to continue later and go back to the start screen. If it can't be
+    /// written the dialog stays open, so the run isn't lost without the player knowing.
+    /// Puzzles are short enough that they aren't saved; leaving one just forfeits it.
+    pub fn save_and_quit(&mut self) {
+        if !self.is_forfeit_confirm() {
+            return;
+        }
+        if self.puzzle.is_some() {
+            self.forfeit_run();
+            return;
+        }
+        let run = self.snapshot_run();
+        match self.saved_run_store.save(&run) {
+            Ok(()) => {
+                self.saved_run = Some(run);
+                self.record_run(RunOutcome::Saved);
+                self.transition_to_start_screen();
+            }
+            Err(e) => {
+                eprintln!("Failed to save run: {}", e);
+                self.emit(GameEvent::RunSaveFailed {
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
+    /// The current run as it would be saved. Cards waiting to explode count as cleared and
+    /// cards still dropping as landed, so the saved board has nothing in flight.
+    pub fn snapshot_run(&self) -> SavedRun {
+        use chrono::Local;
+
+        let mut board = Board::new(self.board.width, self.board.height, self.board.cell_size);
+        board.grid = self.board.grid.clone();
+
+        let mut score = self.score;
+        for (y, row) in self.board.marked_for_removal.iter().enumerate() {
+            for (x, marked) in row.iter().enumerate() {
+                let position = (x as i32, y as i32);
+                if marked.is_some() && board.remove_card(position.0, position.1).is_some() {
+                    let points = self.pending_clear_points.get(&position).copied();
+                    score += self.mods.modify_score(points.unwrap_or(0));
+                }
+            }
         }
+        for dropping in &self.hard_dropping_cards {
+            board.place_card(dropping.target.x, dropping.target.y, dropping.card);
+        }
+        while board.apply_gravity() {}
+
+        SavedRun {
+            difficulty: self.difficulty,
+            score,
+            speed_level: self.speed_level,
+            grid: board.grid,
+            deck: self.deck.cards().to_vec(),
+            current_card: self.current_card.as_ref().map(|playing| playing.card),
+            current_column: self
+                .current_card
+                .as_ref()
+                .map(|playing| playing.target.x)
+                .or(self.last_dropped_x)
+                .unwrap_or(self.board.width / 2),
+            next_card: self.next_card,
+            saved_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            modded: self.run_modded,
+            deck_template: self.deck.template(),
+            relaxed: self.run_relaxed,
+            modified: self.run_modified,
+        }
+    }
+
+    /// Pick up the run left with Save & Quit. The save is used up: continuing again later
+    /// needs a new Save & Quit. Returns false when there is nothing to continue.
+    pub fn continue_saved_run(&mut self) -> bool {
+        let Some(run) = self.saved_run.take() else {
+            return false;
+        };
+        if let Err(e) = self.saved_run_store.clear() {
+            eprintln!("Failed to clear saved run: {}", e);
+        }
+
+        self.start_game(run.difficulty);
+        self.run_seed = None; // The save keeps the draw pile, not the seed's shuffles
+        self.run_log = None;
+        self.run_modded |= run.modded;
+        self.run_relaxed = run.relaxed;
+        self.run_modified = run.modified;
+        self.request_personal_best(); // Again, in case the saved run's mode isn't the current one
+        self.score = run.score;
+        self.speed_level = run.speed_level;
+        self.fall_speed = self.speed_params().fall_speed_at(run.speed_level);
+        self.board.grid = run.grid;
+
+        // The falling card starts again from the top of the column it was in
+        self.current_card = None;
+        self.last_dropped_x = Some(run.current_column);
+        self.next_card = run.current_card;
+        self.spawn_new_card();
+        self.next_card = run.next_card;
+        self.deck = Deck::resume(run.deck_template, run.deck, &self.cards_in_play());
+
+        // A cascade may have been about to continue when the run was saved
+        self.process_combinations();
+        true
+    }
+
+    // Cards drawn from the deck that haven't been cleared yet
+    fn cards_in_play(&self) -> Vec<Card> {
+        let placed = self.board.grid.iter().flatten().flatten().copied();
+        let dropping = self
+            .hard_dropping_cards
+            .iter()
+            .map(|dropping| dropping.card);
+        placed
+            .chain(dropping)
+            .chain(self.current_card.as_ref().map(|playing| playing.card))
+            .chain(self.next_card)
+            .collect()
+    }
+
+    /// Chance the card drawn after the next one finishes a 21, for the odds readout
+    pub fn next_draw_chance(&self) -> Option<f32> {
+        odds::next_draw_chance(&self.board, self.deck.upcoming(), self.difficulty)
+    }
+
+    /// Everything on the board right now, in flight or not, for reproducing board bugs
+    pub fn debug_snapshot(&self) -> DebugSnapshot {
+        let cells_where = |cells: &Vec<Vec<Option<Instant>>>| -> Vec<(i32, i32)> {
+            cells
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|(_, time)| time.is_some())
+                        .map(move |(x, _)| (x as i32, y as i32))
+                })
+                .collect()
+        };
+
+        DebugSnapshot {
+            build: BuildInfo::CURRENT.to_string(),
+            taken_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            difficulty: self.difficulty,
+            score: self.score,
+            speed_level: self.speed_level,
+            grid: self.board.grid.clone(),
+            marked_for_removal: cells_where(&self.board.marked_for_removal),
+            locked: cells_where(&self.board.locked),
+            current_card: self
+                .current_card
+                .as_ref()
+                .map(|playing| (playing.card, (playing.position.x, playing.position.y))),
+            hard_drops: self
+                .hard_dropping_cards
+                .iter()
+                .map(|dropping| (dropping.card, (dropping.target.x, dropping.target.y)))
+                .collect(),
+            deck: self.deck.cards().to_vec(),
+            deck_template: self.deck.template(),
+            next_card: self.next_card,
+            settings: self.settings.clone(),
+        }
+    }
+
+    /// Start a run from a snapshot. Cards that were dropping land straight away, busted
+    /// cards get a fresh lock, and 21s waiting to explode are found and cleared again.
+    pub fn restore_debug_snapshot(&mut self, snapshot: DebugSnapshot) -> Result<(), String> {
+        if !snapshot.fits(self.board.width, self.board.height) {
+            return Err(format!(
+                "the snapshot's board isn't {}x{}",
+                self.board.width, self.board.height
+            ));
+        }
+
+        self.start_game(snapshot.difficulty);
+        self.run_seed = None;
+        self.run_log = None;
+        self.run_modified = true; // Set up by hand, so its score is flagged like an edited save
+        self.score = snapshot.score;
+        self.speed_level = snapshot.speed_level;
+        self.fall_speed = self.speed_params().fall_speed_at(snapshot.speed_level);
+        self.board.grid = snapshot.grid;
+        for (card, (x, y)) in snapshot.hard_drops {
+            self.board.place_card(x, y, card);
+        }
+        let unlock_time = Instant::now() + Duration::from_millis(BUST_LOCK_MS);
+        self.board.lock_cards(&snapshot.locked, unlock_time);
+
+        let cell_size = self.board.cell_size;
+        self.current_card = snapshot.current_card.map(|(card, (x, y))| {
+            let position = Position { x, y };
+            PlayingCard::builder(card, position)
+                .cell_size(cell_size)
+                .visual_position(VisualPosition {
+                    x: (x * cell_size) as f32,
+                    y: (y * cell_size) as f32,
+                })
+                .target(position)
+                .falling(false)
+                .hard_dropping(false)
+                .build()
+        });
+        self.next_card = snapshot.next_card;
+        self.deck = Deck::resume(snapshot.deck_template, snapshot.deck, &self.cards_in_play());
+
+        self.process_combinations();
+        Ok(())
+    }
+
+    /// Write a debug snapshot to the app data directory (F5)
+    pub fn save_debug_snapshot(&mut self) {
+        let result = serde_json::to_string_pretty(&self.debug_snapshot())
+            .map_err(|e| e.into())
+            .and_then(|json| platform_storage()?.write(DEBUG_SNAPSHOT_KEY, &json));
+        match result {
+            Ok(()) => self.emit(GameEvent::SnapshotSaved {
+                key: DEBUG_SNAPSHOT_KEY.to_string(),
+            }),
+            Err(e) => {
+                eprintln!("Failed to save debug snapshot: {}", e);
+                self.emit(GameEvent::SnapshotFailed {
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
+    /// Replace the current run with the one in the app data directory's snapshot (F9)
+    pub fn load_debug_snapshot(&mut self) {
+        let result = platform_storage()
+            .and_then(|storage| {
+                storage
+                    .read(DEBUG_SNAPSHOT_KEY)?
+                    .ok_or_else(|| format!("no {} saved yet", DEBUG_SNAPSHOT_KEY).into())
+            })
+            .and_then(|json| Ok(serde_json::from_str::<DebugSnapshot>(&json)?))
+            .and_then(|snapshot| Ok(self.restore_debug_snapshot(snapshot)?));
+        match result {
+            Ok(()) => self.emit(GameEvent::SnapshotRestored {
+                key: DEBUG_SNAPSHOT_KEY.to_string(),
+            }),
+            Err(e) => {
+                eprintln!("Failed to restore debug snapshot: {}", e);
+                self.emit(GameEvent::SnapshotFailed {
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
+    // Statistics are kept by the score store, so they are written on the worker thread.
+    // Puzzles aren't runs and leave the statistics alone.
+    fn record_run(&mut self, outcome: RunOutcome) {
+        if self.puzzle.is_some() {
+            return;
+        }
+        let column_drops = std::mem::take(&mut self.run_column_drops);
+        self.score_worker.send(ScoreRequest::RecordRun {
+            outcome,
+            column_drops,
+        });
+    }
+
+    // Score goes through the mods' modify_score hook before it is added
+    fn award_points(&mut self, points: i32) -> i32 {
+        let points = self.mods.modify_score(points);
+        self.score = self.score.saturating_add(points); // A mod can hand out any amount
+        points
+    }
+
+    fn count_column_drop(&mut self, x: i32) {
+        let Ok(column) = usize::try_from(x) else {
+            return;
+        };
+        if self.run_column_drops.len() <= column {
+            self.run_column_drops.resize(column + 1, 0);
+        }
+        self.run_column_drops[column] += 1;
+    }
+
+    pub fn transition_to_statistics_screen(&mut self) {
+        if self.is_start_screen() {
+            self.set_state(StatisticsScreen::default());
+        }
+    }
+
+    pub fn return_from_statistics_screen(&mut self) {
+        if self.is_statistics_screen() {
+            self.set_state(StartScreen);
+        }
+    }
+
+    pub fn transition_to_mods_screen(&mut self) {
+        if self.is_start_screen() {
+            self.set_state(ModsScreen::default());
+        }
+    }
+
+    pub fn return_from_mods_screen(&mut self) {
+        if self.is_mods_screen() {
+            self.set_state(StartScreen);
+        }
+    }
+
+    pub fn transition_to_game_setup(&mut self) {
+        if self.is_start_screen() {
+            self.set_state(GameSetup::default());
+        }
+    }
+
+    pub fn return_from_game_setup(&mut self) {
+        if self.is_game_setup() {
+            self.set_state(StartScreen);
+        }
+    }
+
+    /// The highlighted row, while the game setup screen is open
+    pub fn game_setup_selection(&self) -> Option<GameSetupOption> {
+        match &self.state {
+            GameState::GameSetup(setup) => Some(setup.selected),
+            _ => None,
+        }
+    }
+
+    pub fn select_game_setup_option(&mut self, step: i32) {
+        if let GameState::GameSetup(setup) = &mut self.state {
+            setup.selected = setup.selected.cycle(step);
+        }
+    }
+
+    /// Change the highlighted rule for the next run: choices step whichever way `step`
+    /// points, on/off rows flip
+    pub fn change_game_setup_option(&mut self, step: i32) {
+        match self.game_setup_selection() {
+            Some(GameSetupOption::DeckTemplate) => {
+                self.settings.deck_template = self.settings.deck_template.cycle(step);
+            }
+            Some(GameSetupOption::Handicap) => self.settings.adjust_handicap_rows(step),
+            Some(GameSetupOption::HardDropSteer) => {
+                self.settings.hard_drop_steer = !self.settings.hard_drop_steer;
+            }
+            Some(GameSetupOption::SlideDelay) => {
+                self.settings.slide_delay = !self.settings.slide_delay;
+            }
+            Some(GameSetupOption::BustRule) => {
+                self.settings.bust_rule = !self.settings.bust_rule;
+            }
+            Some(GameSetupOption::RelaxMode) => {
+                self.settings.relax_mode = !self.settings.relax_mode;
+            }
+            Some(GameSetupOption::AdaptiveSpeed) => {
+                self.settings.adaptive_speed = !self.settings.adaptive_speed;
+            }
+            Some(GameSetupOption::DrawOdds) => {
+                self.settings.draw_odds = !self.settings.draw_odds;
+            }
+            None => return,
+        }
+        if !self.settings.sound_effects_muted {
+            self.add_audio_event(AudioEvent::DifficultyChange);
+        }
+        self.request_settings_save();
+    }
+
+    pub fn transition_to_seed_entry(&mut self) {
+        if self.is_start_screen() {
+            self.set_state(SeedEntry::default());
+        }
+    }
+
+    pub fn return_from_seed_entry(&mut self) {
+        if self.is_seed_entry() {
+            self.set_state(StartScreen);
+        }
+    }
+
+    pub fn seed_entry(&self) -> Option<SeedEntry> {
+        match &self.state {
+            GameState::SeedEntry(entry) => Some(entry.clone()),
+            _ => None,
+        }
+    }
+
+    // Seed codes are typed like initials: letters only, in capitals, up to the code's length
+    pub fn add_seed_char(&mut self, c: char) {
+        if let GameState::SeedEntry(entry) = &mut self.state
+            && entry.code.len() < RunSeed::LENGTH
+            && c.is_ascii_alphabetic()
+        {
+            entry.code.push(c.to_ascii_uppercase());
+        }
+    }
+
+    /// Replace the typed code, e.g. with one pasted in
+    pub fn set_seed_code(&mut self, code: &str) {
+        if let GameState::SeedEntry(entry) = &mut self.state {
+            entry.code.clear();
+            code.trim().chars().for_each(|c| self.add_seed_char(c));
+        }
+    }
+
+    pub fn remove_seed_char(&mut self) {
+        if let GameState::SeedEntry(entry) = &mut self.state {
+            entry.code.pop();
+        }
+    }
+
+    /// Move the seed entry's on-screen keyboard, e.g. with `VirtualKeyboard::move_left`
+    pub fn move_seed_keyboard(&mut self, step: impl FnOnce(&mut VirtualKeyboard)) {
+        if let GameState::SeedEntry(entry) = &mut self.state {
+            step(&mut entry.keyboard);
+        }
+    }
+
+    /// Start a run from the typed seed code, on the chosen difficulty. False while the code
+    /// isn't a whole seed yet.
+    pub fn play_from_seed(&mut self) -> bool {
+        let Some(seed) = self
+            .seed_entry()
+            .and_then(|entry| RunSeed::parse(&entry.code))
+        else {
+            return false;
+        };
+        self.start_seeded_game(self.settings.difficulty, seed);
+        true
+    }
+
+    pub fn transition_to_puzzle_select(&mut self) {
+        if self.is_start_screen() {
+            self.set_state(PuzzleSelect::default());
+        }
+    }
+
+    pub fn return_from_puzzle_select(&mut self) {
+        if self.is_puzzle_select() {
+            self.set_state(StartScreen);
+        }
+    }
+
+    /// The highlighted level on the puzzle select screen
+    pub fn puzzle_selection(&self) -> Option<usize> {
+        match &self.state {
+            GameState::PuzzleSelect(screen) => Some(screen.selected),
+            _ => None,
+        }
+    }
+
+    pub fn select_puzzle(&mut self, step: i32) {
+        let levels = self.puzzle_levels.len().max(1) as i32;
+        if let GameState::PuzzleSelect(screen) = &mut self.state {
+            screen.selected = (screen.selected as i32 + step).rem_euclid(levels) as usize;
+        }
+    }
+
+    pub fn start_selected_puzzle(&mut self) {
+        if let Some(selected) = self.puzzle_selection() {
+            self.start_puzzle(selected);
+        }
+    }
+
+    /// Open the level editor from the level list, picking up the draft left there last time
+    /// or starting a board-sized one under the next free custom id
+    pub fn transition_to_level_editor(&mut self) {
+        if !self.is_puzzle_select() {
+            return;
+        }
+        if self.level_draft.is_none() {
+            let number = (1..)
+                .find(|n| {
+                    let id = format!("custom_{}", n);
+                    !self.puzzle_levels.iter().any(|level| level.id == id)
+                })
+                .unwrap_or(1);
+            self.level_draft = Some(LevelDraft::new(
+                format!("custom_{}", number),
+                format!("Custom {}", number),
+                self.board.width,
+                self.board.height,
+            ));
+        }
+        self.set_state(LevelEditor::default());
+    }
+
+    pub fn return_from_level_editor(&mut self) {
+        if self.is_level_editor() {
+            self.set_state(PuzzleSelect::default());
+        }
+    }
+
+    /// The editor's column and brush, while it's open
+    pub fn level_editor(&self) -> Option<LevelEditor> {
+        match self.state {
+            GameState::LevelEditor(editor) => Some(editor),
+            _ => None,
+        }
+    }
+
+    pub fn move_editor_column(&mut self, step: i32) {
+        let columns = self.board.width.max(1);
+        if let GameState::LevelEditor(editor) = &mut self.state {
+            editor.column = (editor.column + step).rem_euclid(columns);
+        }
+    }
+
+    pub fn change_editor_brush_value(&mut self, step: i32) {
+        if let GameState::LevelEditor(editor) = &mut self.state {
+            let values = Value::all();
+            let index = values
+                .iter()
+                .position(|&v| v == editor.brush.value)
+                .unwrap_or(0);
+            let next = (index as i32 + step).rem_euclid(values.len() as i32) as usize;
+            editor.brush = Card::new(editor.brush.suit, values[next]);
+        }
+    }
+
+    pub fn cycle_editor_brush_suit(&mut self) {
+        if let GameState::LevelEditor(editor) = &mut self.state {
+            let suits = Suit::all();
+            let index = suits
+                .iter()
+                .position(|&s| s == editor.brush.suit)
+                .unwrap_or(0);
+            editor.brush = Card::new(suits[(index + 1) % suits.len()], editor.brush.value);
+        }
+    }
+
+    pub fn stack_editor_card(&mut self) {
+        if let (Some(editor), Some(draft)) = (self.level_editor(), self.level_draft.as_mut())
+            && draft.stack_card(editor.column, editor.brush)
+        {
+            self.add_audio_event(AudioEvent::DropCard);
+        }
+    }
+
+    pub fn unstack_editor_card(&mut self) {
+        if let (Some(editor), Some(draft)) = (self.level_editor(), self.level_draft.as_mut()) {
+            draft.unstack_card(editor.column);
+        }
+    }
+
+    pub fn queue_editor_card(&mut self) {
+        if let (Some(editor), Some(draft)) = (self.level_editor(), self.level_draft.as_mut()) {
+            draft.queue_card(editor.brush);
+        }
+    }
+
+    pub fn unqueue_editor_card(&mut self) {
+        if self.is_level_editor()
+            && let Some(draft) = self.level_draft.as_mut()
+        {
+            draft.unqueue_card();
+        }
+    }
+
+    pub fn adjust_editor_par(&mut self, steps: i32) {
+        if self.is_level_editor()
+            && let Some(draft) = self.level_draft.as_mut()
+        {
+            draft.adjust_par(steps);
+        }
+    }
+
+    pub fn toggle_editor_difficulty(&mut self) {
+        if self.is_level_editor()
+            && let Some(draft) = self.level_draft.as_mut()
+        {
+            draft.difficulty = match draft.difficulty {
+                Difficulty::Easy => Difficulty::Hard,
+                Difficulty::Hard => Difficulty::Easy,
+            };
+        }
+    }
+
+    /// Play the draft as it stands; the editor comes back with it when the run ends
+    pub fn test_play_draft(&mut self) {
+        let (Some(editor), Some(draft)) = (self.level_editor(), self.level_draft.as_ref()) else {
+            return;
+        };
+        if let Err(message) = draft.check() {
+            self.emit(GameEvent::PuzzleUnavailable { message });
+            return;
+        }
+        let level = draft.level();
+        self.play_puzzle(level, PuzzleOrigin::Editor(editor));
+    }
+
+    /// Write the draft to the data folder and add it to the level list, replacing the level
+    /// it was saved over
+    pub fn save_level_draft(&mut self) {
+        let Some(draft) = self.level_draft.as_ref().filter(|_| self.is_level_editor()) else {
+            return;
+        };
+        if let Err(message) = draft.check() {
+            self.emit(GameEvent::LevelSaveFailed { message });
+            return;
+        }
+        let level = draft.level();
+        match puzzle::save_platform_level(&level) {
+            Ok(path) => {
+                match self.puzzle_levels.iter_mut().find(|l| l.id == level.id) {
+                    Some(existing) => *existing = level,
+                    None => self.puzzle_levels.push(level),
+                }
+                self.emit(GameEvent::LevelSaved {
+                    path: path.display().to_string(),
+                });
+            }
+            Err(e) => {
+                eprintln!("Failed to save puzzle level: {}", e);
+                self.emit(GameEvent::LevelSaveFailed {
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
+    pub fn start_puzzle(&mut self, index: usize) {
+        if let Some(level) = self.puzzle_levels.get(index).cloned() {
+            self.play_puzzle(level, PuzzleOrigin::Level(index));
+        }
+    }
+
+    /// Lay out a puzzle level's board and deal its cards. A level that doesn't fit the
+    /// board leaves the player where they were.
+    fn play_puzzle(&mut self, level: PuzzleLevel, origin: PuzzleOrigin) {
+        let setup = level
+            .grid(self.board.width, self.board.height)
+            .and_then(|grid| Ok((grid, level.card_sequence()?)));
+        let difficulty = level.difficulty;
+        let (grid, mut cards) = match setup {
+            Ok(setup) => setup,
+            Err(message) => {
+                eprintln!("Failed to start puzzle: {}", message);
+                self.emit(GameEvent::PuzzleUnavailable { message });
+                return;
+            }
+        };
+
+        self.start_game(difficulty);
+        self.run_seed = None; // The level deals its own cards
+        self.run_log = None;
+        self.board.grid = grid;
+        cards.reverse(); // Drawn from the end
+        self.deck = Deck::from_cards(cards);
+        self.current_card = None;
+        self.next_card = self.deck.draw();
+        self.puzzle = Some(PuzzleRun { level, origin });
+        self.spawn_new_card();
+    }
+
+    /// The level being played, during a puzzle
+    pub fn current_puzzle(&self) -> Option<&PuzzleLevel> {
+        self.puzzle.as_ref().map(|run| &run.level)
+    }
+
+    /// Cards of the puzzle that have been dealt into play so far
+    pub fn puzzle_cards_used(&self) -> usize {
+        let Some(level) = self.current_puzzle() else {
+            return 0;
+        };
+        let undealt = self.deck.remaining()
+            + usize::from(self.next_card.is_some())
+            + usize::from(self.current_card.is_some());
+        level.cards.len().saturating_sub(undealt)
+    }
+
+    // A puzzle is won once the board is empty, and lost once the last card has settled
+    // with anything left, both only after every clear has played out
+    fn check_puzzle_result(&mut self) {
+        if self.puzzle.is_none()
+            || !self.delayed_destructions.is_empty()
+            || !self.hard_dropping_cards.is_empty()
+            || !self.board.is_settled()
+        {
+            return;
+        }
+        if self.board.grid.iter().flatten().all(Option::is_none) {
+            self.finish_puzzle(true);
+        } else if self.current_card.is_none() && self.next_card.is_none() {
+            self.finish_puzzle(false);
+        }
+    }
+
+    // Rate a clear and keep the stars, then go back to wherever the puzzle was started
+    // from. Test plays from the editor are rated but not kept.
+    fn finish_puzzle(&mut self, solved: bool) {
+        let cards_used = self.puzzle_cards_used();
+        let Some(run) = self.puzzle.take() else {
+            return;
+        };
+        let name = run.level.name.clone();
+
+        if solved {
+            let stars = run.level.stars(cards_used);
+            if let PuzzleOrigin::Level(_) = run.origin {
+                let level_id = run.level.id.clone();
+                let best = self.puzzle_stars.entry(level_id.clone()).or_default();
+                *best = (*best).max(stars); // Shown right away; the worker's answer agrees
+                self.score_worker
+                    .send(ScoreRequest::RecordPuzzle { level_id, stars });
+            }
+            self.emit(GameEvent::PuzzleSolved { name, stars });
+        } else {
+            self.emit(GameEvent::PuzzleFailed { name });
+        }
+
+        self.current_card = None;
+        self.leave_puzzle(run.origin);
+    }
+
+    fn leave_puzzle(&mut self, origin: PuzzleOrigin) {
+        self.game_session_active = false;
+        match origin {
+            PuzzleOrigin::Level(selected) => self.set_state(PuzzleSelect { selected }),
+            PuzzleOrigin::Editor(editor) => self.set_state(editor),
+        }
+    }
+
+    /// The highlighted row on the Mods & Themes screen
+    pub fn mods_selection(&self) -> Option<usize> {
+        match &self.state {
+            GameState::ModsScreen(screen) => Some(screen.selected),
+            _ => None,
+        }
+    }
+
+    pub fn select_mods_row(&mut self, step: i32) {
+        let rows = self.mod_catalog.len() as i32 + 1;
+        if let GameState::ModsScreen(screen) = &mut self.state {
+            screen.selected = (screen.selected as i32 + step).rem_euclid(rows) as usize;
+        }
+    }
+
+    /// Switch the highlighted row on or off. Nothing is reloaded until the next launch.
+    pub fn toggle_mods_row(&mut self) {
+        match self.mods_selection() {
+            Some(0) => self.settings.mods_enabled = !self.settings.mods_enabled,
+            Some(row) => match self.mod_catalog.get(row - 1) {
+                Some(item) => self.settings.toggle_mod(&item.id),
+                None => return,
+            },
+            None => return,
+        }
+        if !self.settings.sound_effects_muted {
+            self.add_audio_event(AudioEvent::StartGame);
+        }
+        self.request_settings_save();
+    }
+
+    pub fn toggle_heatmap(&mut self) {
+        if let GameState::StatisticsScreen(screen) = &mut self.state {
+            screen.show_heatmap = !screen.show_heatmap;
+        }
+    }
+
+    /// Write the statistics and high scores, stamped with this build, to a JSON file in the
+    /// app data directory that can be attached to a bug report
+    pub fn export_statistics(&mut self) {
+        let export = StatisticsExport::new(&self.statistics, &self.high_scores);
+        let result = serde_json::to_string_pretty(&export)
+            .map_err(|e| e.into())
+            .and_then(|json| platform_storage()?.write(STATISTICS_EXPORT_KEY, &json));
+        match result {
+            Ok(()) => self.emit(GameEvent::StatisticsExported {
+                key: STATISTICS_EXPORT_KEY.to_string(),
+            }),
+            Err(e) => {
+                eprintln!("Failed to export statistics: {}", e);
+                self.emit(GameEvent::StatisticsExportFailed {
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
+    /// Ask before throwing away the current run; only offered during play or from the pause menu
+    pub fn transition_to_restart_confirm(&mut self) {
+        let return_to = match &self.state {
+            GameState::Playing(_) => None,
+            GameState::Paused(paused) => Some(paused.clone()),
+            _ => return,
+        };
+        self.set_state(RestartConfirm { return_to });
+    }
+
+    pub fn cancel_restart(&mut self) {
+        let return_to = match &self.state {
+            GameState::RestartConfirm(confirm) => confirm.return_to.clone(),
+            _ => return,
+        };
+        match return_to {
+            Some(paused) => self.set_state(paused),
+            None => self.transition_to_playing(),
+        }
+    }
+
+    /// Start a new run straight away on the same difficulty, skipping the start screen. A
+    /// puzzle starts its level over.
+    pub fn restart_game(&mut self) {
+        if !self.is_restart_confirm() {
+            return;
+        }
+        match self.puzzle.clone() {
+            Some(run) => self.play_puzzle(run.level, run.origin),
+            None => self.start_game(self.difficulty),
+        }
+    }
+
+    pub fn transition_to_how_to_play(&mut self) {
+        if let GameState::Paused(paused) = &self.state {
+            self.set_state(HowToPlay {
+                return_to: paused.clone(),
+            });
+        }
+    }
+
+    pub fn return_from_how_to_play(&mut self) {
+        if let GameState::HowToPlay(how_to_play) = &self.state {
+            self.set_state(how_to_play.return_to.clone());
+        }
+    }
+
+    pub fn transition_to_combination_log(&mut self) {
+        if let GameState::Paused(paused) = &self.state {
+            self.set_state(CombinationLog {
+                return_to: paused.clone(),
+                scroll: 0,
+            });
+        }
+    }
+
+    pub fn return_from_combination_log(&mut self) {
+        if let GameState::CombinationLog(log) = &self.state {
+            self.set_state(log.return_to.clone());
+        }
+    }
+
+    /// Move the log view by `rows`, keeping at least the oldest combination on screen
+    pub fn scroll_combination_log(&mut self, rows: i32) {
+        let last_row = self.combination_history.len().saturating_sub(1);
+        if let GameState::CombinationLog(log) = &mut self.state {
+            log.scroll = (log.scroll as i64 + rows as i64).clamp(0, last_row as i64) as usize;
+        }
+    }
+
+    pub fn transition_to_diagnostics(&mut self) {
+        if let GameState::Settings(settings) = &self.state {
+            self.set_state(Diagnostics {
+                return_to: settings.clone(),
+            });
+        }
+    }
+
+    pub fn return_from_diagnostics(&mut self) {
+        if let GameState::Diagnostics(diagnostics) = &self.state {
+            self.set_state(diagnostics.return_to.clone());
+        }
+    }
+
+    pub fn transition_to_accessibility(&mut self) {
+        if let GameState::Settings(settings) = &self.state {
+            self.set_state(Accessibility {
+                return_to: settings.clone(),
+                selected: AccessibilityOption::ReducedMotion,
+            });
+        }
+    }
+
+    pub fn return_from_accessibility(&mut self) {
+        if let GameState::Accessibility(accessibility) = &self.state {
+            self.set_state(accessibility.return_to.clone());
+        }
+    }
+
+    /// The highlighted row, while the accessibility screen is open
+    pub fn accessibility_selection(&self) -> Option<AccessibilityOption> {
+        match &self.state {
+            GameState::Accessibility(accessibility) => Some(accessibility.selected),
+            _ => None,
+        }
+    }
+
+    pub fn select_accessibility_option(&mut self, step: i32) {
+        if let GameState::Accessibility(accessibility) = &mut self.state {
+            accessibility.selected = accessibility.selected.cycle(step);
+        }
+    }
+
+    /// Change the highlighted accessibility setting: on/off rows flip whichever way `step`
+    /// points, the text size moves up or down a step
+    pub fn change_accessibility_option(&mut self, step: i32) {
+        match self.accessibility_selection() {
+            Some(AccessibilityOption::ReducedMotion) => {
+                self.settings.reduced_motion = !self.settings.reduced_motion;
+            }
+            Some(AccessibilityOption::HighContrast) => {
+                self.settings.high_contrast = !self.settings.high_contrast;
+            }
+            Some(AccessibilityOption::FontScale) => self.settings.adjust_font_scale(step),
+            Some(AccessibilityOption::CardValues) => {
+                self.settings.card_values = !self.settings.card_values;
+            }
+            None => return,
+        }
+        if !self.settings.sound_effects_muted {
+            self.add_audio_event(AudioEvent::StartGame);
+        }
+        self.request_settings_save();
+    }
+
+    pub fn transition_to_appearance(&mut self) {
+        if let GameState::Settings(settings) = &self.state {
+            self.set_state(Appearance {
+                return_to: settings.clone(),
+                selected: AppearanceOption::FeltColor,
+            });
+        }
+    }
+
+    pub fn return_from_appearance(&mut self) {
+        if let GameState::Appearance(appearance) = &self.state {
+            self.set_state(appearance.return_to.clone());
+        }
+    }
+
+    /// The highlighted row, while the appearance screen is open
+    pub fn appearance_selection(&self) -> Option<AppearanceOption> {
+        match &self.state {
+            GameState::Appearance(appearance) => Some(appearance.selected),
+            _ => None,
+        }
+    }
+
+    pub fn select_appearance_option(&mut self, step: i32) {
+        if let GameState::Appearance(appearance) = &mut self.state {
+            appearance.selected = appearance.selected.cycle(step);
+        }
+    }
+
+    /// Step the highlighted appearance setting through its choices
+    pub fn change_appearance_option(&mut self, step: i32) {
+        match self.appearance_selection() {
+            Some(AppearanceOption::FeltColor) => {
+                self.settings.felt_color = self.settings.felt_color.cycle(step);
+            }
+            Some(AppearanceOption::CardBack) => {
+                self.settings.card_back = self.settings.card_back.cycle(step);
+            }
+            Some(AppearanceOption::SeasonalEvents) => {
+                self.settings.seasonal_events = !self.settings.seasonal_events;
+            }
+            None => return,
+        }
+        if !self.settings.sound_effects_muted {
+            self.add_audio_event(AudioEvent::DifficultyChange);
+        }
+        self.request_settings_save();
+    }
+
+    // Event queue management
+    // Add a step to the run's log, after whatever gravity has moved since the last one
+    fn log_step(&mut self, step: RunStep) {
+        let moves = std::mem::take(&mut self.board.gravity_moves);
+        if let Some(log) = &mut self.run_log {
+            log.steps.extend(
+                moves
+                    .into_iter()
+                    .map(|(x, from, to)| RunStep::Fall { x, from, to }),
+            );
+            log.steps.push(step);
+        }
+    }
+
+    pub fn emit(&mut self, event: GameEvent) {
+        if self.difficulty_controller.observe(&event) {
+            self.fall_speed = self.speed_params().fall_speed_at(self.pace_level());
+        }
+        self.events.push(event);
+    }
+
+    // Queue a plain sound cue that has no gameplay event behind it
+    pub fn add_audio_event(&mut self, event: AudioEvent) {
+        self.emit(GameEvent::Sound(event));
+    }
+
+    pub fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Step the output device through the system default and each available device
+    pub fn cycle_audio_device(&mut self, step: i32) {
+        let choices: Vec<Option<String>> = std::iter::once(None)
+            .chain(self.audio_devices.iter().cloned().map(Some))
+            .collect();
+        let current = choices
+            .iter()
+            .position(|choice| *choice == self.settings.audio_device)
+            .unwrap_or(0);
+        let next = (current as i32 + step).rem_euclid(choices.len() as i32) as usize;
+        self.settings.audio_device = choices[next].clone();
+        self.request_settings_save();
+    }
+
+    /// Schedule a settings save; rapid changes (like holding a volume slider) are written once
+    pub fn request_settings_save(&mut self) {
+        self.settings_save_requested = Some(Instant::now());
+    }
+
+    /// Write settings once they have stopped changing for a moment; called once per frame
+    pub fn update_settings_save(&mut self) {
+        let settled = self.settings_save_requested.is_some_and(|requested| {
+            requested.elapsed() >= Duration::from_millis(SETTINGS_SAVE_DEBOUNCE_MS)
+        });
+        if settled {
+            self.flush_settings();
+        }
+    }
+
+    /// Write any pending settings change to the settings store right away
+    pub fn flush_settings(&mut self) {
+        if self.settings_save_requested.take().is_none() {
+            return;
+        }
+
+        if let Err(e) = self.settings_store.save(&self.settings) {
+            eprintln!("Failed to save settings: {}", e);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fuzz"))]
+mod fuzz;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    // Test fixtures for game testing, shared with the UI's tests
+    pub(crate) mod test_fixtures {
+        use super::*;
+        use crate::store::MemorySavedRunStore;
+        use tempfile;
+
+        pub fn create_temp_database() -> (Database, TempDir) {
+            let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+            let db_path = temp_dir.path().join("test_game.db");
+            let db = Database::new(&db_path).expect("Failed to create test database");
+            (db, temp_dir)
+        }
+
+        pub fn create_test_game() -> (Game, TempDir) {
+            let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+            let db_path = temp_dir.path().join("test_game.db");
+
+            let game = Game::builder()
+                .database_path(&db_path)
+                .saved_run_store(Box::new(MemorySavedRunStore::new()))
+                .build()
+                .expect("Failed to create test game");
+
+            (game, temp_dir)
+        }
+
+        pub fn create_test_game_with_config(
+            width: i32,
+            height: i32,
+            difficulty: Difficulty,
+        ) -> (Game, TempDir) {
+            let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+            let db_path = temp_dir.path().join("test_game.db");
+
+            let game = Game::builder()
+                .board_size(width, height)
+                .difficulty(difficulty)
+                .database_path(&db_path)
+                .saved_run_store(Box::new(MemorySavedRunStore::new()))
+                .build()
+                .expect("Failed to create test game");
+
+            (game, temp_dir)
+        }
+
+        // Wait for the score worker to answer everything sent so far and apply the results
+        pub fn finish_score_requests(game: &mut Game) {
+            for response in game.score_worker.wait(Duration::from_secs(2)) {
+                game.handle_score_response(response);
+            }
+        }
+
+        // Drain the event queue down to the sounds it would play
+        pub fn take_audio_events(game: &mut Game) -> Vec<AudioEvent> {
+            game.take_events()
+                .iter()
+                .filter_map(GameEvent::audio_event)
+                .collect()
+        }
+
+        pub fn create_test_playing_card() -> PlayingCard {
+            PlayingCard::builder(
+                Card::new(crate::models::Suit::Hearts, crate::models::Value::Ace),
+                Position { x: 2, y: 1 },
+            )
+            .build()
+        }
+    }
+
+    #[test]
+    fn test_game_builder_basic() {
+        let (game, _temp_dir) = test_fixtures::create_test_game();
+
+        assert_eq!(game.board.width, 10); // Default width
+        assert_eq!(game.board.height, 15); // Default height
+        assert_eq!(game.difficulty, Difficulty::Easy); // Default difficulty
+        assert_eq!(game.score, 0);
+        assert!(game.current_card.is_none());
+        assert!(game.next_card.is_some());
+        assert!(game.is_start_screen());
+    }
+
+    #[test]
+    fn test_game_builder_with_custom_config() {
+        let (game, _temp_dir) =
+            test_fixtures::create_test_game_with_config(8, 12, Difficulty::Hard);
+
+        assert_eq!(game.board.width, 8);
+        assert_eq!(game.board.height, 12);
+        assert_eq!(game.difficulty, Difficulty::Hard);
+    }
+
+    #[test]
+    fn test_game_builder_all_options() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().join("test_game.db");
+
+        let game = Game::builder()
+            .board_size(6, 10)
+            .cell_size(32)
+            .difficulty(Difficulty::Hard)
+            .fall_speed(Duration::from_millis(500))
+            .speed_increase_interval(Duration::from_secs(45))
+            .database_path(&db_path)
+            .build()
+            .expect("Failed to create game");
+
+        assert_eq!(game.board.width, 6);
+        assert_eq!(game.board.height, 10);
+        assert_eq!(game.board.cell_size, 32);
+        assert_eq!(game.difficulty, Difficulty::Hard);
+        assert_eq!(game.fall_speed, Duration::from_millis(500));
+        assert_eq!(game.speed_increase_interval, Duration::from_secs(45));
+    }
+
+    #[test]
+    fn test_game_builder_missing_database_path() {
+        let result = Game::builder().build();
+        assert!(matches!(result, Err(DropJackError::NoScoreStore)));
+    }
+
+    #[test]
+    fn test_game_builder_rejects_bad_sizes() {
+        use crate::store::{MemoryScoreStore, MemorySettingsStore};
+
+        let build = |width, height, cell_size| {
+            Game::builder()
+                .board_size(width, height)
+                .cell_size(cell_size)
+                .score_store(Box::new(MemoryScoreStore::new()))
+                .settings_store(Box::new(MemorySettingsStore::new()))
+                .build()
+        };
+
+        for (width, height, cell_size) in [
+            (3, 10, 48),
+            (10, 5, 48),
+            (10, 15, 0),
+            (10, 15, -8),
+            (MAX_BOARD_WIDTH + 1, 15, 48),
+            (10, MAX_BOARD_HEIGHT + 1, 48),
+        ] {
+            let error = build(width, height, cell_size)
+                .err()
+                .expect("Size should be rejected");
+            assert!(matches!(error, DropJackError::InvalidBoardSize(_)));
+        }
+        let error = build(2, 15, 48).err().unwrap();
+        assert!(error.to_string().contains("at least 4 columns"));
+        let error = build(10, 1000, 48).err().unwrap();
+        assert!(error.to_string().contains("at most"));
+
+        let game = build(MIN_BOARD_WIDTH, MIN_BOARD_HEIGHT, 1).expect("The smallest board builds");
+        assert_eq!(game.board.width, MIN_BOARD_WIDTH);
+        assert_eq!(game.board.height, MIN_BOARD_HEIGHT);
+    }
+
+    #[test]
+    fn test_game_builder_with_memory_stores() {
+        use crate::store::{MemoryScoreStore, MemorySettingsStore};
+
+        // No database path needed when a score store is supplied
+        let mut game = Game::builder()
+            .score_store(Box::new(MemoryScoreStore::new()))
+            .settings_store(Box::new(MemorySettingsStore::new()))
+            .build()
+            .expect("Failed to create game");
+        assert!(game.high_scores.is_empty());
+
+        game.score = 420;
+        game.player_initials = "MEM".to_string();
+        game.save_high_score();
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.high_scores.len(), 1);
+        assert_eq!(game.high_scores[0].score, 420);
+
+        game.settings.music_muted = true;
+        game.request_settings_save();
+        game.flush_settings();
+        assert!(game.settings_store.load().unwrap().unwrap().music_muted);
+    }
+
+    #[test]
+    fn test_settings_save_is_debounced() {
+        use crate::store::{MemoryScoreStore, MemorySettingsStore};
+
+        let mut game = Game::builder()
+            .score_store(Box::new(MemoryScoreStore::new()))
+            .settings_store(Box::new(MemorySettingsStore::new()))
+            .build()
+            .expect("Failed to create game");
+
+        // A fresh change is not written yet
+        game.settings.music_volume = 0.2;
+        game.request_settings_save();
+        game.update_settings_save();
+        assert_eq!(game.settings_store.load().unwrap(), None);
+
+        // Once changes have settled, the next update writes them
+        game.settings_save_requested =
+            Some(Instant::now() - Duration::from_millis(SETTINGS_SAVE_DEBOUNCE_MS));
+        game.update_settings_save();
+        assert_eq!(
+            game.settings_store.load().unwrap().unwrap().music_volume,
+            0.2
+        );
+        assert!(game.settings_save_requested.is_none());
+    }
+
+    #[test]
+    fn test_idle_play_pauses_itself() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        assert_eq!(game.idle_pause_after, None);
+        game.idle_pause_after = Some(Duration::from_secs(IDLE_PAUSE_SECS));
+        game.start_game(Difficulty::Easy);
+
+        // Recent input keeps the run going
+        game.update();
+        assert!(game.is_playing());
+
+        game.last_input_time = Instant::now() - Duration::from_secs(IDLE_PAUSE_SECS);
+        game.update();
+        let GameState::Paused(paused) = &game.state else {
+            panic!("Idle play should pause");
+        };
+        assert_eq!(paused.reason, PauseReason::Idle);
+
+        // Disabled idle pause never triggers
+        game.idle_pause_after = None;
+        game.transition_to_playing();
+        game.last_input_time = Instant::now() - Duration::from_secs(IDLE_PAUSE_SECS);
+        game.update();
+        assert!(game.is_playing());
+    }
+
+    #[test]
+    fn test_focus_loss_only_pauses_play() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.pause_for_focus_loss();
+        assert!(game.is_start_screen());
+
+        game.start_game(Difficulty::Easy);
+        game.pause_for_focus_loss();
+        let GameState::Paused(paused) = &game.state else {
+            panic!("Should be paused");
+        };
+        assert_eq!(paused.reason, PauseReason::FocusLost);
+    }
+
+    #[test]
+    fn test_resume_skips_fall_timer_catch_up() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let card_y = game.current_card.as_ref().unwrap().position.y;
+
+        // Pretend the game sat paused for much longer than a fall step
+        game.transition_to_paused_for(PauseReason::FocusLost);
+        let away = game.fall_speed * 5;
+        game.suspended_at = Some(Instant::now() - away);
+        game.last_fall_time -= away;
+        game.last_speed_increase -= away;
+        let fall_speed = game.fall_speed;
+
+        game.transition_to_playing();
+        game.update();
+
+        // The card didn't drop and the speed didn't step up for the time spent away
+        assert_eq!(game.current_card.as_ref().unwrap().position.y, card_y);
+        assert_eq!(game.fall_speed, fall_speed);
+        assert!(game.suspended_at.is_none());
+    }
+
+    #[test]
+    fn test_leaving_play_suspends_timers() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        assert!(game.suspended_at.is_none());
+
+        // Any way out of play stops the clock, and coming back makes up for it
+        game.transition_to_settings(SettingsOrigin::Playing);
+        assert!(game.suspended_at.is_some());
+        game.return_from_settings();
+        assert!(game.is_playing());
+        assert!(game.suspended_at.is_none());
+
+        game.transition_to_game_over();
+        assert!(game.suspended_at.is_some());
+        assert!(game.game_over_started.is_some());
+    }
+
+    #[test]
+    fn test_settings_from_pause_returns_to_the_same_pause() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        game.transition_to_paused_for(PauseReason::Idle);
+        game.select_pause_option(2);
+        assert_eq!(game.pause_menu_selection(), Some(PauseMenuOption::Settings));
+
+        game.choose_pause_option();
+        assert!(game.is_settings());
+        game.return_from_settings();
+
+        let GameState::Paused(paused) = &game.state else {
+            panic!("Should be back on the pause menu");
+        };
+        assert_eq!(paused.reason, PauseReason::Idle);
+        assert_eq!(paused.selected, PauseMenuOption::Settings);
+    }
+
+    #[test]
+    fn test_diagnostics_returns_to_settings() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        assert!(game.database_path.is_some());
+
+        // Diagnostics only opens from settings
+        game.transition_to_diagnostics();
+        assert!(game.is_start_screen());
+
+        game.transition_to_settings(SettingsOrigin::Playing);
+        game.transition_to_diagnostics();
+        assert!(game.is_diagnostics());
+
+        game.return_from_diagnostics();
+        let GameState::Settings(settings) = &game.state else {
+            panic!("Should be back in settings");
+        };
+        assert_eq!(settings.origin, SettingsOrigin::Playing);
+    }
+
+    #[test]
+    fn test_accessibility_toggles_settings() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+
+        // Accessibility only opens from settings
+        game.transition_to_accessibility();
+        assert!(game.is_start_screen());
+
+        game.transition_to_settings(SettingsOrigin::StartScreen);
+        game.transition_to_accessibility();
+        assert!(game.is_accessibility());
+        assert_eq!(
+            game.accessibility_selection(),
+            Some(AccessibilityOption::ReducedMotion)
+        );
+
+        game.change_accessibility_option(1);
+        assert!(game.settings.reduced_motion);
+
+        game.select_accessibility_option(1);
+        game.change_accessibility_option(-1);
+        assert!(game.settings.high_contrast);
+
+        game.select_accessibility_option(1);
+        game.change_accessibility_option(-1);
+        assert!((game.settings.font_scale - 0.9).abs() < 1e-6);
+
+        game.select_accessibility_option(1);
+        game.change_accessibility_option(1);
+        assert!(game.settings.card_values);
+
+        // Wraps back to the top
+        game.select_accessibility_option(1);
+        assert_eq!(
+            game.accessibility_selection(),
+            Some(AccessibilityOption::ReducedMotion)
+        );
+
+        game.return_from_accessibility();
+        assert!(game.is_settings());
+    }
+
+    #[test]
+    fn test_game_setup_changes_rules() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+
+        // Game setup opens from the main menu
+        game.selected_main_option = MainMenuOption::GameSetup;
+        game.choose_main_option();
+        assert!(game.is_game_setup());
+        assert_eq!(
+            game.game_setup_selection(),
+            Some(GameSetupOption::DeckTemplate)
+        );
+
+        game.change_game_setup_option(1);
+        assert_eq!(
+            game.settings.deck_template,
+            crate::models::DeckTemplate::FaceCardFrenzy
+        );
+
+        game.select_game_setup_option(1);
+        game.change_game_setup_option(1);
+        assert_eq!(game.settings.handicap_rows, 1);
+
+        game.select_game_setup_option(1);
+        game.change_game_setup_option(1);
+        assert!(game.settings.hard_drop_steer);
+
+        game.select_game_setup_option(1);
+        game.change_game_setup_option(1);
+        assert!(!game.settings.slide_delay);
+
+        game.select_game_setup_option(1);
+        game.change_game_setup_option(1);
+        assert!(game.settings.bust_rule);
+
+        game.select_game_setup_option(1);
+        game.change_game_setup_option(1);
+        assert!(game.settings.relax_mode);
+
+        game.select_game_setup_option(1);
+        game.change_game_setup_option(1);
+        assert!(game.settings.adaptive_speed);
+
+        game.select_game_setup_option(1);
+        game.change_game_setup_option(1);
+        assert!(game.settings.draw_odds);
+
+        // Wraps back to the top
+        game.select_game_setup_option(1);
+        assert_eq!(
+            game.game_setup_selection(),
+            Some(GameSetupOption::DeckTemplate)
+        );
+
+        game.return_from_game_setup();
+        assert!(game.is_start_screen());
+    }
+
+    #[test]
+    fn test_appearance_changes_settings() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.transition_to_settings(SettingsOrigin::StartScreen);
+        game.transition_to_appearance();
+        assert!(game.is_appearance());
+
+        game.change_appearance_option(1);
+        assert_eq!(game.settings.felt_color, crate::models::FeltColor::Blue);
+        game.change_appearance_option(-2);
+        assert_eq!(game.settings.felt_color, crate::models::FeltColor::Purple);
+
+        game.select_appearance_option(1);
+        game.change_appearance_option(1);
+        assert_eq!(game.settings.card_back, crate::models::CardBack::Lattice);
+
+        game.season = Some(Season::Winter);
+        assert_eq!(game.active_season(), Some(Season::Winter));
+        game.select_appearance_option(1);
+        game.change_appearance_option(1);
+        assert!(!game.settings.seasonal_events);
+        assert_eq!(game.active_season(), None);
+
+        game.return_from_appearance();
+        assert!(game.is_settings());
+    }
+
+    #[test]
+    fn test_cycle_audio_device() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.audio_devices = vec!["Speakers".to_string(), "Headset".to_string()];
+        assert_eq!(game.settings.audio_device, None);
+
+        game.cycle_audio_device(1);
+        assert_eq!(game.settings.audio_device.as_deref(), Some("Speakers"));
+        game.cycle_audio_device(1);
+        assert_eq!(game.settings.audio_device.as_deref(), Some("Headset"));
+        game.cycle_audio_device(1);
+        assert_eq!(game.settings.audio_device, None);
+        game.cycle_audio_device(-1);
+        assert_eq!(game.settings.audio_device.as_deref(), Some("Headset"));
+
+        // A saved device that's been unplugged steps on from the system default
+        game.settings.audio_device = Some("Unplugged".to_string());
+        game.cycle_audio_device(1);
+        assert_eq!(game.settings.audio_device.as_deref(), Some("Speakers"));
+        assert!(game.settings_save_requested.is_some());
+    }
+
+    #[test]
+    fn test_combination_log_from_pause() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        for chain in 1..=3 {
+            game.record_combination(ClearedCombination::new(Vec::new(), 1, chain));
+        }
+
+        // The log only opens from the pause screen
+        game.transition_to_combination_log();
+        assert!(game.is_playing());
+
+        game.transition_to_paused_for(PauseReason::Idle);
+        game.transition_to_combination_log();
+        assert!(game.is_combination_log());
+
+        // Scrolling stops at the newest and oldest rows
+        game.scroll_combination_log(-1);
+        game.scroll_combination_log(5);
+        let GameState::CombinationLog(log) = &game.state else {
+            panic!("Should be on the combination log");
+        };
+        assert_eq!(log.scroll, 2);
+
+        game.return_from_combination_log();
+        let GameState::Paused(paused) = &game.state else {
+            panic!("Should be paused");
+        };
+        assert_eq!(paused.reason, PauseReason::Idle);
+    }
+
+    #[test]
+    fn test_pause_menu_navigation() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        assert_eq!(game.pause_menu_selection(), None);
+
+        game.transition_to_paused();
+        assert_eq!(game.pause_menu_selection(), Some(PauseMenuOption::Resume));
+
+        // Moving up from the top wraps to the bottom
+        game.select_pause_option(-1);
+        assert_eq!(
+            game.pause_menu_selection(),
+            Some(PauseMenuOption::QuitToMenu)
+        );
+
+        // How to Play comes back to the pause menu with the same entry highlighted
+        game.select_pause_option(-1);
+        game.choose_pause_option();
+        assert!(game.is_how_to_play());
+        game.return_from_how_to_play();
+        assert_eq!(
+            game.pause_menu_selection(),
+            Some(PauseMenuOption::HowToPlay)
+        );
+
+        game.select_pause_option(-1);
+        game.choose_pause_option();
+        assert!(game.is_settings());
+
+        game.transition_to_paused();
+        game.select_pause_option(1);
+        game.choose_pause_option();
+        assert!(game.is_restart_confirm()); // Restart asks first
+    }
+
+    #[test]
+    fn test_restart_confirmation() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Hard);
+        game.score = 500;
+
+        // Cancelling from play goes straight back to the run
+        game.transition_to_restart_confirm();
+        assert!(game.is_restart_confirm());
+        game.cancel_restart();
+        assert!(game.is_playing());
+        assert_eq!(game.score, 500);
+
+        // From the pause menu, cancelling returns to the menu where it was left
+        game.transition_to_paused_for(PauseReason::Idle);
+        game.select_pause_option(1);
+        game.choose_pause_option();
+        assert!(game.is_restart_confirm());
+        game.cancel_restart();
+        let GameState::Paused(paused) = &game.state else {
+            panic!("Should be paused");
+        };
+        assert_eq!(paused.reason, PauseReason::Idle);
+        assert_eq!(paused.selected, PauseMenuOption::Restart);
+
+        game.choose_pause_option();
+        game.restart_game();
+        assert!(game.is_playing());
+        assert_eq!(game.score, 0);
+        assert_eq!(game.difficulty, Difficulty::Hard);
+
+        // Restart isn't offered outside a run
+        game.transition_to_start_screen();
+        game.transition_to_restart_confirm();
+        assert!(game.is_start_screen());
+    }
+
+    #[test]
+    fn test_forfeit_confirmation() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        game.transition_to_paused();
+
+        // Quit to Menu asks first, and cancelling goes back to the pause menu
+        game.select_pause_option(-1);
+        game.choose_pause_option();
+        assert!(game.is_forfeit_confirm());
+        assert_eq!(game.forfeit_selection(), Some(ForfeitOption::Cancel));
+        game.choose_forfeit_option();
+        assert_eq!(
+            game.pause_menu_selection(),
+            Some(PauseMenuOption::QuitToMenu)
+        );
+
+        // Forfeiting counts the run as abandoned
+        game.choose_pause_option();
+        game.select_forfeit_option(-1);
+        assert_eq!(game.forfeit_selection(), Some(ForfeitOption::Forfeit));
+        game.choose_forfeit_option();
+        assert!(game.is_start_screen());
+        assert!(game.saved_run.is_none());
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.statistics.runs_abandoned, 1);
+    }
+
+    #[test]
+    fn test_puzzle_clear_earns_stars() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.transition_to_puzzle_select();
+        assert_eq!(game.puzzle_selection(), Some(0));
+        game.select_puzzle(-1);
+        game.select_puzzle(1);
+        game.start_selected_puzzle();
+
+        // The level's board and cards, in order, instead of a shuffled deck
+        let level = game
+            .current_puzzle()
+            .expect("First Steps is loaded")
+            .clone();
+        assert!(game.is_playing());
+        assert_eq!(game.board.grid, level.grid(10, 15).unwrap());
+        let cards = level.card_sequence().unwrap();
+        assert_eq!(
+            game.current_card.as_ref().map(|card| card.card),
+            Some(cards[0])
+        );
+        assert_eq!(game.next_card, Some(cards[1]));
+        assert_eq!(game.puzzle_cards_used(), 0);
+
+        // Emptying the board wins, back on the level list with the stars kept
+        game.current_card = None;
+        game.board = Board::new(10, 15, game.board.cell_size);
+        game.update();
+        assert_eq!(game.puzzle_selection(), Some(0));
+        assert!(game.puzzle.is_none());
+        assert_eq!(game.puzzle_stars.get(&level.id), Some(&3));
+        assert!(
+            game.take_events()
+                .iter()
+                .any(|event| matches!(event, GameEvent::PuzzleSolved { stars: 3, .. }))
+        );
+
+        // Puzzles aren't runs, so the statistics don't change
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.puzzle_stars.get(&level.id), Some(&3));
+        assert_eq!(game.statistics.runs_finished, 0);
+    }
+
+    #[test]
+    fn test_puzzle_lost_when_cards_run_out() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_puzzle(0);
+        game.deck = Deck::from_cards(Vec::new());
+        game.next_card = None;
+        game.current_card = None;
+        game.update();
+
+        assert_eq!(game.puzzle_selection(), Some(0));
+        assert!(game.puzzle_stars.is_empty());
+        assert!(
+            game.take_events()
+                .iter()
+                .any(|event| matches!(event, GameEvent::PuzzleFailed { .. }))
+        );
+    }
+
+    #[test]
+    fn test_adaptive_speed_eases_off_after_close_calls() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        game.speed_level = 2;
+        let card = Card::new(Suit::Spades, Value::Two);
+        for _ in 0..3 {
+            game.emit(GameEvent::CardPlaced { x: 0, y: 0, card });
+        }
+        assert_eq!(game.pace_level(), 2, "Off unless the setting is on");
+
+        game.settings.adaptive_speed = true;
+        game.start_game(Difficulty::Easy);
+        game.speed_level = 2;
+        for _ in 0..3 {
+            game.emit(GameEvent::CardPlaced { x: 0, y: 0, card });
+        }
+        assert_eq!(game.pace_level(), 1);
+        assert_eq!(game.fall_speed, game.speed_params().fall_speed_at(1));
+
+        // The curve keeps climbing underneath, one level behind
+        game.increase_speed();
+        assert_eq!(game.fall_speed, game.speed_params().fall_speed_at(2));
+    }
+
+    #[test]
+    fn test_runs_from_the_same_seed_deal_the_same_cards() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.settings.handicap_rows = 2;
+        let deal = |game: &mut Game| {
+            let mut cards = vec![game.current_card.as_ref().unwrap().card];
+            cards.extend(game.deck.cards().iter().rev().take(10));
+            (cards, game.board.grid.clone())
+        };
+
+        // Typed in from the start screen, lowercase and all
+        game.transition_to_seed_entry();
+        assert!(game.is_seed_entry());
+        "qwert".chars().for_each(|c| game.add_seed_char(c));
+        assert!(!game.play_from_seed(), "Five letters isn't a seed yet");
+        game.add_seed_char('y');
+        game.add_seed_char('u');
+        assert_eq!(game.seed_entry().unwrap().code, "QWERTY");
+        assert!(game.play_from_seed());
+        assert!(game.is_playing());
+        let seed = RunSeed::parse("QWERTY").unwrap();
+        assert_eq!(game.run_seed, Some(seed));
+        let first = deal(&mut game);
+
+        game.start_seeded_game(Difficulty::Easy, seed);
+        assert_eq!(deal(&mut game), first);
+        game.start_seeded_game(Difficulty::Easy, RunSeed::parse("QWERTZ").unwrap());
+        assert_ne!(deal(&mut game), first);
+    }
+
+    #[test]
+    fn test_relax_mode_clears_a_column_instead_of_ending() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.settings.relax_mode = true;
+        game.start_game(Difficulty::Easy);
+        game.score = 250;
+        game.current_card = None;
+
+        // Twos can never add up to 21, so the full board just sits there
+        let two = Card::new(Suit::Hearts, Value::Two);
+        for row in game.board.grid.iter_mut() {
+            row.fill(Some(two));
+        }
+        let discards = game.deck.discards().len();
+        game.check_game_over();
+
+        let column = game.board.width / 2;
+        assert!(game.is_playing());
+        assert!((0..game.board.height).all(|y| game.board.is_cell_empty(column, y)));
+        assert_eq!(game.score, 250 - RELAX_COLUMN_PENALTY);
+        assert_eq!(
+            game.deck.discards().len(),
+            discards + game.board.height as usize
+        );
+        assert!(game.take_events().iter().any(|event| matches!(
+            event,
+            GameEvent::ColumnCleared { penalty, .. } if *penalty == RELAX_COLUMN_PENALTY
+        )));
+
+        // The score never goes below zero, and the run's flag goes with its scores
+        game.score = 30;
+        for row in game.board.grid.iter_mut() {
+            row.fill(Some(two));
+        }
+        game.check_game_over();
+        assert_eq!(game.score, 0);
+        assert!(game.snapshot_run().relaxed);
+    }
+
+    #[test]
+    fn test_level_editor_test_play_returns_to_the_draft() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.transition_to_puzzle_select();
+        game.transition_to_level_editor();
+        assert!(game.is_level_editor());
+        let draft = game.level_draft.clone().expect("A draft is started");
+        assert!(!game.puzzle_levels.iter().any(|level| level.id == draft.id));
+
+        // A king in column 2, then an ace queued to clear it
+        game.test_play_draft();
+        assert!(game.is_level_editor(), "An empty draft can't be played");
+        game.move_editor_column(-1);
+        game.move_editor_column(3);
+        game.change_editor_brush_value(-1);
+        game.stack_editor_card();
+        game.change_editor_brush_value(1);
+        game.cycle_editor_brush_suit();
+        game.queue_editor_card();
+        let draft = game.level_draft.clone().unwrap();
+        let ace = Card::new(Suit::Hearts, Value::Ace);
+        assert_eq!(
+            draft.grid[14][2],
+            Some(Card::new(Suit::Spades, Value::King))
+        );
+        assert_eq!(draft.cards, vec![ace]);
+
+        game.test_play_draft();
+        assert!(game.is_playing());
+        assert_eq!(game.current_card.as_ref().map(|card| card.card), Some(ace));
+
+        // Clearing it comes back to the editor as it was, with no stars kept
+        game.current_card = None;
+        game.board = Board::new(10, 15, game.board.cell_size);
+        game.update();
+        assert_eq!(game.level_editor().map(|editor| editor.column), Some(2));
+        assert_eq!(game.level_draft, Some(draft));
+        assert!(game.puzzle_stars.is_empty());
+    }
+
+    #[test]
+    fn test_mods_change_score_and_flag_the_run() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        assert!(!game.run_modded);
+
+        game.mods
+            .add_script("triple.rhai", "fn modify_score(points) { points * 3 }")
+            .unwrap();
+        game.start_game(Difficulty::Easy);
+        assert!(game.run_modded);
+        assert_eq!(game.award_points(21), 63);
+        assert_eq!(game.score, 63);
+        assert!(game.snapshot_run().modded);
+    }
+
+    #[test]
+    fn test_modded_score_stops_at_the_limit() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.mods
+            .add_script("huge.rhai", "fn modify_score(points) { 2000000000 }")
+            .unwrap();
+        game.start_game(Difficulty::Easy);
+        game.award_points(21);
+        game.award_points(21);
+        assert_eq!(game.score, i32::MAX);
+    }
+
+    #[test]
+    fn test_column_drops_recorded_with_run() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        game.current_card.as_mut().unwrap().position.x = 2;
+        game.place_current_card();
+        assert_eq!(game.run_column_drops[2], 1);
+
+        game.transition_to_paused();
+        game.transition_to_forfeit_confirm();
+        game.forfeit_run();
+        assert!(game.run_column_drops.is_empty());
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.statistics.column_drops[2], 1);
+        assert_eq!(game.statistics.total_drops(), 1);
+    }
+
+    #[test]
+    fn test_statistics_screen_heatmap_toggle() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.transition_to_start_screen();
+        game.transition_to_statistics_screen();
+        let heatmap_shown = |game: &Game| matches!(&game.state, GameState::StatisticsScreen(screen) if screen.show_heatmap);
+        assert!(game.is_statistics_screen());
+        assert!(!heatmap_shown(&game));
+
+        game.toggle_heatmap();
+        assert!(heatmap_shown(&game));
+
+        game.return_from_statistics_screen();
+        assert!(game.is_start_screen());
+    }
+
+    #[test]
+    fn test_mods_screen_toggles() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.mod_catalog = vec![ModItem {
+            kind: catalog::ModKind::Theme,
+            id: "themes/neon".to_string(),
+            path: std::path::PathBuf::from("themes/neon"),
+            name: "Neon".to_string(),
+            author: "Alex".to_string(),
+            version: "0.3".to_string(),
+        }];
+        game.transition_to_start_screen();
+        game.transition_to_mods_screen();
+        assert!(game.is_mods_screen());
+        assert_eq!(game.mods_selection(), Some(0));
+
+        let mods_enabled = game.settings.mods_enabled;
+        game.toggle_mods_row();
+        assert_eq!(game.settings.mods_enabled, !mods_enabled);
+
+        game.select_mods_row(1);
+        assert_eq!(game.mods_selection(), Some(1));
+        game.toggle_mods_row();
+        assert!(!game.settings.is_mod_enabled("themes/neon"));
+        game.toggle_mods_row();
+        assert!(game.settings.is_mod_enabled("themes/neon"));
+
+        // Wraps past the last item back to the scripts switch
+        game.select_mods_row(1);
+        assert_eq!(game.mods_selection(), Some(0));
+        game.select_mods_row(-1);
+        assert_eq!(game.mods_selection(), Some(1));
+
+        game.return_from_mods_screen();
+        assert!(game.is_start_screen());
+    }
+
+    #[test]
+    fn test_save_and_continue_run() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Hard);
+        game.score = 300;
+        game.speed_level = 2;
+        let seven = Card::new(crate::models::Suit::Clubs, crate::models::Value::Seven);
+        game.board.place_card(0, game.board.height - 1, seven);
+        let current = game.current_card.as_ref().unwrap().card;
+        let next = game.next_card;
+        let deck_left = game.deck.cards().len();
+
+        game.transition_to_paused();
+        game.transition_to_forfeit_confirm();
+        game.select_forfeit_option(1);
+        game.choose_forfeit_option();
+        assert!(game.is_start_screen());
+        assert!(game.saved_run.is_some());
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.statistics.runs_saved, 1);
+
+        assert!(game.continue_saved_run());
+        assert!(game.is_playing());
+        assert_eq!(game.difficulty, Difficulty::Hard);
+        assert_eq!(game.score, 300);
+        assert_eq!(game.speed_level, 2);
+        assert_eq!(
+            game.board.grid[(game.board.height - 1) as usize][0],
+            Some(seven)
+        );
+        assert_eq!(game.current_card.as_ref().map(|c| c.card), Some(current));
+        assert_eq!(game.next_card, next);
+        assert_eq!(game.deck.cards().len(), deck_left);
+
+        // The save is used up
+        assert!(game.saved_run.is_none());
+        assert!(game.saved_run_store.load().unwrap().is_none());
+        assert!(!game.continue_saved_run());
+    }
+
+    #[test]
+    fn test_main_menu_shows_continue_only_for_a_saved_run() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        assert_eq!(game.main_menu().options[0], MainMenuOption::NewGame);
+        assert_eq!(game.main_menu().len(), MainMenuOption::ALL.len() - 1);
+
+        // Up from the first entry wraps to Quit
+        game.select_main_option(-1);
+        assert_eq!(game.selected_main_option, MainMenuOption::Quit);
+
+        game.start_game(Difficulty::Easy);
+        game.transition_to_paused();
+        game.transition_to_forfeit_confirm();
+        game.save_and_quit();
+        assert!(game.is_start_screen());
+        let menu = game.main_menu();
+        assert_eq!(menu.options[0], MainMenuOption::Continue);
+        assert_eq!(menu.len(), MainMenuOption::ALL.len());
+
+        game.select_main_option(1);
+        assert_eq!(game.selected_main_option, MainMenuOption::Continue);
+        game.choose_main_option();
+        assert!(game.is_playing());
+
+        // Back on the menu the entry is gone and the highlight falls to the first row
+        game.transition_to_start_screen();
+        let menu = game.main_menu();
+        assert!(!menu.options.contains(&MainMenuOption::Continue));
+        assert_eq!(menu.focused(game.selected_main_option), 0);
+        game.select_main_option(1);
+        assert_eq!(game.selected_main_option, MainMenuOption::PlayFromSeed);
+    }
+
+    #[test]
+    fn test_snapshot_settles_the_board() {
+        let (mut game, _temp_dir) =
+            test_fixtures::create_test_game_with_config(4, 6, Difficulty::Easy);
+        game.start_game(Difficulty::Easy);
+        let bottom = game.board.height - 1;
+        let king = Card::new(crate::models::Suit::Hearts, crate::models::Value::King);
+        let ace = Card::new(crate::models::Suit::Spades, crate::models::Value::Ace);
+        game.board.place_card(1, bottom, king);
+        game.board.place_card(1, bottom - 1, ace);
+        game.board
+            .mark_cards_for_removal(vec![(1, bottom)], Instant::now() + Duration::from_secs(5));
+
+        game.pending_clear_points.insert((1, bottom), 10);
+
+        // The marked king counts as cleared and the ace drops into its place
+        let run = game.snapshot_run();
+        assert_eq!(run.score, game.score + 10);
+        assert_eq!(run.grid[bottom as usize][1], Some(ace));
+        assert_eq!(run.grid[(bottom - 1) as usize][1], None);
+    }
+
+    #[test]
+    fn test_combination_history_is_bounded() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        for chain in 0..COMBINATION_HISTORY_LIMIT as i32 + 5 {
+            game.record_combination(ClearedCombination::new(Vec::new(), 1, chain));
+        }
+
+        assert_eq!(game.combination_history.len(), COMBINATION_HISTORY_LIMIT);
+        assert_eq!(game.combination_history.front().unwrap().chain, 5);
+        assert_eq!(
+            game.last_combination().unwrap().chain,
+            COMBINATION_HISTORY_LIMIT as i32 + 4
+        );
+    }
+
+    #[test]
+    fn test_first_run_setup_wizard() {
+        use crate::models::ControlScheme;
+        use crate::store::{MemoryScoreStore, MemorySettingsStore};
+
+        let mut game = Game::builder()
+            .score_store(Box::new(MemoryScoreStore::new()))
+            .settings_store(Box::new(MemorySettingsStore::new()))
+            .first_run_setup(true)
+            .build()
+            .expect("Failed to create game");
+        assert!(game.is_setup_wizard());
+
+        // Controls first
+        game.setup_wizard_adjust(1);
+        assert_eq!(game.settings.control_scheme, ControlScheme::Keyboard);
+
+        // Volume: second row is sound effects
+        game.setup_wizard_next();
+        game.setup_wizard_select_row(1);
+        game.setup_wizard_adjust(-1);
+        assert!((game.settings.sound_effects_volume - 0.7).abs() < 0.001);
+
+        // Difficulty, then finishing saves and moves on
+        game.setup_wizard_next();
+        game.setup_wizard_adjust(1);
+        game.setup_wizard_next();
+        assert!(game.is_start_screen());
+
+        let saved = game
+            .settings_store
+            .load()
+            .unwrap()
+            .expect("Settings should be saved");
+        assert_eq!(saved.control_scheme, ControlScheme::Keyboard);
+        assert_eq!(saved.difficulty, Difficulty::Hard);
+    }
+
+    #[test]
+    fn test_setup_wizard_skipped_when_settings_exist() {
+        use crate::store::{MemoryScoreStore, MemorySettingsStore};
+
+        let mut settings_store = MemorySettingsStore::new();
+        settings_store.save(&GameSettings::default()).unwrap();
+
+        let game = Game::builder()
+            .score_store(Box::new(MemoryScoreStore::new()))
+            .settings_store(Box::new(settings_store))
+            .first_run_setup(true)
+            .build()
+            .expect("Failed to create game");
+        assert!(game.is_start_screen());
+    }
+
+    #[test]
+    fn test_game_state_transitions() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+
+        // Should start in StartScreen state
+        assert!(game.is_start_screen());
+        assert!(!game.is_playing());
+        assert!(!game.is_paused());
+        assert!(!game.is_game_over());
+        assert!(!game.is_quit_confirm());
+
+        // Transition to playing
+        game.transition_to_playing();
+        assert!(game.is_playing());
+        assert!(!game.is_start_screen());
+
+        // Transition to paused
+        game.transition_to_paused();
+        assert!(game.is_paused());
+        assert!(!game.is_playing());
+
+        // Transition to game over
+        game.transition_to_game_over();
+        assert!(game.is_game_over());
+        assert!(!game.is_paused());
+
+        // Transition to quit confirm
+        game.transition_to_quit_confirm();
+        assert!(game.is_quit_confirm());
+        assert!(!game.is_game_over());
+
+        // Transition back to start screen
+        game.transition_to_start_screen();
+        assert!(game.is_start_screen());
+        assert!(!game.is_quit_confirm());
+    }
+
+    #[test]
+    fn test_game_over_replay_steps_through_history() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+
+        let start = Instant::now();
+        let king = Card::new(crate::models::Suit::Spades, crate::models::Value::King);
+        for step in 0..3 {
+            game.board.place_card(0, game.board.height - 1 - step, king);
+            game.board_history.record(
+                &game.board.grid,
+                None,
+                start + Duration::from_secs(step as u64),
+            );
+        }
+
+        game.transition_to_game_over();
+        assert_eq!(game.replay_step(), 0);
+        assert!(game.replay_frame().is_none());
+
+        game.step_replay(1);
+        assert_eq!(game.replay_step(), 1);
+        let frame = game.replay_frame().unwrap();
+        assert!(frame.grid[(game.board.height - 3) as usize][0].is_none());
+
+        // Can't go further back than the oldest frame, or past the final board
+        game.step_replay(10);
+        assert_eq!(game.replay_step(), 2);
+        game.step_replay(-10);
+        assert_eq!(game.replay_step(), 0);
+    }
+
+    #[test]
+    fn test_spawn_nudges_past_blocked_columns() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let king = Card::new(crate::models::Suit::Spades, crate::models::Value::King);
+
+        // Nearly full top row: only the far left column is open
+        game.last_dropped_x = Some(game.board.width - 1);
+        for x in 1..game.board.width {
+            game.board.place_card(x, 0, king);
+        }
+        game.current_card = None;
+        game.spawn_new_card();
+        let spawned = game.current_card.as_ref().expect("Card should spawn");
+        assert_eq!(spawned.position.x, 0);
+        assert_eq!(spawned.target.x, 0);
+        assert_eq!(spawned.visual_position.x, 0.0);
+        game.check_game_over();
+        assert!(game.is_playing());
+
+        // A full top row leaves nowhere to spawn, and the run ends cleanly
+        game.board.place_card(0, 0, king);
+        game.current_card = None;
+        game.spawn_new_card();
+        assert!(game.current_card.is_none());
+        game.check_game_over();
+        assert!(game.is_game_over());
+        assert_eq!(game.losing_column, Some(game.board.width - 1));
+    }
+
+    #[test]
+    fn test_top_row_temporarily_occupied_during_cascade() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let spawn_x = game.board.width / 2;
+        let king = Card::new(crate::models::Suit::Spades, crate::models::Value::King);
+        let five = Card::new(crate::models::Suit::Spades, crate::models::Value::Five);
+
+        // A full board whose top card in the spawn column is part of a clear that hasn't
+        // finished yet
+        for x in 0..game.board.width {
+            for y in 0..game.board.height {
+                let card = if (x, y) == (spawn_x, 0) { five } else { king };
+                game.board.place_card(x, y, card);
+            }
+        }
+        game.board
+            .mark_cards_for_removal(vec![(spawn_x, 0)], Instant::now() + Duration::from_secs(60));
+        game.check_game_over();
+        assert!(game.is_playing());
+
+        // Still waiting on the cascade check that follows the clear
+        game.board.marked_for_removal[0][spawn_x as usize] = Some(Instant::now());
+        game.delayed_destructions.push(DelayedDestruction {
+            destruction_time: Instant::now() + Duration::from_secs(60),
+            chain_multiplier: 1,
+        });
+        game.process_card_removals();
+        game.check_game_over();
+        assert!(game.is_playing());
+
+        // Once everything settles the cleared cell is free again
+        game.delayed_destructions.clear();
+        game.check_game_over();
+        assert!(game.is_playing());
+
+        game.board.place_card(spawn_x, 0, king);
+        game.check_game_over();
+        assert!(game.is_game_over());
+        assert_eq!(game.losing_column, Some(spawn_x));
+    }
+
+    #[test]
+    fn test_game_over_zooms_toward_losing_column() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        assert!(game.game_over_zoom_progress().is_none());
+
+        let king = Card::new(crate::models::Suit::Spades, crate::models::Value::King);
+        game.last_dropped_x = Some(4);
+        for x in 0..game.board.width {
+            game.board.place_card(x, 0, king);
+        }
+        game.check_game_over();
+        assert!(game.is_game_over());
+        assert_eq!(game.losing_column, Some(4));
+        assert!(game.game_over_zoom_progress().is_some_and(|p| p < 1.0));
+
+        // Finished zooms and reduced motion go straight to the overlay
+        game.settings.reduced_motion = true;
+        assert!(game.game_over_zoom_progress().is_none());
+        game.settings.reduced_motion = false;
+        game.game_over_started =
+            Some(Instant::now() - Duration::from_millis(GAME_OVER_ZOOM_MS + 1));
+        assert!(game.game_over_zoom_progress().is_none());
+
+        game.start_game(Difficulty::Easy);
+        assert_eq!(game.losing_column, None);
+    }
+
+    #[test]
+    fn test_game_over_detects_new_record() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        test_fixtures::take_audio_events(&mut game);
+
+        game.score = 500;
+        game.transition_to_game_over();
+        test_fixtures::finish_score_requests(&mut game);
+
+        assert_eq!(game.new_record_rank, Some(1));
+        assert!(game.is_celebrating_new_record());
+        let events = game.take_events();
+        assert!(events.contains(&GameEvent::NewRecord { rank: 1 }));
+        assert!(events.contains(&GameEvent::GameOver { score: 500 }));
+    }
+
+    #[test]
+    fn test_leaderboard_period_fetches_its_scores() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        assert_eq!(game.leaderboard_period, LeaderboardPeriod::AllTime);
+
+        game.start_game(Difficulty::Easy);
+        game.score = 400;
+        game.player_initials = "NEW".to_string();
+        game.save_high_score();
+        test_fixtures::finish_score_requests(&mut game);
+
+        // A score from just now is on today's board too
+        game.cycle_leaderboard_period(1);
+        assert_eq!(game.leaderboard_period, LeaderboardPeriod::Today);
+        test_fixtures::finish_score_requests(&mut game);
+        assert!(game.high_scores.iter().any(|s| s.player_initials == "NEW"));
+
+        game.cycle_leaderboard_period(-1);
+        assert_eq!(game.leaderboard_period, LeaderboardPeriod::AllTime);
     }
 
     #[test]
-    fn test_game_builder_basic() {
-        let (game, _temp_dir) = test_fixtures::create_test_game();
+    fn test_blocked_initials_are_turned_away() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.name_filter = NameFilter::new(["BAD"]);
+        game.start_game(Difficulty::Easy);
+        game.score = 500;
+        game.transition_to_game_over();
+        test_fixtures::take_audio_events(&mut game);
 
-        assert_eq!(game.board.width, 10); // Default width
-        assert_eq!(game.board.height, 15); // Default height
-        assert_eq!(game.difficulty, Difficulty::Easy); // Default difficulty
-        assert_eq!(game.score, 0);
-        assert!(game.current_card.is_none());
-        assert!(game.next_card.is_some());
+        for c in "bad".chars() {
+            game.add_initial(c);
+        }
+        game.submit_initials();
+        assert!(game.is_game_over());
+        assert!(game.player_initials.is_empty());
+        assert!(game.initials_rejected_progress().is_some());
+        assert!(
+            test_fixtures::take_audio_events(&mut game).contains(&AudioEvent::InitialsRejected)
+        );
+
+        for c in "BOB".chars() {
+            game.add_initial(c);
+        }
+        game.submit_initials();
         assert!(game.is_start_screen());
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.high_scores[0].player_initials, "BOB");
     }
 
     #[test]
-    fn test_game_builder_with_custom_config() {
-        let (game, _temp_dir) =
-            test_fixtures::create_test_game_with_config(8, 12, Difficulty::Hard);
-
-        assert_eq!(game.board.width, 8);
-        assert_eq!(game.board.height, 12);
-        assert_eq!(game.difficulty, Difficulty::Hard);
-    }
+    fn test_game_over_waits_for_keys_held_from_play() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        game.transition_to_game_over();
+        let started = game.game_over_started.unwrap();
+        let enter =
+            |ms| InputSnapshot::pressing(started + Duration::from_millis(ms), &[input::Key::Enter]);
+        let idle = |ms| InputSnapshot::new(started + Duration::from_millis(ms));
 
-    #[test]
-    fn test_game_builder_all_options() {
-        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
-        let db_path = temp_dir.path().join("test_game.db");
+        // Nothing counts straight away, even with every key up
+        assert!(!game.game_over_accepts_input(&idle(0)));
 
-        let game = Game::builder()
-            .board_size(6, 10)
-            .cell_size(32)
-            .difficulty(Difficulty::Hard)
-            .fall_speed(Duration::from_millis(500))
-            .speed_increase_interval(Duration::from_secs(45))
-            .database_path(&db_path)
-            .build()
-            .expect("Failed to create game");
+        // Past the debounce a key still down from play is ignored until it's let go
+        let late = GAME_OVER_INPUT_DEBOUNCE_MS + 50;
+        assert!(!game.game_over_accepts_input(&enter(late)));
+        assert!(game.game_over_accepts_input(&idle(late + 10)));
+        assert!(game.game_over_accepts_input(&enter(late + 20)));
 
-        assert_eq!(game.board.width, 6);
-        assert_eq!(game.board.height, 10);
-        assert_eq!(game.board.cell_size, 32);
-        assert_eq!(game.difficulty, Difficulty::Hard);
-        assert_eq!(game.fall_speed, Duration::from_millis(500));
-        assert_eq!(game.speed_increase_interval, Duration::from_secs(45));
+        // Only game over waits
+        game.transition_to_start_screen();
+        assert!(!game.game_over_accepts_input(&idle(late + 30)));
     }
 
     #[test]
-    fn test_game_builder_missing_database_path() {
-        let result = Game::builder().build();
-        assert!(result.is_err());
+    fn test_new_personal_best_flashes_once_per_run() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.personal_best, None); // Nothing saved yet, so nothing to beat
+        game.score = 300;
+        game.player_initials = "AAA".to_string();
+        game.save_high_score();
+
+        // Another difficulty keeps its own best
+        game.start_game(Difficulty::Hard);
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.best_to_show(), None);
+
+        game.start_game(Difficulty::Easy);
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.best_to_show(), Some(300));
+        game.take_events();
+
+        // Matching the best isn't beating it
+        game.score = 300;
+        game.update();
+        assert!(game.personal_best_banner_progress().is_none());
+
+        game.score = 301;
+        game.update();
+        assert!(game.personal_best_banner_progress().is_some());
+        assert_eq!(game.best_to_show(), Some(301));
+        assert_eq!(
+            test_fixtures::take_audio_events(&mut game),
+            vec![AudioEvent::NewPersonalBest]
+        );
+
+        game.score = 500;
+        game.update();
+        assert!(test_fixtures::take_audio_events(&mut game).is_empty());
+        assert_eq!(game.best_to_show(), Some(500));
     }
 
     #[test]
-    fn test_game_state_transitions() {
+    fn test_game_over_without_new_record() {
         let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
 
-        // Should start in StartScreen state
-        assert!(game.is_start_screen());
-        assert!(!game.is_playing());
-        assert!(!game.is_paused());
-        assert!(!game.is_game_over());
-        assert!(!game.is_quit_confirm());
+        // Fill the top 3 with better scores
+        for _ in 0..3 {
+            game.score = 1000;
+            game.player_initials = "AAA".to_string();
+            game.save_high_score();
+        }
 
-        // Transition to playing
-        game.transition_to_playing();
-        assert!(game.is_playing());
-        assert!(!game.is_start_screen());
+        game.score = 500;
+        game.transition_to_game_over();
+        test_fixtures::finish_score_requests(&mut game);
 
-        // Transition to paused
-        game.transition_to_paused();
-        assert!(game.is_paused());
-        assert!(!game.is_playing());
+        assert_eq!(game.new_record_rank, None);
+        assert!(!game.is_celebrating_new_record());
+        assert!(!test_fixtures::take_audio_events(&mut game).contains(&AudioEvent::NewRecord));
+    }
 
-        // Transition to game over
-        game.transition_to_game_over();
-        assert!(game.is_game_over());
-        assert!(!game.is_paused());
+    #[test]
+    fn test_game_over_reports_percentile() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        for score in [1000, 1000, 200] {
+            game.score = score;
+            game.player_initials = "AAA".to_string();
+            game.save_high_score();
+        }
 
-        // Transition to quit confirm
-        game.transition_to_quit_confirm();
-        assert!(game.is_quit_confirm());
-        assert!(!game.is_game_over());
+        // Only 200 of the three saved scores is beaten
+        game.score = 500;
+        game.transition_to_game_over();
+        test_fixtures::finish_score_requests(&mut game);
+        assert_eq!(game.run_percentile, Some(33));
 
-        // Transition back to start screen
-        game.transition_to_start_screen();
-        assert!(game.is_start_screen());
-        assert!(!game.is_quit_confirm());
+        // The next run starts without one
+        game.start_game(Difficulty::Easy);
+        assert_eq!(game.run_percentile, None);
     }
 
     #[test]
@@ -911,12 +4551,15 @@ mod tests {
         assert!(game.is_playing());
         assert_eq!(game.difficulty, Difficulty::Hard);
         assert_eq!(game.score, 0);
-        assert_eq!(game.fall_speed, Duration::from_millis(1000));
+        assert_eq!(
+            game.fall_speed,
+            Difficulty::Hard.params().initial_fall_speed
+        );
         assert!(game.current_card.is_some());
-        assert!(!game.pending_audio_events.is_empty());
+        assert!(!game.events.is_empty());
 
         // Should have StartGame audio event
-        let audio_events = game.take_pending_audio_events();
+        let audio_events = test_fixtures::take_audio_events(&mut game);
         assert!(audio_events.contains(&AudioEvent::StartGame));
     }
 
@@ -926,11 +4569,17 @@ mod tests {
 
         // Ensure we have a next card
         assert!(game.next_card.is_some());
+        assert!(game.next_card_reveal_progress().is_none());
 
         game.spawn_new_card();
 
         assert!(game.current_card.is_some());
 
+        // The card drawn to replace it in the preview flips face up
+        assert!(game.next_card_reveal_progress().is_some());
+        game.next_card_dealt = Some(Instant::now() - Duration::from_millis(NEXT_CARD_REVEAL_MS));
+        assert!(game.next_card_reveal_progress().is_none());
+
         let current_card = game.current_card.as_ref().unwrap();
         assert_eq!(current_card.position.y, 0); // Should spawn at top
         assert!(current_card.position.x >= 0 && current_card.position.x < game.board.width);
@@ -949,7 +4598,7 @@ mod tests {
             assert_eq!(game.current_card.as_ref().unwrap().target.x, initial_x - 1);
 
             // Should have MoveLeft audio event
-            let audio_events = game.take_pending_audio_events();
+            let audio_events = test_fixtures::take_audio_events(&mut game);
             assert!(audio_events.contains(&AudioEvent::MoveLeft));
         }
     }
@@ -967,11 +4616,53 @@ mod tests {
             assert_eq!(game.current_card.as_ref().unwrap().target.x, initial_x + 1);
 
             // Should have MoveRight audio event
-            let audio_events = game.take_pending_audio_events();
+            let audio_events = test_fixtures::take_audio_events(&mut game);
             assert!(audio_events.contains(&AudioEvent::MoveRight));
         }
     }
 
+    #[test]
+    fn test_sideways_move_mid_fall_checks_the_row_below() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        let mut card = test_fixtures::create_test_playing_card();
+        card.target.y = 2;
+        card.is_falling = true;
+        game.current_card = Some(card);
+
+        // The next column is open beside the card but taken where it's falling to
+        game.board
+            .place_card(3, 2, test_fixtures::create_test_playing_card().card);
+        game.move_current_card_right();
+        assert_eq!(game.current_card.as_ref().unwrap().target.x, 2);
+
+        game.move_current_card_left();
+        assert_eq!(game.current_card.as_ref().unwrap().target.x, 1);
+    }
+
+    #[test]
+    fn test_step_current_card_toward() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        assert!(!game.step_current_card_toward(5)); // No card to move
+
+        game.current_card = Some(test_fixtures::create_test_playing_card());
+        assert!(game.step_current_card_toward(4));
+        assert_eq!(game.current_card.as_ref().unwrap().target.x, 3);
+
+        // Mid-slide the card keeps its target until it lands in the column
+        assert!(game.step_current_card_toward(4));
+        assert_eq!(game.current_card.as_ref().unwrap().target.x, 3);
+
+        let card = game.current_card.as_mut().unwrap();
+        card.position.x = 4;
+        card.target.x = 4;
+        assert!(!game.step_current_card_toward(4));
+
+        // A blocked column stops the walk
+        game.board
+            .place_card(3, 1, test_fixtures::create_test_playing_card().card);
+        assert!(!game.step_current_card_toward(0));
+    }
+
     #[test]
     fn test_move_current_card_down() {
         let (mut game, _temp_dir) = test_fixtures::create_test_game();
@@ -986,16 +4677,149 @@ mod tests {
             // Should have moved down
             if game.current_card.is_some() {
                 // Still have card (it moved but didn't land)
-                let audio_events = game.take_pending_audio_events();
+                let audio_events = test_fixtures::take_audio_events(&mut game);
                 assert!(audio_events.contains(&AudioEvent::SoftDrop));
             } else {
                 // Card was placed (reached bottom or landed on something)
-                let audio_events = game.take_pending_audio_events();
+                let audio_events = test_fixtures::take_audio_events(&mut game);
                 assert!(audio_events.contains(&AudioEvent::DropCard));
             }
         }
     }
 
+    #[test]
+    fn test_landed_card_slides_into_gap() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let bottom = game.board.height - 1;
+        let king = Card::new(crate::models::Suit::Spades, crate::models::Value::King);
+        game.board.place_card(3, bottom, king);
+        game.board.place_card(4, bottom, king);
+        game.board.place_card(4, bottom - 1, king);
+
+        // Landing on column 3 with column 2 lower beside it waits instead of locking
+        let mut card = test_fixtures::create_test_playing_card();
+        card.position = Position {
+            x: 3,
+            y: bottom - 1,
+        };
+        card.target = card.position;
+        game.current_card = Some(card);
+        game.move_current_card_down();
+        assert!(game.current_card.is_some());
+        assert!(game.lock_started.is_some());
+
+        // The slide moves it over, and it carries on falling
+        game.move_current_card_left();
+        assert!(game.lock_started.is_none());
+        assert_eq!(game.current_card.as_ref().unwrap().position.x, 2);
+        game.move_current_card_down();
+        assert_eq!(game.current_card.as_ref().unwrap().target.y, bottom);
+        game.current_card.as_mut().unwrap().position.y = bottom;
+
+        // Only one slide per card: the next landing locks straight away
+        game.move_current_card_down();
+        assert!(game.current_card.is_none());
+        assert_eq!(
+            game.board.grid[bottom as usize][2],
+            Some(test_fixtures::create_test_playing_card().card)
+        );
+    }
+
+    #[test]
+    fn test_bust_rule_locks_overloaded_column() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let bottom = game.board.height - 1;
+        let spade = |value| Card::new(crate::models::Suit::Spades, value);
+        game.board
+            .place_card(0, bottom, spade(crate::models::Value::Queen));
+        game.board
+            .place_card(0, bottom - 1, spade(crate::models::Value::Nine));
+
+        // Without the rule, stacking past 21 is harmless
+        let mut card = test_fixtures::create_test_playing_card();
+        card.card = spade(crate::models::Value::Eight);
+        card.position = Position {
+            x: 0,
+            y: bottom - 2,
+        };
+        game.current_card = Some(card.clone());
+        game.place_current_card();
+        assert!(!game.board.is_locked(0, bottom));
+
+        // With it, the run busts and locks until its time is up
+        game.board.remove_card(0, bottom - 2);
+        game.settings.bust_rule = true;
+        game.current_card = Some(card);
+        game.place_current_card();
+        for y in (bottom - 2)..=bottom {
+            assert!(game.board.is_locked(0, y));
+        }
+
+        let unlock_time = game.board.locked[bottom as usize][0].unwrap();
+        assert!(unlock_time > Instant::now() + Duration::from_millis(BUST_LOCK_MS - 1000));
+        for row in game.board.locked.iter_mut() {
+            for lock in row.iter_mut().flatten() {
+                *lock = Instant::now();
+            }
+        }
+        game.unlock_busted_cards();
+        assert!(!game.board.is_locked(0, bottom));
+    }
+
+    #[test]
+    fn test_lock_delay_expires() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let bottom = game.board.height - 1;
+        let king = Card::new(crate::models::Suit::Spades, crate::models::Value::King);
+
+        // Hemmed in on both sides: nothing to slide into, so it locks on landing
+        for x in [1, 3] {
+            game.board.place_card(x, bottom, king);
+        }
+        let mut card = test_fixtures::create_test_playing_card();
+        card.position = Position { x: 2, y: bottom };
+        card.target = card.position;
+        game.current_card = Some(card);
+        game.move_current_card_down();
+        assert!(game.current_card.is_none());
+        assert!(game.lock_started.is_none());
+
+        // Open floor beside it isn't a gap either
+        let mut card = test_fixtures::create_test_playing_card();
+        card.position = Position { x: 6, y: bottom };
+        card.target = card.position;
+        game.current_card = Some(card.clone());
+        game.move_current_card_down();
+        assert!(game.lock_started.is_none());
+        game.board.remove_card(6, bottom);
+
+        // Under an overhang it waits, then locks once the delay runs out
+        game.board.place_card(7, bottom - 1, king);
+        game.current_card = Some(card.clone());
+        game.move_current_card_down();
+        assert!(game.lock_started.is_some());
+        game.handle_automatic_card_fall();
+        assert!(game.current_card.is_some());
+
+        // Time spent paused doesn't count towards the delay
+        let started = game.lock_started.unwrap();
+        let away = Duration::from_millis(LOCK_DELAY_MS * 4);
+        game.transition_to_paused_for(PauseReason::FocusLost);
+        game.suspended_at = Some(Instant::now() - away);
+        game.transition_to_playing();
+        assert!(game.lock_started.unwrap() >= started + away);
+        game.handle_automatic_card_fall();
+        assert!(game.current_card.is_some());
+
+        game.lock_started = Some(Instant::now() - Duration::from_millis(LOCK_DELAY_MS + 1));
+        game.handle_automatic_card_fall();
+        assert!(game.current_card.is_none());
+        assert!(game.board.grid[bottom as usize][6].is_some());
+    }
+
     #[test]
     fn test_is_move_valid() {
         let (game, _temp_dir) = test_fixtures::create_test_game();
@@ -1028,10 +4852,229 @@ mod tests {
         assert!(game.current_card.is_some()); // New card spawned
 
         // Should have HardDrop audio event
-        let audio_events = game.take_pending_audio_events();
+        let audio_events = test_fixtures::take_audio_events(&mut game);
         assert!(audio_events.contains(&AudioEvent::HardDrop));
     }
 
+    #[test]
+    fn test_rapid_hard_drops_stack_in_order() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let bottom = game.board.height - 1;
+
+        // Three drops in a row into the same column, before any of them lands
+        let mut dropped = Vec::new();
+        for _ in 0..3 {
+            let card = game.current_card.as_ref().expect("Card should spawn").card;
+            dropped.push(card);
+            game.hard_drop();
+        }
+        let x = game.last_dropped_x.unwrap();
+        let targets: Vec<i32> = game
+            .hard_dropping_cards
+            .iter()
+            .map(|card| card.target.y)
+            .collect();
+        assert_eq!(targets, vec![bottom, bottom - 1, bottom - 2]);
+        assert!((0..3).all(|row| game.board.reserved[(bottom - row) as usize][x as usize]));
+
+        // The falling card can't soft drop into a reserved cell either
+        assert!(!game.is_move_valid(x, bottom - 3, x, bottom - 2));
+
+        // Every card lands where it was headed, in the order it was dropped
+        for _ in 0..200 {
+            game.update_animations();
+        }
+        assert!(game.hard_dropping_cards.is_empty());
+        for (row, card) in dropped.iter().enumerate() {
+            let y = (bottom - row as i32) as usize;
+            assert!(
+                game.board.grid[y][x as usize] == Some(*card)
+                    || game.board.marked_for_removal[y][x as usize].is_some()
+            );
+        }
+        assert!(
+            game.board
+                .reserved
+                .iter()
+                .flatten()
+                .all(|reserved| !reserved)
+        );
+    }
+
+    #[test]
+    fn test_hard_drop_mid_slide_lands_in_the_new_column() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let bottom = game.board.height - 1;
+        let x = game
+            .current_card
+            .as_ref()
+            .expect("Card should spawn")
+            .position
+            .x;
+
+        // Drop straight after moving, before the card has slid over
+        game.move_current_card_right();
+        game.hard_drop();
+
+        let card = &game.hard_dropping_cards[0];
+        assert_eq!(card.position.x, x + 1);
+        assert_eq!(
+            card.target,
+            Position {
+                x: x + 1,
+                y: bottom
+            }
+        );
+        assert!(game.board.reserved[bottom as usize][(x + 1) as usize]);
+        assert!(!game.board.reserved[bottom as usize][x as usize]);
+    }
+
+    #[test]
+    fn test_run_uses_the_chosen_deck_template() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.settings.deck_template = crate::models::DeckTemplate::LowBall;
+        game.start_game(Difficulty::Easy);
+
+        // One card falling and one waiting out of the forty
+        assert_eq!(game.deck.remaining(), 38);
+        assert!(game.deck.cards().iter().all(|card| card.value.value() <= 10
+            && !matches!(
+                card.value,
+                crate::models::Value::Jack
+                    | crate::models::Value::Queen
+                    | crate::models::Value::King
+            )));
+
+        // Saved runs remember it, so continuing rebuilds the same deck
+        let run = game.snapshot_run();
+        assert_eq!(run.deck_template, crate::models::DeckTemplate::LowBall);
+        game.settings.deck_template = crate::models::DeckTemplate::Standard;
+        game.saved_run = Some(run);
+        assert!(game.continue_saved_run());
+        assert_eq!(game.deck.template(), crate::models::DeckTemplate::LowBall);
+        assert_eq!(game.deck.remaining() + game.deck.discards().len(), 38);
+    }
+
+    #[test]
+    fn test_debug_snapshot_round_trip() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Hard);
+        let bottom = game.board.height - 1;
+        game.board.place_card(
+            0,
+            bottom,
+            Card::new(crate::models::Suit::Hearts, crate::models::Value::Two),
+        );
+        game.board.place_card(
+            1,
+            bottom,
+            Card::new(crate::models::Suit::Spades, crate::models::Value::Three),
+        );
+        game.board.lock_cards(&[(1, bottom)], Instant::now());
+        game.score = 420;
+        game.hard_drop();
+
+        let snapshot = game.debug_snapshot();
+        let json = serde_json::to_string(&snapshot).expect("Snapshot should serialize");
+        let snapshot: DebugSnapshot = serde_json::from_str(&json).expect("Snapshot should parse");
+
+        let (mut restored, _temp_dir2) = test_fixtures::create_test_game();
+        restored
+            .restore_debug_snapshot(snapshot.clone())
+            .expect("Snapshot should restore");
+        assert_eq!(restored.difficulty, Difficulty::Hard);
+        assert_eq!(restored.score, 420);
+        assert_eq!(restored.deck.cards(), snapshot.deck.as_slice());
+        assert_eq!(restored.next_card, snapshot.next_card);
+        assert!(restored.board.is_locked(1, bottom));
+        assert!(restored.run_modified);
+        assert!(restored.snapshot_run().modified);
+
+        // The hard drop in flight has landed where it was headed
+        let (card, (x, y)) = snapshot.hard_drops[0];
+        assert_eq!(restored.board.grid[y as usize][x as usize], Some(card));
+        assert_eq!(
+            restored.current_card.as_ref().map(|playing| playing.card),
+            snapshot.current_card.map(|(card, _)| card)
+        );
+
+        // A board of another size isn't one we can set up
+        let mut small = snapshot;
+        small.grid.pop();
+        assert!(restored.restore_debug_snapshot(small).is_err());
+    }
+
+    #[test]
+    fn test_hard_drop_steer() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let bottom = game.board.height - 1;
+        let x = game.current_card.as_ref().unwrap().position.x;
+
+        // Off by default: left moves the newly spawned card instead
+        game.hard_drop();
+        game.move_current_card_left();
+        assert_eq!(game.hard_dropping_cards[0].target.x, x);
+        assert_eq!(game.current_card.as_ref().unwrap().target.x, x - 1);
+
+        game.start_game(Difficulty::Easy);
+        game.settings.hard_drop_steer = true;
+        game.hard_drop();
+        game.move_current_card_right();
+        let steered = &game.hard_dropping_cards[0];
+        assert_eq!(
+            steered.target,
+            Position {
+                x: x + 1,
+                y: bottom
+            }
+        );
+        assert!(game.board.reserved[bottom as usize][(x + 1) as usize]);
+        assert!(!game.board.reserved[bottom as usize][x as usize]);
+        assert_eq!(game.current_card.as_ref().unwrap().target.x, x);
+
+        // A blocked column keeps the drop where it is
+        let king = Card::new(crate::models::Suit::Spades, crate::models::Value::King);
+        game.board.place_card(x + 2, 0, king);
+        game.move_current_card_right();
+        assert_eq!(game.hard_dropping_cards[0].target.x, x + 1);
+
+        // Once the window has passed, input goes back to the falling card
+        game.hard_drop_started =
+            Some(Instant::now() - Duration::from_millis(HARD_DROP_STEER_MS + 100));
+        game.move_current_card_left();
+        assert_eq!(game.hard_dropping_cards[0].target.x, x + 1);
+        assert_eq!(game.current_card.as_ref().unwrap().target.x, x - 1);
+
+        for _ in 0..200 {
+            game.update_animations();
+        }
+        assert!(game.hard_dropping_cards.is_empty());
+        assert!(game.board.grid[bottom as usize][(x + 1) as usize].is_some());
+    }
+
+    #[test]
+    fn test_hard_drop_target_follows_clears() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let bottom = game.board.height - 1;
+        let x = game.current_card.as_ref().unwrap().position.x;
+        let king = Card::new(crate::models::Suit::Spades, crate::models::Value::King);
+        game.board.place_card(x, bottom, king);
+
+        game.hard_drop();
+        assert_eq!(game.hard_dropping_cards[0].target.y, bottom - 1);
+
+        // The card it was headed for is cleared while it's still falling
+        game.board.remove_card(x, bottom);
+        game.revalidate_hard_drop_targets();
+        assert_eq!(game.hard_dropping_cards[0].target.y, bottom);
+        assert!(game.board.reserved[bottom as usize][x as usize]);
+        assert!(!game.board.reserved[(bottom - 1) as usize][x as usize]);
+    }
+
     #[test]
     fn test_add_initial() {
         let (mut game, _temp_dir) = test_fixtures::create_test_game();
@@ -1042,9 +5085,12 @@ mod tests {
 
         assert_eq!(game.player_initials, "ABC");
 
-        // Should limit to 3 characters
-        game.add_initial('D');
-        assert_eq!(game.player_initials, "ABC");
+        // Names run past three letters, up to MAX_NAME_LEN
+        for c in "DEFGHIJKL".chars() {
+            game.add_initial(c);
+        }
+        assert_eq!(game.player_initials, "ABCDEFGHIJ");
+        assert_eq!(game.player_initials.len(), MAX_NAME_LEN);
     }
 
     #[test]
@@ -1074,32 +5120,191 @@ mod tests {
         game.add_audio_event(AudioEvent::DropCard);
         game.add_audio_event(AudioEvent::MakeMatch);
 
-        let events = game.take_pending_audio_events();
+        let events = test_fixtures::take_audio_events(&mut game);
         assert_eq!(events.len(), 2);
         assert!(events.contains(&AudioEvent::DropCard));
         assert!(events.contains(&AudioEvent::MakeMatch));
 
         // Should be empty after taking
-        let events2 = game.take_pending_audio_events();
+        let events2 = test_fixtures::take_audio_events(&mut game);
         assert!(events2.is_empty());
     }
 
     #[test]
-    fn test_take_pending_explosions() {
+    fn test_take_events() {
         let (mut game, _temp_dir) = test_fixtures::create_test_game();
 
         let card = Card::new(crate::models::Suit::Hearts, crate::models::Value::King);
-        game.pending_explosions.push((1, 2, card));
-        game.pending_explosions.push((3, 4, card));
+        game.emit(GameEvent::CardExploded { x: 1, y: 2, card });
+        game.emit(GameEvent::CardExploded { x: 3, y: 4, card });
 
-        let explosions = game.take_pending_explosions();
-        assert_eq!(explosions.len(), 2);
-        assert_eq!(explosions[0], (1, 2, card));
-        assert_eq!(explosions[1], (3, 4, card));
+        let events = game.take_events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0], GameEvent::CardExploded { x: 1, y: 2, card });
+        assert_eq!(events[1], GameEvent::CardExploded { x: 3, y: 4, card });
 
         // Should be empty after taking
-        let explosions2 = game.take_pending_explosions();
-        assert!(explosions2.is_empty());
+        assert!(game.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_combinations_explode_one_group_at_a_time() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let king = Card::new(crate::models::Suit::Hearts, crate::models::Value::King);
+        let ace = Card::new(crate::models::Suit::Hearts, crate::models::Value::Ace);
+        for x in [0, 5] {
+            game.board.place_card(x, 14, ace);
+            game.board.place_card(x, 13, king);
+        }
+        game.process_combinations();
+
+        // Both cards of a combination go together, and the second combination waits one
+        // delay after the first
+        let removal_time = |x: usize, y: usize| game.board.marked_for_removal[y][x].unwrap();
+        assert_eq!(removal_time(0, 13), removal_time(0, 14));
+        assert_eq!(removal_time(5, 13), removal_time(5, 14));
+        let delay = game.balance.combination_delay();
+        assert_eq!(removal_time(5, 13) - removal_time(0, 13), delay);
+
+        // The cascade check follows the last combination
+        assert_eq!(game.delayed_destructions.len(), 1);
+        assert_eq!(
+            game.delayed_destructions[0].destruction_time,
+            removal_time(5, 13) + delay
+        );
+    }
+
+    #[test]
+    fn test_cascade_card_explodes_where_it_was_marked() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        let seven = Card::new(crate::models::Suit::Clubs, crate::models::Value::Seven);
+        let king = Card::new(crate::models::Suit::Hearts, crate::models::Value::King);
+        game.board.place_card(0, 14, seven);
+        game.board.place_card(0, 13, king);
+        game.board.place_card(0, 12, seven);
+
+        // The bottom card goes now; the king's combination is next in line
+        let now = Instant::now();
+        game.board.mark_cards_for_removal(vec![(0, 14)], now);
+        game.board
+            .mark_cards_for_removal(vec![(0, 13)], now + Duration::from_secs(60));
+        game.pending_clear_points.insert((0, 13), 20);
+        game.process_card_removals();
+
+        // Gravity leaves the marked king in place, so its mark, points and explosion stay
+        // together
+        assert_eq!(game.board.grid[13][0], Some(king));
+        assert_eq!(game.board.grid[12][0], Some(seven));
+        game.take_events();
+        let score = game.score;
+        game.board.marked_for_removal[13][0] = Some(Instant::now());
+        game.process_card_removals();
+        assert_eq!(
+            game.take_events(),
+            vec![GameEvent::CardExploded {
+                x: 0,
+                y: 13,
+                card: king
+            }]
+        );
+        assert_eq!(game.score, score + 20);
+        assert_eq!(game.board.grid[14][0], Some(seven));
+    }
+
+    #[test]
+    fn test_landing_card_waits_for_card_in_transit() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        game.board.place_card(
+            0,
+            14,
+            Card::new(crate::models::Suit::Hearts, crate::models::Value::Ace),
+        );
+        game.board.place_card(
+            0,
+            10,
+            Card::new(crate::models::Suit::Spades, crate::models::Value::King),
+        );
+        while game.board.apply_gravity() {}
+        assert!(game.board.is_in_transit(0, 13));
+
+        // A card soft dropping onto the falling king waits above it instead of landing
+        if let Some(card) = game.current_card.as_mut() {
+            card.position = Position { x: 0, y: 12 };
+            card.target = card.position;
+        }
+        game.move_current_card_down();
+        assert_eq!(game.board.grid[12][0], None);
+        assert!(game.current_card.is_some());
+        assert!(game.delayed_destructions.is_empty());
+
+        // Once the king lands it thuds, and its 21 with the ace is found
+        game.take_events();
+        for _ in 0..100 {
+            game.update_animations();
+            if !game.board.has_cards_in_transit() {
+                break;
+            }
+        }
+        assert!(!game.board.has_cards_in_transit());
+        assert_eq!(game.delayed_destructions.len(), 1);
+        assert!(game.take_events().contains(&GameEvent::CardLanded {
+            x: 0,
+            y: 13,
+            card: Card::new(crate::models::Suit::Spades, crate::models::Value::King),
+        }));
+    }
+
+    #[test]
+    fn test_combination_emits_cleared_event() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        game.take_events();
+
+        // King on top of an Ace makes 21
+        game.board.place_card(
+            0,
+            14,
+            Card::new(crate::models::Suit::Hearts, crate::models::Value::Ace),
+        );
+        game.board.place_card(
+            0,
+            13,
+            Card::new(crate::models::Suit::Hearts, crate::models::Value::King),
+        );
+        game.process_combinations();
+
+        let mut king_ace = ClearedCombination::new(
+            vec![
+                Card::new(crate::models::Suit::Hearts, crate::models::Value::King),
+                Card::new(crate::models::Suit::Hearts, crate::models::Value::Ace),
+            ],
+            1,
+            1,
+        );
+        king_ace.natural = true;
+        let events = game.take_events();
+        assert!(events.contains(&GameEvent::CombinationCleared {
+            positions: vec![(0, 13), (0, 14)],
+            points: 21,
+            combinations: vec![king_ace.clone()],
+        }));
+        assert_eq!(game.pending_clear_points.get(&(0, 14)), Some(&11));
+        assert_eq!(game.pending_clear_points.get(&(0, 13)), Some(&10));
+
+        // An ace and a king on their own are a natural, which pays its bonus right away
+        assert!(events.contains(&GameEvent::NaturalBlackjack {
+            positions: vec![(0, 13), (0, 14)],
+            bonus: game.balance.natural_bonus,
+        }));
+        assert_eq!(game.score, game.balance.natural_bonus);
+
+        // The info panel shows the last combination until a new game starts
+        assert_eq!(game.last_combination(), Some(&king_ace));
+        game.start_game(Difficulty::Easy);
+        assert_eq!(game.last_combination(), None);
     }
 
     #[test]
@@ -1110,7 +5315,85 @@ mod tests {
         game.increase_speed();
 
         assert!(game.fall_speed < initial_speed);
-        assert!(game.fall_speed >= Duration::from_millis(100)); // Should not go below minimum
+
+        assert_eq!(game.speed_level, 1);
+
+        // Speed-ups never pass the difficulty's minimum fall speed
+        for _ in 0..200 {
+            game.increase_speed();
+        }
+        assert!(game.fall_speed >= game.difficulty.params().min_fall_speed);
+        assert!(
+            game.fall_speed < game.difficulty.params().min_fall_speed + Duration::from_millis(1)
+        );
+    }
+
+    #[test]
+    fn test_large_boards_shrink_to_fit() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut game = Game::builder()
+            .board_size(20, 30)
+            .fit_board_within(560, 720)
+            .database_path(temp_dir.path().join("test_game.db"))
+            .build()
+            .expect("Failed to create test game");
+        assert_eq!(game.board.cell_size, 24);
+
+        // A new run keeps the fitted cell size
+        game.start_game(Difficulty::Easy);
+        assert_eq!(game.board.cell_size, 24);
+
+        // The default board already fits and isn't scaled up or down
+        assert_eq!(fit_cell_size(48, 10, 15, 560, 720), 48);
+        assert_eq!(fit_cell_size(48, 14, 15, 560, 720), 40);
+    }
+
+    #[test]
+    fn test_start_game_uses_difficulty_fall_speed() {
+        let (mut game, _temp_dir) = test_fixtures::create_test_game();
+        game.start_game(Difficulty::Easy);
+        assert_eq!(
+            game.fall_speed,
+            Difficulty::Easy.params().initial_fall_speed
+        );
+
+        // A builder fall speed applies to every run, not just the first
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut game = Game::builder()
+            .fall_speed(Duration::from_millis(400))
+            .database_path(temp_dir.path().join("test_game.db"))
+            .build()
+            .expect("Failed to create test game");
+        game.start_game(Difficulty::Hard);
+        game.increase_speed();
+        game.start_game(Difficulty::Hard);
+        assert_eq!(game.fall_speed, Duration::from_millis(400));
+        assert_eq!(game.speed_level, 0);
+    }
+
+    #[test]
+    fn test_min_fall_speed_overrides() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut game = Game::builder()
+            .database_path(temp_dir.path().join("test_game.db"))
+            .build()
+            .expect("Failed to create test game");
+        game.start_game(Difficulty::Easy);
+
+        // The settings floor replaces the difficulty's
+        game.settings.min_fall_speed_ms = Some(500);
+        assert_eq!(
+            game.speed_params().min_fall_speed,
+            Duration::from_millis(500)
+        );
+
+        // ...and a builder floor wins over both
+        game.min_fall_speed_override = Some(Duration::from_millis(300));
+        for _ in 0..200 {
+            game.increase_speed();
+        }
+        assert!(game.fall_speed >= Duration::from_millis(300));
+        assert!(game.fall_speed < Duration::from_millis(301));
     }
 
     #[test]
@@ -1122,6 +5405,7 @@ mod tests {
         game.difficulty = Difficulty::Hard;
 
         game.save_high_score();
+        test_fixtures::finish_score_requests(&mut game);
 
         // Should reload high scores
         assert!(!game.high_scores.is_empty());
@@ -1133,7 +5417,7 @@ mod tests {
             .find(|hs| hs.player_initials == "TST" && hs.score == 1500)
             .expect("Should find our high score");
 
-        assert_eq!(our_score.difficulty, "Hard");
+        assert_eq!(our_score.difficulty, Difficulty::Hard);
     }
 
     #[test]
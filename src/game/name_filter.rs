@@ -0,0 +1,122 @@
+// Names the high score table won't take. Names are checked before they're saved, and the
+// same filter is there for anything else players name themselves. The list can be replaced
+// with blocked_names.txt in the app data directory: one name per line, with # comments.
+
+use crate::storage::Storage;
+
+const BLOCKED_NAMES_KEY: &str = "blocked_names.txt";
+
+// Used when there's no blocked_names.txt
+const DEFAULT_BLOCKED_NAMES: [&str; 18] = [
+    "ASS", "CUM", "DIC", "DIK", "FAG", "FCK", "FUC", "FUK", "FUX", "JIZ", "KKK", "NIG", "NGR",
+    "SHT", "SLT", "TIT", "TWT", "WTF",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameFilter {
+    blocked: Vec<String>, // Upper case letters only, to match names the same way
+}
+
+impl Default for NameFilter {
+    fn default() -> Self {
+        NameFilter::new(DEFAULT_BLOCKED_NAMES)
+    }
+}
+
+impl NameFilter {
+    pub fn new<S: AsRef<str>>(blocked: impl IntoIterator<Item = S>) -> Self {
+        let blocked = blocked
+            .into_iter()
+            .map(|name| normalize(name.as_ref()))
+            .filter(|name| !name.is_empty())
+            .collect();
+        NameFilter { blocked }
+    }
+
+    /// The names in a blocked_names.txt, ignoring blank lines and anything after a #
+    pub fn from_text(contents: &str) -> Self {
+        NameFilter::new(
+            contents
+                .lines()
+                .map(|line| line.split('#').next().unwrap_or_default()),
+        )
+    }
+
+    /// The list from storage, or the built-in one when there is no file or it can't be read
+    pub fn load_from(storage: &dyn Storage) -> Self {
+        match storage.read(BLOCKED_NAMES_KEY) {
+            Ok(Some(contents)) => {
+                println!("Loaded blocked names from {}", BLOCKED_NAMES_KEY);
+                Self::from_text(&contents)
+            }
+            Ok(None) => Self::default(),
+            Err(e) => {
+                eprintln!(
+                    "Failed to read {}, using defaults: {}",
+                    BLOCKED_NAMES_KEY, e
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Whether `name` is a blocked name, ignoring case, spaces and punctuation. Only whole
+    /// names count, so an ordinary name that happens to contain one still goes through.
+    pub fn blocks(&self, name: &str) -> bool {
+        let name = normalize(name);
+        self.blocked.contains(&name)
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::FileStorage;
+
+    #[test]
+    fn test_blocks_names_in_any_case() {
+        let filter = NameFilter::default();
+        assert!(filter.blocks("KKK"));
+        assert!(filter.blocks("wtf"));
+        assert!(filter.blocks("W.T.F"));
+        assert!(!filter.blocks("AAA"));
+        assert!(!filter.blocks(""));
+    }
+
+    #[test]
+    fn test_names_containing_a_blocked_name_pass() {
+        let filter = NameFilter::default();
+        for name in [
+            "NIGEL",
+            "BENEDICT",
+            "CASSIDY",
+            "CASSANDRA",
+            "TITAN",
+            "TITUS",
+        ] {
+            assert!(!filter.blocks(name), "{} was turned away", name);
+        }
+    }
+
+    #[test]
+    fn test_file_replaces_the_built_in_list() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let storage = FileStorage::new(temp_dir.path()).unwrap();
+        assert_eq!(NameFilter::load_from(&storage), NameFilter::default());
+
+        storage
+            .write(BLOCKED_NAMES_KEY, "# House rules\nbob\n\nz z z # snoring\n")
+            .unwrap();
+        let filter = NameFilter::load_from(&storage);
+        assert!(filter.blocks("BOB"));
+        assert!(filter.blocks("ZZZ"));
+        assert!(!filter.blocks("WTF"));
+    }
+}
@@ -0,0 +1,77 @@
+// Chance that the next card out of the deck finishes a 21 on the board as it stands, for the
+// optional odds readout in the info panel. Only the stacks under each column's top card are
+// counted, the same run the drop preview totals, so sideways 21s aren't included.
+
+use super::board::Board;
+use crate::models::{Card, Difficulty};
+
+/// Whether dropping `card` on top of some column makes a 21 with the stack beneath it
+pub fn completes_21(board: &Board, card: Card, difficulty: Difficulty) -> bool {
+    (0..board.width).any(|x| board.contiguous_sum_preview(x, card, difficulty) == Some(21))
+}
+
+/// Share of the cards the next draw could be that would finish a 21, from 0.0 to 1.0. None
+/// when there's nothing left to draw.
+pub fn next_draw_chance(board: &Board, upcoming: &[Card], difficulty: Difficulty) -> Option<f32> {
+    if upcoming.is_empty() {
+        return None;
+    }
+    let hits = upcoming
+        .iter()
+        .filter(|&&card| completes_21(board, card, difficulty))
+        .count();
+    Some(hits as f32 / upcoming.len() as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Suit, Value};
+
+    fn card(suit: Suit, value: Value) -> Card {
+        Card::new(suit, value)
+    }
+
+    #[test]
+    fn test_next_draw_chance() {
+        let mut board = Board::new(3, 4, 50);
+        board.place_card(0, 3, card(Suit::Hearts, Value::King));
+        board.place_card(0, 2, card(Suit::Hearts, Value::Five));
+
+        // 15 showing: only a six finishes it
+        let upcoming = [
+            card(Suit::Hearts, Value::Six),
+            card(Suit::Clubs, Value::Six),
+            card(Suit::Hearts, Value::Two),
+            card(Suit::Spades, Value::Queen),
+        ];
+        assert_eq!(
+            next_draw_chance(&board, &upcoming, Difficulty::Easy),
+            Some(0.5)
+        );
+
+        // On Hard the six has to be a heart too
+        assert_eq!(
+            next_draw_chance(&board, &upcoming, Difficulty::Hard),
+            Some(0.25)
+        );
+
+        assert_eq!(next_draw_chance(&board, &[], Difficulty::Easy), None);
+    }
+
+    #[test]
+    fn test_completes_21_counts_aces_as_eleven() {
+        let mut board = Board::new(3, 4, 50);
+        board.place_card(2, 3, card(Suit::Spades, Value::Jack));
+        assert!(completes_21(
+            &board,
+            card(Suit::Spades, Value::Ace),
+            Difficulty::Easy
+        ));
+        assert!(!completes_21(
+            &board,
+            card(Suit::Spades, Value::Nine),
+            Difficulty::Easy
+        ));
+    }
+}
@@ -0,0 +1,236 @@
+// Loads the puzzle levels bundled with the game, and the ones made in the level editor.
+// The bundled files are compiled in, so the browser build has them too; editor levels are
+// files in the puzzles folder of the data directory. A level that fails to parse is
+// reported and left out.
+
+use super::states::LevelEditor;
+use crate::models::PuzzleLevel;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+// Where the level editor saves, under the data directory
+pub const USER_LEVELS_DIR: &str = "puzzles";
+
+const LEVEL_FILES: [(&str, &str); 7] = [
+    (
+        "01_first_steps.json",
+        include_str!("../../assets/puzzles/01_first_steps.json"),
+    ),
+    (
+        "02_stack_attack.json",
+        include_str!("../../assets/puzzles/02_stack_attack.json"),
+    ),
+    (
+        "03_side_by_side.json",
+        include_str!("../../assets/puzzles/03_side_by_side.json"),
+    ),
+    (
+        "04_tower.json",
+        include_str!("../../assets/puzzles/04_tower.json"),
+    ),
+    (
+        "05_clear_the_way.json",
+        include_str!("../../assets/puzzles/05_clear_the_way.json"),
+    ),
+    (
+        "06_suited.json",
+        include_str!("../../assets/puzzles/06_suited.json"),
+    ),
+    (
+        "07_crossroads.json",
+        include_str!("../../assets/puzzles/07_crossroads.json"),
+    ),
+];
+
+/// Every bundled level that parses, in play order
+pub fn load_levels() -> Vec<PuzzleLevel> {
+    LEVEL_FILES
+        .iter()
+        .filter_map(|(file, json)| match PuzzleLevel::parse(json) {
+            Ok(level) => Some(level),
+            Err(e) => {
+                eprintln!("Failed to load puzzle {}: {}", file, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Levels saved by the level editor under `data_dir`, sorted by file name
+pub fn load_user_levels(data_dir: &Path) -> Vec<PuzzleLevel> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(data_dir.join(USER_LEVELS_DIR))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let parsed = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|json| PuzzleLevel::parse(&json));
+            match parsed {
+                Ok(level) => Some(level),
+                Err(e) => {
+                    eprintln!("Failed to load puzzle {}: {}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Write a level into the editor's folder under `data_dir`, named by its id, and return
+/// where it went
+pub fn save_user_level(data_dir: &Path, level: &PuzzleLevel) -> Result<PathBuf, Box<dyn Error>> {
+    let dir = data_dir.join(USER_LEVELS_DIR);
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.json", level.id));
+    std::fs::write(&path, serde_json::to_string_pretty(level)?)?;
+    Ok(path)
+}
+
+/// The bundled levels followed by the player's own
+#[cfg(not(target_os = "emscripten"))]
+pub fn load_platform_levels() -> Vec<PuzzleLevel> {
+    let mut levels = load_levels();
+    match crate::storage::FileStorage::app_data() {
+        Ok(storage) => levels.extend(load_user_levels(&storage.path_for(""))),
+        Err(e) => eprintln!("Failed to look for puzzles: {}", e),
+    }
+    levels
+}
+
+// The browser has no data folder to keep editor levels in
+#[cfg(target_os = "emscripten")]
+pub fn load_platform_levels() -> Vec<PuzzleLevel> {
+    load_levels()
+}
+
+#[cfg(not(target_os = "emscripten"))]
+pub fn save_platform_level(level: &PuzzleLevel) -> Result<PathBuf, Box<dyn Error>> {
+    let storage = crate::storage::FileStorage::app_data()?;
+    save_user_level(&storage.path_for(""), level)
+}
+
+#[cfg(target_os = "emscripten")]
+pub fn save_platform_level(_level: &PuzzleLevel) -> Result<PathBuf, Box<dyn Error>> {
+    Err("Levels can't be saved in the browser".into())
+}
+
+// Where a puzzle was started from, and so where it goes back to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PuzzleOrigin {
+    Level(usize),        // Index in Game::puzzle_levels
+    Editor(LevelEditor), // Test-playing the editor's draft
+}
+
+// The level being played
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuzzleRun {
+    pub level: PuzzleLevel,
+    pub origin: PuzzleOrigin,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::Board;
+    use std::collections::HashSet;
+
+    // The game's default board
+    const WIDTH: i32 = 10;
+    const HEIGHT: i32 = 15;
+
+    fn board_for(level: &PuzzleLevel) -> Board {
+        let mut board = Board::new(WIDTH, HEIGHT, 50);
+        board.grid = level.grid(WIDTH, HEIGHT).unwrap();
+        board
+    }
+
+    #[test]
+    fn test_user_levels_round_trip() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        assert!(load_user_levels(temp_dir.path()).is_empty());
+
+        let mut level = load_levels().remove(0);
+        level.id = "custom_1".to_string();
+        level.name = "Custom 1".to_string();
+        let path = save_user_level(temp_dir.path(), &level).unwrap();
+        assert!(path.ends_with("puzzles/custom_1.json"));
+
+        // A broken file next to it is skipped
+        std::fs::write(temp_dir.path().join("puzzles/custom_2.json"), "{").unwrap();
+        assert_eq!(load_user_levels(temp_dir.path()), vec![level]);
+    }
+
+    #[test]
+    fn test_every_level_loads() {
+        let levels = load_levels();
+        assert_eq!(levels.len(), LEVEL_FILES.len());
+
+        let ids: HashSet<&str> = levels.iter().map(|level| level.id.as_str()).collect();
+        assert_eq!(ids.len(), levels.len(), "Level ids must be unique");
+
+        for level in &levels {
+            let board = board_for(level);
+            assert!(
+                board.check_combinations(level.difficulty).is_empty(),
+                "{} starts with a 21 already on the board",
+                level.name
+            );
+            assert!(
+                level.par <= level.cards.len(),
+                "{} par is out of reach",
+                level.name
+            );
+        }
+    }
+
+    // Drop each card of the solution in its column and let every clear and cascade play
+    // out; the board has to end up empty within par
+    #[test]
+    fn test_every_level_has_a_solution() {
+        for level in load_levels() {
+            let mut board = board_for(&level);
+            let cards = level.card_sequence().unwrap();
+            assert!(
+                level.solution.len() <= level.par,
+                "{} solution is over par",
+                level.name
+            );
+
+            for (&x, card) in level.solution.iter().zip(cards) {
+                let y = (0..HEIGHT)
+                    .rev()
+                    .find(|&y| board.is_cell_empty(x, y))
+                    .unwrap_or_else(|| panic!("{}: column {} is full", level.name, x));
+                board.place_card(x, y, card);
+
+                loop {
+                    let positions =
+                        Board::combination_positions(&board.check_combinations(level.difficulty));
+                    if positions.is_empty() {
+                        break;
+                    }
+                    for &(x, y) in &positions {
+                        board.remove_card(x, y);
+                    }
+                    while board.apply_gravity() {}
+                    board.falling_cards.clear();
+                }
+            }
+
+            assert!(
+                board.grid.iter().flatten().all(Option::is_none),
+                "{} isn't cleared by its solution",
+                level.name
+            );
+        }
+    }
+}
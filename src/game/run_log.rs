@@ -0,0 +1,434 @@
+// A compact record of a seeded run for score submissions: each card drawn, where it landed,
+// which 21s cleared, what exploded and what gravity moved, in the order it happened. Timing
+// isn't kept, so nothing depends on frame rate. `replay` plays the record back on a board of
+// its own, dealing from the seed, and works out the score the run really earned; a record
+// that deals cards the seed doesn't, draws more than play can hold, leaves a card hanging
+// where nothing could be under it, or clears something that isn't a 21, is rejected.
+
+use super::balance::Balance;
+use super::board::Board;
+use super::events::ClearedCombination;
+use super::{RELAX_COLUMN_PENALTY, check_board_size};
+use crate::models::{Card, Deck, DeckTemplate, Difficulty, RunSeed};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunLog {
+    pub seed: String, // The run's seed code
+    pub difficulty: Difficulty,
+    pub deck_template: DeckTemplate,
+    pub handicap_rows: u32,
+    pub width: i32,
+    pub height: i32,
+    pub relaxed: bool,
+    pub steps: Vec<RunStep>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "step", rename_all = "snake_case")]
+pub enum RunStep {
+    Draw, // The next card off the deck, reopening it when it's run out
+    Place {
+        x: i32,
+        y: i32,
+        card: Card,
+    },
+    Clear {
+        paths: Vec<Vec<(i32, i32)>>,
+        chain: i32,
+    },
+    Explode {
+        x: i32,
+        y: i32,
+    },
+    Fall {
+        x: i32,
+        from: i32,
+        to: i32,
+    },
+    ColumnCleared {
+        column: i32,
+    }, // Relax mode emptied a full column
+}
+
+/// A score as it would be uploaded: the claim, and the run that backs it up
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreSubmission {
+    pub player_initials: String,
+    pub score: i32,
+    pub run: RunLog,
+}
+
+impl ScoreSubmission {
+    /// Replay the run and check it earned exactly the claimed score
+    pub fn verify(&self, balance: &Balance) -> Result<(), String> {
+        let earned = self.run.replay(balance)?;
+        if earned != self.score {
+            return Err(format!(
+                "Claims {} points but the run earned {}",
+                self.score, earned
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl RunLog {
+    /// Play the steps back from the seed and return the score they add up to
+    pub fn replay(&self, balance: &Balance) -> Result<i32, String> {
+        let seed = RunSeed::parse(&self.seed).ok_or(format!("Bad seed {:?}", self.seed))?;
+        check_board_size(self.width, self.height)
+            .map_err(|problem| format!("Bad board size: {}", problem))?;
+
+        // Dealt the way Game::start_seeded_game deals
+        let mut board = Board::new(self.width, self.height, 1);
+        let mut deck = Deck::seeded(self.deck_template, seed.value());
+        deck.shuffle();
+        if self.handicap_rows > 0 {
+            let mut rng = StdRng::seed_from_u64(seed.value());
+            for card in board.fill_random_rows(self.handicap_rows as i32, &mut rng) {
+                deck.take(card);
+            }
+        }
+
+        let mut replay = Replay {
+            board,
+            deck,
+            dealt: Vec::new(),
+            pending_points: vec![vec![0; self.width as usize]; self.height as usize],
+            score: 0,
+            last_chain: 0,
+        };
+        for (index, step) in self.steps.iter().enumerate() {
+            replay
+                .apply(step, self, balance)
+                .map_err(|message| format!("Step {}: {}", index + 1, message))?;
+        }
+        Ok(replay.score)
+    }
+}
+
+// The board, deck and score as the record is played back
+struct Replay {
+    board: Board,
+    deck: Deck,
+    dealt: Vec<Card>,              // Drawn and not placed yet
+    pending_points: Vec<Vec<i32>>, // What each marked card scores when it explodes
+    score: i32,
+    last_chain: i32,
+}
+
+impl Replay {
+    fn apply(&mut self, step: &RunStep, log: &RunLog, balance: &Balance) -> Result<(), String> {
+        match *step {
+            RunStep::Draw => {
+                // Only the card in play, the next card and hard drops still falling are
+                // drawn and not placed, and each of those drops holds an empty cell
+                let empty_cells = self
+                    .board
+                    .grid
+                    .iter()
+                    .flatten()
+                    .filter(|cell| cell.is_none());
+                if self.dealt.len() >= 2 + empty_cells.count() {
+                    return Err("More cards drawn than play can hold".to_string());
+                }
+                let card = self.deck.draw().or_else(|| {
+                    self.deck.reset();
+                    self.deck.draw()
+                });
+                self.dealt.push(card.ok_or("The deck is empty")?);
+            }
+            RunStep::Place { x, y, card } => {
+                let index = self
+                    .dealt
+                    .iter()
+                    .position(|&dealt| dealt == card)
+                    .ok_or(format!("{} wasn't dealt", card))?;
+                if !self.board.place_card(x, y, card) {
+                    return Err(format!("Nowhere to place {} at ({}, {})", card, x, y));
+                }
+                self.dealt.remove(index);
+
+                // It rests on the floor or a card, or on cells held for hard drops that
+                // haven't landed yet, one for each card still to be placed
+                let gap = (y + 1..self.board.height)
+                    .take_while(|&below| self.board.is_cell_empty(x, below))
+                    .count();
+                if gap > self.dealt.len() {
+                    return Err(format!("{} at ({}, {}) has nothing under it", card, x, y));
+                }
+            }
+            RunStep::Clear { ref paths, chain } => self.clear(paths, chain, log, balance)?,
+            RunStep::Explode { x, y } => {
+                if !self.is_marked(x, y) {
+                    return Err(format!("Nothing to explode at ({}, {})", x, y));
+                }
+                self.board.marked_for_removal[y as usize][x as usize] = None;
+                if let Some(card) = self.board.remove_card(x, y) {
+                    self.deck.discard(card);
+                }
+                self.score += std::mem::take(&mut self.pending_points[y as usize][x as usize]);
+            }
+            RunStep::Fall { x, from, to } => {
+                let falls_clear = from < to
+                    && !self.board.is_cell_empty(x, from)
+                    && !self.is_marked(x, from)
+                    && (from + 1..=to).all(|y| self.board.is_cell_empty(x, y));
+                if !falls_clear {
+                    return Err(format!(
+                        "No card can fall from ({}, {}) to row {}",
+                        x, from, to
+                    ));
+                }
+                let card = self.board.remove_card(x, from);
+                self.board.grid[to as usize][x as usize] = card;
+            }
+            RunStep::ColumnCleared { column } => {
+                if !log.relaxed || !self.board.is_position_valid(column, 0) {
+                    return Err(format!("Column {} can't be cleared", column));
+                }
+                for y in 0..self.board.height {
+                    if let Some(card) = self.board.remove_card(column, y) {
+                        self.deck.discard(card);
+                    }
+                }
+                self.score -= self.score.min(RELAX_COLUMN_PENALTY);
+            }
+        }
+        Ok(())
+    }
+
+    // Every path has to be a 21 on the board as it stands; the points are worked out the way
+    // Game::emit_combinations_cleared works them out
+    fn clear(
+        &mut self,
+        paths: &[Vec<(i32, i32)>],
+        chain: i32,
+        log: &RunLog,
+        balance: &Balance,
+    ) -> Result<(), String> {
+        if chain != 1 && chain != self.last_chain + 1 {
+            return Err(format!(
+                "Chain {} doesn't follow chain {}",
+                chain, self.last_chain
+            ));
+        }
+        self.last_chain = chain;
+
+        let combinations: Vec<Vec<(i32, i32)>> = self
+            .board
+            .all_combinations(log.difficulty)
+            .into_iter()
+            .map(|mut cells| {
+                cells.sort();
+                cells
+            })
+            .collect();
+        let mut claimed = Vec::new();
+        for path in paths {
+            let mut cells = path.clone();
+            cells.sort();
+            if !combinations.contains(&cells) || cells.iter().any(|cell| claimed.contains(cell)) {
+                return Err(format!("{:?} isn't a 21 on the board", path));
+            }
+            claimed.extend(cells);
+        }
+
+        let now = Instant::now();
+        for path in paths {
+            let cards = path
+                .iter()
+                .filter_map(|&(x, y)| self.board.grid[y as usize][x as usize])
+                .collect();
+            let combination =
+                ClearedCombination::new(cards, balance.length_multiplier(path.len()), chain);
+            for (&(x, y), points) in path.iter().zip(combination.card_points()) {
+                self.pending_points[y as usize][x as usize] += points;
+                self.board.marked_for_removal[y as usize][x as usize] = Some(now);
+            }
+            if self.board.is_natural(path) {
+                self.score += balance.natural_bonus;
+            }
+        }
+        if chain > 1 {
+            self.score += balance.cascade_bonus;
+        }
+        Ok(())
+    }
+
+    fn is_marked(&self, x: i32, y: i32) -> bool {
+        self.board.is_position_valid(x, y)
+            && self.board.marked_for_removal[y as usize][x as usize].is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Game;
+    use crate::store::{MemorySavedRunStore, MemorySettingsStore};
+    use rand::Rng;
+
+    // A seeded run played through a windowless game until it ends, dropping each card into
+    // a random column and letting clears, cascades and gravity play out in real time
+    fn play_seeded_run(seed: &str) -> Game {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut game = Game::builder()
+            .database_path(temp_dir.path().join("run_log.db"))
+            .saved_run_store(Box::new(MemorySavedRunStore::new()))
+            .settings_store(Box::new(MemorySettingsStore::new()))
+            .balance(Balance {
+                combination_delay_ms: 1,
+                ..Balance::default()
+            })
+            .build()
+            .expect("Failed to create game");
+        game.settings.handicap_rows = 2;
+        game.start_seeded_game(Difficulty::Easy, RunSeed::parse(seed).unwrap());
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..400 {
+            if game.is_game_over() {
+                break;
+            }
+            for _ in 0..rng.random_range(0..4) {
+                if rng.random_bool(0.5) {
+                    game.move_current_card_left();
+                } else {
+                    game.move_current_card_right();
+                }
+            }
+            game.hard_drop();
+            for _ in 0..rng.random_range(1..20) {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+                game.update();
+            }
+        }
+        game
+    }
+
+    #[test]
+    fn test_replay_earns_the_runs_score() {
+        let game = play_seeded_run("REPLAY");
+        let submission = game.score_submission().expect("The run should verify");
+        assert_eq!(submission.score, game.score);
+        assert!(game.score > 0, "The run should have cleared something");
+
+        let steps = &submission.run.steps;
+        assert!(
+            steps
+                .iter()
+                .any(|step| matches!(step, RunStep::Fall { .. }))
+        );
+        assert!(
+            steps
+                .iter()
+                .any(|step| matches!(step, RunStep::Clear { .. }))
+        );
+
+        // The record survives the trip to JSON and back
+        let json = serde_json::to_string(&submission).unwrap();
+        let parsed: ScoreSubmission = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, submission);
+        assert!(parsed.verify(&Balance::default()).is_ok());
+    }
+
+    #[test]
+    fn test_doctored_submissions_are_rejected() {
+        let game = play_seeded_run("DOCTOR");
+        let submission = game.score_submission().expect("The run should verify");
+        let balance = Balance::default();
+
+        let mut inflated = submission.clone();
+        inflated.score += 1;
+        assert!(inflated.verify(&balance).is_err());
+
+        // Swapping in a card the seed never dealt
+        let mut swapped = submission.clone();
+        let place = swapped
+            .run
+            .steps
+            .iter_mut()
+            .find_map(|step| match step {
+                RunStep::Place { card, .. } => Some(card),
+                _ => None,
+            })
+            .unwrap();
+        let value = crate::models::Value::all()
+            .into_iter()
+            .find(|&value| value != place.value)
+            .unwrap();
+        *place = Card::new(place.suit, value);
+        assert!(swapped.verify(&balance).is_err());
+
+        // Another seed deals other cards
+        let mut reseeded = submission.clone();
+        reseeded.run.seed = "ZZZZZZ".to_string();
+        assert!(reseeded.verify(&balance).is_err());
+
+        // A card left hanging in the air
+        let mut hanging = submission.clone();
+        let place_y = hanging
+            .run
+            .steps
+            .iter_mut()
+            .find_map(|step| match step {
+                RunStep::Place { y, .. } => Some(y),
+                _ => None,
+            })
+            .unwrap();
+        *place_y = 0;
+        let error = hanging.verify(&balance).unwrap_err();
+        assert!(error.contains("nothing under it"));
+
+        // A clear the board never had
+        let mut invented = submission;
+        invented.run.steps.insert(
+            0,
+            RunStep::Clear {
+                paths: vec![vec![(0, 0), (1, 0)]],
+                chain: 1,
+            },
+        );
+        assert!(invented.verify(&balance).is_err());
+    }
+
+    // A hand written record on an empty board
+    fn forged_log(width: i32, height: i32, steps: Vec<RunStep>) -> RunLog {
+        RunLog {
+            seed: "FORGED".to_string(),
+            difficulty: Difficulty::Easy,
+            deck_template: DeckTemplate::Standard,
+            handicap_rows: 0,
+            width,
+            height,
+            relaxed: false,
+            steps,
+        }
+    }
+
+    #[test]
+    fn test_forged_logs_are_rejected() {
+        let balance = Balance::default();
+
+        // Turned away before a board that size is set up
+        let huge = forged_log(100_000, 100_000, Vec::new());
+        let error = huge.replay(&balance).unwrap_err();
+        assert!(error.contains("too big"));
+        assert!(forged_log(2, 2, Vec::new()).replay(&balance).is_err());
+
+        // Drawing a hand of cards to pick from
+        let hoarded = forged_log(4, 6, vec![RunStep::Draw; 30]);
+        let error = hoarded.replay(&balance).unwrap_err();
+        assert!(error.contains("More cards drawn"));
+        assert!(
+            forged_log(4, 6, vec![RunStep::Draw; 2])
+                .replay(&balance)
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,511 @@
+// Runs score store calls off the render thread. The game sends requests and polls for
+// responses once per frame, so a slow disk never stalls a frame at game over.
+
+use crate::models::{Difficulty, HighScore, RunOutcome, Statistics};
+use crate::store::ScoreStore;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+// How many scores a refresh pulls back for the high score list
+const HIGH_SCORE_LIMIT: usize = 10;
+
+#[derive(Debug, Clone)]
+pub enum ScoreRequest {
+    // Save a score, then send back the refreshed high score list for scores set since
+    // `since` (all of them when None)
+    Save {
+        high_score: HighScore,
+        since: Option<DateTime<Utc>>,
+    },
+    // Send back the high score list for scores set since `since`, when the leaderboard
+    // switches period
+    LoadHighScores {
+        since: Option<DateTime<Utc>>,
+    },
+    // Look up where a finished run would rank on its difficulty's leaderboard, and what
+    // share of the saved scores it beats
+    Rank {
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    },
+    // Look up the best saved score for a run's difficulty and mode as the run starts
+    PersonalBest {
+        difficulty: Difficulty,
+        relaxed: bool,
+    },
+    // Count a run and its drops per column towards the statistics, then send back the
+    // updated totals
+    RecordRun {
+        outcome: RunOutcome,
+        column_drops: Vec<u32>,
+    },
+    // Keep a puzzle clear's stars if they're the level's best, then send back every
+    // level's best
+    RecordPuzzle {
+        level_id: String,
+        stars: u8,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum ScoreResponse {
+    HighScoresUpdated(Vec<HighScore>),
+    Ranked {
+        score: i32,
+        rank: usize,
+        percentile: Option<u8>, // None with no saved scores to compare against
+    },
+    PersonalBest {
+        difficulty: Difficulty,
+        relaxed: bool,
+        score: Option<i32>,
+    },
+    StatisticsUpdated(Statistics),
+    PuzzleStarsUpdated(HashMap<String, u8>),
+    SaveFailed(String),
+    LoadFailed(String),
+    RankFailed(String),
+    PersonalBestFailed(String),
+    RecordRunFailed(String),
+    RecordPuzzleFailed(String),
+}
+
+enum Backend {
+    Thread {
+        requests: Option<Sender<ScoreRequest>>,
+        handle: Option<JoinHandle<()>>,
+    },
+    // Browser builds have no threads, so requests are handled as they are sent
+    #[cfg_attr(not(target_os = "emscripten"), allow(dead_code))]
+    Inline {
+        store: Box<dyn ScoreStore>,
+        responses: Sender<ScoreResponse>,
+    },
+}
+
+pub struct ScoreWorker {
+    backend: Backend,
+    responses: Receiver<ScoreResponse>,
+    pending: usize,
+}
+
+impl ScoreWorker {
+    #[cfg(not(target_os = "emscripten"))]
+    pub fn spawn(mut store: Box<dyn ScoreStore>) -> Self {
+        let (request_sender, request_receiver) = mpsc::channel::<ScoreRequest>();
+        let (response_sender, response_receiver) = mpsc::channel();
+
+        let handle = std::thread::spawn(move || {
+            // Runs until the worker is dropped and the request channel closes
+            for request in request_receiver {
+                let response = handle_request(store.as_mut(), request);
+                if response_sender.send(response).is_err() {
+                    break;
+                }
+            }
+        });
+
+        ScoreWorker {
+            backend: Backend::Thread {
+                requests: Some(request_sender),
+                handle: Some(handle),
+            },
+            responses: response_receiver,
+            pending: 0,
+        }
+    }
+
+    #[cfg(target_os = "emscripten")]
+    pub fn spawn(store: Box<dyn ScoreStore>) -> Self {
+        let (response_sender, response_receiver) = mpsc::channel();
+        ScoreWorker {
+            backend: Backend::Inline {
+                store,
+                responses: response_sender,
+            },
+            responses: response_receiver,
+            pending: 0,
+        }
+    }
+
+    pub fn send(&mut self, request: ScoreRequest) {
+        let sent = match &mut self.backend {
+            Backend::Thread { requests, .. } => requests
+                .as_ref()
+                .is_some_and(|requests| requests.send(request).is_ok()),
+            Backend::Inline { store, responses } => responses
+                .send(handle_request(store.as_mut(), request))
+                .is_ok(),
+        };
+
+        if sent {
+            self.pending += 1;
+        } else {
+            eprintln!("Score worker is no longer running");
+        }
+    }
+
+    /// Responses that have arrived since the last poll, without blocking
+    pub fn poll(&mut self) -> Vec<ScoreResponse> {
+        let responses: Vec<ScoreResponse> = self.responses.try_iter().collect();
+        self.pending -= responses.len();
+        responses
+    }
+
+    /// Block until every request sent so far has been answered (or the timeout passes)
+    #[allow(dead_code)]
+    pub fn wait(&mut self, timeout: Duration) -> Vec<ScoreResponse> {
+        let deadline = Instant::now() + timeout;
+        let mut responses = Vec::new();
+
+        while self.pending > 0 {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.responses.recv_timeout(remaining) {
+                Ok(response) => {
+                    self.pending -= 1;
+                    responses.push(response);
+                }
+                Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        responses
+    }
+
+    /// Whether any request is still waiting for a response
+    #[allow(dead_code)]
+    pub fn is_busy(&self) -> bool {
+        self.pending > 0
+    }
+}
+
+impl Drop for ScoreWorker {
+    fn drop(&mut self) {
+        // Close the channel and let the worker finish queued writes before the game exits
+        if let Backend::Thread { requests, handle } = &mut self.backend {
+            requests.take();
+            if let Some(handle) = handle.take()
+                && handle.join().is_err()
+            {
+                eprintln!("Score worker panicked");
+            }
+        }
+    }
+}
+
+fn handle_request(store: &mut dyn ScoreStore, request: ScoreRequest) -> ScoreResponse {
+    match request {
+        ScoreRequest::Save { high_score, since } => {
+            let result = store
+                .add_high_score(&high_score)
+                .and_then(|_| high_scores_since(store, since));
+            match result {
+                Ok(scores) => ScoreResponse::HighScoresUpdated(scores),
+                Err(e) => ScoreResponse::SaveFailed(e.to_string()),
+            }
+        }
+        ScoreRequest::LoadHighScores { since } => match high_scores_since(store, since) {
+            Ok(scores) => ScoreResponse::HighScoresUpdated(scores),
+            Err(e) => ScoreResponse::LoadFailed(e.to_string()),
+        },
+        ScoreRequest::Rank {
+            score,
+            difficulty,
+            relaxed,
+        } => {
+            let result = store
+                .get_score_rank(score, difficulty, relaxed)
+                .and_then(|rank| {
+                    Ok((
+                        rank,
+                        store.get_score_percentile(score, difficulty, relaxed)?,
+                    ))
+                });
+            match result {
+                Ok((rank, percentile)) => ScoreResponse::Ranked {
+                    score,
+                    rank,
+                    percentile,
+                },
+                Err(e) => ScoreResponse::RankFailed(e.to_string()),
+            }
+        }
+        ScoreRequest::PersonalBest {
+            difficulty,
+            relaxed,
+        } => match store.get_personal_best(difficulty, relaxed) {
+            Ok(score) => ScoreResponse::PersonalBest {
+                difficulty,
+                relaxed,
+                score,
+            },
+            Err(e) => ScoreResponse::PersonalBestFailed(e.to_string()),
+        },
+        ScoreRequest::RecordRun {
+            outcome,
+            column_drops,
+        } => {
+            let result = store
+                .record_run(outcome, &column_drops)
+                .and_then(|_| store.get_statistics());
+            match result {
+                Ok(statistics) => ScoreResponse::StatisticsUpdated(statistics),
+                Err(e) => ScoreResponse::RecordRunFailed(e.to_string()),
+            }
+        }
+        ScoreRequest::RecordPuzzle { level_id, stars } => {
+            let result = store
+                .record_puzzle_stars(&level_id, stars)
+                .and_then(|_| store.get_puzzle_stars());
+            match result {
+                Ok(puzzle_stars) => ScoreResponse::PuzzleStarsUpdated(puzzle_stars),
+                Err(e) => ScoreResponse::RecordPuzzleFailed(e.to_string()),
+            }
+        }
+    }
+}
+
+fn high_scores_since(
+    store: &mut dyn ScoreStore,
+    since: Option<DateTime<Utc>>,
+) -> Result<Vec<HighScore>, Box<dyn std::error::Error>> {
+    match since {
+        Some(since) => store.get_high_scores_since(since, HIGH_SCORE_LIMIT),
+        None => store.get_high_scores(HIGH_SCORE_LIMIT),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DeckTemplate;
+    use crate::store::MemoryScoreStore;
+    use std::error::Error;
+
+    mod test_fixtures {
+        use super::*;
+
+        pub fn create_high_score(score: i32) -> HighScore {
+            HighScore {
+                id: None,
+                player_initials: "WRK".to_string(),
+                score,
+                difficulty: Difficulty::Easy,
+                date: Utc::now(),
+                modded: false,
+                deck_template: DeckTemplate::Standard,
+                relaxed: false,
+                modified: false,
+            }
+        }
+
+        // Store whose writes always fail, to exercise error reporting
+        pub struct FailingStore;
+
+        impl ScoreStore for FailingStore {
+            fn add_high_score(&mut self, _: &HighScore) -> Result<i64, Box<dyn Error>> {
+                Err("disk full".into())
+            }
+
+            fn get_high_scores(&self, _: usize) -> Result<Vec<HighScore>, Box<dyn Error>> {
+                Ok(Vec::new())
+            }
+
+            fn get_high_scores_since(
+                &self,
+                _: DateTime<Utc>,
+                _: usize,
+            ) -> Result<Vec<HighScore>, Box<dyn Error>> {
+                Ok(Vec::new())
+            }
+
+            fn get_score_rank(
+                &self,
+                _: i32,
+                _: Difficulty,
+                _: bool,
+            ) -> Result<usize, Box<dyn Error>> {
+                Ok(1)
+            }
+
+            fn get_score_percentile(
+                &self,
+                _: i32,
+                _: Difficulty,
+                _: bool,
+            ) -> Result<Option<u8>, Box<dyn Error>> {
+                Ok(None)
+            }
+
+            fn get_personal_best(
+                &self,
+                _: Difficulty,
+                _: bool,
+            ) -> Result<Option<i32>, Box<dyn Error>> {
+                Ok(None)
+            }
+
+            fn record_run(&mut self, _: RunOutcome, _: &[u32]) -> Result<(), Box<dyn Error>> {
+                Err("disk full".into())
+            }
+
+            fn get_statistics(&self) -> Result<Statistics, Box<dyn Error>> {
+                Ok(Statistics::default())
+            }
+
+            fn get_puzzle_stars(&self) -> Result<HashMap<String, u8>, Box<dyn Error>> {
+                Ok(HashMap::new())
+            }
+
+            fn record_puzzle_stars(&mut self, _: &str, _: u8) -> Result<(), Box<dyn Error>> {
+                Err("disk full".into())
+            }
+        }
+    }
+
+    #[test]
+    fn test_save_returns_refreshed_scores() {
+        let mut worker = ScoreWorker::spawn(Box::new(MemoryScoreStore::new()));
+        worker.send(ScoreRequest::Save {
+            high_score: test_fixtures::create_high_score(100),
+            since: None,
+        });
+        worker.send(ScoreRequest::Save {
+            high_score: test_fixtures::create_high_score(300),
+            since: None,
+        });
+        assert!(worker.is_busy());
+
+        let responses = worker.wait(Duration::from_secs(1));
+        assert_eq!(responses.len(), 2);
+        assert!(!worker.is_busy());
+
+        match responses.last() {
+            Some(ScoreResponse::HighScoresUpdated(scores)) => {
+                assert_eq!(scores.len(), 2);
+                assert_eq!(scores[0].score, 300);
+            }
+            other => panic!("Unexpected response: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_high_scores_since() {
+        let mut worker = ScoreWorker::spawn(Box::new(MemoryScoreStore::new()));
+        let mut old = test_fixtures::create_high_score(900);
+        old.date = old.date - chrono::Days::new(30);
+        worker.send(ScoreRequest::Save {
+            high_score: old,
+            since: None,
+        });
+        worker.send(ScoreRequest::Save {
+            high_score: test_fixtures::create_high_score(100),
+            since: None,
+        });
+        worker.send(ScoreRequest::LoadHighScores {
+            since: Some(Utc::now() - chrono::Days::new(1)),
+        });
+
+        match worker.wait(Duration::from_secs(1)).last() {
+            Some(ScoreResponse::HighScoresUpdated(scores)) => {
+                assert_eq!(scores.len(), 1);
+                assert_eq!(scores[0].score, 100);
+            }
+            other => panic!("Unexpected response: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_rank_sees_earlier_saves() {
+        let mut worker = ScoreWorker::spawn(Box::new(MemoryScoreStore::new()));
+        worker.send(ScoreRequest::Save {
+            high_score: test_fixtures::create_high_score(500),
+            since: None,
+        });
+        worker.send(ScoreRequest::Rank {
+            score: 200,
+            difficulty: Difficulty::Easy,
+            relaxed: false,
+        });
+
+        let responses = worker.wait(Duration::from_secs(1));
+        assert!(matches!(
+            responses.last(),
+            Some(ScoreResponse::Ranked {
+                score: 200,
+                rank: 2,
+                percentile: Some(0)
+            })
+        ));
+    }
+
+    #[test]
+    fn test_personal_best_sees_earlier_saves() {
+        let mut worker = ScoreWorker::spawn(Box::new(MemoryScoreStore::new()));
+        worker.send(ScoreRequest::Save {
+            high_score: test_fixtures::create_high_score(500),
+            since: None,
+        });
+        worker.send(ScoreRequest::PersonalBest {
+            difficulty: Difficulty::Easy,
+            relaxed: false,
+        });
+        worker.send(ScoreRequest::PersonalBest {
+            difficulty: Difficulty::Hard,
+            relaxed: false,
+        });
+
+        let responses = worker.wait(Duration::from_secs(1));
+        assert!(matches!(
+            responses.as_slice(),
+            [
+                ScoreResponse::HighScoresUpdated(_),
+                ScoreResponse::PersonalBest {
+                    difficulty: Difficulty::Easy,
+                    score: Some(500),
+                    ..
+                },
+                ScoreResponse::PersonalBest {
+                    difficulty: Difficulty::Hard,
+                    score: None,
+                    ..
+                },
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_record_run_returns_statistics() {
+        let mut worker = ScoreWorker::spawn(Box::new(MemoryScoreStore::new()));
+        worker.send(ScoreRequest::RecordRun {
+            outcome: RunOutcome::Abandoned,
+            column_drops: vec![0, 2],
+        });
+
+        let responses = worker.wait(Duration::from_secs(1));
+        assert!(matches!(
+            responses.as_slice(),
+            [ScoreResponse::StatisticsUpdated(statistics)]
+                if statistics.runs_abandoned == 1 && statistics.column_drops == vec![0, 2]
+        ));
+    }
+
+    #[test]
+    fn test_save_failure_is_reported() {
+        let mut worker = ScoreWorker::spawn(Box::new(test_fixtures::FailingStore));
+        worker.send(ScoreRequest::Save {
+            high_score: test_fixtures::create_high_score(100),
+            since: None,
+        });
+
+        let responses = worker.wait(Duration::from_secs(1));
+        assert!(matches!(
+            responses.as_slice(),
+            [ScoreResponse::SaveFailed(message)] if message == "disk full"
+        ));
+    }
+}
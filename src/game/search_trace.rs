@@ -0,0 +1,105 @@
+// Debug overlay of the combination search: the steps Board::find_all_paths_to_21 took from
+// the last card placed, played back a few at a time so the path can be watched growing,
+// backing off and finding 21s. F7 turns it on and off.
+
+use std::time::Instant;
+
+const STEPS_PER_SECOND: f32 = 30.0;
+const LONGEST_PLAYBACK_SECS: f32 = 8.0; // Big searches speed up to finish within this
+
+/// One move of the search
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchStep {
+    Enter { x: i32, y: i32 }, // The path moved onto this card
+    Found(Vec<(i32, i32)>),   // The path as it stands makes 21
+    Leave { x: i32, y: i32 }, // Backed off this card
+}
+
+/// Where the search had got to after some of its steps
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchView {
+    pub path: Vec<(i32, i32)>, // The path being explored, from the placed card
+    pub visited: Vec<(i32, i32)>, // Every card the search has stepped on so far
+    pub found: Vec<Vec<(i32, i32)>>, // 21s found so far
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchTrace {
+    pub steps: Vec<SearchStep>,
+    started: Instant,
+}
+
+impl SearchTrace {
+    pub fn new(steps: Vec<SearchStep>, started: Instant) -> Self {
+        SearchTrace { steps, started }
+    }
+
+    /// How many steps have played by `now`
+    pub fn steps_shown(&self, now: Instant) -> usize {
+        let rate = STEPS_PER_SECOND.max(self.steps.len() as f32 / LONGEST_PLAYBACK_SECS);
+        let elapsed = now.saturating_duration_since(self.started).as_secs_f32();
+        ((elapsed * rate) as usize).min(self.steps.len())
+    }
+
+    /// The search as it stood after its first `count` steps
+    pub fn view(&self, count: usize) -> SearchView {
+        let mut view = SearchView::default();
+        for step in self.steps.iter().take(count) {
+            match step {
+                SearchStep::Enter { x, y } => {
+                    view.path.push((*x, *y));
+                    if !view.visited.contains(&(*x, *y)) {
+                        view.visited.push((*x, *y));
+                    }
+                }
+                SearchStep::Found(path) => view.found.push(path.clone()),
+                SearchStep::Leave { .. } => {
+                    view.path.pop();
+                }
+            }
+        }
+        view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_view_follows_the_steps() {
+        let trace = SearchTrace::new(
+            vec![
+                SearchStep::Enter { x: 0, y: 2 },
+                SearchStep::Enter { x: 0, y: 1 },
+                SearchStep::Found(vec![(0, 2), (0, 1)]),
+                SearchStep::Leave { x: 0, y: 1 },
+                SearchStep::Enter { x: 1, y: 2 },
+                SearchStep::Leave { x: 1, y: 2 },
+                SearchStep::Leave { x: 0, y: 2 },
+            ],
+            Instant::now(),
+        );
+
+        assert_eq!(trace.view(0), SearchView::default());
+        let midway = trace.view(5);
+        assert_eq!(midway.path, vec![(0, 2), (1, 2)]);
+        assert_eq!(midway.visited, vec![(0, 2), (0, 1), (1, 2)]);
+        assert_eq!(midway.found, vec![vec![(0, 2), (0, 1)]]);
+        assert!(trace.view(7).path.is_empty());
+    }
+
+    #[test]
+    fn test_playback_speeds_up_for_big_searches() {
+        let started = Instant::now();
+        let small = SearchTrace::new(vec![SearchStep::Leave { x: 0, y: 0 }; 10], started);
+        assert_eq!(small.steps_shown(started), 0);
+        assert_eq!(small.steps_shown(started + Duration::from_millis(100)), 3);
+        assert_eq!(small.steps_shown(started + Duration::from_secs(60)), 10);
+
+        let big = SearchTrace::new(vec![SearchStep::Leave { x: 0, y: 0 }; 8000], started);
+        let end = started + Duration::from_secs_f32(LONGEST_PLAYBACK_SECS);
+        assert_eq!(big.steps_shown(end), 8000);
+    }
+}
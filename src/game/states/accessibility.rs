@@ -0,0 +1,42 @@
+use super::settings::Settings;
+
+// Rows on the accessibility screen, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccessibilityOption {
+    #[default]
+    ReducedMotion,
+    HighContrast,
+    FontScale,
+    CardValues,
+}
+
+impl AccessibilityOption {
+    pub const ALL: [AccessibilityOption; 4] = [
+        AccessibilityOption::ReducedMotion,
+        AccessibilityOption::HighContrast,
+        AccessibilityOption::FontScale,
+        AccessibilityOption::CardValues,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AccessibilityOption::ReducedMotion => "Reduced Motion",
+            AccessibilityOption::HighContrast => "High Contrast",
+            AccessibilityOption::FontScale => "Text Size",
+            AccessibilityOption::CardValues => "Card Values",
+        }
+    }
+
+    // Move through the list, wrapping at either end
+    pub fn cycle(&self, step: i32) -> AccessibilityOption {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|o| o == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accessibility {
+    pub return_to: Settings, // Settings screen to restore when leaving
+    pub selected: AccessibilityOption,
+}
@@ -0,0 +1,39 @@
+use super::settings::Settings;
+
+// Rows on the appearance screen, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AppearanceOption {
+    #[default]
+    FeltColor,
+    CardBack,
+    SeasonalEvents,
+}
+
+impl AppearanceOption {
+    pub const ALL: [AppearanceOption; 3] = [
+        AppearanceOption::FeltColor,
+        AppearanceOption::CardBack,
+        AppearanceOption::SeasonalEvents,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AppearanceOption::FeltColor => "Felt Color",
+            AppearanceOption::CardBack => "Card Back",
+            AppearanceOption::SeasonalEvents => "Seasonal Events",
+        }
+    }
+
+    // Move through the list, wrapping at either end
+    pub fn cycle(&self, step: i32) -> AppearanceOption {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|o| o == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Appearance {
+    pub return_to: Settings, // Settings screen to restore when leaving
+    pub selected: AppearanceOption,
+}
@@ -0,0 +1,7 @@
+use super::paused::Paused;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CombinationLog {
+    pub return_to: Paused, // Pause screen to restore when leaving the log
+    pub scroll: usize,     // Index of the top row, counted from the newest combination
+}
@@ -0,0 +1,6 @@
+use super::settings::Settings;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics {
+    pub return_to: Settings, // Settings screen to restore when leaving diagnostics
+}
@@ -0,0 +1,39 @@
+use super::paused::Paused;
+
+// Choices in the quit-to-menu dialog, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ForfeitOption {
+    #[default]
+    Cancel,
+    SaveAndQuit,
+    Forfeit,
+}
+
+impl ForfeitOption {
+    pub const ALL: [ForfeitOption; 3] = [
+        ForfeitOption::Cancel,
+        ForfeitOption::SaveAndQuit,
+        ForfeitOption::Forfeit,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ForfeitOption::Cancel => "Keep Playing",
+            ForfeitOption::SaveAndQuit => "Save & Quit",
+            ForfeitOption::Forfeit => "Forfeit Run",
+        }
+    }
+
+    // Move through the dialog, wrapping at either end
+    pub fn cycle(&self, step: i32) -> ForfeitOption {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|o| o == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForfeitConfirm {
+    pub return_to: Paused, // Pause menu to go back to on cancel
+    pub selected: ForfeitOption,
+}
@@ -0,0 +1,52 @@
+// Rows on the game setup screen, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameSetupOption {
+    #[default]
+    DeckTemplate,
+    Handicap,
+    HardDropSteer,
+    SlideDelay,
+    BustRule,
+    RelaxMode,
+    AdaptiveSpeed,
+    DrawOdds,
+}
+
+impl GameSetupOption {
+    pub const ALL: [GameSetupOption; 8] = [
+        GameSetupOption::DeckTemplate,
+        GameSetupOption::Handicap,
+        GameSetupOption::HardDropSteer,
+        GameSetupOption::SlideDelay,
+        GameSetupOption::BustRule,
+        GameSetupOption::RelaxMode,
+        GameSetupOption::AdaptiveSpeed,
+        GameSetupOption::DrawOdds,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameSetupOption::DeckTemplate => "Deck",
+            GameSetupOption::Handicap => "Handicap",
+            GameSetupOption::HardDropSteer => "Hard Drop Steer",
+            GameSetupOption::SlideDelay => "Slide Delay",
+            GameSetupOption::BustRule => "Bust Rule",
+            GameSetupOption::RelaxMode => "Relax Mode",
+            GameSetupOption::AdaptiveSpeed => "Adaptive Speed",
+            GameSetupOption::DrawOdds => "21 Odds",
+        }
+    }
+
+    // Move through the list, wrapping at either end
+    pub fn cycle(&self, step: i32) -> GameSetupOption {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|o| o == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+}
+
+// The rules the next run is played by, picked from the main menu before starting
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GameSetup {
+    pub selected: GameSetupOption,
+}
@@ -1,29 +1,75 @@
-use crate::game::Game;
-use crate::ui::animated_background::AnimatedBackground;
-use crate::ui::particle_system::ParticleSystem;
-use raylib::prelude::*;
-use std::any::Any;
+use super::{
+    Accessibility, Appearance, CombinationLog, Diagnostics, ForfeitConfirm, GameOver, GameSetup,
+    HowToPlay, LevelEditor, ModsScreen, Paused, Playing, PuzzleSelect, QuitConfirm, RestartConfirm,
+    SeedEntry, Settings, SetupWizard, StartScreen, StatisticsScreen,
+};
 
-// Trait that all game states must implement
-pub trait GameState {
-    fn should_update(&self) -> bool {
-        false // Default: most states don't update
-    }
-
-    fn state_name(&self) -> &'static str;
+// The screen the game is on, carrying whatever that screen keeps track of. States only carry
+// game logic; drawing each state lives in the UI layer (see ui::screens). Game::set_state
+// moves between them and runs the enter/exit hooks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameState {
+    StartScreen(StartScreen),
+    Playing(Playing),
+    Paused(Paused),
+    GameOver(GameOver),
+    QuitConfirm(QuitConfirm),
+    Settings(Settings),
+    SetupWizard(SetupWizard),
+    CombinationLog(CombinationLog),
+    HowToPlay(HowToPlay),
+    RestartConfirm(RestartConfirm),
+    ForfeitConfirm(ForfeitConfirm),
+    Accessibility(Accessibility),
+    Appearance(Appearance),
+    GameSetup(GameSetup),
+    Diagnostics(Diagnostics),
+    StatisticsScreen(StatisticsScreen),
+    ModsScreen(ModsScreen),
+    PuzzleSelect(PuzzleSelect),
+    LevelEditor(LevelEditor),
+    SeedEntry(SeedEntry),
+}
 
-    fn render(
-        &self,
-        d: &mut RaylibDrawHandle,
-        game: &Game,
-        has_controller: bool,
-        title_font: &Font,
-        font: &Font,
-        card_atlas: &Texture2D,
-        particle_system: &mut ParticleSystem,
-        animated_background: &mut AnimatedBackground,
-    );
+impl GameState {
+    /// Whether the game moves on by itself in this state; everything else waits for input
+    pub fn should_update(&self) -> bool {
+        matches!(self, GameState::Playing(_))
+    }
+}
 
-    // Enable downcasting for accessing specific state data
-    fn as_any(&self) -> &dyn Any;
+// Each screen's data goes straight into set_state: `game.set_state(Paused::new(reason))`
+macro_rules! game_state_from {
+    ($($state:ident),* $(,)?) => {
+        $(
+            impl From<$state> for GameState {
+                fn from(state: $state) -> Self {
+                    GameState::$state(state)
+                }
+            }
+        )*
+    };
 }
+
+game_state_from!(
+    StartScreen,
+    Playing,
+    Paused,
+    GameOver,
+    QuitConfirm,
+    Settings,
+    SetupWizard,
+    CombinationLog,
+    HowToPlay,
+    RestartConfirm,
+    ForfeitConfirm,
+    Accessibility,
+    Appearance,
+    GameSetup,
+    Diagnostics,
+    StatisticsScreen,
+    ModsScreen,
+    PuzzleSelect,
+    LevelEditor,
+    SeedEntry,
+);
@@ -0,0 +1,6 @@
+use super::paused::Paused;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HowToPlay {
+    pub return_to: Paused, // Pause screen to restore when leaving the rules
+}
@@ -0,0 +1,16 @@
+use crate::models::{Card, Suit, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelEditor {
+    pub column: i32, // Column the next card is stacked on or taken from
+    pub brush: Card, // Card that's stacked or queued next
+}
+
+impl Default for LevelEditor {
+    fn default() -> Self {
+        LevelEditor {
+            column: 0,
+            brush: Card::new(Suit::Spades, Value::Ace),
+        }
+    }
+}
@@ -1,18 +1,45 @@
 // Game state modules
 pub mod game_state;
-pub mod shared_renderer;
 
+pub mod accessibility;
+pub mod appearance;
+pub mod combination_log;
+pub mod diagnostics;
+pub mod forfeit_confirm;
 pub mod game_over;
+pub mod game_setup;
+pub mod how_to_play;
+pub mod level_editor;
+pub mod mods_screen;
 pub mod paused;
 pub mod playing;
+pub mod puzzle_select;
 pub mod quit_confirm;
+pub mod restart_confirm;
+pub mod seed_entry;
 pub mod settings;
+pub mod setup_wizard;
 pub mod start_screen;
+pub mod statistics_screen;
 
+pub use accessibility::{Accessibility, AccessibilityOption};
+pub use appearance::{Appearance, AppearanceOption};
+pub use combination_log::CombinationLog;
+pub use diagnostics::Diagnostics;
+pub use forfeit_confirm::{ForfeitConfirm, ForfeitOption};
 pub use game_over::GameOver;
+pub use game_setup::{GameSetup, GameSetupOption};
 pub use game_state::GameState;
-pub use paused::Paused;
+pub use how_to_play::HowToPlay;
+pub use level_editor::LevelEditor;
+pub use mods_screen::ModsScreen;
+pub use paused::{PauseMenuOption, PauseReason, Paused};
 pub use playing::Playing;
+pub use puzzle_select::PuzzleSelect;
 pub use quit_confirm::QuitConfirm;
-pub use settings::Settings;
-pub use start_screen::StartScreen;
+pub use restart_confirm::RestartConfirm;
+pub use seed_entry::SeedEntry;
+pub use settings::{Settings, SettingsOrigin};
+pub use setup_wizard::{SetupStep, SetupWizard};
+pub use start_screen::{MainMenuOption, MenuModel, StartScreen};
+pub use statistics_screen::StatisticsScreen;
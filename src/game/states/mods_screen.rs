@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModsScreen {
+    pub selected: usize, // 0: the scripts switch, then one row per catalog item
+}
@@ -1,98 +1,61 @@
-use crate::game::Game;
-use crate::ui::DrawingHelpers;
-use crate::ui::animated_background::AnimatedBackground;
-use crate::ui::particle_system::ParticleSystem;
-use raylib::prelude::*;
-
-use super::game_state::GameState;
-use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Paused;
-
-impl Paused {
-    fn render_content(
-        d: &mut RaylibDrawHandle,
-        game: &Game,
-        has_controller: bool,
-        title_font: &Font,
-        font: &Font,
-    ) {
-        // Draw paused text using title font
-        SharedRenderer::draw_centered_title(
-            d,
-            title_font,
-            "GAME PAUSED",
-            250.0,
-            60.0,
-            2.5,
-            Color::WHITE,
-        );
-
-        // Draw current score (not final score)
-        let score_text = format!("Current Score: {}", game.score);
-        SharedRenderer::draw_text(d, font, &score_text, 530.0, 330.0, 36.0, 1.5, Color::WHITE);
+// Why play was paused, so the pause screen can explain automatic pauses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PauseReason {
+    #[default]
+    Manual,
+    FocusLost, // The window lost focus (alt-tab, switching browser tabs)
+    Idle,      // No input for the idle timeout
+}
 
-        // Draw conditional pause instructions based on controller availability
-        DrawingHelpers::draw_pause_instructions(d, font, has_controller);
-    }
+// Entries in the pause menu, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PauseMenuOption {
+    #[default]
+    Resume,
+    Restart,
+    Settings,
+    HowToPlay,
+    QuitToMenu,
 }
 
-impl OverlayState for Paused {
-    fn render_overlay_content(
-        &self,
-        d: &mut RaylibDrawHandle,
-        game: &Game,
-        has_controller: bool,
-        title_font: &Font,
-        font: &Font,
-    ) {
-        Self::render_content(d, game, has_controller, title_font, font);
+impl PauseMenuOption {
+    pub const ALL: [PauseMenuOption; 5] = [
+        PauseMenuOption::Resume,
+        PauseMenuOption::Restart,
+        PauseMenuOption::Settings,
+        PauseMenuOption::HowToPlay,
+        PauseMenuOption::QuitToMenu,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PauseMenuOption::Resume => "Resume",
+            PauseMenuOption::Restart => "Restart",
+            PauseMenuOption::Settings => "Settings",
+            PauseMenuOption::HowToPlay => "How to Play",
+            PauseMenuOption::QuitToMenu => "Quit to Menu",
+        }
     }
 
-    fn get_background_renderer() -> fn(
-        &mut RaylibDrawHandle,
-        &Game,
-        bool,
-        &Font,
-        &Font,
-        &Texture2D,
-        &mut ParticleSystem,
-        &mut AnimatedBackground,
-    ) {
-        BackgroundRenderer::render_game_view
+    // Move through the menu, wrapping at either end
+    pub fn cycle(&self, step: i32) -> PauseMenuOption {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|o| o == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
     }
 }
 
-impl GameState for Paused {
-    fn state_name(&self) -> &'static str {
-        "Paused"
-    }
-
-    fn render(
-        &self,
-        d: &mut RaylibDrawHandle,
-        game: &Game,
-        has_controller: bool,
-        title_font: &Font,
-        font: &Font,
-        card_atlas: &Texture2D,
-        particle_system: &mut ParticleSystem,
-        animated_background: &mut AnimatedBackground,
-    ) {
-        self.render_overlay(
-            d,
-            game,
-            has_controller,
-            title_font,
-            font,
-            card_atlas,
-            particle_system,
-            animated_background,
-        );
-    }
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Paused {
+    pub reason: PauseReason,
+    pub selected: PauseMenuOption, // Highlighted menu entry
+}
 
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
+impl Paused {
+    pub fn new(reason: PauseReason) -> Self {
+        Paused {
+            reason,
+            selected: PauseMenuOption::Resume,
+        }
     }
 }
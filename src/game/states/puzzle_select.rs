@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PuzzleSelect {
+    pub selected: usize, // Index into Game::puzzle_levels
+}
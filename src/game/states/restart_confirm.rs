@@ -0,0 +1,6 @@
+use super::paused::Paused;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestartConfirm {
+    pub return_to: Option<Paused>, // Pause screen to go back to on cancel; None resumes play
+}
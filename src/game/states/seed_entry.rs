@@ -0,0 +1,8 @@
+use crate::models::VirtualKeyboard;
+
+// Typing in a seed code from the start screen, to play the same deck as a friend
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeedEntry {
+    pub code: String,              // Letters typed so far, in capitals
+    pub keyboard: VirtualKeyboard, // On-screen keyboard for gamepad entry
+}
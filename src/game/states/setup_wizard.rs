@@ -0,0 +1,77 @@
+// Pages of the first-run setup, in the order they are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupStep {
+    Controls,
+    Volume,
+    Difficulty,
+}
+
+impl SetupStep {
+    pub const ALL: [SetupStep; 3] = [
+        SetupStep::Controls,
+        SetupStep::Volume,
+        SetupStep::Difficulty,
+    ];
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|step| step == self).unwrap_or(0)
+    }
+
+    /// The following step, or None when this is the last one
+    pub fn next(&self) -> Option<SetupStep> {
+        Self::ALL.get(self.index() + 1).copied()
+    }
+
+    pub fn previous(&self) -> Option<SetupStep> {
+        self.index().checked_sub(1).map(|index| Self::ALL[index])
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            SetupStep::Controls => "Controls",
+            SetupStep::Volume => "Volume",
+            SetupStep::Difficulty => "Difficulty",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetupWizard {
+    pub step: SetupStep,
+    pub volume_row: usize, // 0: Music, 1: Sound Effects (only used on the volume step)
+}
+
+impl SetupWizard {
+    pub fn new() -> Self {
+        SetupWizard {
+            step: SetupStep::Controls,
+            volume_row: 0,
+        }
+    }
+}
+
+impl Default for SetupWizard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setup_steps_in_order() {
+        let mut step = SetupStep::Controls;
+        let mut visited = vec![step];
+        while let Some(next) = step.next() {
+            step = next;
+            visited.push(step);
+        }
+
+        assert_eq!(visited, SetupStep::ALL);
+        assert_eq!(SetupStep::Difficulty.next(), None);
+        assert_eq!(SetupStep::Controls.previous(), None);
+        assert_eq!(SetupStep::Volume.previous(), Some(SetupStep::Controls));
+    }
+}
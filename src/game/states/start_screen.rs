@@ -1,51 +1,80 @@
-use crate::game::Game;
-use crate::ui::DrawingHelpers;
-use crate::ui::animated_background::AnimatedBackground;
-use crate::ui::particle_system::ParticleSystem;
-use raylib::prelude::*;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StartScreen;
+
+// Entries the main menu can show, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MainMenuOption {
+    Continue, // Only while a run put aside with Save & Quit is waiting
+    #[default]
+    NewGame,
+    PlayFromSeed,
+    Puzzles,
+    GameSetup,
+    Settings,
+    Statistics,
+    Mods,
+    Quit,
+}
 
-use super::game_state::GameState;
+impl MainMenuOption {
+    pub const ALL: [MainMenuOption; 9] = [
+        MainMenuOption::Continue,
+        MainMenuOption::NewGame,
+        MainMenuOption::PlayFromSeed,
+        MainMenuOption::Puzzles,
+        MainMenuOption::GameSetup,
+        MainMenuOption::Settings,
+        MainMenuOption::Statistics,
+        MainMenuOption::Mods,
+        MainMenuOption::Quit,
+    ];
 
-// Individual game state implementations
+    pub fn label(&self) -> &'static str {
+        match self {
+            MainMenuOption::Continue => "Continue",
+            MainMenuOption::NewGame => "Start New Game",
+            MainMenuOption::PlayFromSeed => "Play from Seed",
+            MainMenuOption::Puzzles => "Puzzles",
+            MainMenuOption::GameSetup => "Game Setup",
+            MainMenuOption::Settings => "Settings",
+            MainMenuOption::Statistics => "Statistics",
+            MainMenuOption::Mods => "Mods & Themes",
+            MainMenuOption::Quit => "Quit",
+        }
+    }
+}
+
+// The main menu as it stands right now. Which entries show depends on the game (see
+// Game::main_menu), so it's built again each time it's drawn or read rather than kept.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct StartScreen;
+pub struct MenuModel {
+    pub options: Vec<MainMenuOption>,
+}
+
+impl MenuModel {
+    pub fn len(&self) -> usize {
+        self.options.len()
+    }
 
-impl GameState for StartScreen {
-    fn state_name(&self) -> &'static str {
-        "StartScreen"
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
     }
 
-    fn render(
-        &self,
-        d: &mut RaylibDrawHandle,
-        game: &Game,
-        has_controller: bool,
-        title_font: &Font,
-        font: &Font,
-        card_atlas: &Texture2D,
-        _particle_system: &mut ParticleSystem,
-        animated_background: &mut AnimatedBackground,
-    ) {
-        // Draw a sophisticated gradient background
-        DrawingHelpers::draw_gradient_background(d);
-
-        // Draw animated background cards instead of static ones
-        animated_background.draw(d, card_atlas);
-
-        // Main title with shadow effect
-        DrawingHelpers::draw_title_with_shadow(d, title_font);
-
-        // Subtitle with elegant styling
-        DrawingHelpers::draw_subtitle(d, font);
-
-        // Main menu with three options
-        DrawingHelpers::draw_main_menu(d, font, game, has_controller);
-
-        // High scores in two columns (Easy/Hard)
-        DrawingHelpers::draw_high_scores_panel(d, title_font, font, game);
+    /// The row `selected` is on. An entry that has gone away leaves focus on the first row.
+    pub fn focused(&self, selected: MainMenuOption) -> usize {
+        self.options
+            .iter()
+            .position(|&option| option == selected)
+            .unwrap_or(0)
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
+    /// The entry `step` rows from `selected`, wrapping at either end
+    pub fn cycle(&self, selected: MainMenuOption, step: i32) -> MainMenuOption {
+        if self.options.is_empty() {
+            return selected;
+        }
+        let len = self.options.len() as i32;
+        let index = (self.focused(selected) as i32 + step).rem_euclid(len);
+        self.options[index as usize]
     }
 }
@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatisticsScreen {
+    pub show_heatmap: bool, // Tint the board columns by how often cards are dropped there
+}
@@ -0,0 +1,41 @@
+//! DropJack as a library. The binary in main.rs is a thin wrapper that opens the window and
+//! runs the game; everything it plays is here, so bots, solvers and other front ends can
+//! drive the same core without a window.
+//!
+//! The stable API is:
+//!
+//! - [`game`]: [`game::Game`] and its [`game::GameBuilder`], which run a whole game one
+//!   `update()` at a time. Play through the same methods the input handler calls
+//!   (`move_current_card_left`, `hard_drop`, ...), and read what happened from
+//!   `take_events()`.
+//! - [`board`]: the grid on its own, with placing, gravity and the search for 21s.
+//! - [`models`]: cards, decks, seeds, difficulties and the other plain data the game uses.
+//! - [`store`] and [`storage`]: where scores, settings and saved runs are kept. The memory
+//!   stores keep a headless game from touching anything on disk.
+//! - [`DropJackError`]: what can go wrong setting a game up, from `GameBuilder::build` on.
+//!
+//! [`ui`] is the raylib front end the binary runs, and isn't meant to be built on. Its
+//! screens read the player through [`game::input::InputSnapshot`], which has no raylib types
+//! in it, so recorded or generated frames can be played in with `ui::handle_state_input`.
+//! `examples/bot.rs` plays a seeded run with no window:
+//!
+//! ```text
+//! cargo run --example bot -- SEEDCD
+//! ```
+
+mod audio;
+pub mod build_info;
+mod database;
+pub mod error;
+pub mod game;
+mod integrity;
+pub mod models;
+mod mods;
+mod presence;
+pub mod storage;
+pub mod store;
+pub mod ui;
+mod update_check;
+
+pub use error::DropJackError;
+pub use game::board;
@@ -1,13 +1,11 @@
-mod audio;
-mod database;
-mod game;
-mod models;
-mod ui;
-
+use dropjack::{build_info, game, ui};
+#[cfg(target_os = "emscripten")]
+use dropjack::{storage, store};
 use std::fs;
 use std::path::PathBuf;
-// Removed unused Duration import
+use std::time::Duration;
 
+#[cfg(not(target_os = "emscripten"))]
 fn get_app_data_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
     let app_name = "DropJack";
 
@@ -25,19 +23,53 @@ fn get_app_data_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
 // Removed unused create_game_with_difficulty function
 // This function demonstrated builder usage but wasn't called in the current codebase
 
-fn main() {
+#[cfg(not(target_os = "emscripten"))]
+fn create_game() -> Result<game::Game, Box<dyn std::error::Error>> {
     // Get the proper application data directory
-    let app_data_dir = get_app_data_dir().expect("Failed to create application data directory");
+    let app_data_dir = get_app_data_dir()?;
 
     // Set the database path within the app data directory
     let db_path = app_data_dir.join("highscores.db");
 
     // Initialize the game with default configuration using builder pattern
     // This demonstrates how the builder makes it easy to create different game configurations
-    let mut game = game::Game::builder()
+    Ok(game::Game::builder()
         .database_path(&db_path)
-        .build()
-        .expect("Failed to initialize game");
+        .fit_board_within(
+            ui::config::BoardConfig::PLAY_AREA_WIDTH,
+            ui::config::BoardConfig::PLAY_AREA_HEIGHT,
+        )
+        .first_run_setup(true)
+        .idle_pause_after(Some(Duration::from_secs(game::IDLE_PAUSE_SECS)))
+        .build()?)
+}
+
+// The browser has no persistent filesystem, so scores go to localStorage as JSON
+#[cfg(target_os = "emscripten")]
+fn create_game() -> Result<game::Game, Box<dyn std::error::Error>> {
+    let score_store = store::JsonScoreStore::new(storage::platform_storage()?)?;
+    Ok(game::Game::builder()
+        .score_store(Box::new(score_store))
+        .fit_board_within(
+            ui::config::BoardConfig::PLAY_AREA_WIDTH,
+            ui::config::BoardConfig::PLAY_AREA_HEIGHT,
+        )
+        .first_run_setup(true)
+        .idle_pause_after(Some(Duration::from_secs(game::IDLE_PAUSE_SECS)))
+        .build()?)
+}
+
+fn main() {
+    build_info::install_crash_reporter();
+
+    if let Err(e) = ui::check_assets() {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+    let mut game = create_game().unwrap_or_else(|e| {
+        eprintln!("Failed to initialize game: {}", e);
+        std::process::exit(1);
+    });
 
     // The builder pattern makes it easy to create custom configurations if needed:
     // let mut game = game::Game::builder()
@@ -49,7 +81,10 @@ fn main() {
     //     .expect("Failed to initialize custom game");
 
     // Create and run the UI
-    let mut game_ui = ui::GameUI::new();
+    let mut game_ui = ui::GameUI::new().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
     game_ui.run(&mut game);
 }
 
@@ -86,6 +121,11 @@ mod tests {
         assert_eq!(dir1, dir2);
     }
 
+    #[test]
+    fn test_assets_are_found_from_the_crate_root() {
+        assert!(ui::check_assets().is_ok());
+    }
+
     // Integration test for the main application setup
     #[test]
     fn test_application_initialization() {
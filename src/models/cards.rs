@@ -1,6 +1,9 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Suit {
     Spades,
     Hearts,
@@ -30,7 +33,7 @@ impl Suit {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Value {
     Ace,
     Two,
@@ -100,7 +103,7 @@ impl Value {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Card {
     pub suit: Suit,
     pub value: Value,
@@ -111,6 +114,15 @@ impl Card {
         Card { suit, value }
     }
 
+    /// The most the card can count for, so 11 for an ace
+    pub fn high_value(&self) -> u8 {
+        if self.value == Value::Ace {
+            11
+        } else {
+            self.value.value()
+        }
+    }
+
     // For Ace, we need to check if it should be 1 or 11
     pub fn blackjack_values(&self) -> Vec<u8> {
         if self.value == Value::Ace {
@@ -127,37 +139,213 @@ impl fmt::Display for Card {
     }
 }
 
+// Rule variants that change what's in the deck. Runs played with anything but Standard are
+// flagged in the high scores, since the odds of a 21 change with the cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DeckTemplate {
+    #[default]
+    Standard,
+    FaceCardFrenzy, // Two of every jack, queen and king
+    LowBall,        // No face cards at all
+}
+
+impl DeckTemplate {
+    pub const ALL: [DeckTemplate; 3] = [
+        DeckTemplate::Standard,
+        DeckTemplate::FaceCardFrenzy,
+        DeckTemplate::LowBall,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeckTemplate::Standard => "Standard",
+            DeckTemplate::FaceCardFrenzy => "Face Card Frenzy",
+            DeckTemplate::LowBall => "Low Ball",
+        }
+    }
+
+    /// Short label shown next to scores from runs with this deck; None for Standard
+    pub fn tag(&self) -> Option<&'static str> {
+        match self {
+            DeckTemplate::Standard => None,
+            DeckTemplate::FaceCardFrenzy => Some("FACES"),
+            DeckTemplate::LowBall => Some("LOW"),
+        }
+    }
+
+    /// The template with this name, for reading it back from the database
+    pub fn from_name(name: &str) -> Option<DeckTemplate> {
+        Self::ALL
+            .into_iter()
+            .find(|template| template.name() == name)
+    }
+
+    pub fn cycle(&self, step: i32) -> DeckTemplate {
+        super::cycle_in(&Self::ALL, *self, step)
+    }
+
+    /// How many of each card of this value go in the deck
+    pub fn copies(&self, value: Value) -> usize {
+        let face = matches!(value, Value::Jack | Value::Queen | Value::King);
+        match self {
+            DeckTemplate::Standard => 1,
+            DeckTemplate::FaceCardFrenzy if face => 2,
+            DeckTemplate::FaceCardFrenzy => 1,
+            DeckTemplate::LowBall if face => 0,
+            DeckTemplate::LowBall => 1,
+        }
+    }
+}
+
+// One deck, built from a template. Cards leave the draw pile onto the board and join the discard pile once
+// they're cleared; only the discards are shuffled back in, so a card on the board can't be
+// drawn a second time.
 pub struct Deck {
     cards: Vec<Card>,
+    discards: Vec<Card>,
+    template: DeckTemplate,
+    rng: StdRng, // Every shuffle of this deck, so a seeded deck deals the same way each time
 }
 
 impl Deck {
     pub fn new() -> Self {
-        let cards = Suit::all()
+        Self::new_with_template(DeckTemplate::Standard)
+    }
+
+    /// An unshuffled deck holding the template's cards
+    pub fn new_with_template(template: DeckTemplate) -> Self {
+        Self::with_rng(template, StdRng::from_rng(&mut rand::rng()))
+    }
+
+    /// An unshuffled deck whose shuffles all follow from `seed`
+    pub fn seeded(template: DeckTemplate, seed: u64) -> Self {
+        Self::with_rng(template, StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(template: DeckTemplate, rng: StdRng) -> Self {
+        Deck {
+            cards: Self::full_deck(template),
+            discards: Vec::new(),
+            template,
+            rng,
+        }
+    }
+
+    fn full_deck(template: DeckTemplate) -> Vec<Card> {
+        Suit::all()
             .into_iter()
             .flat_map(|suit| {
-                Value::all()
-                    .into_iter()
-                    .map(move |value| Card::new(suit, value))
+                Value::all().into_iter().flat_map(move |value| {
+                    std::iter::repeat_n(Card::new(suit, value), template.copies(value))
+                })
             })
-            .collect();
-        Deck { cards }
+            .collect()
     }
 
     pub fn shuffle(&mut self) {
         use rand::seq::SliceRandom;
-        let mut rng = rand::rng();
-        self.cards.shuffle(&mut rng);
+        self.cards.shuffle(&mut self.rng);
     }
 
+    /// The next card. An empty draw pile is refilled from the shuffled discards first; None
+    /// only when every card is in play.
     pub fn draw(&mut self) -> Option<Card> {
+        if self.cards.is_empty() && !self.discards.is_empty() {
+            self.cards.append(&mut self.discards);
+            self.shuffle();
+        }
         self.cards.pop()
     }
 
+    /// Put a card that has left the board on the discard pile. Cards the template doesn't
+    /// have (a handicap face card in a Low Ball run) are dropped instead.
+    pub fn discard(&mut self, card: Card) {
+        if self.template.copies(card.value) > 0 {
+            self.discards.push(card);
+        }
+    }
+
+    /// Take one of these cards out of the draw pile, if it's there
+    pub fn take(&mut self, card: Card) -> bool {
+        match self.cards.iter().position(|&drawable| drawable == card) {
+            Some(index) => {
+                self.cards.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// A fresh shuffled deck from the same template, with nothing discarded. A seeded deck
+    /// carries on from its seed.
     pub fn reset(&mut self) {
-        *self = Deck::new();
+        self.cards = Self::full_deck(self.template);
+        self.discards.clear();
         self.shuffle();
     }
+
+    /// A deck holding exactly these cards, drawn from the end, with nothing discarded
+    pub fn from_cards(cards: Vec<Card>) -> Self {
+        Deck {
+            cards,
+            discards: Vec::new(),
+            template: DeckTemplate::Standard,
+            rng: StdRng::from_rng(&mut rand::rng()),
+        }
+    }
+
+    /// A deck drawing these cards, from the end, in a run with `in_play` on the board or
+    /// waiting to drop. Every other card of the template counts as discarded. Saves only
+    /// keep the draw pile, so this is how a resumed run gets its discards back.
+    pub fn resume(template: DeckTemplate, cards: Vec<Card>, in_play: &[Card]) -> Self {
+        let mut discards = Self::full_deck(template);
+        for card in cards.iter().chain(in_play) {
+            if let Some(index) = discards.iter().position(|discard| discard == card) {
+                discards.swap_remove(index);
+            }
+        }
+        Deck {
+            cards,
+            discards,
+            template,
+            rng: StdRng::from_rng(&mut rand::rng()),
+        }
+    }
+
+    pub fn template(&self) -> DeckTemplate {
+        self.template
+    }
+
+    /// Cards left to draw, the next one last
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// The cards the next draw could be: the draw pile, or the discards when the pile is
+    /// empty and they're about to be shuffled back in
+    pub fn upcoming(&self) -> &[Card] {
+        if self.cards.is_empty() {
+            &self.discards
+        } else {
+            &self.cards
+        }
+    }
+
+    /// How many cards are left to draw before the discards are shuffled back in
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Cards cleared from the board since the deck was last shuffled
+    pub fn discards(&self) -> &[Card] {
+        &self.discards
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // Raylib color enum for card rendering
@@ -246,6 +434,10 @@ mod tests {
         let five = Card::new(Suit::Clubs, Value::Five);
         let five_values = five.blackjack_values();
         assert_eq!(five_values, vec![5]);
+
+        assert_eq!(ace.high_value(), 11);
+        assert_eq!(king.high_value(), 10);
+        assert_eq!(five.high_value(), 5);
     }
 
     #[test]
@@ -312,6 +504,87 @@ mod tests {
         assert_eq!(deck.cards.len(), 52);
     }
 
+    #[test]
+    fn test_deck_reshuffles_only_discards() {
+        let mut deck = Deck::new();
+        let mut on_board = Vec::new();
+        while let Some(card) = deck.draw() {
+            on_board.push(card);
+        }
+        assert_eq!(deck.remaining(), 0);
+
+        // Clear ten cards; the rest stay on the board
+        for card in on_board.drain(..10) {
+            deck.discard(card);
+        }
+        assert_eq!(deck.discards().len(), 10);
+        assert_eq!(deck.upcoming(), deck.discards());
+
+        let mut redrawn = Vec::new();
+        while let Some(card) = deck.draw() {
+            redrawn.push(card);
+        }
+        assert_eq!(redrawn.len(), 10);
+        assert!(redrawn.iter().all(|card| !on_board.contains(card)));
+        assert!(deck.discards().is_empty());
+    }
+
+    #[test]
+    fn test_deck_resume() {
+        let ace = Card::new(Suit::Hearts, Value::Ace);
+        let king = Card::new(Suit::Spades, Value::King);
+        let queen = Card::new(Suit::Clubs, Value::Queen);
+
+        let deck = Deck::resume(DeckTemplate::Standard, vec![ace], &[king, queen]);
+        assert_eq!(deck.remaining(), 1);
+        assert_eq!(deck.discards().len(), 49);
+        assert!(!deck.discards().contains(&ace));
+        assert!(!deck.discards().contains(&king));
+        assert!(!deck.discards().contains(&queen));
+    }
+
+    #[test]
+    fn test_deck_templates() {
+        let frenzy = Deck::new_with_template(DeckTemplate::FaceCardFrenzy);
+        assert_eq!(frenzy.remaining(), 64);
+        let kings = frenzy
+            .cards()
+            .iter()
+            .filter(|card| card.value == Value::King)
+            .count();
+        assert_eq!(kings, 8);
+
+        let mut low_ball = Deck::new_with_template(DeckTemplate::LowBall);
+        assert_eq!(low_ball.remaining(), 40);
+        assert!(
+            low_ball
+                .cards()
+                .iter()
+                .all(|card| card.value.value() < 10 || card.value == Value::Ten)
+        );
+
+        // Cards the template doesn't have never reach the discards
+        let king = Card::new(Suit::Clubs, Value::King);
+        assert!(!low_ball.take(king));
+        low_ball.discard(king);
+        assert!(low_ball.discards().is_empty());
+
+        let two = Card::new(Suit::Clubs, Value::Two);
+        assert!(low_ball.take(two));
+        assert_eq!(low_ball.remaining(), 39);
+        low_ball.discard(two);
+        assert_eq!(low_ball.discards(), &[two]);
+
+        // Resetting keeps the template
+        low_ball.reset();
+        assert_eq!(low_ball.template(), DeckTemplate::LowBall);
+        assert_eq!(low_ball.remaining(), 40);
+
+        for template in DeckTemplate::ALL {
+            assert_eq!(DeckTemplate::from_name(template.name()), Some(template));
+        }
+    }
+
     #[test]
     fn test_deck_shuffle() {
         let mut deck1 = Deck::new();
@@ -339,6 +612,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_seeded_decks_deal_alike() {
+        let deal = |seed| {
+            let mut deck = Deck::seeded(DeckTemplate::Standard, seed);
+            deck.shuffle();
+            let mut cards: Vec<Card> = (0..60).filter_map(|_| deck.draw()).collect();
+            // Carry on past a reset, as a run does once every card is in play
+            deck.reset();
+            cards.extend(std::iter::from_fn(|| deck.draw()).take(10));
+            cards
+        };
+        assert_eq!(deal(7), deal(7));
+        assert_ne!(deal(7), deal(8));
+    }
+
     mod test_fixtures {
         use super::*;
 
@@ -349,7 +637,7 @@ mod tests {
                 Card::new(Suit::Diamonds, Value::Queen),
                 Card::new(Suit::Clubs, Value::Ten),
             ];
-            Deck { cards }
+            Deck::from_cards(cards)
         }
 
         pub fn create_blackjack_hand() -> Vec<Card> {
@@ -1,29 +1,263 @@
 // Database-related models
 
+use super::{DeckTemplate, Difficulty};
+use crate::build_info::BuildInfo;
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HighScore {
     #[allow(dead_code)] // Used by database operations
     pub id: Option<i64>,
     pub player_initials: String,
     pub score: i32,
-    pub difficulty: String,
-    pub date: String,
+    pub difficulty: Difficulty,
+    #[serde(with = "score_date")]
+    pub date: DateTime<Utc>, // When the run ended, to the second
+    #[serde(default)]
+    pub modded: bool, // Played with mod scripts changing the rules
+    #[serde(default)]
+    pub deck_template: DeckTemplate, // Anything but Standard is flagged next to the score
+    #[serde(default)]
+    pub relaxed: bool, // Played in relax mode, so it's kept off the regular leaderboard
+    #[serde(default)]
+    pub modified: bool, // Its saved row, or the saved run it came from, was edited by hand
+}
+
+// How dates were written before scores kept a timestamp, in the player's local time
+pub const LEGACY_DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// A date written in LEGACY_DATE_FORMAT, read as local time
+pub fn parse_legacy_date(text: &str) -> Option<DateTime<Utc>> {
+    let local = NaiveDateTime::parse_from_str(text, LEGACY_DATE_FORMAT)
+        .ok()?
+        .and_local_timezone(Local)
+        .earliest()?;
+    Some(local.with_timezone(&Utc))
+}
+
+// Score files keep the date as seconds since the epoch. Files from before that have it in
+// LEGACY_DATE_FORMAT, and are read the same way the database migrates them.
+mod score_date {
+    use super::parse_legacy_date;
+    use chrono::{DateTime, Utc};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StoredDate {
+        Timestamp(i64),
+        Legacy(String),
+    }
+
+    pub fn serialize<S: Serializer>(
+        date: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(date.timestamp())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let date = match StoredDate::deserialize(deserializer)? {
+            StoredDate::Timestamp(seconds) => DateTime::from_timestamp(seconds, 0),
+            StoredDate::Legacy(text) => parse_legacy_date(&text),
+        };
+        date.ok_or_else(|| D::Error::custom("invalid score date"))
+    }
+}
+
+// Which scores the leaderboard shows, by when they were set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeaderboardPeriod {
+    Today,
+    ThisWeek, // Since Monday
+    #[default]
+    AllTime,
+}
+
+impl LeaderboardPeriod {
+    pub const ALL: [LeaderboardPeriod; 3] = [
+        LeaderboardPeriod::Today,
+        LeaderboardPeriod::ThisWeek,
+        LeaderboardPeriod::AllTime,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LeaderboardPeriod::Today => "Today",
+            LeaderboardPeriod::ThisWeek => "This Week",
+            LeaderboardPeriod::AllTime => "All Time",
+        }
+    }
+
+    pub fn cycle(&self, step: i32) -> LeaderboardPeriod {
+        super::cycle_in(&Self::ALL, *self, step)
+    }
+
+    /// Local midnight at the start of the period, for a player whose clock reads `now`.
+    /// None for all time.
+    pub fn start(&self, now: DateTime<Local>) -> Option<DateTime<Utc>> {
+        let today = now.date_naive();
+        let first_day = match self {
+            LeaderboardPeriod::Today => today,
+            LeaderboardPeriod::ThisWeek => {
+                today - Days::new(today.weekday().num_days_from_monday() as u64)
+            }
+            LeaderboardPeriod::AllTime => return None,
+        };
+        local_midnight(first_day)
+    }
+}
+
+fn local_midnight(day: NaiveDate) -> Option<DateTime<Utc>> {
+    let midnight = day
+        .and_hms_opt(0, 0, 0)?
+        .and_local_timezone(Local)
+        .earliest()?;
+    Some(midnight.with_timezone(&Utc))
+}
+
+// Longest name the game over screen takes; player_initials holds a name up to this long
+pub const MAX_NAME_LEN: usize = 10;
+
+// Letters the start screen leaderboard has room for
+pub const SHORT_NAME_LEN: usize = 3;
+
+impl HighScore {
+    /// The name as the leaderboard shows it: initials as entered, longer names cut to
+    /// their first SHORT_NAME_LEN letters
+    pub fn short_name(&self) -> &str {
+        match self.player_initials.char_indices().nth(SHORT_NAME_LEN) {
+            Some((end, _)) => &self.player_initials[..end],
+            None => &self.player_initials,
+        }
+    }
+}
+
+/// Percentage of `total` saved scores that a score beat outright, rounded down, when
+/// `below` of them are lower. None with nothing saved to compare against.
+pub fn score_percentile(below: usize, total: usize) -> Option<u8> {
+    (total > 0).then(|| (below.min(total) * 100 / total) as u8)
+}
+
+// How a run ended, for the statistics counters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    Finished,  // Played until the board filled up
+    Abandoned, // Quit to the menu from the pause menu
+    Saved,     // Saved from the pause menu to continue later
+}
+
+impl RunOutcome {
+    /// Name of the counter this outcome adds to (a row in the stats table)
+    pub fn stat_name(&self) -> &'static str {
+        match self {
+            RunOutcome::Finished => "runs_finished",
+            RunOutcome::Abandoned => "runs_abandoned",
+            RunOutcome::Saved => "runs_saved",
+        }
+    }
+}
+
+// Lifetime counters kept alongside the high scores
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Statistics {
+    #[serde(default)]
+    pub runs_finished: u32,
+    #[serde(default)]
+    pub runs_abandoned: u32,
+    #[serde(default)]
+    pub runs_saved: u32,
+    #[serde(default)]
+    pub column_drops: Vec<u32>, // Cards placed in each board column, left to right
+}
+
+/// Name of the stats table row counting drops into `column`
+pub fn column_drops_stat_name(column: usize) -> String {
+    format!("{}{}", COLUMN_DROPS_PREFIX, column)
+}
+
+pub const COLUMN_DROPS_PREFIX: &str = "column_drops_";
+
+impl Statistics {
+    pub fn record(&mut self, outcome: RunOutcome) {
+        let counter = match outcome {
+            RunOutcome::Finished => &mut self.runs_finished,
+            RunOutcome::Abandoned => &mut self.runs_abandoned,
+            RunOutcome::Saved => &mut self.runs_saved,
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    /// Add one run's per-column drop counts to the totals
+    pub fn record_column_drops(&mut self, drops: &[u32]) {
+        if self.column_drops.len() < drops.len() {
+            self.column_drops.resize(drops.len(), 0);
+        }
+        for (total, &count) in self.column_drops.iter_mut().zip(drops) {
+            *total = total.saturating_add(count);
+        }
+    }
+
+    pub fn total_drops(&self) -> u32 {
+        self.column_drops.iter().sum()
+    }
+
+    /// Fraction of all drops that went into `column`, 0.0 before any have been counted
+    pub fn column_share(&self, column: usize) -> f32 {
+        let total = self.total_drops();
+        if total == 0 {
+            return 0.0;
+        }
+        self.column_drops.get(column).copied().unwrap_or(0) as f32 / total as f32
+    }
+}
+
+pub const STATISTICS_EXPORT_KEY: &str = "statistics_export.json";
+
+// Lifetime statistics and the top scores written out for sharing, stamped with the build
+// that wrote them
+#[derive(Debug, Serialize)]
+pub struct StatisticsExport<'a> {
+    pub build: BuildInfo,
+    pub exported: String,
+    pub statistics: &'a Statistics,
+    pub high_scores: &'a [HighScore],
+}
+
+impl<'a> StatisticsExport<'a> {
+    pub fn new(statistics: &'a Statistics, high_scores: &'a [HighScore]) -> Self {
+        StatisticsExport {
+            build: BuildInfo::CURRENT,
+            exported: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            statistics,
+            high_scores,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     mod test_fixtures {
         use super::*;
-        use chrono::Utc;
 
         pub fn create_test_high_score() -> HighScore {
             HighScore {
                 id: None,
                 player_initials: "ABC".to_string(),
                 score: 1500,
-                difficulty: "Medium".to_string(),
-                date: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                difficulty: Difficulty::Easy,
+                date: Utc::now(),
+                modded: false,
+                deck_template: DeckTemplate::Standard,
+                relaxed: false,
+                modified: false,
             }
         }
 
@@ -32,8 +266,12 @@ mod tests {
                 id: Some(id),
                 player_initials: "XYZ".to_string(),
                 score: 2000,
-                difficulty: "Hard".to_string(),
-                date: "2024-01-15 14:30:00".to_string(),
+                difficulty: Difficulty::Hard,
+                date: Utc.with_ymd_and_hms(2024, 1, 15, 14, 30, 0).unwrap(),
+                modded: false,
+                deck_template: DeckTemplate::Standard,
+                relaxed: false,
+                modified: false,
             }
         }
 
@@ -43,42 +281,149 @@ mod tests {
                     id: Some(1),
                     player_initials: "AAA".to_string(),
                     score: 1000,
-                    difficulty: "Easy".to_string(),
-                    date: "2024-01-01 10:00:00".to_string(),
+                    difficulty: Difficulty::Easy,
+                    date: Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+                    modded: false,
+                    deck_template: DeckTemplate::Standard,
+                    relaxed: false,
+                    modified: false,
                 },
                 HighScore {
                     id: Some(2),
                     player_initials: "BBB".to_string(),
                     score: 1500,
-                    difficulty: "Medium".to_string(),
-                    date: "2024-01-02 11:00:00".to_string(),
+                    difficulty: Difficulty::Easy,
+                    date: Utc.with_ymd_and_hms(2024, 1, 2, 11, 0, 0).unwrap(),
+                    modded: false,
+                    deck_template: DeckTemplate::Standard,
+                    relaxed: false,
+                    modified: false,
                 },
                 HighScore {
                     id: Some(3),
                     player_initials: "CCC".to_string(),
                     score: 2000,
-                    difficulty: "Hard".to_string(),
-                    date: "2024-01-03 12:00:00".to_string(),
+                    difficulty: Difficulty::Hard,
+                    date: Utc.with_ymd_and_hms(2024, 1, 3, 12, 0, 0).unwrap(),
+                    modded: false,
+                    deck_template: DeckTemplate::Standard,
+                    relaxed: false,
+                    modified: false,
                 },
             ]
         }
     }
 
+    #[test]
+    fn test_statistics_record() {
+        let mut statistics = Statistics::default();
+        statistics.record(RunOutcome::Abandoned);
+        statistics.record(RunOutcome::Abandoned);
+        statistics.record(RunOutcome::Finished);
+
+        assert_eq!(statistics.runs_abandoned, 2);
+        assert_eq!(statistics.runs_finished, 1);
+        assert_eq!(statistics.runs_saved, 0);
+    }
+
+    #[test]
+    fn test_statistics_column_drops() {
+        let mut statistics = Statistics::default();
+        statistics.record_column_drops(&[1, 0, 2]);
+        statistics.record_column_drops(&[0, 3, 1, 4]);
+
+        assert_eq!(statistics.column_drops, vec![1, 3, 3, 4]);
+        assert_eq!(statistics.total_drops(), 11);
+        assert_eq!(statistics.column_share(3), 4.0 / 11.0);
+        assert_eq!(statistics.column_share(9), 0.0);
+        assert_eq!(Statistics::default().column_share(0), 0.0);
+    }
+
+    #[test]
+    fn test_statistics_export_carries_the_build() {
+        let mut statistics = Statistics::default();
+        statistics.record(RunOutcome::Finished);
+        let scores = test_fixtures::create_multiple_high_scores();
+        let export = StatisticsExport::new(&statistics, &scores);
+
+        let json: serde_json::Value = serde_json::to_value(&export).unwrap();
+        assert_eq!(json["build"]["version"], env!("CARGO_PKG_VERSION"));
+        assert!(json["build"]["git_hash"].is_string());
+        assert_eq!(json["statistics"]["runs_finished"], 1);
+        assert_eq!(
+            json["high_scores"].as_array().map(Vec::len),
+            Some(scores.len())
+        );
+    }
+
     #[test]
     fn test_high_score_creation() {
         let high_score = HighScore {
             id: None,
             player_initials: "TEST".to_string(),
             score: 1234,
-            difficulty: "Easy".to_string(),
-            date: "2024-01-01 12:00:00".to_string(),
+            difficulty: Difficulty::Easy,
+            date: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+            modded: false,
+            deck_template: DeckTemplate::Standard,
+            relaxed: false,
+            modified: false,
         };
 
         assert!(high_score.id.is_none());
         assert_eq!(high_score.player_initials, "TEST");
         assert_eq!(high_score.score, 1234);
-        assert_eq!(high_score.difficulty, "Easy");
-        assert_eq!(high_score.date, "2024-01-01 12:00:00");
+        assert_eq!(high_score.difficulty, Difficulty::Easy);
+        assert_eq!(high_score.date.timestamp(), 1704110400);
+    }
+
+    #[test]
+    fn test_high_score_json_keeps_the_difficulty_name() {
+        // The JSON score store reads files written when difficulty was a string
+        let high_score = test_fixtures::create_high_score_with_id(7);
+        let json = serde_json::to_value(&high_score).unwrap();
+        assert_eq!(json["difficulty"], "Hard");
+
+        let parsed: HighScore = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.difficulty, Difficulty::Hard);
+    }
+
+    #[test]
+    fn test_high_score_json_reads_legacy_dates() {
+        let high_score = test_fixtures::create_high_score_with_id(7);
+        let mut json = serde_json::to_value(&high_score).unwrap();
+        assert_eq!(json["date"], high_score.date.timestamp());
+        let parsed: HighScore = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(parsed.date, high_score.date);
+
+        // Files from before timestamps wrote the local time out
+        json["date"] = "2024-01-15 14:30:00".into();
+        let parsed: HighScore = serde_json::from_value(json.clone()).unwrap();
+        let local = Local.with_ymd_and_hms(2024, 1, 15, 14, 30, 0).unwrap();
+        assert_eq!(parsed.date, local.with_timezone(&Utc));
+
+        json["date"] = "last Tuesday".into();
+        assert!(serde_json::from_value::<HighScore>(json).is_err());
+    }
+
+    #[test]
+    fn test_leaderboard_periods_start_at_local_midnight() {
+        // A Wednesday afternoon
+        let now = Local.with_ymd_and_hms(2024, 1, 17, 15, 0, 0).unwrap();
+        let midnight = |day| {
+            Local
+                .with_ymd_and_hms(2024, 1, day, 0, 0, 0)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        assert_eq!(LeaderboardPeriod::Today.start(now), Some(midnight(17)));
+        assert_eq!(LeaderboardPeriod::ThisWeek.start(now), Some(midnight(15)));
+        assert_eq!(LeaderboardPeriod::AllTime.start(now), None);
+
+        assert_eq!(
+            LeaderboardPeriod::AllTime.cycle(1),
+            LeaderboardPeriod::Today
+        );
     }
 
     #[test]
@@ -88,7 +433,7 @@ mod tests {
         assert_eq!(high_score.id, Some(42));
         assert_eq!(high_score.player_initials, "XYZ");
         assert_eq!(high_score.score, 2000);
-        assert_eq!(high_score.difficulty, "Hard");
+        assert_eq!(high_score.difficulty, Difficulty::Hard);
     }
 
     #[test]
@@ -98,9 +443,9 @@ mod tests {
         assert!(high_score.id.is_none());
         assert_eq!(high_score.player_initials, "ABC");
         assert_eq!(high_score.score, 1500);
-        assert_eq!(high_score.difficulty, "Medium");
+        assert_eq!(high_score.difficulty, Difficulty::Easy);
         // Date should be recent (within last minute)
-        assert!(!high_score.date.is_empty());
+        assert!(Utc::now() - high_score.date < chrono::TimeDelta::minutes(1));
     }
 
     #[test]
@@ -125,12 +470,32 @@ mod tests {
 
         // Test that string fields can be modified
         high_score.player_initials = "NEW".to_string();
-        high_score.difficulty = "Hard".to_string();
-        high_score.date = "2024-12-31 23:59:59".to_string();
+        high_score.difficulty = Difficulty::Hard;
+        high_score.date = Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap();
 
         assert_eq!(high_score.player_initials, "NEW");
-        assert_eq!(high_score.difficulty, "Hard");
-        assert_eq!(high_score.date, "2024-12-31 23:59:59");
+        assert_eq!(high_score.difficulty, Difficulty::Hard);
+        assert_eq!(high_score.date.year(), 2024);
+    }
+
+    #[test]
+    fn test_short_name_fits_the_leaderboard() {
+        let mut high_score = test_fixtures::create_test_high_score();
+        assert_eq!(high_score.short_name(), "ABC");
+
+        high_score.player_initials = "JO".to_string();
+        assert_eq!(high_score.short_name(), "JO");
+
+        high_score.player_initials = "BEATRIX".to_string();
+        assert_eq!(high_score.short_name(), "BEA");
+    }
+
+    #[test]
+    fn test_score_percentile() {
+        assert_eq!(score_percentile(0, 0), None);
+        assert_eq!(score_percentile(0, 4), Some(0));
+        assert_eq!(score_percentile(7, 9), Some(77));
+        assert_eq!(score_percentile(9, 9), Some(100));
     }
 
     #[test]
@@ -0,0 +1,37 @@
+// The whole board at one moment, written with F5 and read back with F9 so a reported board
+// bug can be set up again exactly. Unlike a SavedRun nothing is settled first: cards waiting
+// to explode, busted cards and cards still dropping are all kept where they are.
+
+use super::GameSettings;
+use super::cards::{Card, DeckTemplate};
+use super::game::Difficulty;
+use serde::{Deserialize, Serialize};
+
+pub const DEBUG_SNAPSHOT_KEY: &str = "debug_snapshot.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DebugSnapshot {
+    pub build: String, // Game version and commit that wrote the snapshot
+    pub taken_at: String,
+    pub difficulty: Difficulty,
+    pub score: i32,
+    pub speed_level: u32,
+    pub grid: Vec<Vec<Option<Card>>>, // Rows top to bottom, like Board::grid
+    pub marked_for_removal: Vec<(i32, i32)>, // Restoring finds these clears again
+    pub locked: Vec<(i32, i32)>,      // Busted cards, which restore with a fresh lock
+    pub current_card: Option<(Card, (i32, i32))>,
+    pub hard_drops: Vec<(Card, (i32, i32))>, // Cards still dropping, and where they'll land
+    pub deck: Vec<Card>,                     // Cards left to draw, the next one last
+    #[serde(default)]
+    pub deck_template: DeckTemplate,
+    pub next_card: Option<Card>,
+    pub settings: GameSettings, // For reading only; restoring keeps the player's settings
+}
+
+impl DebugSnapshot {
+    /// Whether the snapshot's board fits a board of this size
+    pub fn fits(&self, width: i32, height: i32) -> bool {
+        self.grid.len() == height as usize
+            && self.grid.iter().all(|row| row.len() == width as usize)
+    }
+}
@@ -1,9 +1,9 @@
 use super::cards::Card;
 use std::fmt::Display;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 // Position of a card on the board
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Position {
     pub x: i32,
     pub y: i32,
@@ -117,12 +117,64 @@ impl Display for Difficulty {
     }
 }
 
-// Delayed destruction entry for cascading effects
+// Fall speed tuning for a difficulty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DifficultyParams {
+    pub initial_fall_speed: Duration, // Time between automatic drops at the start of a run
+    pub ramp_percent: u32, // Each speed level keeps this percent of the gap above the floor
+    pub min_fall_speed: Duration, // The floor the speed curve approaches but never passes
+}
+
+impl Difficulty {
+    /// The number a difficulty is stored as in the score database
+    pub fn level(self) -> i64 {
+        match self {
+            Difficulty::Easy => 0,
+            Difficulty::Hard => 1,
+        }
+    }
+
+    pub fn from_level(level: i64) -> Option<Self> {
+        match level {
+            0 => Some(Difficulty::Easy),
+            1 => Some(Difficulty::Hard),
+            _ => None,
+        }
+    }
+
+    pub fn params(self) -> DifficultyParams {
+        match self {
+            Difficulty::Easy => DifficultyParams {
+                initial_fall_speed: Duration::from_millis(1000),
+                ramp_percent: 88,
+                min_fall_speed: Duration::from_millis(150),
+            },
+            Difficulty::Hard => DifficultyParams {
+                initial_fall_speed: Duration::from_millis(800),
+                ramp_percent: 84,
+                min_fall_speed: Duration::from_millis(120),
+            },
+        }
+    }
+}
+
+impl DifficultyParams {
+    /// Fall time at a speed level (0 = start of a run). The curve closes in on the floor
+    /// exponentially: big steps early on, smaller ones as play gets fast.
+    pub fn fall_speed_at(&self, level: u32) -> Duration {
+        // A starting speed already below the floor just stays put
+        let floor = self.min_fall_speed.min(self.initial_fall_speed);
+        let gap = self.initial_fall_speed - floor;
+        let remaining = (self.ramp_percent as f64 / 100.0).powi(level.min(i32::MAX as u32) as i32);
+        floor + gap.mul_f64(remaining)
+    }
+}
+
+// Cascade check waiting for every combination of the last step to explode
 #[derive(Debug, Clone)]
 pub struct DelayedDestruction {
     pub destruction_time: Instant,
     pub chain_multiplier: i32,
-    pub combination_index: usize,
 }
 
 // A card falling due to gravity
@@ -196,6 +248,41 @@ mod tests {
         assert_eq!(format!("{}", Difficulty::Hard), "Hard");
     }
 
+    #[test]
+    fn test_difficulty_params_ramp_to_floor() {
+        for difficulty in [Difficulty::Easy, Difficulty::Hard] {
+            let params = difficulty.params();
+            assert_eq!(params.fall_speed_at(0), params.initial_fall_speed);
+
+            // Each level is faster, by a smaller step than the one before
+            let steps: Vec<Duration> = (0..4)
+                .map(|level| params.fall_speed_at(level) - params.fall_speed_at(level + 1))
+                .collect();
+            assert!(steps.windows(2).all(|pair| pair[0] > pair[1]));
+
+            // Far enough along, the curve sits on the floor without passing it
+            let fastest = params.fall_speed_at(1000);
+            assert!(fastest >= params.min_fall_speed);
+            assert!(fastest - params.min_fall_speed < Duration::from_millis(1));
+        }
+
+        // Hard starts faster than Easy
+        assert!(
+            Difficulty::Hard.params().initial_fall_speed
+                < Difficulty::Easy.params().initial_fall_speed
+        );
+    }
+
+    #[test]
+    fn test_fall_speed_floor_above_start() {
+        let params = DifficultyParams {
+            initial_fall_speed: Duration::from_millis(100),
+            ramp_percent: 90,
+            min_fall_speed: Duration::from_millis(120),
+        };
+        assert_eq!(params.fall_speed_at(5), Duration::from_millis(100));
+    }
+
     #[test]
     fn test_difficulty_equality() {
         assert_eq!(Difficulty::Easy, Difficulty::Easy);
@@ -308,12 +395,10 @@ mod tests {
         let destruction = DelayedDestruction {
             destruction_time: now + Duration::from_millis(500),
             chain_multiplier: 2,
-            combination_index: 1,
         };
 
         assert!(destruction.destruction_time > now);
         assert_eq!(destruction.chain_multiplier, 2);
-        assert_eq!(destruction.combination_index, 1);
     }
 
     #[test]
@@ -0,0 +1,131 @@
+// On-screen keyboard used for gamepad text entry (initials, and anything else that needs letters)
+
+// A single key on the virtual keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VirtualKey {
+    Letter(char),
+    Backspace,
+    Done,
+}
+
+impl VirtualKey {
+    pub fn label(&self) -> String {
+        match self {
+            VirtualKey::Letter(c) => c.to_string(),
+            VirtualKey::Backspace => "DEL".to_string(),
+            VirtualKey::Done => "END".to_string(),
+        }
+    }
+}
+
+// A-Z laid out in a 7x4 grid, with DEL and END filling the last two slots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VirtualKeyboard {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl VirtualKeyboard {
+    pub const COLUMNS: usize = 7;
+    pub const ROWS: usize = 4;
+
+    pub fn new() -> Self {
+        VirtualKeyboard { row: 0, col: 0 }
+    }
+
+    pub fn key_at(row: usize, col: usize) -> VirtualKey {
+        let index = row * Self::COLUMNS + col;
+        match index {
+            0..=25 => VirtualKey::Letter((b'A' + index as u8) as char),
+            26 => VirtualKey::Backspace,
+            _ => VirtualKey::Done,
+        }
+    }
+
+    pub fn selected_key(&self) -> VirtualKey {
+        Self::key_at(self.row, self.col)
+    }
+
+    // Navigation wraps around on every edge so the whole grid is reachable from anywhere
+    pub fn move_left(&mut self) {
+        self.col = (self.col + Self::COLUMNS - 1) % Self::COLUMNS;
+    }
+
+    pub fn move_right(&mut self) {
+        self.col = (self.col + 1) % Self::COLUMNS;
+    }
+
+    pub fn move_up(&mut self) {
+        self.row = (self.row + Self::ROWS - 1) % Self::ROWS;
+    }
+
+    pub fn move_down(&mut self) {
+        self.row = (self.row + 1) % Self::ROWS;
+    }
+
+    pub fn reset(&mut self) {
+        *self = VirtualKeyboard::new();
+    }
+}
+
+impl Default for VirtualKeyboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyboard_starts_on_a() {
+        let keyboard = VirtualKeyboard::new();
+        assert_eq!(keyboard.selected_key(), VirtualKey::Letter('A'));
+    }
+
+    #[test]
+    fn test_keyboard_layout_covers_alphabet() {
+        let mut letters = Vec::new();
+        for row in 0..VirtualKeyboard::ROWS {
+            for col in 0..VirtualKeyboard::COLUMNS {
+                if let VirtualKey::Letter(c) = VirtualKeyboard::key_at(row, col) {
+                    letters.push(c);
+                }
+            }
+        }
+
+        let expected: Vec<char> = ('A'..='Z').collect();
+        assert_eq!(letters, expected);
+        assert_eq!(VirtualKeyboard::key_at(3, 5), VirtualKey::Backspace);
+        assert_eq!(VirtualKeyboard::key_at(3, 6), VirtualKey::Done);
+    }
+
+    #[test]
+    fn test_keyboard_navigation_wraps() {
+        let mut keyboard = VirtualKeyboard::new();
+
+        keyboard.move_left();
+        assert_eq!(keyboard.selected_key(), VirtualKey::Letter('G'));
+
+        keyboard.move_up();
+        assert_eq!(keyboard.selected_key(), VirtualKey::Done);
+
+        keyboard.move_right();
+        assert_eq!(keyboard.selected_key(), VirtualKey::Letter('V'));
+
+        keyboard.move_down();
+        assert_eq!(keyboard.selected_key(), VirtualKey::Letter('A'));
+    }
+
+    #[test]
+    fn test_keyboard_reset() {
+        let mut keyboard = VirtualKeyboard::new();
+        keyboard.move_down();
+        keyboard.move_right();
+        assert_eq!(keyboard.selected_key(), VirtualKey::Letter('I'));
+
+        keyboard.reset();
+        assert_eq!(keyboard.selected_key(), VirtualKey::Letter('A'));
+    }
+}
@@ -2,23 +2,286 @@
 
 pub mod cards;
 pub mod database;
+pub mod debug_snapshot;
 pub mod game;
-pub mod ui;
+pub mod keyboard;
+pub mod puzzle;
+pub mod run_seed;
+pub mod saved_run;
+pub mod season;
 
 // Re-export common models for easy access
-pub use cards::{Card, CardColor, Deck, Suit, Value};
-pub use database::HighScore;
+pub use cards::{Card, CardColor, Deck, DeckTemplate, Suit, Value};
+pub use database::{
+    COLUMN_DROPS_PREFIX, HighScore, LEGACY_DATE_FORMAT, LeaderboardPeriod, MAX_NAME_LEN,
+    RunOutcome, SHORT_NAME_LEN, STATISTICS_EXPORT_KEY, Statistics, StatisticsExport,
+    column_drops_stat_name, parse_legacy_date, score_percentile,
+};
+pub use debug_snapshot::{DEBUG_SNAPSHOT_KEY, DebugSnapshot};
 pub use game::{
-    DelayedDestruction, Difficulty, FallingCard, PlayingCard, Position, VisualPosition,
+    DelayedDestruction, Difficulty, DifficultyParams, FallingCard, PlayingCard, Position,
+    VisualPosition,
 };
-pub use ui::Particle;
+pub use keyboard::{VirtualKey, VirtualKeyboard};
+pub use puzzle::{MAX_STARS, PuzzleLevel};
+pub use run_seed::RunSeed;
+pub use saved_run::SavedRun;
+pub use season::Season;
 
 // Export builder patterns for easy access - only export what we actually use
 // (Removed unused wildcard imports and unused builder exports)
 
+use crate::store::SettingsStore;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+// Which button prompts to show. Input from both keyboard and gamepad is always accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ControlScheme {
+    #[default]
+    Auto, // Controller prompts whenever a gamepad is connected
+    Keyboard,
+    Controller,
+}
+
+impl ControlScheme {
+    pub const ALL: [ControlScheme; 3] = [
+        ControlScheme::Auto,
+        ControlScheme::Keyboard,
+        ControlScheme::Controller,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ControlScheme::Auto => "Automatic",
+            ControlScheme::Keyboard => "Keyboard",
+            ControlScheme::Controller => "Controller",
+        }
+    }
+
+    pub fn cycle(&self, step: i32) -> ControlScheme {
+        cycle_in(&Self::ALL, *self, step)
+    }
+
+    /// Whether to show gamepad prompts, given whether a gamepad is actually connected
+    pub fn shows_controller_prompts(&self, controller_connected: bool) -> bool {
+        match self {
+            ControlScheme::Auto => controller_connected,
+            ControlScheme::Keyboard => false,
+            ControlScheme::Controller => true,
+        }
+    }
+}
+
+// How the board looks behind the pause, game over and in-game settings overlays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PausedBoard {
+    #[default]
+    Hidden, // No cards, so pausing can't be used to study the board
+    Blurred, // A low-resolution snapshot: looks like the game, too soft to read
+    Shown,
+}
+
+impl PausedBoard {
+    pub const ALL: [PausedBoard; 3] = [
+        PausedBoard::Hidden,
+        PausedBoard::Blurred,
+        PausedBoard::Shown,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PausedBoard::Hidden => "Hidden",
+            PausedBoard::Blurred => "Blurred",
+            PausedBoard::Shown => "Shown",
+        }
+    }
+
+    pub fn cycle(&self, step: i32) -> PausedBoard {
+        cycle_in(&Self::ALL, *self, step)
+    }
+}
+
+// Streamer mode hides player initials and enlarges the score and next card for viewers;
+// OverlayFile also keeps the score and speed in a text file for OBS text sources, and
+// LiveFeed serves the whole game state on localhost for overlays and companion viewers.
+// The browser build can't serve the feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StreamerMode {
+    #[default]
+    Off,
+    On,
+    OverlayFile,
+    LiveFeed,
+}
+
+impl StreamerMode {
+    pub const ALL: [StreamerMode; 4] = [
+        StreamerMode::Off,
+        StreamerMode::On,
+        StreamerMode::OverlayFile,
+        StreamerMode::LiveFeed,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StreamerMode::Off => "Off",
+            StreamerMode::On => "On",
+            StreamerMode::OverlayFile => "On + File",
+            StreamerMode::LiveFeed => "On + Feed",
+        }
+    }
+
+    pub fn cycle(&self, step: i32) -> StreamerMode {
+        cycle_in(&Self::ALL, *self, step)
+    }
+
+    pub fn is_on(&self) -> bool {
+        !matches!(self, StreamerMode::Off)
+    }
+
+    pub fn writes_overlay_file(&self) -> bool {
+        matches!(self, StreamerMode::OverlayFile)
+    }
+
+    pub fn serves_live_feed(&self) -> bool {
+        matches!(self, StreamerMode::LiveFeed)
+    }
+}
+
+// Preset for the kind of device the game is played on. Handheld runs fullscreen at the
+// game's native 1280x800 (the Steam Deck panel, and scaled by the display elsewhere),
+// shows gamepad prompts and enlarges the small print.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DisplayProfile {
+    #[default]
+    Desktop,
+    Handheld,
+}
+
+impl DisplayProfile {
+    pub const ALL: [DisplayProfile; 2] = [DisplayProfile::Desktop, DisplayProfile::Handheld];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DisplayProfile::Desktop => "Desktop",
+            DisplayProfile::Handheld => "Handheld",
+        }
+    }
+
+    pub fn cycle(&self, step: i32) -> DisplayProfile {
+        cycle_in(&Self::ALL, *self, step)
+    }
+
+    pub fn fullscreen(&self) -> bool {
+        matches!(self, DisplayProfile::Handheld)
+    }
+
+    /// Multiplier for the small print (control hints, toasts), which is hard to read on a small screen
+    pub fn text_scale(&self) -> f32 {
+        match self {
+            DisplayProfile::Desktop => 1.0,
+            DisplayProfile::Handheld => 1.25,
+        }
+    }
+
+    /// The profile suggested by the environment the game was launched in
+    pub fn detect() -> DisplayProfile {
+        Self::detect_from(|name| std::env::var(name).ok())
+    }
+
+    // Steam sets SteamDeck=1 when running on a Deck (including SteamOS desktop mode)
+    fn detect_from(var: impl Fn(&str) -> Option<String>) -> DisplayProfile {
+        match var("SteamDeck").as_deref() {
+            Some("1") => DisplayProfile::Handheld,
+            _ => DisplayProfile::Desktop,
+        }
+    }
+}
+
+// Colour of the table felt under the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FeltColor {
+    #[default]
+    Green,
+    Blue,
+    Red,
+    Purple,
+}
+
+impl FeltColor {
+    pub const ALL: [FeltColor; 4] = [
+        FeltColor::Green,
+        FeltColor::Blue,
+        FeltColor::Red,
+        FeltColor::Purple,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FeltColor::Green => "Green",
+            FeltColor::Blue => "Blue",
+            FeltColor::Red => "Red",
+            FeltColor::Purple => "Purple",
+        }
+    }
+
+    pub fn cycle(&self, step: i32) -> FeltColor {
+        cycle_in(&Self::ALL, *self, step)
+    }
+}
+
+// Design printed on the backs of the face-down cards drifting behind the menus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CardBack {
+    #[default]
+    Classic, // Red with a single diamond
+    Lattice,   // Blue with a diamond lattice
+    Starburst, // Purple with a gold star
+}
+
+impl CardBack {
+    pub const ALL: [CardBack; 3] = [CardBack::Classic, CardBack::Lattice, CardBack::Starburst];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardBack::Classic => "Classic",
+            CardBack::Lattice => "Lattice",
+            CardBack::Starburst => "Starburst",
+        }
+    }
+
+    pub fn cycle(&self, step: i32) -> CardBack {
+        cycle_in(&Self::ALL, *self, step)
+    }
+}
+
+fn cycle_in<T: Copy + PartialEq>(options: &[T], current: T, step: i32) -> T {
+    let len = options.len() as i32;
+    let index = options.iter().position(|o| *o == current).unwrap_or(0) as i32;
+    options[(index + step).rem_euclid(len) as usize]
+}
+
+// Range of the accessibility text size, in steps of FONT_SCALE_STEP
+pub const MIN_FONT_SCALE: f32 = 0.8;
+pub const MAX_FONT_SCALE: f32 = 1.5;
+const FONT_SCALE_STEP: f32 = 0.1;
+
+// Most rows the handicap option can fill before a run starts
+pub const MAX_HANDICAP_ROWS: u32 = 5;
+
+fn default_font_scale() -> f32 {
+    1.0
+}
+
+fn default_seasonal_events() -> bool {
+    true
+}
+
+fn default_slide_delay() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GameSettings {
     pub music_volume: f32, // 0.0 to 1.0
     pub music_muted: bool,
@@ -26,8 +289,62 @@ pub struct GameSettings {
     pub sound_effects_muted: bool,
     pub vsync_enabled: bool,
     pub difficulty: game::Difficulty, // Game difficulty setting
+    #[serde(default)] // Settings files from before first-run setup don't have this
+    pub control_scheme: ControlScheme,
+    #[serde(default)]
+    pub min_fall_speed_ms: Option<u64>, // Fastest fall time; None uses the difficulty's floor
+    #[serde(default)]
+    pub stick_dead_zone: Option<f32>, // Analog stick travel ignored for soft drop (0.0-0.9); None uses the default
+    #[serde(default)]
+    pub paused_board: PausedBoard,
+    #[serde(default)]
+    pub audio_device: Option<String>, // Output device name; None follows the system default
+    #[serde(default)]
+    pub touch_controls: bool, // Tap/swipe play and the on-screen pause button
+    #[serde(default)]
+    pub display_profile: DisplayProfile,
+    #[serde(default)]
+    pub streamer_mode: StreamerMode,
+    #[serde(default)]
+    pub mirrored_layout: bool, // Board on the right and the info panel on the left
+    #[serde(default)]
+    pub reduced_motion: bool, // Fades instead of explosions, confetti and drifting backgrounds
+    #[serde(default)]
+    pub high_contrast: bool, // Black panels, bold white text and outlined cards
+    #[serde(default = "default_font_scale")]
+    pub font_scale: f32, // Text size multiplier, MIN_FONT_SCALE to MAX_FONT_SCALE
+    #[serde(default)]
+    pub card_values: bool, // Blackjack value badge on every card in play
+    #[serde(default)]
+    pub draw_odds: bool, // Chance the next draw finishes a 21, under the next card preview
+    #[serde(default)]
+    pub deck_template: DeckTemplate, // Cards the next run's deck is built from
+    #[serde(default)]
+    pub handicap_rows: u32, // Bottom rows filled with cards at the start of a run, up to MAX_HANDICAP_ROWS
+    #[serde(default)]
+    pub hard_drop_steer: bool, // Left/right can nudge a hard drop into the next column just after it starts
+    #[serde(default = "default_slide_delay")]
+    pub slide_delay: bool, // A card landing beside a gap it could tuck into waits a moment before locking
+    #[serde(default)]
+    pub bust_rule: bool, // A one-suit run stacked past 21 in a column locks for a while
+    #[serde(default)]
+    pub relax_mode: bool, // Runs never end: a full board clears a column for a score penalty
+    #[serde(default)]
+    pub adaptive_speed: bool, // The fall speed eases off or picks up with how the run is going
+    #[serde(default)]
+    pub mods_enabled: bool, // Load mod scripts from the mods folder at startup
+    #[serde(default)]
+    pub check_for_updates: bool, // Ask the release feed for a newer version at startup
+    #[serde(default)]
+    pub disabled_mods: Vec<String>, // Catalog ids of scripts and packs switched off on the Mods & Themes screen
+    #[serde(default)]
+    pub felt_color: FeltColor,
+    #[serde(default)]
+    pub card_back: CardBack,
+    #[serde(default = "default_seasonal_events")]
+    pub seasonal_events: bool, // Snow in December and pumpkins in October
     #[serde(skip)]
-    pub selected_option: usize, // 0: Music, 1: SFX, 2: VSync, 3: Difficulty (for settings navigation)
+    pub selected_option: usize, // 0: Music, 1: SFX, 2: VSync, 3: Difficulty, 4: Paused Board, 5: Audio Device, 6: Touch Controls, 7: Display Profile, 8: Streamer Mode, 9: Layout, 10: Accessibility, 11: Appearance, 12: Diagnostics (for settings navigation)
 }
 
 impl Default for GameSettings {
@@ -39,88 +356,122 @@ impl Default for GameSettings {
             sound_effects_muted: false,
             vsync_enabled: true,
             difficulty: game::Difficulty::Easy,
+            control_scheme: ControlScheme::Auto,
+            min_fall_speed_ms: None,
+            stick_dead_zone: None,
+            paused_board: PausedBoard::Hidden,
+            audio_device: None,
+            touch_controls: false,
+            display_profile: DisplayProfile::Desktop,
+            streamer_mode: StreamerMode::Off,
+            mirrored_layout: false,
+            reduced_motion: false,
+            high_contrast: false,
+            font_scale: 1.0,
+            card_values: false,
+            draw_odds: false,
+            deck_template: DeckTemplate::Standard,
+            handicap_rows: 0,
+            hard_drop_steer: false,
+            slide_delay: true,
+            bust_rule: false,
+            relax_mode: false,
+            adaptive_speed: false,
+            mods_enabled: false,
+            check_for_updates: false,
+            disabled_mods: Vec::new(),
+            felt_color: FeltColor::Green,
+            card_back: CardBack::Classic,
+            seasonal_events: true,
             selected_option: 0,
         }
     }
 }
 
 impl GameSettings {
-    /// Get the path to the settings file
-    pub fn settings_file_path() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
-        Self::settings_file_path_with_name("settings.json")
+    /// Switch to a display profile along with the settings it implies: Handheld wants
+    /// gamepad prompts and touch play, Desktop goes back to following the connected devices
+    pub fn apply_display_profile(&mut self, profile: DisplayProfile) {
+        self.display_profile = profile;
+        match profile {
+            DisplayProfile::Desktop => {
+                self.control_scheme = ControlScheme::Auto;
+                self.touch_controls = false;
+            }
+            DisplayProfile::Handheld => {
+                self.control_scheme = ControlScheme::Controller;
+                self.touch_controls = true;
+            }
+        }
     }
 
-    /// Get the path to a settings file with a custom name (for testing)
-    #[cfg(test)]
-    pub fn settings_file_path_with_name(
-        filename: &str,
-    ) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
-        let test_dir = std::path::PathBuf::from("/tmp/dropjack_test_settings");
-        std::fs::create_dir_all(&test_dir)?;
-        Ok(test_dir.join(filename))
+    /// Nudge the text size by whole steps, staying within the allowed range
+    pub fn adjust_font_scale(&mut self, steps: i32) {
+        // Count in whole steps so repeated presses don't drift (0.1 isn't exact in f32)
+        let current = (self.font_scale / FONT_SCALE_STEP).round() as i32;
+        self.font_scale =
+            ((current + steps) as f32 * FONT_SCALE_STEP).clamp(MIN_FONT_SCALE, MAX_FONT_SCALE);
     }
 
-    #[cfg(not(test))]
+    pub fn adjust_handicap_rows(&mut self, steps: i32) {
+        self.handicap_rows = self
+            .handicap_rows
+            .saturating_add_signed(steps)
+            .min(MAX_HANDICAP_ROWS);
+    }
+
+    /// Whether a script or pack from the Mods & Themes screen is switched on
+    pub fn is_mod_enabled(&self, id: &str) -> bool {
+        !self.disabled_mods.iter().any(|disabled| disabled == id)
+    }
+
+    pub fn toggle_mod(&mut self, id: &str) {
+        if self.is_mod_enabled(id) {
+            self.disabled_mods.push(id.to_string());
+        } else {
+            self.disabled_mods.retain(|disabled| disabled != id);
+        }
+    }
+
+    /// Multiplier for scalable text: the display profile's boost times the player's text size
+    pub fn text_scale(&self) -> f32 {
+        self.display_profile.text_scale() * self.font_scale.clamp(MIN_FONT_SCALE, MAX_FONT_SCALE)
+    }
+
+    /// Get the path to a settings file with a custom name (for testing)
+    #[cfg(test)]
     pub fn settings_file_path_with_name(
         filename: &str,
     ) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
-        let app_data_dir = dirs::data_dir()
-            .ok_or("Could not determine data directory")?
-            .join("DropJack");
-
-        // Ensure the directory exists
-        std::fs::create_dir_all(&app_data_dir)?;
-
-        Ok(app_data_dir.join(filename))
+        Ok(crate::storage::FileStorage::app_data()?.path_for(filename))
     }
 
-    /// Load settings from disk, returning default settings if file doesn't exist or is corrupted
-    pub fn load() -> Self {
-        match Self::try_load() {
-            Ok(settings) => {
+    /// Load settings from a store. Returns None when nothing has been saved yet (first run);
+    /// settings that exist but can't be read fall back to defaults
+    pub fn load_from(store: &dyn SettingsStore) -> Option<Self> {
+        match store.load() {
+            Ok(Some(settings)) => {
                 println!("Settings loaded successfully");
-                settings
+                Some(settings)
+            }
+            Ok(None) => {
+                println!("No saved settings, using defaults");
+                None
             }
             Err(e) => {
                 println!("Failed to load settings, using defaults: {}", e);
-                Self::default()
+                Some(Self::default())
             }
         }
     }
-
-    /// Attempt to load settings from disk
-    fn try_load() -> Result<Self, Box<dyn std::error::Error>> {
-        let settings_path = Self::settings_file_path()?;
-
-        if !settings_path.exists() {
-            return Err("Settings file does not exist".into());
-        }
-
-        let contents = std::fs::read_to_string(settings_path)?;
-        let mut settings: GameSettings = serde_json::from_str(&contents)?;
-
-        // Reset UI state (selected_option should always start at 0)
-        settings.selected_option = 0;
-
-        Ok(settings)
-    }
-
-    /// Save settings to disk
-    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let settings_path = Self::settings_file_path()?;
-
-        let contents = serde_json::to_string_pretty(self)?;
-        std::fs::write(settings_path, contents)?;
-
-        println!("Settings saved successfully");
-        Ok(())
-    }
 }
 
 // Import for tests
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storage::FileStorage;
+    use crate::store::JsonSettingsStore;
 
     #[test]
     fn test_game_settings_default() {
@@ -130,9 +481,97 @@ mod tests {
         assert_eq!(settings.sound_effects_volume, 0.8);
         assert_eq!(settings.sound_effects_muted, false);
         assert_eq!(settings.vsync_enabled, true);
+        assert_eq!(settings.control_scheme, ControlScheme::Auto);
         assert_eq!(settings.selected_option, 0);
     }
 
+    #[test]
+    fn test_settings_without_new_fields_still_load() {
+        // Settings saved before the control scheme existed, and with the language setting
+        // that has since been dropped
+        let json = r#"{"music_volume":0.5,"music_muted":false,"sound_effects_volume":0.5,
+            "sound_effects_muted":false,"vsync_enabled":true,"difficulty":"Hard",
+            "language":"English"}"#;
+        let settings: GameSettings = serde_json::from_str(json).unwrap();
+
+        assert_eq!(settings.difficulty, game::Difficulty::Hard);
+        assert_eq!(settings.control_scheme, ControlScheme::Auto);
+        assert_eq!(settings.paused_board, PausedBoard::Hidden);
+        assert_eq!(settings.streamer_mode, StreamerMode::Off);
+        assert_eq!(settings.font_scale, 1.0);
+    }
+
+    #[test]
+    fn test_control_scheme_cycle_and_prompts() {
+        assert_eq!(ControlScheme::Auto.cycle(1), ControlScheme::Keyboard);
+        assert_eq!(ControlScheme::Auto.cycle(-1), ControlScheme::Controller);
+
+        assert!(ControlScheme::Auto.shows_controller_prompts(true));
+        assert!(!ControlScheme::Auto.shows_controller_prompts(false));
+        assert!(!ControlScheme::Keyboard.shows_controller_prompts(true));
+        assert!(ControlScheme::Controller.shows_controller_prompts(false));
+    }
+
+    #[test]
+    fn test_toggle_mod() {
+        let mut settings = GameSettings::default();
+        assert!(settings.is_mod_enabled("audio/retro"));
+
+        settings.toggle_mod("audio/retro");
+        assert!(!settings.is_mod_enabled("audio/retro"));
+        assert!(settings.is_mod_enabled("themes/neon"));
+
+        settings.toggle_mod("audio/retro");
+        assert!(settings.is_mod_enabled("audio/retro"));
+        assert!(settings.disabled_mods.is_empty());
+    }
+
+    #[test]
+    fn test_font_scale_steps_within_range() {
+        let mut settings = GameSettings::default();
+        settings.adjust_font_scale(2);
+        assert!((settings.font_scale - 1.2).abs() < 1e-6);
+
+        settings.adjust_handicap_rows(-1);
+        assert_eq!(settings.handicap_rows, 0);
+        settings.adjust_handicap_rows(10);
+        assert_eq!(settings.handicap_rows, MAX_HANDICAP_ROWS);
+
+        settings.adjust_font_scale(10);
+        assert_eq!(settings.font_scale, MAX_FONT_SCALE);
+
+        settings.adjust_font_scale(-20);
+        assert_eq!(settings.font_scale, MIN_FONT_SCALE);
+
+        // The display profile's boost stacks on top
+        settings.font_scale = 1.2;
+        settings.display_profile = DisplayProfile::Handheld;
+        assert!((settings.text_scale() - 1.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_display_profile_detection_and_preset() {
+        let on_deck = |name: &str| (name == "SteamDeck").then(|| "1".to_string());
+        assert_eq!(
+            DisplayProfile::detect_from(on_deck),
+            DisplayProfile::Handheld
+        );
+        assert_eq!(
+            DisplayProfile::detect_from(|_| None),
+            DisplayProfile::Desktop
+        );
+
+        let mut settings = GameSettings::default();
+        settings.apply_display_profile(DisplayProfile::Handheld);
+        assert!(settings.display_profile.fullscreen());
+        assert_eq!(settings.control_scheme, ControlScheme::Controller);
+        assert!(settings.touch_controls);
+
+        settings.apply_display_profile(DisplayProfile::Desktop);
+        assert_eq!(settings.control_scheme, ControlScheme::Auto);
+        assert!(!settings.touch_controls);
+    }
+
     #[test]
     fn test_game_settings_serialization() {
         let settings = GameSettings {
@@ -142,6 +581,33 @@ mod tests {
             sound_effects_muted: false,
             vsync_enabled: false,
             difficulty: game::Difficulty::Hard,
+            control_scheme: ControlScheme::Keyboard,
+            min_fall_speed_ms: Some(120),
+            stick_dead_zone: Some(0.2),
+            paused_board: PausedBoard::Blurred,
+            audio_device: Some("USB Headset".to_string()),
+            touch_controls: true,
+            display_profile: DisplayProfile::Handheld,
+            streamer_mode: StreamerMode::OverlayFile,
+            mirrored_layout: true,
+            reduced_motion: true,
+            high_contrast: true,
+            font_scale: 1.2,
+            card_values: true,
+            draw_odds: true,
+            deck_template: DeckTemplate::LowBall,
+            handicap_rows: 3,
+            hard_drop_steer: true,
+            slide_delay: false,
+            bust_rule: true,
+            relax_mode: true,
+            adaptive_speed: true,
+            mods_enabled: true,
+            check_for_updates: true,
+            disabled_mods: vec!["themes/neon".to_string()],
+            felt_color: FeltColor::Purple,
+            card_back: CardBack::Lattice,
+            seasonal_events: false,
             selected_option: 2, // This should be skipped in serialization
         };
 
@@ -155,6 +621,33 @@ mod tests {
         assert_eq!(deserialized.sound_effects_muted, false);
         assert_eq!(deserialized.vsync_enabled, false);
         assert_eq!(deserialized.difficulty, game::Difficulty::Hard);
+        assert_eq!(deserialized.control_scheme, ControlScheme::Keyboard);
+        assert_eq!(deserialized.audio_device.as_deref(), Some("USB Headset"));
+        assert!(deserialized.touch_controls);
+        assert_eq!(deserialized.display_profile, DisplayProfile::Handheld);
+        assert_eq!(deserialized.streamer_mode, StreamerMode::OverlayFile);
+        assert!(deserialized.mirrored_layout);
+        assert!(deserialized.reduced_motion);
+        assert!(deserialized.high_contrast);
+        assert_eq!(deserialized.font_scale, 1.2);
+        assert!(deserialized.card_values);
+        assert!(deserialized.draw_odds);
+        assert_eq!(deserialized.deck_template, DeckTemplate::LowBall);
+        assert_eq!(deserialized.handicap_rows, 3);
+        assert!(deserialized.hard_drop_steer);
+        assert!(!deserialized.slide_delay);
+        assert!(deserialized.bust_rule);
+        assert!(deserialized.relax_mode);
+        assert!(deserialized.adaptive_speed);
+        assert!(deserialized.mods_enabled);
+        assert!(deserialized.check_for_updates);
+        assert_eq!(deserialized.disabled_mods, vec!["themes/neon".to_string()]);
+        assert_eq!(deserialized.felt_color, FeltColor::Purple);
+        assert_eq!(deserialized.card_back, CardBack::Lattice);
+        assert!(!deserialized.seasonal_events);
+        assert_eq!(deserialized.min_fall_speed_ms, Some(120));
+        assert_eq!(deserialized.stick_dead_zone, Some(0.2));
+        assert_eq!(deserialized.paused_board, PausedBoard::Blurred);
 
         // Check that selected_option is reset to default (0) since it's marked #[serde(skip)]
         assert_eq!(deserialized.selected_option, 0);
@@ -162,15 +655,13 @@ mod tests {
 
     #[test]
     fn test_game_settings_load_nonexistent_file() {
-        // Use a unique filename for this test
-        let settings_path =
-            GameSettings::settings_file_path_with_name("test_nonexistent.json").unwrap();
-        let _ = std::fs::remove_file(&settings_path);
+        // Use an empty directory so no settings file exists
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let storage = FileStorage::new(temp_dir.path()).unwrap();
+        let store = JsonSettingsStore::new(Box::new(storage));
 
-        // Loading settings when file doesn't exist should return defaults
-        let settings = GameSettings::load();
-        assert_eq!(settings.music_volume, 0.7);
-        assert_eq!(settings.selected_option, 0);
+        // Nothing saved yet means this is the first run
+        assert_eq!(GameSettings::load_from(&store), None);
     }
 
     #[test]
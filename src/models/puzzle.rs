@@ -0,0 +1,179 @@
+// Handcrafted puzzle levels: a preset board and a fixed run of cards, won by clearing the
+// board before the cards run out. Levels are JSON files in assets/puzzles. Boards are rows
+// of card codes ("KS", "10H") and dots for empty cells, top to bottom, with the last row
+// resting on the bottom of the board.
+
+use super::cards::{Card, Suit, Value};
+use super::game::Difficulty;
+use serde::{Deserialize, Serialize};
+
+// Best rating a level can earn
+pub const MAX_STARS: u8 = 3;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PuzzleLevel {
+    pub id: String, // Stars are stored under this, so it must never change once shipped
+    pub name: String,
+    pub difficulty: Difficulty,
+    pub par: usize, // Cards a clear needs for three stars
+    pub board: Vec<String>,
+    pub cards: Vec<String>, // Dealt in order, the first one straight into play
+    #[serde(default)]
+    pub solution: Vec<i32>, // A column for each card that clears the board, checked by the tests
+}
+
+impl PuzzleLevel {
+    /// The level from its JSON file, with every card code checked
+    pub fn parse(json: &str) -> Result<PuzzleLevel, String> {
+        let level: PuzzleLevel = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        level.card_sequence()?;
+        level.board_rows()?;
+        Ok(level)
+    }
+
+    /// The preset board laid out on a board of this size, rows top to bottom like Board::grid
+    pub fn grid(&self, width: i32, height: i32) -> Result<Vec<Vec<Option<Card>>>, String> {
+        let rows = self.board_rows()?;
+        if rows.len() > height as usize || rows.iter().any(|row| row.len() > width as usize) {
+            return Err(format!(
+                "{} doesn't fit a {}x{} board",
+                self.name, width, height
+            ));
+        }
+
+        let mut grid = vec![vec![None; width as usize]; height as usize];
+        let top = height as usize - rows.len();
+        for (y, row) in rows.into_iter().enumerate() {
+            for (x, card) in row.into_iter().enumerate() {
+                grid[top + y][x] = card;
+            }
+        }
+        Ok(grid)
+    }
+
+    /// The cards the level deals, first card first
+    pub fn card_sequence(&self) -> Result<Vec<Card>, String> {
+        self.cards.iter().map(|code| parse_card(code)).collect()
+    }
+
+    /// Rating for a clear that used this many cards: three at par, two within two over, and
+    /// one for any clear at all
+    pub fn stars(&self, cards_used: usize) -> u8 {
+        if cards_used <= self.par {
+            MAX_STARS
+        } else if cards_used <= self.par + 2 {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn board_rows(&self) -> Result<Vec<Vec<Option<Card>>>, String> {
+        self.board
+            .iter()
+            .map(|row| {
+                row.split_whitespace()
+                    .map(|code| match code {
+                        "." => Ok(None),
+                        code => parse_card(code).map(Some),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// A card from its code: the value ("A", "2"-"10", "J", "Q", "K") then the suit's initial
+pub fn parse_card(code: &str) -> Result<Card, String> {
+    let invalid = || format!("Invalid card code \"{}\"", code);
+    let split = code.len().checked_sub(1).ok_or_else(invalid)?;
+    let (value, suit) = code.split_at_checked(split).ok_or_else(invalid)?;
+
+    let suit = match suit.to_ascii_uppercase().as_str() {
+        "S" => Suit::Spades,
+        "H" => Suit::Hearts,
+        "D" => Suit::Diamonds,
+        "C" => Suit::Clubs,
+        _ => return Err(invalid()),
+    };
+    let value = Value::all()
+        .into_iter()
+        .find(|candidate| candidate.symbol().eq_ignore_ascii_case(value))
+        .ok_or_else(invalid)?;
+    Ok(Card::new(suit, value))
+}
+
+/// The code parse_card reads back as this card, e.g. "10H"
+pub fn card_code(card: Card) -> String {
+    let suit = match card.suit {
+        Suit::Spades => "S",
+        Suit::Hearts => "H",
+        Suit::Diamonds => "D",
+        Suit::Clubs => "C",
+    };
+    format!("{}{}", card.value.symbol(), suit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(board: &[&str], cards: &[&str]) -> PuzzleLevel {
+        PuzzleLevel {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            difficulty: Difficulty::Easy,
+            par: 2,
+            board: board.iter().map(|row| row.to_string()).collect(),
+            cards: cards.iter().map(|code| code.to_string()).collect(),
+            solution: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_card() {
+        assert_eq!(parse_card("10H"), Ok(Card::new(Suit::Hearts, Value::Ten)));
+        assert_eq!(parse_card("as"), Ok(Card::new(Suit::Spades, Value::Ace)));
+        assert_eq!(parse_card("QC"), Ok(Card::new(Suit::Clubs, Value::Queen)));
+        assert!(parse_card("1H").is_err());
+        assert!(parse_card("KX").is_err());
+        assert!(parse_card("").is_err());
+
+        for suit in Suit::all() {
+            for value in Value::all() {
+                let card = Card::new(suit, value);
+                assert_eq!(parse_card(&card_code(card)), Ok(card));
+            }
+        }
+    }
+
+    #[test]
+    fn test_grid_rests_on_the_bottom_row() {
+        let level = level(&["KS . .", ". 2D 9H"], &["AS"]);
+        let grid = level.grid(3, 4).unwrap();
+
+        assert!(grid[0].iter().chain(&grid[1]).all(Option::is_none));
+        assert_eq!(grid[2][0], Some(Card::new(Suit::Spades, Value::King)));
+        assert_eq!(grid[3][1], Some(Card::new(Suit::Diamonds, Value::Two)));
+        assert_eq!(grid[3][2], Some(Card::new(Suit::Hearts, Value::Nine)));
+
+        assert!(level.grid(2, 4).is_err());
+        assert!(level.grid(3, 1).is_err());
+    }
+
+    #[test]
+    fn test_stars() {
+        let level = level(&[], &["AS", "KS", "QS", "JS", "10S"]);
+        assert_eq!(level.stars(1), 3);
+        assert_eq!(level.stars(2), 3);
+        assert_eq!(level.stars(4), 2);
+        assert_eq!(level.stars(5), 1);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_codes() {
+        let json = r#"{"id":"x","name":"X","difficulty":"Easy","par":1,
+            "board":[". ZZ ."],"cards":["AS"]}"#;
+        assert!(PuzzleLevel::parse(json).is_err());
+    }
+}
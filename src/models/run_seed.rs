@@ -0,0 +1,77 @@
+// The seed a run's deck is shuffled from. It's written as a short code of letters so players
+// can read it out to a friend or type it on the on-screen keyboard, and the same code deals
+// the same cards.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunSeed(u64);
+
+impl RunSeed {
+    pub const LENGTH: usize = 6; // Letters in a seed code
+    const CODES: u64 = 26u64.pow(Self::LENGTH as u32);
+
+    pub fn random() -> Self {
+        RunSeed(rand::random_range(0..Self::CODES))
+    }
+
+    /// The seed a code stands for, in either case. None unless it's exactly LENGTH letters.
+    pub fn parse(code: &str) -> Option<Self> {
+        let code = code.trim();
+        if code.len() != Self::LENGTH || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let value = code.bytes().fold(0, |value, letter| {
+            value * 26 + u64::from(letter.to_ascii_uppercase() - b'A')
+        });
+        Some(RunSeed(value))
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+}
+
+// The code, in capitals
+impl fmt::Display for RunSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut letters = [b'A'; Self::LENGTH];
+        let mut value = self.0;
+        for letter in letters.iter_mut().rev() {
+            *letter = b'A' + (value % 26) as u8;
+            value /= 26;
+        }
+        f.write_str(std::str::from_utf8(&letters).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() {
+        for _ in 0..50 {
+            let seed = RunSeed::random();
+            let code = seed.to_string();
+            assert_eq!(code.len(), RunSeed::LENGTH);
+            assert_eq!(RunSeed::parse(&code), Some(seed));
+        }
+
+        assert_eq!(RunSeed::parse("AAAAAA").map(RunSeed::value), Some(0));
+        assert_eq!(RunSeed::parse("aaaaab").map(RunSeed::value), Some(1));
+        assert_eq!(
+            RunSeed::parse(" ZZZZZZ ").map(|seed| seed.to_string()),
+            Some("ZZZZZZ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_bad_codes_are_rejected() {
+        assert_eq!(RunSeed::parse(""), None);
+        assert_eq!(RunSeed::parse("ABCDE"), None);
+        assert_eq!(RunSeed::parse("ABCDEFG"), None);
+        assert_eq!(RunSeed::parse("ABC1EF"), None);
+        assert_eq!(RunSeed::parse("ABCDÉ"), None);
+    }
+}
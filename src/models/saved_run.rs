@@ -0,0 +1,71 @@
+// A run put aside from the pause menu with "Save & Quit", to be continued from the start screen.
+// Only the settled board is kept: cards mid-clear count as cleared and mid-drop cards as landed,
+// and the falling card starts again from the top of its column.
+
+use super::cards::{Card, DeckTemplate};
+use super::game::Difficulty;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedRun {
+    pub difficulty: Difficulty,
+    pub score: i32,
+    pub speed_level: u32,
+    pub grid: Vec<Vec<Option<Card>>>, // Rows top to bottom, like Board::grid
+    pub deck: Vec<Card>,              // Cards left to draw, the next one last
+    pub current_card: Option<Card>,
+    pub current_column: i32,
+    pub next_card: Option<Card>,
+    pub saved_at: String,
+    #[serde(default)]
+    pub modded: bool, // Mod scripts were changing the rules when the run was saved
+    #[serde(default)]
+    pub deck_template: DeckTemplate, // What the deck was built from, to rebuild its discards
+    #[serde(default)]
+    pub relaxed: bool, // Started in relax mode, which carries on even if the setting changes
+    #[serde(default)]
+    pub modified: bool, // The save file was edited by hand, so its score is flagged too
+}
+
+impl SavedRun {
+    /// Whether the saved board fits a board of this size
+    pub fn fits(&self, width: i32, height: i32) -> bool {
+        self.grid.len() == height as usize
+            && self.grid.iter().all(|row| row.len() == width as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Suit, Value};
+
+    #[test]
+    fn test_saved_run_round_trip() {
+        let ace = Card::new(Suit::Spades, Value::Ace);
+        let mut grid = vec![vec![None; 3]; 2];
+        grid[1][0] = Some(ace);
+
+        let run = SavedRun {
+            difficulty: Difficulty::Hard,
+            score: 420,
+            speed_level: 3,
+            grid,
+            deck: vec![Card::new(Suit::Hearts, Value::King)],
+            current_card: Some(ace),
+            current_column: 1,
+            next_card: None,
+            saved_at: "2024-01-15 14:30:00".to_string(),
+            modded: true,
+            deck_template: DeckTemplate::Standard,
+            relaxed: false,
+            modified: false,
+        };
+
+        let json = serde_json::to_string(&run).unwrap();
+        let loaded: SavedRun = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, run);
+        assert!(loaded.fits(3, 2));
+        assert!(!loaded.fits(10, 15));
+    }
+}
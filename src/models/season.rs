@@ -0,0 +1,39 @@
+// Times of year that get their own decorations: snow falls in December and pumpkins turn up
+// in October. The rest of the year is undecorated.
+
+use chrono::{Datelike, Local};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    Winter,
+    Halloween,
+}
+
+impl Season {
+    /// The season for a month, 1 (January) to 12
+    pub fn for_month(month: u32) -> Option<Season> {
+        match month {
+            10 => Some(Season::Halloween),
+            12 => Some(Season::Winter),
+            _ => None,
+        }
+    }
+
+    /// The season on the local calendar today
+    pub fn current() -> Option<Season> {
+        Self::for_month(Local::now().month())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_month() {
+        assert_eq!(Season::for_month(10), Some(Season::Halloween));
+        assert_eq!(Season::for_month(12), Some(Season::Winter));
+        assert_eq!(Season::for_month(1), None);
+        assert_eq!(Season::for_month(11), None);
+    }
+}
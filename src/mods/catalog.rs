@@ -0,0 +1,216 @@
+// Everything the Mods & Themes screen lists, found in the app data directory:
+//
+//   mods/<file>.rhai          scripts; `// name:`, `// author:` and `// version:` comment
+//                             lines at the top of the file describe them
+//   themes/<folder>/          theme packs: theme.toml plus an atlas.png of card art
+//   audio/<folder>/           audio packs: pack.toml plus .ogg files named like the ones
+//                             in assets/audio that they replace
+//
+// theme.toml and pack.toml hold the same `name`, `author` and `version` keys.
+
+use super::MODS_DIR;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+pub const THEMES_DIR: &str = "themes";
+pub const AUDIO_PACKS_DIR: &str = "audio";
+pub const THEME_ATLAS: &str = "atlas.png";
+const THEME_MANIFEST: &str = "theme.toml";
+const AUDIO_PACK_MANIFEST: &str = "pack.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModKind {
+    Script,
+    Theme,
+    AudioPack,
+}
+
+impl ModKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ModKind::Script => "Script",
+            ModKind::Theme => "Theme",
+            ModKind::AudioPack => "Audio Pack",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModItem {
+    pub kind: ModKind,
+    pub id: String, // Path under the data directory, e.g. "themes/neon"; saved in settings
+    pub path: PathBuf, // The script file or the pack folder
+    pub name: String,
+    pub author: String,
+    pub version: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Manifest {
+    name: Option<String>,
+    author: Option<String>,
+    version: Option<String>,
+}
+
+/// Scripts, then theme packs, then audio packs, each sorted by folder or file name
+pub fn discover(data_dir: &Path) -> Vec<ModItem> {
+    let mut items = Vec::new();
+
+    for path in sorted_entries(&data_dir.join(MODS_DIR)) {
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "rhai") {
+            let header = std::fs::read_to_string(&path).unwrap_or_default();
+            items.push(item(
+                ModKind::Script,
+                MODS_DIR,
+                &path,
+                script_manifest(&header),
+            ));
+        }
+    }
+
+    for (kind, dir, manifest_name) in [
+        (ModKind::Theme, THEMES_DIR, THEME_MANIFEST),
+        (ModKind::AudioPack, AUDIO_PACKS_DIR, AUDIO_PACK_MANIFEST),
+    ] {
+        for path in sorted_entries(&data_dir.join(dir)) {
+            let manifest_path = path.join(manifest_name);
+            if !manifest_path.is_file() {
+                continue;
+            }
+            let manifest = std::fs::read_to_string(&manifest_path)
+                .map_err(|e| e.to_string())
+                .and_then(|contents| toml::from_str(&contents).map_err(|e| e.to_string()))
+                .unwrap_or_else(|e| {
+                    eprintln!("Invalid {}: {}", manifest_path.display(), e);
+                    Manifest::default()
+                });
+            items.push(item(kind, dir, &path, manifest));
+        }
+    }
+
+    items
+}
+
+/// What's installed in the app data directory (nothing in the browser)
+#[cfg(not(target_os = "emscripten"))]
+pub fn discover_platform() -> Vec<ModItem> {
+    match crate::storage::FileStorage::app_data() {
+        Ok(storage) => discover(&storage.path_for("")),
+        Err(e) => {
+            eprintln!("Failed to look for mods and themes: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(target_os = "emscripten")]
+pub fn discover_platform() -> Vec<ModItem> {
+    Vec::new()
+}
+
+fn item(kind: ModKind, dir: &str, path: &Path, manifest: Manifest) -> ModItem {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    ModItem {
+        kind,
+        id: format!("{}/{}", dir, file_name),
+        path: path.to_path_buf(),
+        name: manifest.name.unwrap_or(stem),
+        author: manifest.author.unwrap_or_else(|| "Unknown".to_string()),
+        version: manifest.version.unwrap_or_default(),
+    }
+}
+
+// `// key: value` lines in the comment block at the top of a script
+fn script_manifest(source: &str) -> Manifest {
+    let mut manifest = Manifest::default();
+    for line in source.lines().map(str::trim) {
+        let Some(comment) = line.strip_prefix("//") else {
+            break;
+        };
+        let Some((key, value)) = comment.split_once(':') else {
+            continue;
+        };
+        let value = Some(value.trim().to_string());
+        match key.trim() {
+            "name" => manifest.name = value,
+            "author" => manifest.author = value,
+            "version" => manifest.version = value,
+            _ => {}
+        }
+    }
+    manifest
+}
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discover_reads_metadata() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let data_dir = temp_dir.path();
+
+        std::fs::create_dir_all(data_dir.join(MODS_DIR)).unwrap();
+        std::fs::write(
+            data_dir.join(MODS_DIR).join("double.rhai"),
+            "// name: Double Points\n// author: Sam\n// version: 1.2\nfn modify_score(p) { p * 2 }\n",
+        )
+        .unwrap();
+        std::fs::write(
+            data_dir.join(MODS_DIR).join("plain.rhai"),
+            "fn on_level_up(l) { }\n",
+        )
+        .unwrap();
+
+        let neon = data_dir.join(THEMES_DIR).join("neon");
+        std::fs::create_dir_all(&neon).unwrap();
+        std::fs::write(
+            neon.join(THEME_MANIFEST),
+            "name = \"Neon\"\nauthor = \"Alex\"\nversion = \"0.3\"\n",
+        )
+        .unwrap();
+        // A folder without a manifest isn't a pack
+        std::fs::create_dir_all(data_dir.join(AUDIO_PACKS_DIR).join("loose")).unwrap();
+
+        let items = discover(data_dir);
+        assert_eq!(items.len(), 3);
+
+        assert_eq!(items[0].id, "mods/double.rhai");
+        assert_eq!(items[0].name, "Double Points");
+        assert_eq!(items[0].author, "Sam");
+        assert_eq!(items[0].version, "1.2");
+
+        assert_eq!(items[1].name, "plain");
+        assert_eq!(items[1].author, "Unknown");
+
+        assert_eq!(items[2].kind, ModKind::Theme);
+        assert_eq!(items[2].id, "themes/neon");
+        assert_eq!(items[2].name, "Neon");
+    }
+
+    #[test]
+    fn test_discover_empty_data_dir() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        assert!(discover(temp_dir.path()).is_empty());
+    }
+}
@@ -0,0 +1,324 @@
+// Rule variants written as Rhai scripts. Every `.rhai` file in the mods folder of the app
+// data directory is loaded at startup when mods are enabled. A script defines whichever
+// hooks it needs; hooks a script leaves out are skipped:
+//
+//   fn on_card_placed(x, y, card) { }   card is #{ rank: "K", value: 10, suit: "Hearts" }
+//   fn on_combination(length, chain) { } return an integer to add it to the score
+//   fn on_level_up(level) { }            speed level, counting from 1 like the info panel
+//   fn modify_score(points) { }          return the points to award instead
+//
+// Scripts are sandboxed: they can't import modules, eval code or touch files, and each
+// hook call is cut off after a fixed amount of work.
+
+pub mod catalog;
+
+use crate::models::Card;
+#[cfg(not(target_os = "emscripten"))]
+use crate::storage::FileStorage;
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{AST, Dynamic, Engine, Map, Scope};
+use std::path::Path;
+
+pub const MODS_DIR: &str = "mods";
+const SCRIPT_EXTENSION: &str = "rhai";
+
+// Sandbox limits, per hook call
+const MAX_OPERATIONS: u64 = 100_000;
+const MAX_CALL_LEVELS: usize = 32;
+const MAX_EXPR_DEPTH: usize = 64;
+const MAX_STRING_SIZE: usize = 10_000;
+const MAX_COLLECTION_SIZE: usize = 1_000;
+
+struct ModScript {
+    name: String, // File name, for log messages
+    ast: AST,
+}
+
+pub struct ModScripts {
+    engine: Engine,
+    scripts: Vec<ModScript>,
+}
+
+impl ModScripts {
+    /// No scripts; every hook leaves the game unchanged
+    pub fn empty() -> Self {
+        ModScripts {
+            engine: sandboxed_engine(),
+            scripts: Vec::new(),
+        }
+    }
+
+    /// Load every script in `dir` that isn't in `disabled` (by catalog id), in file name
+    /// order. Scripts that fail to compile are reported and skipped; a missing folder just
+    /// means no mods.
+    pub fn load_dir(dir: &Path, disabled: &[String]) -> Self {
+        let mut mods = Self::empty();
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return mods,
+            Err(e) => {
+                eprintln!("Failed to read mods folder {}: {}", dir.display(), e);
+                return mods;
+            }
+        };
+
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == SCRIPT_EXTENSION))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            if disabled.contains(&format!("{}/{}", MODS_DIR, name)) {
+                continue;
+            }
+            let source = match std::fs::read_to_string(&path) {
+                Ok(source) => source,
+                Err(e) => {
+                    eprintln!("Failed to read mod {}: {}", name, e);
+                    continue;
+                }
+            };
+            if let Err(e) = mods.add_script(&name, &source) {
+                eprintln!("Failed to load mod {}: {}", name, e);
+            }
+        }
+
+        if !mods.is_empty() {
+            println!("Loaded {} mod script(s)", mods.scripts.len());
+        }
+        mods
+    }
+
+    /// The mods folder in the app data directory
+    #[cfg(not(target_os = "emscripten"))]
+    pub fn load_platform(disabled: &[String]) -> Self {
+        match FileStorage::app_data() {
+            Ok(storage) => Self::load_dir(&storage.path_for(MODS_DIR), disabled),
+            Err(e) => {
+                eprintln!("Failed to find the mods folder: {}", e);
+                Self::empty()
+            }
+        }
+    }
+
+    // The browser has no mods folder to read scripts from
+    #[cfg(target_os = "emscripten")]
+    pub fn load_platform(_disabled: &[String]) -> Self {
+        println!("Mods aren't available in the browser");
+        Self::empty()
+    }
+
+    /// Compile a script and add it after the ones already loaded
+    pub fn add_script(
+        &mut self,
+        name: &str,
+        source: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let ast = self.engine.compile(source)?;
+        self.scripts.push(ModScript {
+            name: name.to_string(),
+            ast,
+        });
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scripts.is_empty()
+    }
+
+    pub fn on_card_placed(&self, x: i32, y: i32, card: &Card) {
+        let mut card_map = Map::new();
+        card_map.insert("rank".into(), card.value.symbol().into());
+        card_map.insert("value".into(), (card.value.value() as i64).into());
+        card_map.insert("suit".into(), format!("{:?}", card.suit).into());
+
+        for script in &self.scripts {
+            self.call(
+                script,
+                "on_card_placed",
+                (x as i64, y as i64, card_map.clone()),
+            );
+        }
+    }
+
+    /// Extra points the scripts award for a cleared combination
+    pub fn on_combination(&self, length: usize, chain: i32) -> i32 {
+        self.scripts
+            .iter()
+            .filter_map(|script| {
+                self.call(script, "on_combination", (length as i64, chain as i64))
+                    .and_then(as_points)
+            })
+            .fold(0, i32::saturating_add)
+    }
+
+    pub fn on_level_up(&self, level: u32) {
+        for script in &self.scripts {
+            self.call(script, "on_level_up", (level as i64,));
+        }
+    }
+
+    /// Points to award after each script has had a chance to change them, in load order
+    pub fn modify_score(&self, points: i32) -> i32 {
+        self.scripts.iter().fold(points, |points, script| {
+            self.call(script, "modify_score", (points as i64,))
+                .and_then(as_points)
+                .unwrap_or(points)
+        })
+    }
+
+    // Calls a hook if the script defines it. Errors are reported and treated as the hook
+    // not returning anything, so a broken mod can't stop the game.
+    fn call(
+        &self,
+        script: &ModScript,
+        hook: &str,
+        args: impl rhai::FuncArgs + Clone,
+    ) -> Option<Dynamic> {
+        let mut arg_values = Vec::new();
+        args.clone().parse(&mut arg_values);
+        let defined = script
+            .ast
+            .iter_functions()
+            .any(|function| function.name == hook && function.params.len() == arg_values.len());
+        if !defined {
+            return None;
+        }
+
+        let mut scope = Scope::new();
+        match self
+            .engine
+            .call_fn::<Dynamic>(&mut scope, &script.ast, hook, args)
+        {
+            Ok(result) => Some(result),
+            Err(e) => {
+                eprintln!("Mod {} failed in {}: {}", script.name, hook, e);
+                None
+            }
+        }
+    }
+}
+
+// Hooks that don't return an integer leave the score alone
+fn as_points(result: Dynamic) -> Option<i32> {
+    result
+        .as_int()
+        .ok()
+        .map(|points| points.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+}
+
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_module_resolver(DummyModuleResolver::new());
+    engine.disable_symbol("eval");
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(MAX_CALL_LEVELS);
+    engine.set_max_expr_depths(MAX_EXPR_DEPTH, MAX_EXPR_DEPTH);
+    engine.set_max_string_size(MAX_STRING_SIZE);
+    engine.set_max_array_size(MAX_COLLECTION_SIZE);
+    engine.set_max_map_size(MAX_COLLECTION_SIZE);
+    engine.on_print(|text| println!("[mod] {}", text));
+    engine.on_debug(|text, source, position| {
+        println!(
+            "[mod debug] {} {:?} {}",
+            source.unwrap_or(""),
+            position,
+            text
+        )
+    });
+    engine
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Suit, Value};
+
+    #[test]
+    fn test_hooks_change_the_score() {
+        let mut mods = ModScripts::empty();
+        mods.add_script(
+            "double.rhai",
+            "fn modify_score(points) { points * 2 }
+             fn on_combination(length, chain) { if length >= 3 { 100 } }",
+        )
+        .unwrap();
+        mods.add_script("plus_one.rhai", "fn modify_score(points) { points + 1 }")
+            .unwrap();
+
+        assert_eq!(mods.modify_score(21), 43);
+        assert_eq!(mods.on_combination(3, 1), 100);
+        assert_eq!(mods.on_combination(2, 1), 0);
+    }
+
+    #[test]
+    fn test_missing_and_failing_hooks_are_skipped() {
+        let mut mods = ModScripts::empty();
+        mods.add_script("quiet.rhai", "fn on_level_up(level) { }")
+            .unwrap();
+        mods.add_script(
+            "broken.rhai",
+            "fn modify_score(points) { points / 0 }
+             fn on_card_placed(x, y, card) { if card.suit != \"Hearts\" { throw \"no\" } }",
+        )
+        .unwrap();
+
+        assert_eq!(mods.modify_score(21), 21);
+        mods.on_level_up(2);
+        mods.on_card_placed(0, 0, &Card::new(Suit::Spades, Value::Ace));
+        assert!(mods.add_script("bad.rhai", "fn (").is_err());
+    }
+
+    #[test]
+    fn test_sandbox_limits() {
+        let mut mods = ModScripts::empty();
+        mods.add_script(
+            "runaway.rhai",
+            "fn modify_score(points) { loop { points += 1; } }",
+        )
+        .unwrap();
+        assert_eq!(mods.modify_score(21), 21);
+
+        assert!(
+            mods.add_script("eval.rhai", "fn modify_score(points) { eval(\"1\") }")
+                .is_err()
+        );
+        mods.scripts.clear();
+        mods.add_script(
+            "import.rhai",
+            "fn modify_score(points) { import \"other\" as other; 1 }",
+        )
+        .unwrap();
+        assert_eq!(mods.modify_score(21), 21);
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        std::fs::write(
+            temp_dir.path().join("b.rhai"),
+            "fn modify_score(points) { points + 2 }",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("a.rhai"),
+            "fn modify_score(points) { points * 10 }",
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("notes.txt"), "not a script").unwrap();
+
+        let mods = ModScripts::load_dir(temp_dir.path(), &[]);
+        assert_eq!(mods.scripts.len(), 2);
+        assert_eq!(mods.modify_score(1), 12);
+
+        let mods = ModScripts::load_dir(temp_dir.path(), &["mods/a.rhai".to_string()]);
+        assert_eq!(mods.modify_score(1), 3);
+
+        assert!(ModScripts::load_dir(&temp_dir.path().join("missing"), &[]).is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+// Discord Rich Presence over Discord's local IPC socket. Needs a Discord application id in
+// DROPJACK_DISCORD_APP_ID; without one (or without Discord running) nothing is shown.
+
+use super::{Activity, Presence, format_score};
+use discord_rich_presence::{DiscordIpc, DiscordIpcClient, activity};
+use std::time::{Duration, Instant};
+
+const APP_ID_VAR: &str = "DROPJACK_DISCORD_APP_ID";
+const UPDATE_INTERVAL: Duration = Duration::from_secs(5); // Discord drops updates sent faster than this
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+pub struct DiscordPresence {
+    client: DiscordIpcClient,
+    connected: bool,
+    shown: Option<Presence>,
+    last_attempt: Option<Instant>, // Last connect or update, for rate limiting
+}
+
+impl DiscordPresence {
+    /// Presence for the application id in the environment, or None if it isn't set
+    pub fn from_env() -> Option<Self> {
+        let app_id = std::env::var(APP_ID_VAR).ok().filter(|id| !id.is_empty())?;
+        Some(DiscordPresence {
+            client: DiscordIpcClient::new(&app_id),
+            connected: false,
+            shown: None,
+            last_attempt: None,
+        })
+    }
+
+    /// Show the latest presence. Called every frame; only changes are sent, and no more
+    /// often than Discord allows.
+    pub fn update(&mut self, presence: &Presence) {
+        if self.shown.as_ref() == Some(presence) {
+            return;
+        }
+
+        let wait = if self.connected {
+            UPDATE_INTERVAL
+        } else {
+            RECONNECT_INTERVAL
+        };
+        if self.last_attempt.is_some_and(|last| last.elapsed() < wait) {
+            return;
+        }
+        self.last_attempt = Some(Instant::now());
+
+        if !self.connected {
+            // Discord not running is normal, so don't shout about it
+            if self.client.connect().is_err() {
+                return;
+            }
+            self.connected = true;
+        }
+
+        let details = match presence.activity {
+            Activity::Menu => presence.activity.label().to_string(),
+            _ => format!("{} — {}", presence.activity.label(), presence.difficulty),
+        };
+        let state = format!("{} pts", format_score(presence.score));
+        let mut payload = activity::Activity::new().details(details.as_str());
+        if presence.activity != Activity::Menu {
+            payload = payload.state(state.as_str());
+        }
+
+        match self.client.set_activity(payload) {
+            Ok(()) => self.shown = Some(presence.clone()),
+            Err(e) => {
+                eprintln!("Discord presence update failed: {}", e);
+                self.connected = false;
+            }
+        }
+    }
+}
+
+impl Drop for DiscordPresence {
+    fn drop(&mut self) {
+        if self.connected {
+            let _ = self.client.close();
+        }
+    }
+}
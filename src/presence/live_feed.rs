@@ -0,0 +1,357 @@
+// Serves the game as it's played on localhost, for stream overlays, bots and companion
+// viewers. `GET /state` answers with the current state as JSON; `GET /events` keeps the
+// connection open as a Server-Sent Events stream that gets the state again each time it
+// changes, which a web page reads with EventSource. Polled once per frame; it never blocks.
+
+use super::{Activity, Presence};
+use crate::game::Game;
+use crate::models::{Card, Difficulty};
+use serde::Serialize;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream};
+
+pub const FEED_PORT: u16 = 7422;
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+const MAX_BACKLOG_BYTES: usize = 1024 * 1024; // A viewer this far behind is dropped
+
+// What viewers see, as JSON
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FeedState {
+    pub activity: Activity,
+    pub difficulty: Difficulty,
+    pub score: i32,
+    pub speed: u32, // Counts from 1 like the info panel
+    pub next_card: Option<Card>,
+    pub current_card: Option<FeedCard>,
+    pub board: Vec<Vec<Option<Card>>>, // Rows top to bottom, like Board::grid
+}
+
+// The card in play and the cell it's in
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct FeedCard {
+    pub card: Card,
+    pub x: i32,
+    pub y: i32,
+}
+
+impl FeedState {
+    pub fn of(game: &Game) -> Self {
+        let presence = Presence::of(game);
+        FeedState {
+            activity: presence.activity,
+            difficulty: presence.difficulty,
+            score: presence.score,
+            speed: presence.speed_level + 1,
+            next_card: game.next_card,
+            current_card: game.current_card.as_ref().map(|playing| FeedCard {
+                card: playing.card,
+                x: playing.position.x,
+                y: playing.position.y,
+            }),
+            board: game.board.grid.clone(),
+        }
+    }
+}
+
+pub struct LiveFeed {
+    listener: Option<TcpListener>, // None when the port couldn't be opened
+    viewers: Vec<Viewer>,
+    sent: Option<String>, // Last state sent to subscribers, so an unchanged one isn't resent
+}
+
+impl LiveFeed {
+    /// The feed on FEED_PORT. If the port can't be opened that's reported once and the
+    /// feed serves nothing, rather than trying again every frame.
+    pub fn start() -> Self {
+        Self::listen(FEED_PORT).unwrap_or_else(|e| {
+            eprintln!("Failed to start the live feed on port {}: {}", FEED_PORT, e);
+            LiveFeed {
+                listener: None,
+                viewers: Vec::new(),
+                sent: None,
+            }
+        })
+    }
+
+    /// The feed on `port` of localhost only; port 0 picks a free one
+    pub fn listen(port: u16) -> io::Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        listener.set_nonblocking(true)?;
+        Ok(LiveFeed {
+            listener: Some(listener),
+            viewers: Vec::new(),
+            sent: None,
+        })
+    }
+
+    #[cfg(test)]
+    fn port(&self) -> Option<u16> {
+        let address = self.listener.as_ref()?.local_addr().ok()?;
+        Some(address.port())
+    }
+
+    /// Take in new viewers and send the game's state to whoever is waiting on it
+    pub fn update(&mut self, game: &Game) {
+        self.accept();
+        // Working out the state is only worth it with someone watching
+        if !self.viewers.is_empty() {
+            self.publish(&FeedState::of(game));
+        }
+    }
+
+    fn accept(&mut self) {
+        let Some(listener) = &self.listener else {
+            return;
+        };
+        while let Ok((stream, _)) = listener.accept() {
+            match stream.set_nonblocking(true) {
+                Ok(()) => self.viewers.push(Viewer::new(stream)),
+                Err(e) => eprintln!("Failed to set up a live feed viewer: {}", e),
+            }
+        }
+    }
+
+    fn publish(&mut self, state: &FeedState) {
+        let json = match serde_json::to_string(state) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("Failed to encode the live feed: {}", e);
+                return;
+            }
+        };
+        let changed = self.sent.as_ref() != Some(&json);
+        for viewer in &mut self.viewers {
+            viewer.serve(&json, changed);
+        }
+        self.viewers.retain(Viewer::is_open);
+        self.sent = Some(json);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Reading,    // Waiting on the whole request
+    Subscribed, // Gets every new state as an event
+    Closing,    // Answered; closed once the answer has gone out
+    Closed,
+}
+
+struct Viewer {
+    stream: TcpStream,
+    stage: Stage,
+    request: Vec<u8>,
+    outgoing: Vec<u8>, // Bytes the socket hasn't taken yet
+}
+
+impl Viewer {
+    fn new(stream: TcpStream) -> Self {
+        Viewer {
+            stream,
+            stage: Stage::Reading,
+            request: Vec::new(),
+            outgoing: Vec::new(),
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.stage != Stage::Closed
+    }
+
+    fn serve(&mut self, json: &str, changed: bool) {
+        let mut buffer = [0; 1024];
+        loop {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => {
+                    self.stage = Stage::Closed;
+                    return;
+                }
+                // Only the request matters; anything a subscriber sends after it is ignored
+                Ok(read) if self.stage == Stage::Reading => {
+                    self.request.extend_from_slice(&buffer[..read])
+                }
+                Ok(_) => {}
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => {
+                    self.stage = Stage::Closed;
+                    return;
+                }
+            }
+        }
+
+        match self.stage {
+            Stage::Reading => self.answer(json),
+            Stage::Subscribed if changed => self.queue_event(json),
+            _ => {}
+        }
+        self.flush();
+    }
+
+    fn answer(&mut self, json: &str) {
+        let Some(end) = self.request.windows(4).position(|w| w == b"\r\n\r\n") else {
+            if self.request.len() > MAX_REQUEST_BYTES {
+                self.stage = Stage::Closed;
+            }
+            return;
+        };
+        let head = String::from_utf8_lossy(&self.request[..end]).into_owned();
+        let path = head
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix("GET "))
+            .and_then(|rest| rest.split_whitespace().next())
+            .unwrap_or("");
+
+        match path {
+            "/state" => {
+                self.queue_response("200 OK", "application/json", json);
+                self.stage = Stage::Closing;
+            }
+            "/events" => {
+                self.outgoing.extend_from_slice(
+                    b"HTTP/1.1 200 OK\r\n\
+                      Content-Type: text/event-stream\r\n\
+                      Cache-Control: no-cache\r\n\
+                      Access-Control-Allow-Origin: *\r\n\r\n",
+                );
+                self.queue_event(json);
+                self.stage = Stage::Subscribed;
+            }
+            _ => {
+                self.queue_response("404 Not Found", "text/plain", "Try /state or /events\n");
+                self.stage = Stage::Closing;
+            }
+        }
+    }
+
+    fn queue_response(&mut self, status: &str, content_type: &str, body: &str) {
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\n\
+             Access-Control-Allow-Origin: *\r\nConnection: close\r\n\r\n{}",
+            status,
+            content_type,
+            body.len(),
+            body
+        );
+        self.outgoing.extend_from_slice(response.as_bytes());
+    }
+
+    fn queue_event(&mut self, json: &str) {
+        self.outgoing
+            .extend_from_slice(format!("data: {}\n\n", json).as_bytes());
+        if self.outgoing.len() > MAX_BACKLOG_BYTES {
+            self.stage = Stage::Closed;
+        }
+    }
+
+    fn flush(&mut self) {
+        while !self.outgoing.is_empty() {
+            match self.stream.write(&self.outgoing) {
+                Ok(0) => {
+                    self.stage = Stage::Closed;
+                    return;
+                }
+                Ok(written) => {
+                    self.outgoing.drain(..written);
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => return,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => {
+                    self.stage = Stage::Closed;
+                    return;
+                }
+            }
+        }
+        if self.stage == Stage::Closing {
+            self.stage = Stage::Closed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Suit, Value};
+    use std::time::{Duration, Instant};
+
+    fn state(score: i32) -> FeedState {
+        FeedState {
+            activity: Activity::Playing,
+            difficulty: Difficulty::Easy,
+            score,
+            speed: 1,
+            next_card: Some(Card::new(Suit::Hearts, Value::Ace)),
+            current_card: None,
+            board: vec![vec![None; 2]; 2],
+        }
+    }
+
+    fn request(feed: &LiveFeed, path: &str) -> TcpStream {
+        let mut stream = TcpStream::connect(("127.0.0.1", feed.port().unwrap())).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_millis(20)))
+            .unwrap();
+        stream
+    }
+
+    // Keep the feed running until the viewer has read `wanted` or the connection closes
+    fn read_until(feed: &mut LiveFeed, stream: &mut TcpStream, score: i32, wanted: &str) -> String {
+        let started = Instant::now();
+        let mut received = Vec::new();
+        let mut buffer = [0; 4096];
+        while !String::from_utf8_lossy(&received).contains(wanted) {
+            assert!(
+                started.elapsed() < Duration::from_secs(5),
+                "Never got {:?}",
+                wanted
+            );
+            feed.accept();
+            feed.publish(&state(score));
+            match stream.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => received.extend_from_slice(&buffer[..read]),
+                Err(_) => {}
+            }
+        }
+        String::from_utf8_lossy(&received).into_owned()
+    }
+
+    #[test]
+    fn test_state_is_served_as_json() {
+        let mut feed = LiveFeed::listen(0).unwrap();
+        let mut stream = request(&feed, "/state");
+        let response = read_until(&mut feed, &mut stream, 120, "\"score\":120");
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("application/json"));
+
+        let body = response.split("\r\n\r\n").nth(1).unwrap();
+        let json: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(json["speed"], 1);
+        assert_eq!(json["next_card"]["value"], "Ace");
+
+        let mut missing = request(&feed, "/nope");
+        assert!(read_until(&mut feed, &mut missing, 120, "404").contains("404 Not Found"));
+    }
+
+    #[test]
+    fn test_events_follow_the_game() {
+        let mut feed = LiveFeed::listen(0).unwrap();
+        let mut stream = request(&feed, "/events");
+        let first = read_until(&mut feed, &mut stream, 10, "\"score\":10");
+        assert!(first.contains("text/event-stream"));
+        assert!(first.contains("data: {"));
+
+        // A new score is pushed without asking again
+        read_until(&mut feed, &mut stream, 25, "\"score\":25");
+
+        // A viewer that leaves is let go
+        drop(stream);
+        let started = Instant::now();
+        while !feed.viewers.is_empty() {
+            assert!(started.elapsed() < Duration::from_secs(5));
+            feed.publish(&state(30));
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+}
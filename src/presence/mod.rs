@@ -0,0 +1,138 @@
+// What the player is doing right now, for the window title, stream overlays and (with the
+// `discord` feature) the player's Discord profile. Kept free of raylib so it can be worked
+// out from the game alone.
+
+#[cfg(feature = "discord")]
+mod discord;
+
+#[cfg(not(target_os = "emscripten"))]
+mod live_feed;
+mod overlay_file;
+
+#[cfg(feature = "discord")]
+pub use discord::DiscordPresence;
+#[cfg(not(target_os = "emscripten"))]
+pub use live_feed::LiveFeed;
+pub use overlay_file::OverlayFile;
+
+use crate::game::Game;
+use crate::models::Difficulty;
+
+const APP_NAME: &str = "DropJack";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum Activity {
+    Menu, // Start screen, setup and settings outside a run
+    Playing,
+    Paused, // Any screen opened from a run in progress
+    GameOver,
+}
+
+impl Activity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Activity::Menu => "In the menus",
+            Activity::Playing => "Playing",
+            Activity::Paused => "Paused",
+            Activity::GameOver => "Game Over",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Presence {
+    pub activity: Activity,
+    pub difficulty: Difficulty,
+    pub score: i32,
+    pub speed_level: u32,
+}
+
+impl Presence {
+    pub fn of(game: &Game) -> Self {
+        let activity = if game.is_game_over() {
+            Activity::GameOver
+        } else if game.is_playing() {
+            Activity::Playing
+        } else if game.game_session_active {
+            Activity::Paused
+        } else {
+            Activity::Menu
+        };
+
+        Presence {
+            activity,
+            difficulty: game.difficulty,
+            score: game.score,
+            speed_level: game.speed_level,
+        }
+    }
+
+    /// e.g. "DropJack — Hard — 1,240 pts"; just the game's name outside a run
+    pub fn window_title(&self) -> String {
+        let score = format!("{} pts", format_score(self.score));
+        match self.activity {
+            Activity::Menu => APP_NAME.to_string(),
+            Activity::Playing => format!("{} — {} — {}", APP_NAME, self.difficulty, score),
+            Activity::Paused => {
+                format!("{} — {} — {} (Paused)", APP_NAME, self.difficulty, score)
+            }
+            Activity::GameOver => format!("{} — Game Over — {}", APP_NAME, score),
+        }
+    }
+}
+
+/// Score with thousands separators, e.g. 1240 -> "1,240"
+pub fn format_score(score: i32) -> String {
+    let digits = score.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    if score < 0 {
+        grouped.insert(0, '-');
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_score() {
+        assert_eq!(format_score(0), "0");
+        assert_eq!(format_score(999), "999");
+        assert_eq!(format_score(1240), "1,240");
+        assert_eq!(format_score(1234567), "1,234,567");
+        assert_eq!(format_score(-4200), "-4,200");
+    }
+
+    #[test]
+    fn test_window_title() {
+        let presence = Presence {
+            activity: Activity::Playing,
+            difficulty: Difficulty::Hard,
+            score: 1240,
+            speed_level: 2,
+        };
+        assert_eq!(presence.window_title(), "DropJack — Hard — 1,240 pts");
+
+        let paused = Presence {
+            activity: Activity::Paused,
+            ..presence.clone()
+        };
+        assert_eq!(
+            paused.window_title(),
+            "DropJack — Hard — 1,240 pts (Paused)"
+        );
+
+        let menu = Presence {
+            activity: Activity::Menu,
+            ..presence
+        };
+        assert_eq!(menu.window_title(), "DropJack");
+    }
+}
@@ -0,0 +1,81 @@
+// Keeps the current score and speed in a plain text file that streaming software (e.g. an
+// OBS "Text (GDI+/FreeType2)" source reading from a file) can show on top of the stream.
+
+use super::{Presence, format_score};
+use crate::storage::Storage;
+
+const OVERLAY_KEY: &str = "stream_overlay.txt";
+
+pub struct OverlayFile {
+    storage: Box<dyn Storage>,
+    written: Option<String>, // Last contents written, so the file only changes when the run does
+}
+
+impl OverlayFile {
+    pub fn new(storage: Box<dyn Storage>) -> Self {
+        OverlayFile {
+            storage,
+            written: None,
+        }
+    }
+
+    /// Rewrite the file if the score or speed has changed since the last write
+    pub fn update(&mut self, presence: &Presence) {
+        let text = overlay_text(presence);
+        if self.written.as_ref() == Some(&text) {
+            return;
+        }
+
+        match self.storage.write(OVERLAY_KEY, &text) {
+            Ok(()) => self.written = Some(text),
+            Err(e) => {
+                eprintln!("Failed to write stream overlay file: {}", e);
+                // Don't retry every frame; the next score change will try again
+                self.written = Some(text);
+            }
+        }
+    }
+}
+
+/// e.g. "Score: 1,240\nSpeed: 3\n"; speed counts from 1 like the info panel
+pub fn overlay_text(presence: &Presence) -> String {
+    format!(
+        "Score: {}\nSpeed: {}\n",
+        format_score(presence.score),
+        presence.speed_level + 1
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Difficulty;
+    use crate::presence::Activity;
+    use crate::storage::FileStorage;
+
+    #[test]
+    fn test_overlay_file_tracks_score() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut overlay = OverlayFile::new(Box::new(FileStorage::new(temp_dir.path()).unwrap()));
+        let path = temp_dir.path().join(OVERLAY_KEY);
+
+        let mut presence = Presence {
+            activity: Activity::Playing,
+            difficulty: Difficulty::Easy,
+            score: 1240,
+            speed_level: 2,
+        };
+        overlay.update(&presence);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "Score: 1,240\nSpeed: 3\n"
+        );
+
+        presence.score = 1500;
+        overlay.update(&presence);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "Score: 1,500\nSpeed: 3\n"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+// Persistent key/value storage for settings and high scores.
+// Native builds write one file per key into the app data directory; browser builds
+// (wasm32-unknown-emscripten) have no persistent filesystem and use localStorage instead.
+
+use std::error::Error;
+use std::io::Write;
+use std::path::PathBuf;
+
+pub trait Storage: Send {
+    /// Read the value stored under `key`, or None if nothing has been saved yet
+    fn read(&self, key: &str) -> Result<Option<String>, Box<dyn Error>>;
+
+    /// Store `contents` under `key`, replacing any previous value
+    fn write(&self, key: &str, contents: &str) -> Result<(), Box<dyn Error>>;
+
+    /// Delete whatever is stored under `key`; removing a missing key is not an error
+    fn remove(&self, key: &str) -> Result<(), Box<dyn Error>>;
+}
+
+/// Storage backed by plain files in a directory, one file per key
+pub struct FileStorage {
+    dir: PathBuf,
+}
+
+impl FileStorage {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Result<Self, Box<dyn Error>> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(FileStorage { dir })
+    }
+
+    /// Storage rooted at the application data directory
+    #[cfg(test)]
+    pub fn app_data() -> Result<Self, Box<dyn Error>> {
+        Self::new("/tmp/dropjack_test_settings")
+    }
+
+    #[cfg(not(test))]
+    pub fn app_data() -> Result<Self, Box<dyn Error>> {
+        let app_data_dir = dirs::data_dir()
+            .ok_or("Could not determine data directory")?
+            .join("DropJack");
+        Self::new(app_data_dir)
+    }
+
+    pub fn path_for(&self, key: &str) -> PathBuf {
+        self.dir.join(key)
+    }
+}
+
+impl Storage for FileStorage {
+    fn read(&self, key: &str) -> Result<Option<String>, Box<dyn Error>> {
+        let path = self.path_for(key);
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(std::fs::read_to_string(path)?))
+    }
+
+    fn write(&self, key: &str, contents: &str) -> Result<(), Box<dyn Error>> {
+        // Write to a temp file and rename it into place, so a crash mid-write leaves
+        // either the old file or the new one, never a truncated mix
+        let temp_path = self.dir.join(format!("{}.tmp", key));
+        {
+            let mut file = std::fs::File::create(&temp_path)?;
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()?;
+        }
+        std::fs::rename(&temp_path, self.path_for(key))?;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Box<dyn Error>> {
+        match std::fs::remove_file(self.path_for(key)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Storage backed by the browser's localStorage, reached through the emscripten runtime
+#[cfg(target_os = "emscripten")]
+pub struct LocalStorage;
+
+#[cfg(target_os = "emscripten")]
+mod emscripten {
+    use std::ffi::{CStr, CString, c_char};
+
+    unsafe extern "C" {
+        fn emscripten_run_script(script: *const c_char);
+        fn emscripten_run_script_string(script: *const c_char) -> *const c_char;
+    }
+
+    pub fn run_script(script: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let script = CString::new(script)?;
+        unsafe { emscripten_run_script(script.as_ptr()) };
+        Ok(())
+    }
+
+    pub fn run_script_string(script: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let script = CString::new(script)?;
+        // The returned buffer is owned by emscripten and reused on the next call, so copy it out
+        let result = unsafe { CStr::from_ptr(emscripten_run_script_string(script.as_ptr())) };
+        Ok(result.to_str()?.to_string())
+    }
+}
+
+#[cfg(target_os = "emscripten")]
+impl Storage for LocalStorage {
+    fn read(&self, key: &str) -> Result<Option<String>, Box<dyn Error>> {
+        // Round-trip through JSON so a missing key ("null") is distinguishable from an empty value
+        let script = format!(
+            "JSON.stringify(localStorage.getItem({}))",
+            serde_json::to_string(&storage_key(key))?
+        );
+        let json = emscripten::run_script_string(&script)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    fn write(&self, key: &str, contents: &str) -> Result<(), Box<dyn Error>> {
+        let script = format!(
+            "localStorage.setItem({}, {})",
+            serde_json::to_string(&storage_key(key))?,
+            serde_json::to_string(contents)?
+        );
+        emscripten::run_script(&script)
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Box<dyn Error>> {
+        let script = format!(
+            "localStorage.removeItem({})",
+            serde_json::to_string(&storage_key(key))?
+        );
+        emscripten::run_script(&script)
+    }
+}
+
+// localStorage is shared by everything on the page's origin, so namespace our keys
+#[cfg(target_os = "emscripten")]
+fn storage_key(key: &str) -> String {
+    format!("dropjack/{}", key)
+}
+
+/// The storage used for this platform
+#[cfg(not(target_os = "emscripten"))]
+pub fn platform_storage() -> Result<Box<dyn Storage>, Box<dyn Error>> {
+    Ok(Box::new(FileStorage::app_data()?))
+}
+
+#[cfg(target_os = "emscripten")]
+pub fn platform_storage() -> Result<Box<dyn Storage>, Box<dyn Error>> {
+    Ok(Box::new(LocalStorage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_storage_missing_key() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let storage = FileStorage::new(temp_dir.path()).unwrap();
+
+        assert_eq!(storage.read("missing.json").unwrap(), None);
+    }
+
+    #[test]
+    fn test_file_storage_round_trip() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let storage = FileStorage::new(temp_dir.path()).unwrap();
+
+        storage.write("data.json", "{\"a\":1}").unwrap();
+        assert_eq!(
+            storage.read("data.json").unwrap(),
+            Some("{\"a\":1}".to_string())
+        );
+
+        // Writing again replaces the previous value
+        storage.write("data.json", "{}").unwrap();
+        assert_eq!(storage.read("data.json").unwrap(), Some("{}".to_string()));
+
+        // The temp file used for the atomic write is renamed away
+        assert!(!temp_dir.path().join("data.json.tmp").exists());
+    }
+
+    #[test]
+    fn test_file_storage_remove() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let storage = FileStorage::new(temp_dir.path()).unwrap();
+
+        storage.write("data.json", "{}").unwrap();
+        storage.remove("data.json").unwrap();
+        assert_eq!(storage.read("data.json").unwrap(), None);
+
+        // Removing again is fine
+        storage.remove("data.json").unwrap();
+    }
+}
@@ -0,0 +1,749 @@
+// Persistence backends for high scores, statistics, settings and saved runs.
+// The game only talks to the ScoreStore/SettingsStore/SavedRunStore traits; GameBuilder decides which
+// implementation backs them (SQLite, JSON over a Storage, or in-memory for tests).
+
+use crate::error::DropJackError;
+use crate::integrity::Signer;
+use crate::models::{
+    Difficulty, GameSettings, HighScore, RunOutcome, SavedRun, Statistics, score_percentile,
+};
+use crate::storage::Storage;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+
+#[allow(dead_code)] // Only the browser build stores scores as JSON
+const SCORES_KEY: &str = "highscores.json";
+#[allow(dead_code)]
+const STATISTICS_KEY: &str = "statistics.json";
+#[allow(dead_code)]
+const PUZZLE_STARS_KEY: &str = "puzzle_stars.json";
+const SETTINGS_KEY: &str = "settings.json";
+const SAVED_RUN_KEY: &str = "savedrun.json";
+
+// Send so the store can live on the score worker thread
+pub trait ScoreStore: Send {
+    /// Save a score, returning the id it was stored under
+    fn add_high_score(&mut self, high_score: &HighScore) -> Result<i64, Box<dyn Error>>;
+
+    /// Best scores first, across all difficulties
+    fn get_high_scores(&self, limit: usize) -> Result<Vec<HighScore>, Box<dyn Error>>;
+
+    /// Best scores set at or after `since`, across all difficulties
+    fn get_high_scores_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<HighScore>, Box<dyn Error>>;
+
+    /// Rank a score would take among saved scores for the same difficulty, counting relax
+    /// mode runs only against each other (1 = best)
+    fn get_score_rank(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<usize, Box<dyn Error>>;
+
+    /// Share of the saved scores for the same difficulty and mode that a score beats, as a
+    /// percentage (0-100), or None before the first score is saved
+    fn get_score_percentile(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<Option<u8>, Box<dyn Error>>;
+
+    /// Best saved score for the difficulty, again keeping relax mode runs apart, or None
+    /// before the first score is saved
+    fn get_personal_best(
+        &self,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<Option<i32>, Box<dyn Error>>;
+
+    /// Count a run, and how many cards it dropped into each column, towards the lifetime statistics
+    fn record_run(
+        &mut self,
+        outcome: RunOutcome,
+        column_drops: &[u32],
+    ) -> Result<(), Box<dyn Error>>;
+
+    fn get_statistics(&self) -> Result<Statistics, Box<dyn Error>>;
+
+    /// Best star rating for each puzzle level cleared so far, by level id
+    fn get_puzzle_stars(&self) -> Result<HashMap<String, u8>, Box<dyn Error>>;
+
+    /// Keep a puzzle clear's star rating if it beats the level's best so far
+    fn record_puzzle_stars(&mut self, level_id: &str, stars: u8) -> Result<(), Box<dyn Error>>;
+}
+
+pub trait SettingsStore {
+    /// Load saved settings, or None if nothing has been saved yet
+    fn load(&self) -> Result<Option<GameSettings>, Box<dyn Error>>;
+
+    fn save(&mut self, settings: &GameSettings) -> Result<(), Box<dyn Error>>;
+}
+
+pub trait SavedRunStore {
+    /// The run put aside with Save & Quit, or None if there isn't one
+    fn load(&self) -> Result<Option<SavedRun>, Box<dyn Error>>;
+
+    /// Keep a run to continue later, replacing any earlier one
+    fn save(&mut self, run: &SavedRun) -> Result<(), Box<dyn Error>>;
+
+    /// Forget the saved run (it has been continued)
+    fn clear(&mut self) -> Result<(), Box<dyn Error>>;
+}
+
+/// Scores kept in memory only - nothing survives the process
+#[allow(dead_code)]
+#[derive(Default)]
+pub struct MemoryScoreStore {
+    scores: Vec<HighScore>,
+    statistics: Statistics,
+    puzzle_stars: HashMap<String, u8>,
+}
+
+#[allow(dead_code)]
+impl MemoryScoreStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_contents(
+        scores: Vec<HighScore>,
+        statistics: Statistics,
+        puzzle_stars: HashMap<String, u8>,
+    ) -> Self {
+        MemoryScoreStore {
+            scores,
+            statistics,
+            puzzle_stars,
+        }
+    }
+}
+
+impl ScoreStore for MemoryScoreStore {
+    fn add_high_score(&mut self, high_score: &HighScore) -> Result<i64, Box<dyn Error>> {
+        let id = self.scores.iter().filter_map(|s| s.id).max().unwrap_or(0) + 1;
+        self.scores.push(HighScore {
+            id: Some(id),
+            ..high_score.clone()
+        });
+        Ok(id)
+    }
+
+    fn get_high_scores(&self, limit: usize) -> Result<Vec<HighScore>, Box<dyn Error>> {
+        let mut scores = self.scores.clone();
+        scores.sort_by_key(|s| std::cmp::Reverse(s.score));
+        scores.truncate(limit);
+        Ok(scores)
+    }
+
+    fn get_high_scores_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<HighScore>, Box<dyn Error>> {
+        let mut scores: Vec<HighScore> = self
+            .scores
+            .iter()
+            .filter(|s| s.date >= since)
+            .cloned()
+            .collect();
+        scores.sort_by_key(|s| std::cmp::Reverse(s.score));
+        scores.truncate(limit);
+        Ok(scores)
+    }
+
+    fn get_score_rank(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<usize, Box<dyn Error>> {
+        let better_scores = self
+            .scores
+            .iter()
+            .filter(|s| s.difficulty == difficulty && s.relaxed == relaxed && s.score > score)
+            .count();
+        Ok(better_scores + 1)
+    }
+
+    fn get_score_percentile(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<Option<u8>, Box<dyn Error>> {
+        let (below, total) = self
+            .scores
+            .iter()
+            .filter(|s| s.difficulty == difficulty && s.relaxed == relaxed)
+            .fold((0, 0), |(below, total), s| {
+                (below + usize::from(s.score < score), total + 1)
+            });
+        Ok(score_percentile(below, total))
+    }
+
+    fn get_personal_best(
+        &self,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<Option<i32>, Box<dyn Error>> {
+        Ok(self
+            .scores
+            .iter()
+            .filter(|s| s.difficulty == difficulty && s.relaxed == relaxed)
+            .map(|s| s.score)
+            .max())
+    }
+
+    fn record_run(
+        &mut self,
+        outcome: RunOutcome,
+        column_drops: &[u32],
+    ) -> Result<(), Box<dyn Error>> {
+        self.statistics.record(outcome);
+        self.statistics.record_column_drops(column_drops);
+        Ok(())
+    }
+
+    fn get_statistics(&self) -> Result<Statistics, Box<dyn Error>> {
+        Ok(self.statistics.clone())
+    }
+
+    fn get_puzzle_stars(&self) -> Result<HashMap<String, u8>, Box<dyn Error>> {
+        Ok(self.puzzle_stars.clone())
+    }
+
+    fn record_puzzle_stars(&mut self, level_id: &str, stars: u8) -> Result<(), Box<dyn Error>> {
+        let best = self.puzzle_stars.entry(level_id.to_string()).or_default();
+        *best = (*best).max(stars);
+        Ok(())
+    }
+}
+
+/// Scores serialized as a JSON list (and statistics as a JSON object), for platforms
+/// without SQLite (e.g. the browser)
+#[allow(dead_code)]
+pub struct JsonScoreStore {
+    storage: Box<dyn Storage>,
+    scores: MemoryScoreStore,
+}
+
+#[allow(dead_code)]
+impl JsonScoreStore {
+    pub fn new(storage: Box<dyn Storage>) -> Result<Self, Box<dyn Error>> {
+        let scores = match storage.read(SCORES_KEY)? {
+            Some(contents) => serde_json::from_str(&contents)?,
+            None => Vec::new(),
+        };
+        let statistics = match storage.read(STATISTICS_KEY)? {
+            Some(contents) => serde_json::from_str(&contents)?,
+            None => Statistics::default(),
+        };
+        let puzzle_stars = match storage.read(PUZZLE_STARS_KEY)? {
+            Some(contents) => serde_json::from_str(&contents)?,
+            None => HashMap::new(),
+        };
+
+        Ok(JsonScoreStore {
+            storage,
+            scores: MemoryScoreStore::with_contents(scores, statistics, puzzle_stars),
+        })
+    }
+}
+
+impl ScoreStore for JsonScoreStore {
+    fn add_high_score(&mut self, high_score: &HighScore) -> Result<i64, Box<dyn Error>> {
+        let id = self.scores.add_high_score(high_score)?;
+        self.storage
+            .write(SCORES_KEY, &serde_json::to_string(&self.scores.scores)?)?;
+        Ok(id)
+    }
+
+    fn get_high_scores(&self, limit: usize) -> Result<Vec<HighScore>, Box<dyn Error>> {
+        self.scores.get_high_scores(limit)
+    }
+
+    fn get_high_scores_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<HighScore>, Box<dyn Error>> {
+        self.scores.get_high_scores_since(since, limit)
+    }
+
+    fn get_score_rank(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<usize, Box<dyn Error>> {
+        self.scores.get_score_rank(score, difficulty, relaxed)
+    }
+
+    fn get_score_percentile(
+        &self,
+        score: i32,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<Option<u8>, Box<dyn Error>> {
+        self.scores.get_score_percentile(score, difficulty, relaxed)
+    }
+
+    fn get_personal_best(
+        &self,
+        difficulty: Difficulty,
+        relaxed: bool,
+    ) -> Result<Option<i32>, Box<dyn Error>> {
+        self.scores.get_personal_best(difficulty, relaxed)
+    }
+
+    fn record_run(
+        &mut self,
+        outcome: RunOutcome,
+        column_drops: &[u32],
+    ) -> Result<(), Box<dyn Error>> {
+        self.scores.record_run(outcome, column_drops)?;
+        self.storage.write(
+            STATISTICS_KEY,
+            &serde_json::to_string(&self.scores.statistics)?,
+        )
+    }
+
+    fn get_statistics(&self) -> Result<Statistics, Box<dyn Error>> {
+        self.scores.get_statistics()
+    }
+
+    fn get_puzzle_stars(&self) -> Result<HashMap<String, u8>, Box<dyn Error>> {
+        self.scores.get_puzzle_stars()
+    }
+
+    fn record_puzzle_stars(&mut self, level_id: &str, stars: u8) -> Result<(), Box<dyn Error>> {
+        self.scores.record_puzzle_stars(level_id, stars)?;
+        self.storage.write(
+            PUZZLE_STARS_KEY,
+            &serde_json::to_string(&self.scores.puzzle_stars)?,
+        )
+    }
+}
+
+/// Settings kept in memory only - nothing survives the process
+#[derive(Default)]
+pub struct MemorySettingsStore {
+    settings: Option<GameSettings>,
+}
+
+impl MemorySettingsStore {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SettingsStore for MemorySettingsStore {
+    fn load(&self) -> Result<Option<GameSettings>, Box<dyn Error>> {
+        Ok(self.settings.clone())
+    }
+
+    fn save(&mut self, settings: &GameSettings) -> Result<(), Box<dyn Error>> {
+        self.settings = Some(settings.clone());
+        Ok(())
+    }
+}
+
+/// Settings saved as pretty-printed JSON (settings.json on disk, localStorage in the browser)
+pub struct JsonSettingsStore {
+    storage: Box<dyn Storage>,
+}
+
+impl JsonSettingsStore {
+    pub fn new(storage: Box<dyn Storage>) -> Self {
+        JsonSettingsStore { storage }
+    }
+}
+
+impl SettingsStore for JsonSettingsStore {
+    fn load(&self) -> Result<Option<GameSettings>, Box<dyn Error>> {
+        let Some(contents) = self.storage.read(SETTINGS_KEY)? else {
+            return Ok(None);
+        };
+        let mut settings: GameSettings = serde_json::from_str(&contents)
+            .map_err(|e| DropJackError::SettingsCorrupt(e.to_string()))?;
+
+        // Reset UI state (selected_option should always start at 0)
+        settings.selected_option = 0;
+
+        Ok(Some(settings))
+    }
+
+    fn save(&mut self, settings: &GameSettings) -> Result<(), Box<dyn Error>> {
+        let contents = serde_json::to_string_pretty(settings)?;
+        self.storage.write(SETTINGS_KEY, &contents)
+    }
+}
+
+/// Saved run kept in memory only - nothing survives the process
+#[derive(Default)]
+pub struct MemorySavedRunStore {
+    run: Option<SavedRun>,
+}
+
+impl MemorySavedRunStore {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SavedRunStore for MemorySavedRunStore {
+    fn load(&self) -> Result<Option<SavedRun>, Box<dyn Error>> {
+        Ok(self.run.clone())
+    }
+
+    fn save(&mut self, run: &SavedRun) -> Result<(), Box<dyn Error>> {
+        self.run = Some(run.clone());
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Box<dyn Error>> {
+        self.run = None;
+        Ok(())
+    }
+}
+
+// A saved run with the signature of its JSON alongside, in the same object
+#[derive(Serialize, Deserialize)]
+struct SignedRun {
+    #[serde(flatten)]
+    run: SavedRun,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+}
+
+/// Saved run as JSON (savedrun.json on disk, localStorage in the browser). Runs are signed
+/// where there's a machine key, and one whose signature doesn't match loads marked modified.
+pub struct JsonSavedRunStore {
+    storage: Box<dyn Storage>,
+    signer: Option<Signer>,
+}
+
+impl JsonSavedRunStore {
+    pub fn new(storage: Box<dyn Storage>) -> Self {
+        Self::with_signer(storage, Signer::for_this_machine())
+    }
+
+    pub fn with_signer(storage: Box<dyn Storage>, signer: Option<Signer>) -> Self {
+        JsonSavedRunStore { storage, signer }
+    }
+}
+
+impl SavedRunStore for JsonSavedRunStore {
+    fn load(&self) -> Result<Option<SavedRun>, Box<dyn Error>> {
+        let Some(contents) = self.storage.read(SAVED_RUN_KEY)? else {
+            return Ok(None);
+        };
+        let SignedRun { mut run, signature } = serde_json::from_str(&contents)?;
+        if let Some(signer) = &self.signer {
+            let unsigned = serde_json::to_string(&run)?;
+            run.modified |=
+                !signature.is_some_and(|signature| signer.verify(&unsigned, &signature));
+        }
+        Ok(Some(run))
+    }
+
+    fn save(&mut self, run: &SavedRun) -> Result<(), Box<dyn Error>> {
+        let unsigned = serde_json::to_string(run)?;
+        let signed = SignedRun {
+            run: run.clone(),
+            signature: self.signer.as_ref().map(|signer| signer.sign(&unsigned)),
+        };
+        self.storage
+            .write(SAVED_RUN_KEY, &serde_json::to_string(&signed)?)
+    }
+
+    fn clear(&mut self) -> Result<(), Box<dyn Error>> {
+        self.storage.remove(SAVED_RUN_KEY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DeckTemplate;
+    use crate::storage::FileStorage;
+
+    mod test_fixtures {
+        use super::*;
+
+        pub fn create_high_score(initials: &str, score: i32, difficulty: Difficulty) -> HighScore {
+            HighScore {
+                id: None,
+                player_initials: initials.to_string(),
+                score,
+                difficulty,
+                date: chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 1, 15, 14, 30, 0).unwrap(),
+                modded: false,
+                deck_template: DeckTemplate::Standard,
+                relaxed: false,
+                modified: false,
+            }
+        }
+    }
+
+    #[test]
+    fn test_memory_score_store_ordering_and_rank() {
+        let mut store = MemoryScoreStore::new();
+        store
+            .add_high_score(&test_fixtures::create_high_score(
+                "AAA",
+                100,
+                Difficulty::Easy,
+            ))
+            .unwrap();
+        store
+            .add_high_score(&test_fixtures::create_high_score(
+                "BBB",
+                300,
+                Difficulty::Easy,
+            ))
+            .unwrap();
+        store
+            .add_high_score(&test_fixtures::create_high_score(
+                "CCC",
+                200,
+                Difficulty::Hard,
+            ))
+            .unwrap();
+
+        let scores = store.get_high_scores(2).unwrap();
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0].player_initials, "BBB");
+        assert_eq!(scores[1].player_initials, "CCC");
+
+        // Rank only counts scores from the same difficulty
+        assert_eq!(
+            store.get_score_rank(400, Difficulty::Easy, false).unwrap(),
+            1
+        );
+        assert_eq!(
+            store.get_score_rank(150, Difficulty::Easy, false).unwrap(),
+            2
+        );
+        assert_eq!(
+            store.get_score_rank(150, Difficulty::Hard, false).unwrap(),
+            2
+        );
+        assert_eq!(
+            store.get_score_rank(150, Difficulty::Easy, true).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_memory_score_store_assigns_ids() {
+        let mut store = MemoryScoreStore::new();
+        let first = store
+            .add_high_score(&test_fixtures::create_high_score(
+                "AAA",
+                100,
+                Difficulty::Easy,
+            ))
+            .unwrap();
+        let second = store
+            .add_high_score(&test_fixtures::create_high_score(
+                "BBB",
+                100,
+                Difficulty::Easy,
+            ))
+            .unwrap();
+
+        assert_ne!(first, second);
+        assert!(
+            store
+                .get_high_scores(10)
+                .unwrap()
+                .iter()
+                .all(|s| s.id.is_some())
+        );
+    }
+
+    #[test]
+    fn test_json_score_store_persistence() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+
+        {
+            let storage = Box::new(FileStorage::new(temp_dir.path()).unwrap());
+            let mut store = JsonScoreStore::new(storage).unwrap();
+            store
+                .add_high_score(&test_fixtures::create_high_score(
+                    "PER",
+                    999,
+                    Difficulty::Hard,
+                ))
+                .unwrap();
+        }
+
+        let storage = Box::new(FileStorage::new(temp_dir.path()).unwrap());
+        let store = JsonScoreStore::new(storage).unwrap();
+        let scores = store.get_high_scores(10).unwrap();
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].player_initials, "PER");
+        assert_eq!(scores[0].score, 999);
+    }
+
+    #[test]
+    fn test_json_settings_store_round_trip() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let storage = Box::new(FileStorage::new(temp_dir.path()).unwrap());
+        let mut store = JsonSettingsStore::new(storage);
+
+        assert_eq!(store.load().unwrap(), None);
+
+        let settings = GameSettings {
+            difficulty: Difficulty::Hard,
+            selected_option: 2, // UI state, should not come back
+            ..GameSettings::default()
+        };
+        store.save(&settings).unwrap();
+
+        let loaded = store.load().unwrap().expect("Settings should be saved");
+        assert_eq!(loaded.difficulty, Difficulty::Hard);
+        assert_eq!(loaded.selected_option, 0);
+    }
+
+    #[test]
+    fn test_json_settings_store_reports_corrupt_settings() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let storage = Box::new(FileStorage::new(temp_dir.path()).unwrap());
+        storage.write(SETTINGS_KEY, "{ not json").unwrap();
+        let store = JsonSettingsStore::new(storage);
+
+        let error = store.load().unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<DropJackError>(),
+            Some(DropJackError::SettingsCorrupt(_))
+        ));
+    }
+
+    #[test]
+    fn test_json_score_store_statistics_persist() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+
+        {
+            let storage = Box::new(FileStorage::new(temp_dir.path()).unwrap());
+            let mut store = JsonScoreStore::new(storage).unwrap();
+            store.record_run(RunOutcome::Abandoned, &[]).unwrap();
+            store.record_run(RunOutcome::Finished, &[0, 3]).unwrap();
+        }
+
+        let storage = Box::new(FileStorage::new(temp_dir.path()).unwrap());
+        let store = JsonScoreStore::new(storage).unwrap();
+        let statistics = store.get_statistics().unwrap();
+        assert_eq!(statistics.runs_abandoned, 1);
+        assert_eq!(statistics.runs_finished, 1);
+        assert_eq!(statistics.column_drops, vec![0, 3]);
+    }
+
+    #[test]
+    fn test_json_score_store_keeps_best_puzzle_stars() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+
+        {
+            let storage = Box::new(FileStorage::new(temp_dir.path()).unwrap());
+            let mut store = JsonScoreStore::new(storage).unwrap();
+            store.record_puzzle_stars("tower", 2).unwrap();
+            store.record_puzzle_stars("tower", 1).unwrap();
+            store.record_puzzle_stars("suited", 3).unwrap();
+        }
+
+        let storage = Box::new(FileStorage::new(temp_dir.path()).unwrap());
+        let store = JsonScoreStore::new(storage).unwrap();
+        let stars = store.get_puzzle_stars().unwrap();
+        assert_eq!(stars.get("tower"), Some(&2));
+        assert_eq!(stars.get("suited"), Some(&3));
+        assert_eq!(stars.len(), 2);
+    }
+
+    #[test]
+    fn test_json_saved_run_store_round_trip() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let storage = Box::new(FileStorage::new(temp_dir.path()).unwrap());
+        let mut store = JsonSavedRunStore::new(storage);
+        assert_eq!(store.load().unwrap(), None);
+
+        let run = SavedRun {
+            difficulty: Difficulty::Easy,
+            score: 84,
+            speed_level: 1,
+            grid: vec![vec![None; 2]; 2],
+            deck: Vec::new(),
+            current_card: None,
+            current_column: 0,
+            next_card: None,
+            saved_at: "2024-01-15 14:30:00".to_string(),
+            modded: false,
+            deck_template: DeckTemplate::Standard,
+            relaxed: false,
+            modified: false,
+        };
+        store.save(&run).unwrap();
+        assert_eq!(store.load().unwrap(), Some(run));
+
+        store.clear().unwrap();
+        assert_eq!(store.load().unwrap(), None);
+    }
+
+    #[test]
+    fn test_edited_saved_run_loads_modified() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let store = |path: &std::path::Path| {
+            let storage = Box::new(FileStorage::new(path).unwrap());
+            JsonSavedRunStore::with_signer(storage, Some(Signer::new(b"test-machine")))
+        };
+
+        let mut run = SavedRun {
+            difficulty: Difficulty::Easy,
+            score: 84,
+            speed_level: 1,
+            grid: vec![vec![None; 2]; 2],
+            deck: Vec::new(),
+            current_card: None,
+            current_column: 0,
+            next_card: None,
+            saved_at: "2024-01-15 14:30:00".to_string(),
+            modded: false,
+            deck_template: DeckTemplate::Standard,
+            relaxed: false,
+            modified: false,
+        };
+        store(temp_dir.path()).save(&run).unwrap();
+        assert_eq!(store(temp_dir.path()).load().unwrap(), Some(run.clone()));
+
+        let path = temp_dir.path().join(SAVED_RUN_KEY);
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("\"signature\""));
+        std::fs::write(&path, contents.replace("\"score\":84", "\"score\":8400")).unwrap();
+
+        run.score = 8400;
+        run.modified = true;
+        assert_eq!(store(temp_dir.path()).load().unwrap(), Some(run));
+    }
+
+    #[test]
+    fn test_memory_settings_store() {
+        let mut store = MemorySettingsStore::new();
+        assert_eq!(store.load().unwrap(), None);
+
+        let settings = GameSettings {
+            music_muted: true,
+            ..GameSettings::default()
+        };
+        store.save(&settings).unwrap();
+        assert_eq!(store.load().unwrap(), Some(settings));
+    }
+}
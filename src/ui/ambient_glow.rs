@@ -0,0 +1,174 @@
+use crate::game::Game;
+use crate::ui::config::{AmbientGlowConfig, BoardConfig, InfoPanelConfig, ScreenConfig};
+use raylib::prelude::*;
+
+// A soft light travelling around the board frame and a shine now and then across the info
+// panel, so the play screen never sits completely still. Decoration only: it runs on the
+// frame's delta time rather than the game clock, and reduced motion leaves it out.
+pub struct AmbientGlow {
+    elapsed: f32, // Seconds of play it has animated through, wrapped to keep floats precise
+}
+
+impl AmbientGlow {
+    pub fn new() -> Self {
+        AmbientGlow { elapsed: 0.0 }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.elapsed = (self.elapsed + delta_time) % AmbientGlowConfig::WRAP_SECONDS;
+    }
+
+    pub fn draw(&self, d: &mut RaylibDrawHandle, game: &Game) {
+        if game.settings.reduced_motion {
+            return;
+        }
+
+        let cell_size = game.board.cell_size;
+        let (origin_x, origin_y) = BoardConfig::origin(
+            game.board.width,
+            game.board.height,
+            cell_size,
+            game.settings.mirrored_layout,
+        );
+        let inset = AmbientGlowConfig::FRAME_OUTSET;
+        let frame = Rectangle::new(
+            (origin_x - inset) as f32,
+            (origin_y - inset) as f32,
+            (game.board.width * cell_size + inset * 2) as f32,
+            (game.board.height * cell_size + inset * 2) as f32,
+        );
+        self.draw_frame_glow(d, frame);
+
+        let panel = Rectangle::new(
+            InfoPanelConfig::x(game.settings.mirrored_layout) as f32,
+            BoardConfig::OFFSET_Y as f32,
+            InfoPanelConfig::WIDTH as f32,
+            (ScreenConfig::HEIGHT - 2 * BoardConfig::OFFSET_Y) as f32,
+        );
+        self.draw_panel_shine(d, panel);
+    }
+
+    // The light and a fading tail behind it, a lap of the frame every FRAME_LAP_SECONDS
+    fn draw_frame_glow(&self, d: &mut RaylibDrawHandle, frame: Rectangle) {
+        let lap = self.elapsed / AmbientGlowConfig::FRAME_LAP_SECONDS;
+        for step in 0..AmbientGlowConfig::TAIL_STEPS {
+            let behind = step as f32 * AmbientGlowConfig::TAIL_SPACING;
+            let fade = 1.0 - step as f32 / AmbientGlowConfig::TAIL_STEPS as f32;
+            let point = point_on_perimeter(frame, (lap - behind).rem_euclid(1.0));
+            d.draw_circle_gradient(
+                point.x as i32,
+                point.y as i32,
+                AmbientGlowConfig::GLOW_RADIUS * fade,
+                AmbientGlowConfig::GLOW_COLOR.fade(fade),
+                Color::BLANK,
+            );
+        }
+    }
+
+    // A pale band that crosses the panel once every SHINE_INTERVAL_SECONDS
+    fn draw_panel_shine(&self, d: &mut RaylibDrawHandle, panel: Rectangle) {
+        let Some(progress) = shine_progress(self.elapsed) else {
+            return;
+        };
+
+        let half = AmbientGlowConfig::SHINE_WIDTH / 2.0;
+        let center = panel.x - half + (panel.width + half * 2.0) * progress;
+        let (y, height) = (panel.y as i32, panel.height as i32);
+
+        // Each half of the band fades out towards its edge, cut off where the panel ends
+        let halves = [
+            (
+                center - half,
+                center,
+                Color::BLANK,
+                AmbientGlowConfig::SHINE_COLOR,
+            ),
+            (
+                center,
+                center + half,
+                AmbientGlowConfig::SHINE_COLOR,
+                Color::BLANK,
+            ),
+        ];
+        for (start, end, from, to) in halves {
+            let left = start.max(panel.x);
+            let right = end.min(panel.x + panel.width);
+            if right <= left {
+                continue;
+            }
+            let span = end - start;
+            let color_at = |x: f32| lerp_color(from, to, (x - start) / span);
+            d.draw_rectangle_gradient_h(
+                left as i32,
+                y,
+                (right - left) as i32,
+                height,
+                color_at(left),
+                color_at(right),
+            );
+        }
+    }
+}
+
+/// Where a shine sweep is across the panel, from 0.0 to 1.0, or None between sweeps
+fn shine_progress(elapsed: f32) -> Option<f32> {
+    let into_interval = elapsed % AmbientGlowConfig::SHINE_INTERVAL_SECONDS;
+    (into_interval < AmbientGlowConfig::SHINE_SECONDS)
+        .then(|| into_interval / AmbientGlowConfig::SHINE_SECONDS)
+}
+
+/// The point `fraction` of the way round a rectangle's edge, clockwise from the top left
+fn point_on_perimeter(rect: Rectangle, fraction: f32) -> Vector2 {
+    let mut distance = fraction * 2.0 * (rect.width + rect.height);
+    if distance < rect.width {
+        return Vector2::new(rect.x + distance, rect.y);
+    }
+    distance -= rect.width;
+    if distance < rect.height {
+        return Vector2::new(rect.x + rect.width, rect.y + distance);
+    }
+    distance -= rect.height;
+    if distance < rect.width {
+        return Vector2::new(rect.x + rect.width - distance, rect.y + rect.height);
+    }
+    distance -= rect.width;
+    Vector2::new(rect.x, rect.y + rect.height - distance)
+}
+
+fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color::new(
+        mix(from.r, to.r),
+        mix(from.g, to.g),
+        mix(from.b, to.b),
+        mix(from.a, to.a),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_on_perimeter_goes_round_clockwise() {
+        let rect = Rectangle::new(10.0, 20.0, 100.0, 50.0);
+        assert_eq!(point_on_perimeter(rect, 0.0), Vector2::new(10.0, 20.0));
+        assert_eq!(point_on_perimeter(rect, 0.25), Vector2::new(85.0, 20.0));
+        assert_eq!(point_on_perimeter(rect, 0.5), Vector2::new(110.0, 70.0));
+        assert_eq!(point_on_perimeter(rect, 0.75), Vector2::new(35.0, 70.0));
+        assert_eq!(point_on_perimeter(rect, 0.875), Vector2::new(10.0, 57.5));
+    }
+
+    #[test]
+    fn test_shine_only_between_pauses() {
+        assert_eq!(shine_progress(0.0), Some(0.0));
+        let halfway = AmbientGlowConfig::SHINE_SECONDS / 2.0;
+        assert_eq!(shine_progress(halfway), Some(0.5));
+        assert_eq!(shine_progress(AmbientGlowConfig::SHINE_SECONDS + 0.1), None);
+
+        // And again the next time round
+        let next = shine_progress(AmbientGlowConfig::SHINE_INTERVAL_SECONDS + halfway);
+        assert!(next.is_some_and(|progress| (progress - 0.5).abs() < 1e-4));
+    }
+}
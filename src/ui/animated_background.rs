@@ -1,8 +1,10 @@
-use crate::models::{Card, Deck, Suit, Value};
+use crate::models::{Card, CardBack, Deck, Season, Suit, Value};
 use crate::ui::atlas_card_renderer::AtlasCardRenderer;
 use crate::ui::atlas_card_renderer::CardRenderOptions;
+use crate::ui::card_back_renderer::CardBackRenderer;
 use crate::ui::config::AnimationConfig;
 use crate::ui::config::ScreenConfig;
+use crate::ui::seasons::SeasonalDecorations;
 use raylib::prelude::*;
 
 #[derive(Clone)]
@@ -14,6 +16,7 @@ pub struct AnimatedCard {
     pub card: Card,
     pub size: f32,
     pub alpha: u8,
+    pub face_down: bool, // Shows the card back design instead of the face
 }
 
 impl AnimatedCard {
@@ -41,6 +44,7 @@ impl AnimatedCard {
             card,
             size,
             alpha: AnimationConfig::ALPHA,
+            face_down: false,
         }
     }
 
@@ -79,7 +83,7 @@ impl AnimatedCard {
         }
     }
 
-    pub fn draw(&self, d: &mut RaylibDrawHandle, atlas: &Texture2D) {
+    pub fn draw(&self, d: &mut RaylibDrawHandle, atlas: &Texture2D, card_back: CardBack) {
         let tint = Color::new(255, 255, 255, self.alpha);
         let options = CardRenderOptions::new(
             (self.position.x - self.size / 2.0) as i32,
@@ -89,12 +93,19 @@ impl AnimatedCard {
         .with_rotation(self.rotation)
         .with_tint(tint);
 
-        AtlasCardRenderer::draw_card_with_options(d, atlas, self.card, options);
+        if self.face_down {
+            CardBackRenderer::draw(d, card_back, options);
+        } else {
+            AtlasCardRenderer::draw_card_with_options(d, atlas, self.card, options);
+        }
     }
 }
 
 pub struct AnimatedBackground {
     cards: Vec<AnimatedCard>,
+    reduced_motion: bool, // Cards hold still where they are
+    card_back: CardBack,
+    decorations: SeasonalDecorations,
 }
 
 impl AnimatedBackground {
@@ -131,6 +142,8 @@ impl AnimatedBackground {
                 let y = grid_y + (rand::random::<f32>() - 0.5) * AnimationConfig::RANDOMNESS;
 
                 let mut animated_card = AnimatedCard::new(card);
+                // Every other card lies face down to show off the card back
+                animated_card.face_down = i % 2 == 1;
                 animated_card.position = Vector2::new(
                     x.max(animated_card.size / 2.0)
                         .min(ScreenConfig::WIDTH as f32 - animated_card.size / 2.0),
@@ -142,16 +155,40 @@ impl AnimatedBackground {
             })
             .collect();
 
-        Self { cards }
+        Self {
+            cards,
+            reduced_motion: false,
+            card_back: CardBack::default(),
+            decorations: SeasonalDecorations::new(),
+        }
+    }
+
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        self.reduced_motion = reduced_motion;
+    }
+
+    pub fn set_card_back(&mut self, card_back: CardBack) {
+        self.card_back = card_back;
+    }
+
+    pub fn set_season(&mut self, season: Option<Season>) {
+        self.decorations.set_season(season);
     }
 
     pub fn update(&mut self, delta_time: f32) {
+        if self.reduced_motion {
+            return;
+        }
         self.cards
             .iter_mut()
             .for_each(|card| card.update(delta_time));
+        self.decorations.update(delta_time);
     }
 
     pub fn draw(&self, d: &mut RaylibDrawHandle, atlas: &Texture2D) {
-        self.cards.iter().for_each(|card| card.draw(d, atlas));
+        self.cards
+            .iter()
+            .for_each(|card| card.draw(d, atlas, self.card_back));
+        self.decorations.draw(d);
     }
 }
@@ -18,6 +18,7 @@ pub struct CardRenderOptions {
     pub size: i32,
     pub rotation: f32,
     pub tint: Color,
+    pub width_scale: f32, // Squashes the card sideways, as when it turns over
 }
 
 impl CardRenderOptions {
@@ -28,6 +29,7 @@ impl CardRenderOptions {
             size,
             rotation: 0.0,
             tint: Color::WHITE,
+            width_scale: 1.0,
         }
     }
 
@@ -40,12 +42,17 @@ impl CardRenderOptions {
         self.tint = tint;
         self
     }
+
+    pub fn with_width_scale(mut self, width_scale: f32) -> Self {
+        self.width_scale = width_scale;
+        self
+    }
 }
 
 impl AtlasCardRenderer {
     /// Draw a card from the atlas with basic parameters (no rotation, white tint)
     pub fn draw_card(
-        d: &mut RaylibDrawHandle,
+        d: &mut impl RaylibDraw,
         atlas: &Texture2D,
         card: Card,
         x: i32,
@@ -58,7 +65,7 @@ impl AtlasCardRenderer {
 
     /// Draw a card from the atlas with full customization options
     pub fn draw_card_with_options(
-        d: &mut RaylibDrawHandle,
+        d: &mut impl RaylibDraw,
         atlas: &Texture2D,
         card: Card,
         options: CardRenderOptions,
@@ -75,7 +82,7 @@ impl AtlasCardRenderer {
         let dest_rect = Rectangle::new(
             options.x as f32,
             options.y as f32,
-            options.size as f32,
+            options.size as f32 * options.width_scale,
             options.size as f32,
         );
 
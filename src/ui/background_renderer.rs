@@ -1,5 +1,6 @@
+use crate::models::FeltColor;
 use crate::ui::config::ScreenConfig;
-use crate::ui::config::{BackgroundConfig, BoardConfig};
+use crate::ui::config::{BackgroundConfig, BoardConfig, FeltConfig};
 use raylib::color::Color;
 use raylib::drawing::{RaylibDraw, RaylibDrawHandle};
 use std::sync::LazyLock;
@@ -163,17 +164,21 @@ impl BackgroundRenderer {
         board_width: i32,
         board_height: i32,
         cell_size: i32,
+        mirrored: bool,
+        felt: FeltColor,
     ) {
         let board_pixel_width = board_width * cell_size;
         let board_pixel_height = board_height * cell_size;
-        let center_x = BoardConfig::OFFSET_X + board_pixel_width / 2;
-        let center_y = BoardConfig::OFFSET_Y + board_pixel_height / 2;
+        let (origin_x, origin_y) =
+            BoardConfig::origin(board_width, board_height, cell_size, mirrored);
+        let center_x = origin_x + board_pixel_width / 2;
+        let center_y = origin_y + board_pixel_height / 2;
 
         // Enhanced decorative frame system with more depth
         // Outermost shadow
         d.draw_rectangle(
-            BoardConfig::OFFSET_X - BoardConfig::SHADOW_SIZE / 2,
-            BoardConfig::OFFSET_Y - BoardConfig::SHADOW_SIZE / 2,
+            origin_x - BoardConfig::SHADOW_SIZE / 2,
+            origin_y - BoardConfig::SHADOW_SIZE / 2,
             board_pixel_width + BoardConfig::SHADOW_SIZE,
             board_pixel_height + BoardConfig::SHADOW_SIZE,
             BoardConfig::SHADOW_COLOR,
@@ -181,8 +186,8 @@ impl BackgroundRenderer {
 
         // Outer dark wood frame
         d.draw_rectangle(
-            BoardConfig::OFFSET_X - BoardConfig::OUTER_FRAME_OFFSET,
-            BoardConfig::OFFSET_Y - BoardConfig::OUTER_FRAME_OFFSET,
+            origin_x - BoardConfig::OUTER_FRAME_OFFSET,
+            origin_y - BoardConfig::OUTER_FRAME_OFFSET,
             board_pixel_width + BoardConfig::OUTER_FRAME_SIZE,
             board_pixel_height + BoardConfig::OUTER_FRAME_SIZE,
             BoardConfig::OUTER_FRAME_COLOR,
@@ -190,8 +195,8 @@ impl BackgroundRenderer {
 
         // Middle wood frame with grain effect
         d.draw_rectangle(
-            BoardConfig::OFFSET_X - BoardConfig::MIDDLE_FRAME_OFFSET,
-            BoardConfig::OFFSET_Y - BoardConfig::MIDDLE_FRAME_OFFSET,
+            origin_x - BoardConfig::MIDDLE_FRAME_OFFSET,
+            origin_y - BoardConfig::MIDDLE_FRAME_OFFSET,
             board_pixel_width + BoardConfig::MIDDLE_FRAME_SIZE,
             board_pixel_height + BoardConfig::MIDDLE_FRAME_SIZE,
             BoardConfig::MIDDLE_FRAME_COLOR,
@@ -201,18 +206,18 @@ impl BackgroundRenderer {
         for i in 0..BoardConfig::GRAIN_LINES {
             let grain_offset = i * BoardConfig::GRAIN_SPACING;
             d.draw_line(
-                BoardConfig::OFFSET_X - BoardConfig::MIDDLE_FRAME_OFFSET + grain_offset,
-                BoardConfig::OFFSET_Y - BoardConfig::MIDDLE_FRAME_OFFSET,
-                BoardConfig::OFFSET_X - BoardConfig::MIDDLE_FRAME_OFFSET + grain_offset,
-                BoardConfig::OFFSET_Y + board_pixel_height + BoardConfig::MIDDLE_FRAME_OFFSET,
+                origin_x - BoardConfig::MIDDLE_FRAME_OFFSET + grain_offset,
+                origin_y - BoardConfig::MIDDLE_FRAME_OFFSET,
+                origin_x - BoardConfig::MIDDLE_FRAME_OFFSET + grain_offset,
+                origin_y + board_pixel_height + BoardConfig::MIDDLE_FRAME_OFFSET,
                 BoardConfig::GRAIN_COLOR,
             );
         }
 
         // Inner bevel frame
         d.draw_rectangle(
-            BoardConfig::OFFSET_X - BoardConfig::INNER_FRAME_OFFSET,
-            BoardConfig::OFFSET_Y - BoardConfig::INNER_FRAME_OFFSET,
+            origin_x - BoardConfig::INNER_FRAME_OFFSET,
+            origin_y - BoardConfig::INNER_FRAME_OFFSET,
             board_pixel_width + BoardConfig::INNER_FRAME_SIZE,
             board_pixel_height + BoardConfig::INNER_FRAME_SIZE,
             BoardConfig::INNER_FRAME_COLOR,
@@ -220,8 +225,8 @@ impl BackgroundRenderer {
 
         // Innermost highlight frame
         d.draw_rectangle(
-            BoardConfig::OFFSET_X - BoardConfig::HIGHLIGHT_FRAME_OFFSET,
-            BoardConfig::OFFSET_Y - BoardConfig::HIGHLIGHT_FRAME_OFFSET,
+            origin_x - BoardConfig::HIGHLIGHT_FRAME_OFFSET,
+            origin_y - BoardConfig::HIGHLIGHT_FRAME_OFFSET,
             board_pixel_width + BoardConfig::HIGHLIGHT_FRAME_SIZE,
             board_pixel_height + BoardConfig::HIGHLIGHT_FRAME_SIZE,
             BoardConfig::HIGHLIGHT_FRAME_COLOR,
@@ -229,7 +234,7 @@ impl BackgroundRenderer {
 
         let cache = &*BOARD_CACHE;
 
-        // Create realistic radial lighting on green felt (like casino table lighting) - OPTIMIZED
+        // Create realistic radial lighting on the felt (like casino table lighting) - OPTIMIZED
         let max_radius = ((board_pixel_width * board_pixel_width
             + board_pixel_height * board_pixel_height) as f32)
             .sqrt()
@@ -241,6 +246,7 @@ impl BackgroundRenderer {
         let step_height = (board_pixel_height as f32 / cache.gradient_steps as f32).ceil() as i32;
 
         // Pre-compute base colors for each position
+        let shade = FeltConfig::shade(felt);
         let mut base_colors =
             Vec::with_capacity((cache.gradient_steps * cache.gradient_steps) as usize);
 
@@ -249,19 +255,19 @@ impl BackgroundRenderer {
                 let x_ratio = cache.x_ratios[x as usize];
                 let y_ratio = cache.y_ratios[y as usize];
 
-                let base_r = 20.0 + y_ratio * 15.0;
-                let base_g = 80.0 + x_ratio * 30.0;
-                let base_b = 30.0 + (x_ratio + y_ratio) * 10.0;
+                let channel = |i: usize| {
+                    shade.base[i] + x_ratio * shade.x_range[i] + y_ratio * shade.y_range[i]
+                };
 
-                base_colors.push((base_r, base_g, base_b));
+                base_colors.push((channel(0), channel(1), channel(2)));
             }
         }
 
         // Now render with pre-computed values
         for y in 0..cache.gradient_steps {
             for x in 0..cache.gradient_steps {
-                let rect_x = BoardConfig::OFFSET_X + x * step_width;
-                let rect_y = BoardConfig::OFFSET_Y + y * step_height;
+                let rect_x = origin_x + x * step_width;
+                let rect_y = origin_y + y * step_height;
 
                 // Make rectangles overlap slightly to eliminate gaps
                 let rect_width = if x == cache.gradient_steps - 1 {
@@ -287,9 +293,9 @@ impl BackgroundRenderer {
                 let color_index = (y * cache.gradient_steps + x) as usize;
                 let (base_r, base_g, base_b) = base_colors[color_index];
 
-                let r = (base_r * light_factor) as u8;
-                let g = (base_g * light_factor + 10.0) as u8;
-                let b = (base_b * light_factor) as u8;
+                let r = (base_r * light_factor + shade.glow[0]) as u8;
+                let g = (base_g * light_factor + shade.glow[1]) as u8;
+                let b = (base_b * light_factor + shade.glow[2]) as u8;
 
                 let color = Color::new(r, g, b, 255);
                 d.draw_rectangle(rect_x, rect_y, rect_width, rect_height, color);
@@ -302,8 +308,8 @@ impl BackgroundRenderer {
 
         for i in 0..BoardConfig::TEXTURE_COUNT as usize {
             let (x_offset, y_offset) = cache.texture_coords[i];
-            let x = BoardConfig::OFFSET_X + x_offset % board_pixel_width;
-            let y = BoardConfig::OFFSET_Y + y_offset % board_pixel_height;
+            let x = origin_x + x_offset % board_pixel_width;
+            let y = origin_y + y_offset % board_pixel_height;
 
             // Distance from the center affects texture visibility - optimized calculation
             let dx = x - center_x;
@@ -322,13 +328,13 @@ impl BackgroundRenderer {
         // Add a subtle fabric weave pattern
         for i in 0..BackgroundConfig::VERTICAL_WEAVE_LINES {
             let spacing = board_pixel_width / BackgroundConfig::VERTICAL_WEAVE_LINES;
-            let x = BoardConfig::OFFSET_X + i * spacing;
+            let x = origin_x + i * spacing;
             for j in 0..BackgroundConfig::WEAVE_LINE_VARIATIONS {
                 d.draw_line(
                     x + j,
-                    BoardConfig::OFFSET_Y,
+                    origin_y,
                     x + j,
-                    BoardConfig::OFFSET_Y + board_pixel_height,
+                    origin_y + board_pixel_height,
                     Color::new(
                         0,
                         0,
@@ -342,12 +348,12 @@ impl BackgroundRenderer {
 
         for i in 0..BackgroundConfig::HORIZONTAL_WEAVE_LINES {
             let spacing = board_pixel_height / BackgroundConfig::HORIZONTAL_WEAVE_LINES;
-            let y = BoardConfig::OFFSET_Y + i * spacing;
+            let y = origin_y + i * spacing;
             for j in 0..BackgroundConfig::WEAVE_LINE_VARIATIONS {
                 d.draw_line(
-                    BoardConfig::OFFSET_X,
+                    origin_x,
                     y + j,
-                    BoardConfig::OFFSET_X + board_pixel_width,
+                    origin_x + board_pixel_width,
                     y + j,
                     Color::new(
                         0,
@@ -365,7 +371,7 @@ impl BackgroundRenderer {
         let max_height_distance = (board_pixel_height / 2) as f32;
 
         for x in 0..=board_width {
-            let line_x = BoardConfig::OFFSET_X + x * cell_size;
+            let line_x = origin_x + x * cell_size;
             let distance_from_center = (line_x - center_x).abs() as f32;
             let distance_ratio = distance_from_center / max_width_distance;
 
@@ -374,15 +380,15 @@ impl BackgroundRenderer {
 
             d.draw_line(
                 line_x,
-                BoardConfig::OFFSET_Y,
+                origin_y,
                 line_x,
-                BoardConfig::OFFSET_Y + board_pixel_height,
+                origin_y + board_pixel_height,
                 Color::new(0, 0, 0, alpha),
             );
         }
 
         for y in 0..=board_height {
-            let line_y = BoardConfig::OFFSET_Y + y * cell_size;
+            let line_y = origin_y + y * cell_size;
             let distance_from_center = (line_y - center_y).abs() as f32;
             let distance_ratio = distance_from_center / max_height_distance;
 
@@ -390,9 +396,9 @@ impl BackgroundRenderer {
             let alpha = (50.0 * (1.0 - distance_ratio * 0.6)) as u8;
 
             d.draw_line(
-                BoardConfig::OFFSET_X,
+                origin_x,
                 line_y,
-                BoardConfig::OFFSET_X + board_pixel_width,
+                origin_x + board_pixel_width,
                 line_y,
                 Color::new(0, 0, 0, alpha),
             );
@@ -402,19 +408,10 @@ impl BackgroundRenderer {
         let corner_glow_size = 30;
         for corner in 0..4 {
             let (corner_x, corner_y) = match corner {
-                0 => (BoardConfig::OFFSET_X, BoardConfig::OFFSET_Y), // Top-left
-                1 => (
-                    BoardConfig::OFFSET_X + board_pixel_width,
-                    BoardConfig::OFFSET_Y,
-                ), // Top-right
-                2 => (
-                    BoardConfig::OFFSET_X,
-                    BoardConfig::OFFSET_Y + board_pixel_height,
-                ), // Bottom-left
-                _ => (
-                    BoardConfig::OFFSET_X + board_pixel_width,
-                    BoardConfig::OFFSET_Y + board_pixel_height,
-                ), // Bottom-right
+                0 => (origin_x, origin_y),                      // Top-left
+                1 => (origin_x + board_pixel_width, origin_y),  // Top-right
+                2 => (origin_x, origin_y + board_pixel_height), // Bottom-left
+                _ => (origin_x + board_pixel_width, origin_y + board_pixel_height), // Bottom-right
             };
 
             for i in 0..corner_glow_size {
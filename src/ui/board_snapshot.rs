@@ -0,0 +1,138 @@
+// Low-resolution copy of the board drawn behind the pause and game over overlays, so the
+// screen still looks like the game without giving the player a readable layout to study.
+
+use crate::game::Game;
+use crate::models::PausedBoard;
+use crate::ui::atlas_card_renderer::AtlasCardRenderer;
+use crate::ui::config::{BoardConfig, BoardSnapshotConfig};
+use raylib::prelude::*;
+
+pub struct BoardSnapshot {
+    texture: Option<RenderTexture2D>,
+    captured: bool, // Whether the texture holds the current board
+}
+
+impl BoardSnapshot {
+    pub fn new() -> Self {
+        BoardSnapshot {
+            texture: None,
+            captured: false,
+        }
+    }
+
+    /// Whether the current screen draws the board from the snapshot
+    pub fn is_wanted(game: &Game) -> bool {
+        game.settings.paused_board == PausedBoard::Blurred
+            && (game.is_paused()
+                || game.is_combination_log()
+                || game.is_how_to_play()
+                || game.is_restart_confirm()
+                || game.is_forfeit_confirm()
+                || game.is_game_over()
+                || game.is_settings()
+                || game.is_accessibility())
+    }
+
+    /// Capture the board once when a blurred overlay opens, and forget it when it closes.
+    /// The board doesn't change while paused, so one capture lasts the whole overlay.
+    pub fn update(
+        &mut self,
+        rl: &mut RaylibHandle,
+        thread: &RaylibThread,
+        game: &Game,
+        card_atlas: &Texture2D,
+    ) {
+        if !Self::is_wanted(game) {
+            self.captured = false;
+            return;
+        }
+        if self.captured {
+            return;
+        }
+
+        let cell_size = (game.board.cell_size / BoardSnapshotConfig::DOWNSCALE).max(1);
+        let width = (game.board.width * cell_size) as u32;
+        let height = (game.board.height * cell_size) as u32;
+
+        // Recreate the texture if the board size changed since the last capture
+        let size_matches = self.texture.as_ref().is_some_and(|texture| {
+            texture.texture.width as u32 == width && texture.texture.height as u32 == height
+        });
+        if !size_matches {
+            self.texture = match rl.load_render_texture(thread, width, height) {
+                Ok(texture) => {
+                    // Bilinear filtering when scaled back up is what softens the cards
+                    unsafe {
+                        use raylib::ffi::{SetTextureFilter, TextureFilter};
+                        SetTextureFilter(
+                            texture.texture,
+                            TextureFilter::TEXTURE_FILTER_BILINEAR as i32,
+                        );
+                    }
+                    Some(texture)
+                }
+                Err(e) => {
+                    eprintln!("Failed to create board snapshot texture: {}", e);
+                    None
+                }
+            };
+        }
+
+        let Some(texture) = self.texture.as_mut() else {
+            return;
+        };
+
+        {
+            let mut t = rl.begin_texture_mode(thread, texture);
+            t.clear_background(Color::BLANK);
+            for y in 0..game.board.height {
+                for x in 0..game.board.width {
+                    if let Some(card) = game.board.grid[y as usize][x as usize] {
+                        AtlasCardRenderer::draw_card(
+                            &mut t,
+                            card_atlas,
+                            card,
+                            x * cell_size,
+                            y * cell_size,
+                            cell_size,
+                        );
+                    }
+                }
+            }
+        }
+        self.captured = true;
+    }
+
+    /// Draw the snapshot scaled up over the board area, if one has been captured
+    pub fn draw(&self, d: &mut RaylibDrawHandle, game: &Game) {
+        let Some(texture) = self.texture.as_ref().filter(|_| self.captured) else {
+            return;
+        };
+
+        let source_width = texture.texture.width as f32;
+        let source_height = texture.texture.height as f32;
+        // Render textures are stored upside down, so flip the source vertically
+        let source = Rectangle::new(0.0, 0.0, source_width, -source_height);
+        let (origin_x, origin_y) = BoardConfig::origin(
+            game.board.width,
+            game.board.height,
+            game.board.cell_size,
+            game.settings.mirrored_layout,
+        );
+        let dest = Rectangle::new(
+            origin_x as f32,
+            origin_y as f32,
+            (game.board.width * game.board.cell_size) as f32,
+            (game.board.height * game.board.cell_size) as f32,
+        );
+
+        d.draw_texture_pro(
+            texture,
+            source,
+            dest,
+            Vector2::zero(),
+            0.0,
+            BoardSnapshotConfig::TINT,
+        );
+    }
+}
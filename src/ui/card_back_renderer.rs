@@ -0,0 +1,83 @@
+use crate::models::CardBack;
+use crate::ui::atlas_card_renderer::CardRenderOptions;
+use crate::ui::config::CardBackConfig;
+use raylib::color::Color;
+use raylib::drawing::RaylibDraw;
+use raylib::math::{Rectangle, Vector2};
+
+/// Draws face-down cards. Options work as for atlas cards: the card rotates about its
+/// top-left corner, the tint's alpha fades the whole card and the width scale squashes it.
+pub struct CardBackRenderer;
+
+impl CardBackRenderer {
+    pub fn draw(d: &mut impl RaylibDraw, back: CardBack, options: CardRenderOptions) {
+        let (panel_color, pattern_color) = CardBackConfig::colors(back);
+        let alpha = options.tint.a;
+        let size = options.size as f32;
+        let squash = options.width_scale;
+        let corner = Vector2::new(options.x as f32, options.y as f32);
+        let rotation = options.rotation;
+        // A point on the card, given relative to its top-left corner before rotation and
+        // squashing
+        let at = |x: f32, y: f32| {
+            let (sin, cos) = rotation.to_radians().sin_cos();
+            let x = x * squash;
+            Vector2::new(corner.x + x * cos - y * sin, corner.y + x * sin + y * cos)
+        };
+
+        d.draw_rectangle_pro(
+            Rectangle::new(corner.x, corner.y, size * squash, size),
+            Vector2::zero(),
+            rotation,
+            fade(CardBackConfig::BORDER_COLOR, alpha),
+        );
+        let inset = size * CardBackConfig::BORDER_RATIO;
+        let panel_corner = at(inset, inset);
+        d.draw_rectangle_pro(
+            Rectangle::new(
+                panel_corner.x,
+                panel_corner.y,
+                (size - inset * 2.0) * squash,
+                size - inset * 2.0,
+            ),
+            Vector2::zero(),
+            rotation,
+            fade(panel_color, alpha),
+        );
+
+        let pattern_color = fade(pattern_color, alpha);
+        let center = at(size / 2.0, size / 2.0);
+        let shape_size = size * squash; // Polygons can't squash, so they shrink instead
+        match back {
+            CardBack::Classic => {
+                d.draw_poly(center, 4, shape_size * 0.3, rotation, pattern_color);
+            }
+            CardBack::Lattice => {
+                let spacing = size * 0.22;
+                for row in -1..=1 {
+                    for column in -1..=1 {
+                        let diamond = at(
+                            size / 2.0 + column as f32 * spacing,
+                            size / 2.0 + row as f32 * spacing,
+                        );
+                        d.draw_poly(diamond, 4, shape_size * 0.09, rotation, pattern_color);
+                    }
+                }
+            }
+            CardBack::Starburst => {
+                // Two squares a quarter turn apart make an eight-pointed star
+                d.draw_poly(center, 4, shape_size * 0.3, rotation, pattern_color);
+                d.draw_poly(center, 4, shape_size * 0.3, rotation + 45.0, pattern_color);
+            }
+        }
+    }
+}
+
+fn fade(color: Color, alpha: u8) -> Color {
+    Color::new(
+        color.r,
+        color.g,
+        color.b,
+        (color.a as u16 * alpha as u16 / 255) as u8,
+    )
+}
@@ -94,4 +94,42 @@ impl CardRenderer {
             CardRendererConfig::BORDER_HIGHLIGHT_COLOR,
         );
     }
+
+    /// Small round badge in the card's bottom-right corner showing what it counts for
+    pub fn draw_value_badge(
+        d: &mut RaylibDrawHandle,
+        card: Card,
+        card_x: i32,
+        card_y: i32,
+        size: i32,
+    ) {
+        let radius = (size as f32 * CardRendererConfig::BADGE_RADIUS_RATIO)
+            .max(CardRendererConfig::BADGE_MIN_RADIUS);
+        let center_x = card_x as f32 + size as f32 - radius - CardRendererConfig::BADGE_INSET;
+        let center_y = card_y as f32 + size as f32 - radius - CardRendererConfig::BADGE_INSET;
+
+        d.draw_circle(
+            center_x as i32,
+            center_y as i32,
+            radius,
+            CardRendererConfig::BADGE_COLOR,
+        );
+        d.draw_circle_lines(
+            center_x as i32,
+            center_y as i32,
+            radius,
+            CardRendererConfig::BADGE_BORDER_COLOR,
+        );
+
+        let text = card.high_value().to_string();
+        let font_size = (radius * CardRendererConfig::BADGE_TEXT_RATIO) as i32;
+        let text_width = d.measure_text(&text, font_size);
+        d.draw_text(
+            &text,
+            center_x as i32 - text_width / 2,
+            center_y as i32 - font_size / 2,
+            font_size,
+            CardRendererConfig::BADGE_TEXT_COLOR,
+        );
+    }
 }
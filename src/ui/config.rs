@@ -2,6 +2,8 @@
 ///
 /// This module provides structured configuration for UI components,
 /// improving maintainability and reducing magic numbers throughout the codebase.
+use super::theme::Theme;
+use crate::models::{CardBack, FeltColor};
 use raylib::prelude::*;
 
 /// Screen and layout configuration
@@ -40,6 +42,11 @@ impl TextConfig {
     pub const SHADOW_COLOR_2: Color = Color::new(0, 0, 0, 100);
     pub const SHADOW_COLOR_3: Color = Color::new(0, 0, 0, 50);
     pub const SUBTITLE_SHADOW_COLOR: Color = Color::new(0, 0, 0, 80);
+
+    // Option rows on the settings-style screens; labels grow with the text size setting up
+    // to the most that fits their 40px rows
+    pub const OPTION_LABEL_SIZE: f32 = 24.0;
+    pub const OPTION_LABEL_MAX_SIZE: f32 = 32.0;
 }
 
 /// Animation configuration constants
@@ -56,6 +63,111 @@ impl AnimationConfig {
     pub const RANDOMNESS: f32 = 50.0;
 }
 
+/// Table felt under the board. Each channel is `base + x * x_range + y * y_range` across
+/// the board, dimmed away from the centre, with `glow` added back on top.
+pub struct FeltShade {
+    pub base: [f32; 3],
+    pub x_range: [f32; 3],
+    pub y_range: [f32; 3],
+    pub glow: [f32; 3],
+}
+
+pub struct FeltConfig;
+
+impl FeltConfig {
+    pub const GREEN: FeltShade = FeltShade {
+        base: [20.0, 80.0, 30.0],
+        x_range: [0.0, 30.0, 10.0],
+        y_range: [15.0, 0.0, 10.0],
+        glow: [0.0, 10.0, 0.0],
+    };
+    pub const BLUE: FeltShade = FeltShade {
+        base: [15.0, 40.0, 90.0],
+        x_range: [0.0, 10.0, 30.0],
+        y_range: [10.0, 10.0, 0.0],
+        glow: [0.0, 0.0, 10.0],
+    };
+    pub const RED: FeltShade = FeltShade {
+        base: [95.0, 20.0, 25.0],
+        x_range: [30.0, 0.0, 0.0],
+        y_range: [0.0, 10.0, 10.0],
+        glow: [10.0, 0.0, 0.0],
+    };
+    pub const PURPLE: FeltShade = FeltShade {
+        base: [60.0, 25.0, 85.0],
+        x_range: [15.0, 0.0, 25.0],
+        y_range: [10.0, 10.0, 0.0],
+        glow: [5.0, 0.0, 10.0],
+    };
+
+    pub fn shade(felt: FeltColor) -> &'static FeltShade {
+        match felt {
+            FeltColor::Green => &Self::GREEN,
+            FeltColor::Blue => &Self::BLUE,
+            FeltColor::Red => &Self::RED,
+            FeltColor::Purple => &Self::PURPLE,
+        }
+    }
+}
+
+/// Card back designs, drawn with shapes since the card atlas only has faces
+pub struct CardBackConfig;
+
+impl CardBackConfig {
+    pub const BORDER_RATIO: f32 = 0.08; // White edge, as a share of the card size
+    pub const BORDER_COLOR: Color = Color::new(245, 245, 240, 255);
+
+    // (panel, pattern) for each design
+    pub const CLASSIC: (Color, Color) =
+        (Color::new(170, 30, 40, 255), Color::new(240, 200, 200, 255));
+    pub const LATTICE: (Color, Color) =
+        (Color::new(30, 60, 150, 255), Color::new(190, 210, 250, 255));
+    pub const STARBURST: (Color, Color) =
+        (Color::new(80, 30, 120, 255), Color::new(255, 215, 0, 255));
+
+    pub fn colors(back: CardBack) -> (Color, Color) {
+        match back {
+            CardBack::Classic => Self::CLASSIC,
+            CardBack::Lattice => Self::LATTICE,
+            CardBack::Starburst => Self::STARBURST,
+        }
+    }
+}
+
+/// December snow, falling behind the menus and resting on the board frame
+pub struct WinterConfig;
+
+impl WinterConfig {
+    pub const SNOWFLAKE_COUNT: usize = 90;
+    pub const MIN_FALL_SPEED: f32 = 25.0; // Pixels per second
+    pub const MAX_FALL_SPEED: f32 = 70.0;
+    pub const MIN_RADIUS: f32 = 1.5;
+    pub const MAX_RADIUS: f32 = 3.5;
+    pub const SWAY_SPEED: f32 = 1.2; // Radians per second
+    pub const SWAY_DISTANCE: f32 = 12.0;
+    pub const SNOW_COLOR: Color = Color::new(255, 255, 255, 170);
+
+    // Snow on the board frame
+    pub const CAP_RADIUS: f32 = 8.0;
+    pub const CAP_SPACING: i32 = 11;
+    pub const CAP_DEPTH: i32 = 4;
+    pub const CAP_COLOR: Color = Color::new(245, 250, 255, 255);
+}
+
+/// October pumpkins, bobbing behind the menus and sitting on the board frame
+pub struct HalloweenConfig;
+
+impl HalloweenConfig {
+    pub const PUMPKIN_COUNT: usize = 7;
+    pub const PUMPKIN_SIZE: f32 = 44.0;
+    pub const BOB_SPEED: f32 = 1.5; // Radians per second
+    pub const BOB_DISTANCE: f32 = 6.0;
+    pub const FRAME_PUMPKIN_SIZE: f32 = 40.0;
+    pub const PUMPKIN_COLOR: Color = Color::new(240, 125, 20, 255);
+    pub const RIB_COLOR: Color = Color::new(200, 90, 10, 255);
+    pub const STEM_COLOR: Color = Color::new(70, 110, 40, 255);
+}
+
 /// High score display configuration
 pub struct HighScoreConfig;
 
@@ -100,6 +212,14 @@ impl HighScoreConfig {
     pub const DIFFICULTY_SPACING: f32 = 1.0;
     pub const SCORE_SIZE: f32 = 20.0;
     pub const SCORE_SPACING: f32 = 1.0;
+
+    // Today / This Week / All Time tabs, right of the title
+    pub const TAB_SIZE: f32 = 18.0;
+    pub const TAB_SPACING: f32 = 1.0;
+    pub const TAB_GAP: f32 = 16.0;
+    pub const TAB_MARGIN: i32 = 20; // From the panel's right edge
+    pub const TAB_Y_OFFSET: i32 = 12;
+    pub const TAB_COLOR: Color = Color::new(200, 200, 200, 160);
 }
 
 /// Main menu configuration
@@ -109,12 +229,12 @@ impl MainMenuConfig {
     // Layout
     pub const BASE_X: i32 = (ScreenConfig::WIDTH - MainMenuConfig::OPTION_WIDTH) / 2;
     pub const BASE_Y: i32 = 260;
-    pub const OPTION_SPACING: i32 = 60;
+    pub const ROOM_FOR_OPTIONS: usize = 7; // Rows that fit at full spacing; longer menus pack closer
+    pub const OPTION_SPACING: i32 = 55;
     pub const OPTION_WIDTH: i32 = 240;
     pub const OPTION_HEIGHT: i32 = 45;
     pub const TEXT_X_OFFSET: i32 = 20;
-    pub const TEXT_Y_OFFSET: i32 = 12;
-    pub const INSTRUCTION_Y_OFFSET: i32 = 180;
+    pub const INSTRUCTION_Y_OFFSET: i32 = 390;
 
     // Colors
     pub const SELECTED_BG: Color = Color::new(255, 215, 0, 180);
@@ -122,13 +242,64 @@ impl MainMenuConfig {
     pub const SELECTED_TEXT_COLOR: Color = Color::new(0, 0, 0, 255);
     pub const UNSELECTED_TEXT_COLOR: Color = Color::new(240, 240, 240, 255);
     pub const BORDER_COLOR: Color = Color::new(255, 215, 0, 255);
-    pub const INSTRUCTION_COLOR: Color = Color::new(200, 200, 200, 255);
 
     // Typography
     pub const TEXT_SIZE: f32 = 28.0;
     pub const TEXT_SPACING: f32 = 1.2;
-    pub const INSTRUCTION_SIZE: f32 = 22.0;
-    pub const INSTRUCTION_SPACING: f32 = 1.0;
+
+    // Version and build stamp in the bottom right corner
+    pub const BUILD_INFO_SIZE: f32 = 16.0;
+    pub const BUILD_INFO_MARGIN: f32 = 12.0;
+    pub const BUILD_INFO_COLOR: Color = Color::new(255, 255, 255, 110);
+}
+
+/// Pause menu configuration (entries share the main menu's look)
+pub struct PauseMenuConfig;
+
+impl PauseMenuConfig {
+    pub const BASE_Y: i32 = 420;
+    pub const INSTRUCTION_Y: i32 = 730;
+
+    // Why the game paused itself, between the score and the menu
+    pub const REASON_Y: f32 = 375.0;
+    pub const REASON_WIDTH: f32 = 640.0;
+    pub const REASON_HEIGHT: f32 = 40.0;
+    pub const REASON_SIZE: f32 = 24.0;
+}
+
+/// Settings screen configuration
+pub struct SettingsConfig;
+
+impl SettingsConfig {
+    pub const OPTION_COUNT: usize = 13;
+    pub const PANEL_WIDTH: i32 = 400;
+    pub const PANEL_Y: i32 = 185;
+    pub const PANEL_HEIGHT: i32 = 550; // Room for all thirteen options
+    pub const ROW_PITCH: i32 = 40;
+}
+
+/// Menu widgets: the focus highlight on panel rows and the sliders
+pub struct WidgetConfig;
+
+impl WidgetConfig {
+    pub const ROW_INSET: i32 = 5; // Panel rows sit this far inside the panel edge
+    pub const ROW_HEIGHT: i32 = 40;
+    pub const TEXT_INSET: f32 = 10.0;
+    pub const FOCUS_BACKGROUND: Color = Color::new(255, 255, 0, 80);
+    pub const FOCUS_BORDER: Color = Color::YELLOW;
+    pub const FOCUS_TEXT_COLOR: Color = Color::YELLOW;
+    pub const DISABLED_TEXT_COLOR: Color = Color::GRAY;
+    pub const FOCUS_DISABLED_TEXT_COLOR: Color = Color::ORANGE;
+    pub const FOCUS_SLIDE_RATE: f32 = 18.0; // Higher settles faster; per second
+    pub const FOCUS_PULSE_SPEED: f32 = 4.0; // Radians per second
+    pub const FOCUS_PULSE_AMOUNT: f32 = 0.35; // How far the highlight fades at the pulse's low
+    pub const FOCUS_STALE_SECONDS: f32 = 0.25; // Longer unseen than this and it starts over
+
+    pub const SLIDER_WIDTH: f32 = 80.0;
+    pub const SLIDER_HEIGHT: f32 = 8.0;
+    pub const SLIDER_TRACK_COLOR: Color = Color::DARKGRAY;
+    pub const SLIDER_FILL_COLOR: Color = Color::GREEN;
+    pub const SLIDER_BORDER_COLOR: Color = Color::WHITE;
 }
 
 /// Instructions and controls configuration
@@ -163,28 +334,96 @@ impl InstructionsConfig {
     pub const QUIT_COLOR: Color = Color::new(255, 150, 150, 255);
     pub const RESUME_COLOR: Color = Color::new(150, 255, 150, 255);
 
+    // Hint bars along the bottom of menus and screens
+    pub const HINT_SIZE: f32 = 22.0;
+    pub const HINT_SPACING: f32 = 1.0;
+    pub const HINT_LINE_HEIGHT: f32 = 28.0;
+    pub const HINT_MAX_WIDTH: f32 = 1160.0;
+    pub const HINT_COLOR: Color = Color::new(200, 200, 200, 255);
+    pub const HINT_SEPARATOR: &str = "  |  ";
+
     // Game Over instructions
-    pub const GAME_OVER_X: f32 = 440.0;
     pub const GAME_OVER_Y: f32 = 530.0;
-    pub const GAME_OVER_X_ALT: f32 = 420.0;
     pub const GAME_OVER_SIZE: f32 = 20.0;
+    pub const GAME_OVER_CONTROLLER_Y: f32 = 720.0; // Below the on-screen keyboard
 
     // Quit confirmation
-    pub const QUIT_CONFIRM_QUIT_X: f32 = 560.0;
     pub const QUIT_CONFIRM_QUIT_Y: f32 = 400.0;
-    pub const QUIT_CONFIRM_CANCEL_X: f32 = 545.0;
     pub const QUIT_CONFIRM_CANCEL_Y: f32 = 440.0;
-    pub const QUIT_CONFIRM_CANCEL_X_ALT: f32 = 510.0;
     pub const QUIT_CONFIRM_SIZE: f32 = 24.0;
     pub const QUIT_CONFIRM_SPACING: f32 = 1.2;
+}
+
+/// New record banner configuration
+pub struct NewRecordConfig;
+
+impl NewRecordConfig {
+    // Layout
+    pub const START_Y: f32 = -100.0;
+    pub const BANNER_Y: f32 = 130.0;
+    pub const BANNER_HEIGHT: i32 = 100;
+    pub const BORDER_THICKNESS: i32 = 4;
+
+    // Animation
+    pub const SLIDE_IN_SECONDS: f32 = 0.5;
+    pub const PULSE_SPEED: f32 = 8.0;
+    pub const PULSE_AMOUNT: f32 = 4.0;
+
+    // Typography
+    pub const TITLE_SIZE: f32 = 56.0;
+    pub const RANK_SIZE: f32 = 26.0;
+
+    // Colors
+    pub const BANNER_COLOR: Color = Color::new(120, 20, 40, 230);
+    pub const BORDER_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const TEXT_COLOR: Color = Color::new(255, 215, 0, 255);
+}
+
+/// Game over zoom toward the column that reached the top
+pub struct GameOverZoomConfig;
+
+impl GameOverZoomConfig {
+    // Animation: the camera closes in over the first part, then the overlay fades up
+    pub const MAX_ZOOM: f32 = 1.6;
+    pub const ZOOM_PORTION: f32 = 0.7;
+    pub const FADE_ALPHA: u8 = 200; // Where the game over overlay takes over
 
-    // Pause instructions
-    pub const PAUSE_FORFEIT_X: f32 = 560.0;
-    pub const PAUSE_FORFEIT_Y: f32 = 420.0;
-    pub const PAUSE_RESUME_X: f32 = 555.0;
-    pub const PAUSE_RESUME_Y: f32 = 460.0;
-    pub const PAUSE_RESUME_X_ALT: f32 = 495.0;
-    pub const PAUSE_QUIT_X: f32 = 505.0;
+    // Colors
+    pub const TINT_COLOR: Color = Color::new(220, 30, 30, 120);
+}
+
+/// Initials the name filter turned away, on the game over screen
+pub struct InitialsRejectedConfig;
+
+impl InitialsRejectedConfig {
+    // Animation: the box swings side to side, settling as it goes
+    pub const SHAKE_DISTANCE: f32 = 12.0; // Pixels either way at the start
+    pub const SHAKE_CYCLES: f32 = 4.0;
+
+    // Colors
+    pub const BORDER_COLOR: Color = Color::new(230, 60, 60, 255);
+}
+
+/// On-screen keyboard configuration
+pub struct VirtualKeyboardConfig;
+
+impl VirtualKeyboardConfig {
+    // Layout
+    pub const Y: i32 = 520;
+    pub const KEY_WIDTH: i32 = 52;
+    pub const KEY_HEIGHT: i32 = 40;
+    pub const KEY_GAP: i32 = 8;
+
+    // Typography
+    pub const TEXT_SIZE: f32 = 22.0;
+    pub const SMALL_TEXT_SIZE: f32 = 16.0; // For DEL/END labels
+
+    // Colors
+    pub const KEY_COLOR: Color = Color::new(40, 40, 40, 220);
+    pub const KEY_BORDER_COLOR: Color = Color::new(120, 120, 120, 255);
+    pub const SELECTED_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const SELECTED_TEXT_COLOR: Color = Color::new(30, 30, 30, 255);
+    pub const TEXT_COLOR: Color = Color::WHITE;
 }
 
 /// Background rendering configuration
@@ -225,10 +464,16 @@ impl BackgroundConfig {
 pub struct BoardConfig;
 
 impl BoardConfig {
-    // Board positioning on screen
-    pub const OFFSET_X: i32 = 100;
+    // Top edge of the info panel, level with the top of the default 10x15 board
     pub const OFFSET_Y: i32 = 50;
 
+    // Area boards are shrunk to fit and centered in, left of the info panel.
+    // The default board fills its height and lands at (100, 50).
+    pub const PLAY_AREA_CENTER_X: i32 = 340;
+    pub const PLAY_AREA_CENTER_Y: i32 = 410;
+    pub const PLAY_AREA_WIDTH: i32 = 560;
+    pub const PLAY_AREA_HEIGHT: i32 = 720;
+
     // Board rendering
     pub const GRADIENT_STEPS: i32 = 25;
     pub const TEXTURE_COUNT: i32 = 120;
@@ -262,6 +507,130 @@ impl InfoPanelConfig {
     // Panel positioning and dimensions
     pub const X: i32 = 700;
     pub const WIDTH: i32 = 520;
+
+    // Last combination, beside the next card preview
+    pub const LAST_COMBO_X_OFFSET: i32 = 220;
+    pub const LAST_COMBO_CARD_SIZE: i32 = 32;
+    pub const LAST_COMBO_CARD_GAP: i32 = 4;
+    pub const LAST_COMBO_MAX_WIDTH: i32 = 270;
+
+    // Cards left to draw and cleared, and the 21 odds, under the next card preview
+    pub const DECK_COUNT_Y_OFFSET: i32 = 310;
+    pub const DECK_COUNT_SIZE: f32 = 18.0;
+    pub const DECK_COUNT_MAX_WIDTH: f32 = 190.0;
+    pub const DRAW_ODDS_Y_OFFSET: i32 = 330;
+
+    // Widest the info panel texts may grow at larger text sizes before they shrink to fit
+    pub const DIFFICULTY_MAX_WIDTH: f32 = 260.0;
+    pub const SPEED_MAX_WIDTH: f32 = 170.0;
+    pub const SCORE_MAX_WIDTH: f32 = 460.0;
+    pub const NEXT_LABEL_MAX_WIDTH: f32 = 180.0;
+
+    // Personal best under the score, and the "New PB!" banner beside it once it's passed
+    pub const BEST_SIZE: f32 = 18.0;
+    pub const BEST_MAX_WIDTH: f32 = 190.0;
+    pub const PB_BANNER_PADDING: f32 = 6.0;
+    pub const PB_BANNER_FLASHES: f32 = 5.0; // Blinks over the banner's time on screen
+    pub const PB_BANNER_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const PB_BANNER_TEXT_COLOR: Color = Color::new(40, 30, 0, 255);
+
+    // Adaptive speed arrow beside the speed level, kept faint so it doesn't draw the eye
+    pub const PACE_ARROW_SIZE: f32 = 10.0;
+    pub const PACE_ARROW_COLOR: Color = Color::new(255, 255, 255, 110);
+}
+
+/// Color tables for the UI themes
+pub struct ThemeConfig;
+
+impl ThemeConfig {
+    pub const STANDARD: Theme = Theme {
+        panel_color: Color::new(40, 40, 60, 200),
+        panel_border_color: Color::WHITE,
+        panel_border_thickness: 1.0,
+        solid_panels: false,
+        text_color: Color::WHITE,
+        highlight_text_color: Color::new(255, 215, 0, 255), // Gold
+        bold_text: false,
+        card_outline_color: Color::BLANK,
+        card_outline_thickness: 0.0,
+    };
+
+    pub const HIGH_CONTRAST: Theme = Theme {
+        panel_color: Color::BLACK,
+        panel_border_color: Color::WHITE,
+        panel_border_thickness: 3.0,
+        solid_panels: true,
+        text_color: Color::WHITE,
+        highlight_text_color: Color::WHITE,
+        bold_text: true,
+        card_outline_color: Color::WHITE,
+        card_outline_thickness: 3.0,
+    };
+}
+
+/// Streamer mode sizes, picked to stay readable in a downscaled stream
+pub struct StreamerConfig;
+
+impl StreamerConfig {
+    pub const SCORE_SIZE: f32 = 42.0; // Up from 30
+    pub const NEXT_CARD_SCALE: f32 = 1.5; // Of the board's cell size
+    pub const HIDDEN_INITIALS: &'static str = "***";
+}
+
+impl BoardConfig {
+    /// Top-left corner of a board, centered in the play area
+    pub const fn origin(
+        board_width: i32,
+        board_height: i32,
+        cell_size: i32,
+        mirrored: bool,
+    ) -> (i32, i32) {
+        (
+            Self::play_area_center_x(mirrored) - board_width * cell_size / 2,
+            Self::PLAY_AREA_CENTER_Y - board_height * cell_size / 2,
+        )
+    }
+
+    /// The mirrored layout moves the play area to the right half of the screen
+    pub const fn play_area_center_x(mirrored: bool) -> i32 {
+        if mirrored {
+            ScreenConfig::WIDTH - Self::PLAY_AREA_CENTER_X
+        } else {
+            Self::PLAY_AREA_CENTER_X
+        }
+    }
+}
+
+impl InfoPanelConfig {
+    /// Left edge of the info panel; the mirrored layout moves it to the left of the screen
+    pub const fn x(mirrored: bool) -> i32 {
+        if mirrored {
+            ScreenConfig::WIDTH - Self::X - Self::WIDTH
+        } else {
+            Self::X
+        }
+    }
+}
+
+impl TouchConfig {
+    /// The pause button, kept at the same spot within the info panel in either layout
+    pub const fn pause_button(mirrored: bool) -> (i32, i32, i32, i32) {
+        let (x, y, width, height) = Self::PAUSE_BUTTON;
+        (
+            x - InfoPanelConfig::X + InfoPanelConfig::x(mirrored),
+            y,
+            width,
+            height,
+        )
+    }
+}
+
+/// Blurred board snapshot configuration
+pub struct BoardSnapshotConfig;
+
+impl BoardSnapshotConfig {
+    pub const DOWNSCALE: i32 = 8; // Snapshot cells are this many times smaller than board cells
+    pub const TINT: Color = Color::new(255, 255, 255, 220);
 }
 
 /// Particle system configuration
@@ -306,6 +675,63 @@ impl ParticleConfig {
     ];
     pub const COLOR_YELLOW: Color = Color::YELLOW;
     pub const COLOR_BLACK: Color = Color::new(30, 30, 30, 255);
+
+    // Confetti burst for new records
+    pub const CONFETTI_COUNT: usize = 90;
+    pub const CONFETTI_LIFE: f32 = 2.2;
+    pub const CONFETTI_LIFE_VARIATION: f32 = 0.08;
+    pub const CONFETTI_SIZE: f32 = 4.0;
+    pub const CONFETTI_MIN_SPEED: f32 = 220.0;
+    pub const CONFETTI_SPEED_RANGE: f32 = 260.0;
+    pub const CONFETTI_SPREAD: f32 = 2.2; // Radians of the upward fan
+    pub const CONFETTI_ACCELERATION_Y: f32 = 320.0;
+    pub const CONFETTI_COLORS: [Color; 6] = [
+        Color::new(255, 215, 0, 255),   // Gold
+        Color::new(255, 80, 80, 255),   // Red
+        Color::new(80, 200, 255, 255),  // Blue
+        Color::new(120, 255, 120, 255), // Green
+        Color::new(255, 120, 220, 255), // Pink
+        Color::WHITE,
+    ];
+
+    // Golden ring for natural blackjacks
+    pub const GOLDEN_COUNT: usize = 48;
+    pub const GOLDEN_LIFE: f32 = 1.2;
+    pub const GOLDEN_SIZE: f32 = 5.0;
+    pub const GOLDEN_MIN_SPEED: f32 = 160.0;
+    pub const GOLDEN_SPEED_RANGE: f32 = 140.0;
+    pub const GOLDEN_ACCELERATION_Y: f32 = 120.0;
+    pub const GOLDEN_COLORS: [Color; 3] = [
+        Color::new(255, 215, 0, 255),   // Gold
+        Color::new(255, 240, 150, 255), // Pale gold
+        Color::new(255, 180, 40, 255),  // Amber
+    ];
+
+    // Dust kicked up when a card moved by gravity lands
+    pub const DUST_COUNT: usize = 10;
+    pub const DUST_LIFE: f32 = 0.35;
+    pub const DUST_SIZE: f32 = 2.5;
+    pub const DUST_MIN_SPEED: f32 = 30.0;
+    pub const DUST_SPEED_RANGE: f32 = 40.0;
+    pub const DUST_UPWARD_SPEED: f32 = -25.0;
+    pub const DUST_ACCELERATION_Y: f32 = 90.0;
+    pub const DUST_COLOR: Color = Color::new(210, 200, 180, 200);
+
+    // Reduced motion: a single glow that fades in place instead of a burst
+    pub const FADE_LIFE: f32 = 0.5;
+    pub const FADE_SIZE_RATIO: f32 = 0.4; // Of the exploding card's size
+    pub const CONFETTI_FADE_SIZE: f32 = 60.0;
+    pub const GOLDEN_FADE_SIZE: f32 = 50.0;
+}
+
+/// Controller analog input configuration
+pub struct ControllerConfig;
+
+impl ControllerConfig {
+    pub const DEFAULT_STICK_DEAD_ZONE: f32 = 0.3;
+    pub const MAX_STICK_DEAD_ZONE: f32 = 0.9;
+    pub const SOFT_DROP_SLOWEST_MS: u64 = 250; // Step time just past the dead zone
+    pub const MOVE_REPEAT_MS: u64 = 150; // Between sideways steps while a direction is held
 }
 
 /// Performance optimization constants
@@ -339,6 +765,440 @@ impl FPSConfig {
     pub const MEDIUM_FPS_THRESHOLD: f32 = 30.0;
 }
 
+/// Diagnostics screen configuration
+pub struct DiagnosticsConfig;
+
+impl DiagnosticsConfig {
+    // Layout
+    pub const TITLE_Y: f32 = 50.0;
+    pub const TITLE_SIZE: f32 = 60.0;
+    pub const CONTENT_Y: i32 = 160;
+    pub const LEFT_COLUMN_X: i32 = 100;
+    pub const RIGHT_COLUMN_X: i32 = 680;
+    pub const VALUE_OFFSET_X: i32 = 190;
+    pub const SECTION_SIZE: f32 = 30.0;
+    pub const TEXT_SIZE: f32 = 20.0;
+    pub const LINE_HEIGHT: i32 = 28;
+    pub const SECTION_GAP: i32 = 24;
+    pub const MAX_VALUE_CHARS: usize = 36; // Longer values (paths) keep their tail
+    pub const INSTRUCTIONS_Y: i32 = 740;
+
+    // Colors
+    pub const PANEL_COLOR: Color = Color::new(0, 0, 0, 150);
+    pub const SECTION_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const LABEL_COLOR: Color = Color::new(220, 220, 220, 255);
+    pub const OK_COLOR: Color = Color::new(120, 230, 120, 255);
+    pub const PROBLEM_COLOR: Color = Color::new(255, 110, 110, 255);
+}
+
+/// How to Play screen (opened from the pause menu) configuration
+pub struct HowToPlayConfig;
+
+impl HowToPlayConfig {
+    // Layout
+    pub const TITLE_Y: f32 = 60.0;
+    pub const TITLE_SIZE: f32 = 60.0;
+    pub const TEXT_X: f32 = 200.0;
+    pub const RULES_Y: i32 = 180;
+    pub const LINE_SPACING: i32 = 40;
+    pub const SECTION_GAP: i32 = 30;
+    pub const TEXT_SIZE: f32 = 24.0;
+    pub const HEADING_SIZE: f32 = 30.0;
+    pub const INSTRUCTIONS_Y: i32 = 740;
+
+    // Colors
+    pub const OVERLAY_ALPHA: u8 = 220;
+    pub const HEADING_COLOR: Color = Color::new(255, 215, 0, 255);
+}
+
+/// Touchscreen controls configuration
+pub struct TouchConfig;
+
+impl TouchConfig {
+    // Gesture thresholds
+    pub const TAP_MAX_DISTANCE: f32 = 20.0; // Finger movement still counted as a tap
+    pub const TAP_MAX_MS: u64 = 300;
+    pub const SWIPE_MIN_DISTANCE: f32 = 60.0;
+
+    // Pause button (x, y, width, height), bottom of the info panel in the standard layout
+    pub const PAUSE_BUTTON: (i32, i32, i32, i32) = (730, 700, 160, 60);
+    pub const BUTTON_TEXT_SIZE: f32 = 28.0;
+    pub const BUTTON_COLOR: Color = Color::new(0, 0, 0, 140);
+    pub const BUTTON_BORDER_COLOR: Color = Color::new(255, 255, 255, 200);
+}
+
+/// Combination log (pause screen history) configuration
+pub struct CombinationLogConfig;
+
+impl CombinationLogConfig {
+    // Layout
+    pub const TITLE_Y: f32 = 50.0;
+    pub const TITLE_SIZE: f32 = 60.0;
+    pub const SUMMARY_Y: i32 = 130;
+    pub const LIST_X: i32 = 240;
+    pub const LIST_Y: i32 = 180;
+    pub const VISIBLE_ROWS: usize = 11;
+    pub const ROW_HEIGHT: i32 = 48;
+    pub const CARD_SIZE: i32 = 36;
+    pub const CARD_GAP: i32 = 4;
+    pub const NUMBER_WIDTH: i32 = 60;
+    pub const VALUE_X: i32 = 420; // Columns after the cards, relative to LIST_X
+    pub const POINTS_X: i32 = 540;
+    pub const CHAIN_X: i32 = 660;
+    pub const TEXT_SIZE: f32 = 22.0;
+    pub const INSTRUCTIONS_Y: i32 = 740;
+
+    // Colors
+    pub const OVERLAY_ALPHA: u8 = 220;
+    pub const ROW_COLOR: Color = Color::new(255, 255, 255, 20);
+    pub const NUMBER_COLOR: Color = Color::new(180, 180, 180, 255);
+    pub const VALUE_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const POINTS_COLOR: Color = Color::new(120, 230, 120, 255);
+    pub const CHAIN_COLOR: Color = Color::new(255, 150, 200, 255);
+}
+
+/// First-run setup wizard configuration
+pub struct SetupWizardConfig;
+
+impl SetupWizardConfig {
+    // Layout
+    pub const TITLE_Y: f32 = 140.0;
+    pub const TITLE_SIZE: f32 = 64.0;
+    pub const STEP_Y: f32 = 260.0;
+    pub const STEP_SIZE: f32 = 28.0;
+    pub const PANEL_WIDTH: i32 = 560;
+    pub const PANEL_Y: i32 = 320;
+    pub const PANEL_HEIGHT: i32 = 220;
+    pub const ROW_Y: i32 = 370;
+    pub const ROW_SPACING: i32 = 60;
+    pub const ROW_HEIGHT: i32 = 44;
+    pub const VALUE_SIZE: f32 = 32.0;
+    pub const HINT_Y: i32 = 500;
+    pub const HINT_SIZE: f32 = 20.0;
+    pub const SLIDER_WIDTH: i32 = 160;
+    pub const SLIDER_HEIGHT: i32 = 10;
+    pub const INSTRUCTIONS_Y: i32 = 620;
+    pub const OVERLAY_ALPHA: u8 = 170;
+
+    // Colors
+    pub const PANEL_COLOR: Color = Color::new(40, 40, 60, 220);
+    pub const SELECTED_COLOR: Color = Color::new(255, 255, 0, 80);
+    pub const STEP_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const HINT_COLOR: Color = Color::new(200, 200, 200, 255);
+}
+
+/// Progress bar shown while startup assets load
+pub struct LoadingConfig;
+
+impl LoadingConfig {
+    // Layout
+    pub const BAR_WIDTH: i32 = 600;
+    pub const BAR_HEIGHT: i32 = 24;
+    pub const BAR_Y: i32 = 380;
+    pub const LABEL_GAP: i32 = 36; // Label baseline above the bar
+    pub const LIST_GAP: i32 = 30; // Missing assets list below the bar
+    pub const LINE_HEIGHT: i32 = 26;
+    pub const TEXT_SIZE: i32 = 20;
+
+    // Colors
+    pub const BACKGROUND_COLOR: Color = Color::new(10, 60, 30, 255);
+    pub const TEXT_COLOR: Color = Color::new(230, 230, 230, 255);
+    pub const BAR_BACKGROUND_COLOR: Color = Color::new(0, 0, 0, 120);
+    pub const BAR_FILL_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const BAR_BORDER_COLOR: Color = Color::new(255, 255, 255, 160);
+    pub const MISSING_COLOR: Color = Color::new(255, 110, 110, 255);
+    pub const NOTE_COLOR: Color = Color::new(180, 180, 180, 255);
+}
+
+/// Toast notification configuration
+pub struct ToastConfig;
+
+impl ToastConfig {
+    // Layout
+    pub const Y: i32 = 20;
+    pub const PADDING: i32 = 12;
+    pub const SPACING: i32 = 8;
+    pub const FONT_SIZE: f32 = 20.0;
+    pub const MAX_VISIBLE: usize = 3;
+
+    // Timing
+    pub const LIFETIME_MS: u64 = 4000;
+    pub const FADE_MS: u64 = 600;
+
+    // Colors
+    pub const BACKGROUND_COLOR: Color = Color::new(120, 20, 20, 220);
+    pub const BORDER_COLOR: Color = Color::new(255, 120, 120, 255);
+    pub const TEXT_COLOR: Color = Color::new(255, 255, 255, 255);
+}
+
+/// Next card preview turning over when a new card is drawn
+pub struct NextCardRevealConfig;
+
+impl NextCardRevealConfig {
+    pub const START_SCALE: f32 = 0.85; // Of the preview's size, growing to full as it turns
+    pub const START_TILT: f32 = -8.0; // Degrees, straightening as it turns
+}
+
+/// Ambient light on the board frame and info panel during play
+pub struct AmbientGlowConfig;
+
+impl AmbientGlowConfig {
+    // Frame glow
+    pub const FRAME_OUTSET: i32 = 4; // Runs along the frame, just clear of the cards
+    pub const FRAME_LAP_SECONDS: f32 = 24.0;
+    pub const GLOW_RADIUS: f32 = 14.0;
+    pub const TAIL_STEPS: usize = 8;
+    pub const TAIL_SPACING: f32 = 0.004; // Of a lap between the tail's glows
+    pub const GLOW_COLOR: Color = Color::new(255, 225, 150, 70);
+
+    // Panel shine
+    pub const SHINE_INTERVAL_SECONDS: f32 = 9.0;
+    pub const SHINE_SECONDS: f32 = 1.6;
+    pub const SHINE_WIDTH: f32 = 120.0;
+    pub const SHINE_COLOR: Color = Color::new(255, 255, 255, 22);
+
+    // Both cycles line up again after this long, so the clock can wrap without a jump
+    pub const WRAP_SECONDS: f32 = 72.0;
+}
+
+/// Points that rise from a cleared combination
+pub struct ScorePopupConfig;
+
+impl ScorePopupConfig {
+    // Layout
+    pub const POINTS_SIZE: f32 = 30.0;
+    pub const BREAKDOWN_SIZE: f32 = 18.0;
+    pub const LINE_GAP: f32 = 2.0;
+    pub const RISE: f32 = 60.0; // Pixels travelled over the popup's life
+    pub const MAX_VISIBLE: usize = 6;
+
+    // Timing
+    pub const LIFETIME_MS: u64 = 1200;
+    pub const FADE_MS: u64 = 400;
+
+    // Colors
+    pub const POINTS_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const BREAKDOWN_COLOR: Color = Color::new(255, 255, 255, 230);
+    pub const SHADOW_COLOR: Color = Color::new(0, 0, 0, 160);
+}
+
+/// Game over replay of the final boards
+pub struct ReplayConfig;
+
+impl ReplayConfig {
+    // Caption bar along the bottom while replaying
+    pub const BAR_Y: i32 = 720;
+    pub const BAR_HEIGHT: i32 = 70;
+    pub const TITLE_SIZE: f32 = 26.0;
+    pub const HINT_SIZE: f32 = 18.0;
+    pub const BAR_COLOR: Color = Color::new(0, 0, 0, 190);
+    pub const TITLE_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const HINT_COLOR: Color = Color::LIGHTGRAY;
+
+    // "Replay" hint on the game over screen, under the initials instructions
+    pub const HINT_Y: f32 = 565.0;
+    pub const HINT_CONTROLLER_Y: f32 = 750.0; // Below the on-screen keyboard instructions
+}
+
+/// Statistics screen: lifetime run counters and where cards get dropped
+pub struct StatisticsConfig;
+
+impl StatisticsConfig {
+    // Layout
+    pub const TITLE_Y: f32 = 60.0;
+    pub const TITLE_SIZE: f32 = 60.0;
+    pub const COUNTERS_Y: i32 = 160;
+    pub const TEXT_SIZE: f32 = 24.0;
+    pub const LABEL_SIZE: f32 = 18.0;
+    pub const CHART_Y: i32 = 230;
+    pub const CHART_WIDTH: i32 = 560;
+    pub const CHART_HEIGHT: i32 = 380;
+    pub const LABEL_GAP: i32 = 10; // Between the chart and the per-column counts
+    pub const LABEL_LINE_HEIGHT: i32 = 24;
+    pub const BAR_INSET: i32 = 6;
+    pub const INSTRUCTIONS_Y: i32 = 740;
+
+    // Colors
+    pub const OVERLAY_ALPHA: u8 = 220;
+    pub const CHART_BG: Color = Color::new(20, 20, 35, 230);
+    pub const GRID_COLOR: Color = Color::new(255, 255, 255, 30);
+    pub const BAR_COLOR: Color = Color::new(255, 215, 0, 200);
+    pub const HEAT_COLD: Color = Color::new(40, 80, 200, 170);
+    pub const HEAT_HOT: Color = Color::new(230, 50, 40, 210);
+}
+
+/// Mods & Themes screen: installed scripts and packs with their on/off switches
+pub struct ModsScreenConfig;
+
+impl ModsScreenConfig {
+    // Layout
+    pub const TITLE_Y: f32 = 60.0;
+    pub const TITLE_SIZE: f32 = 60.0;
+    pub const LIST_X: i32 = 200;
+    pub const LIST_Y: i32 = 170;
+    pub const LIST_WIDTH: i32 = 880;
+    pub const VISIBLE_ROWS: usize = 10;
+    pub const ROW_HEIGHT: i32 = 48;
+    pub const TEXT_X_OFFSET: i32 = 16;
+    pub const TEXT_Y_OFFSET: i32 = 12;
+    pub const KIND_X: i32 = 700; // Relative to LIST_X
+    pub const TEXT_SIZE: f32 = 22.0;
+    pub const HINT_Y: i32 = 690;
+    pub const INSTRUCTIONS_Y: i32 = 740;
+
+    // Colors
+    pub const OVERLAY_ALPHA: u8 = 220;
+    pub const ROW_COLOR: Color = Color::new(255, 255, 255, 20);
+    pub const SELECTED_COLOR: Color = Color::new(255, 255, 0, 80);
+    pub const ON_COLOR: Color = Color::new(120, 230, 120, 255);
+    pub const OFF_COLOR: Color = Color::new(160, 160, 160, 255);
+    pub const KIND_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const HINT_COLOR: Color = Color::new(200, 200, 200, 255);
+}
+
+/// Puzzle select screen: the bundled levels with their best star ratings
+pub struct PuzzleSelectConfig;
+
+impl PuzzleSelectConfig {
+    // Layout
+    pub const TITLE_Y: f32 = 60.0;
+    pub const TITLE_SIZE: f32 = 60.0;
+    pub const LIST_X: i32 = 240;
+    pub const LIST_Y: i32 = 170;
+    pub const LIST_WIDTH: i32 = 800;
+    pub const VISIBLE_ROWS: usize = 10;
+    pub const ROW_HEIGHT: i32 = 48;
+    pub const TEXT_X_OFFSET: i32 = 16;
+    pub const TEXT_Y_OFFSET: i32 = 12;
+    pub const DETAILS_X: i32 = 420; // Difficulty and par, relative to LIST_X
+    pub const STARS_X: i32 = 660; // Centre of the first star, relative to LIST_X
+    pub const STAR_RADIUS: f32 = 13.0;
+    pub const STAR_SPACING: f32 = 34.0;
+    pub const TEXT_SIZE: f32 = 22.0;
+    pub const HINT_Y: i32 = 690;
+    pub const INSTRUCTIONS_Y: i32 = 740;
+
+    // Colors
+    pub const OVERLAY_ALPHA: u8 = 220;
+    pub const ROW_COLOR: Color = Color::new(255, 255, 255, 20);
+    pub const SELECTED_COLOR: Color = Color::new(255, 255, 0, 80);
+    pub const DETAILS_COLOR: Color = Color::new(200, 200, 200, 255);
+    pub const STAR_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const EMPTY_STAR_COLOR: Color = Color::new(255, 255, 255, 45);
+    pub const HINT_COLOR: Color = Color::new(200, 200, 200, 255);
+}
+
+/// Level editor: the draft board on the left, its settings, brush and queue on the right
+pub struct LevelEditorConfig;
+
+impl LevelEditorConfig {
+    // Layout
+    pub const BOARD_X: i32 = 80;
+    pub const BOARD_Y: i32 = 40;
+    pub const CELL_SIZE: i32 = 48;
+    pub const PANEL_X: i32 = 620;
+    pub const TEXT_SIZE: f32 = 22.0;
+    pub const LINE_HEIGHT: f32 = 32.0;
+    pub const BRUSH_Y: i32 = 150;
+    pub const BRUSH_X_OFFSET: i32 = 90; // From PANEL_X, leaving room for the label
+    pub const BRUSH_SIZE: i32 = 72;
+    pub const QUEUE_Y: i32 = 250;
+    pub const QUEUE_CARD_SIZE: i32 = 44;
+    pub const QUEUE_GAP: i32 = 6;
+    pub const QUEUE_PER_ROW: usize = 10;
+    pub const CONTROLS_Y: i32 = 480;
+    pub const CONTROLS_SIZE: f32 = 18.0;
+    pub const CONTROLS_LINE_HEIGHT: f32 = 30.0;
+
+    // Colors
+    pub const OVERLAY_ALPHA: u8 = 230;
+    pub const BOARD_COLOR: Color = Color::new(0, 0, 0, 120);
+    pub const COLUMN_COLOR: Color = Color::new(255, 255, 0, 50);
+    pub const BORDER_COLOR: Color = Color::new(255, 255, 255, 90);
+    pub const DETAILS_COLOR: Color = Color::new(200, 200, 200, 255);
+    pub const CONTROLS_COLOR: Color = Color::new(170, 170, 170, 255);
+}
+
+/// Run seeds: the Play from Seed screen, and the seed in the corner of the game over screen
+pub struct SeedConfig;
+
+impl SeedConfig {
+    // Play from Seed layout
+    pub const TITLE_Y: f32 = 200.0;
+    pub const TITLE_SIZE: f32 = 56.0;
+    pub const PROMPT_Y: f32 = 290.0;
+    pub const PROMPT_SIZE: f32 = 24.0;
+    pub const BOX_Y: i32 = 340;
+    pub const BOX_WIDTH: i32 = 300;
+    pub const BOX_HEIGHT: i32 = 70;
+    pub const CODE_SIZE: f32 = 44.0;
+    pub const DIFFICULTY_Y: f32 = 430.0;
+    pub const HINT_SIZE: f32 = 20.0;
+    pub const HINT_Y: f32 = 480.0;
+    pub const HINT_CONTROLLER_Y: f32 = 740.0; // Below the on-screen keyboard
+
+    // Game over corner
+    pub const GAME_OVER_MARGIN: f32 = 20.0;
+    pub const GAME_OVER_SIZE: f32 = 20.0;
+
+    // Colors
+    pub const PROMPT_COLOR: Color = Color::new(200, 200, 200, 255);
+    pub const HINT_COLOR: Color = Color::LIGHTGRAY;
+    pub const GAME_OVER_COLOR: Color = Color::new(255, 255, 255, 170);
+}
+
+/// Running sum shown above the column the current card is over
+pub struct ColumnSumConfig;
+
+impl ColumnSumConfig {
+    // Layout, measured up from the top of the board
+    pub const BOTTOM_GAP: i32 = 22;
+    pub const HEIGHT: i32 = 24;
+    pub const PADDING: i32 = 8;
+    pub const FONT_SIZE: i32 = 18;
+
+    // Colors
+    pub const BACKGROUND_COLOR: Color = Color::new(20, 20, 20, 200);
+    pub const TEXT_COLOR: Color = Color::new(255, 255, 255, 255);
+    pub const TWENTY_ONE_COLOR: Color = Color::new(80, 220, 100, 255); // The drop completes 21
+    pub const BUST_COLOR: Color = Color::new(230, 80, 80, 255);
+}
+
+/// Cards of the next combination to explode flash together in the moment before they go
+pub struct ExplosionFlashConfig;
+
+impl ExplosionFlashConfig {
+    pub const COLOR: Color = Color::WHITE;
+    pub const MAX_ALPHA: f32 = 0.7;
+    pub const CYCLES: f32 = 2.5; // Flashes over one combination delay, ending on a peak
+    pub const STEADY_ALPHA: f32 = 0.4; // With reduced motion the cards light up without flashing
+}
+
+/// Gray cover over cards locked by the bust rule, with the seconds left until they unlock
+pub struct LockedCardConfig;
+
+impl LockedCardConfig {
+    pub const FONT_SIZE: i32 = 22;
+    pub const COVER_COLOR: Color = Color::new(90, 90, 90, 200);
+    pub const BORDER_COLOR: Color = Color::new(160, 160, 160, 255);
+    pub const TEXT_COLOR: Color = Color::new(235, 235, 235, 255);
+}
+
+/// Debug overlay of the combination search (F7)
+pub struct SearchTraceConfig;
+
+impl SearchTraceConfig {
+    pub const VISITED_COLOR: Color = Color::new(80, 160, 255, 60); // Cards the search stepped on
+    pub const PATH_COLOR: Color = Color::new(255, 220, 60, 230); // The path being explored
+    pub const FOUND_COLOR: Color = Color::new(60, 230, 120, 230); // 21s found so far
+    pub const PATH_THICKNESS: f32 = 5.0;
+    pub const FOUND_THICKNESS: f32 = 3.0;
+    pub const HEAD_RADIUS: f32 = 9.0; // Dot on the card the path is exploring from
+    pub const CAPTION_SIZE: i32 = 18;
+    pub const CAPTION_GAP: i32 = 24; // Caption sits this far above the board
+    pub const CAPTION_COLOR: Color = Color::new(255, 255, 255, 230);
+}
+
 /// Fallback card renderer configuration (when atlas is not available)
 pub struct CardRendererConfig;
 
@@ -365,4 +1225,13 @@ impl CardRendererConfig {
     pub const TOP_HIGHLIGHT_HEIGHT: i32 = 3;
     pub const LEFT_HIGHLIGHT_WIDTH: i32 = 2;
     pub const BORDER_THICKNESS: i32 = 2;
+
+    // Card value badge
+    pub const BADGE_RADIUS_RATIO: f32 = 0.17;
+    pub const BADGE_MIN_RADIUS: f32 = 9.0;
+    pub const BADGE_INSET: f32 = 3.0;
+    pub const BADGE_TEXT_RATIO: f32 = 1.2; // Font size relative to the radius
+    pub const BADGE_COLOR: Color = Color::new(20, 20, 20, 220);
+    pub const BADGE_BORDER_COLOR: Color = Color::new(255, 215, 0, 255);
+    pub const BADGE_TEXT_COLOR: Color = Color::WHITE;
 }
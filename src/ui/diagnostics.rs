@@ -0,0 +1,221 @@
+// Snapshot of runtime health for the diagnostics screen: audio, graphics assets and storage.
+// Everything here used to only be printed to stdout at startup.
+
+use super::FontCollection;
+use crate::audio::AudioSystem;
+use crate::game::Game;
+use std::path::Path;
+
+pub struct DiagnosticsLine {
+    pub label: String,
+    pub value: String,
+    pub ok: bool, // Drawn as healthy (true) or as a problem (false)
+}
+
+pub struct DiagnosticsSection {
+    pub title: &'static str,
+    pub lines: Vec<DiagnosticsLine>,
+}
+
+pub struct DiagnosticsReport {
+    pub sections: Vec<DiagnosticsSection>,
+}
+
+impl DiagnosticsLine {
+    fn new(label: impl Into<String>, value: impl Into<String>, ok: bool) -> Self {
+        DiagnosticsLine {
+            label: label.into(),
+            value: value.into(),
+            ok,
+        }
+    }
+}
+
+impl DiagnosticsReport {
+    pub fn collect(game: &Game, audio: &AudioSystem, fonts: &[&FontCollection]) -> Self {
+        DiagnosticsReport {
+            sections: vec![
+                Self::audio_section(audio),
+                Self::graphics_section(fonts),
+                Self::storage_section(game.database_path.as_deref()),
+            ],
+        }
+    }
+
+    fn audio_section(audio: &AudioSystem) -> DiagnosticsSection {
+        let device_ok = audio.has_output_device();
+        let fallback_ok = audio.has_fallback_sound();
+        let (loaded, total) = audio.get_audio_stats();
+
+        let mut lines = vec![
+            DiagnosticsLine::new(
+                "Output device",
+                match audio.output_device_name() {
+                    Some(name) => name,
+                    None if device_ok => "OK",
+                    None => "Unavailable",
+                },
+                device_ok,
+            ),
+            DiagnosticsLine::new(
+                "Fallback sound",
+                if fallback_ok { "click.ogg" } else { "Missing" },
+                fallback_ok,
+            ),
+            DiagnosticsLine::new(
+                "Event sounds",
+                format!("{}/{} loaded", loaded, total),
+                loaded == total,
+            ),
+        ];
+
+        // Missing files play the fallback click, so list them rather than the loaded ones
+        for (path, _) in audio
+            .sound_file_status()
+            .into_iter()
+            .filter(|(_, loaded)| !loaded)
+        {
+            let file_name = Path::new(&path)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or(path);
+            lines.push(DiagnosticsLine::new("  Missing", file_name, false));
+        }
+
+        DiagnosticsSection {
+            title: "Audio",
+            lines,
+        }
+    }
+
+    fn graphics_section(fonts: &[&FontCollection]) -> DiagnosticsSection {
+        let lines: Vec<DiagnosticsLine> = fonts
+            .iter()
+            .map(|font| {
+                let name = Path::new(&font.path)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| font.path.clone());
+                let value = if font.sdf {
+                    "Distance field"
+                } else {
+                    "Bitmap fallback"
+                };
+                DiagnosticsLine::new(name, value, font.sdf)
+            })
+            .collect();
+
+        DiagnosticsSection {
+            title: "Graphics",
+            lines,
+        }
+    }
+
+    fn storage_section(database_path: Option<&Path>) -> DiagnosticsSection {
+        let Some(path) = database_path else {
+            return DiagnosticsSection {
+                title: "Storage",
+                lines: vec![DiagnosticsLine::new(
+                    "High scores",
+                    "Not stored in SQLite",
+                    true,
+                )],
+            };
+        };
+
+        let mut lines = vec![DiagnosticsLine::new(
+            "Database",
+            path.display().to_string(),
+            path.exists(),
+        )];
+
+        match std::fs::metadata(path) {
+            Ok(metadata) => {
+                lines.push(DiagnosticsLine::new(
+                    "Database size",
+                    format_bytes(metadata.len()),
+                    true,
+                ));
+            }
+            Err(e) => lines.push(DiagnosticsLine::new("Database size", e.to_string(), false)),
+        }
+
+        if let Some(data_dir) = path.parent() {
+            match directory_size(data_dir) {
+                Ok(size) => lines.push(DiagnosticsLine::new(
+                    "Data dir usage",
+                    format_bytes(size),
+                    true,
+                )),
+                Err(e) => lines.push(DiagnosticsLine::new("Data dir usage", e.to_string(), false)),
+            }
+        }
+
+        DiagnosticsSection {
+            title: "Storage",
+            lines,
+        }
+    }
+}
+
+/// Total size of all files under a directory, recursively
+pub fn directory_size(path: &Path) -> std::io::Result<u64> {
+    let mut total = 0;
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            total += directory_size(&entry.path())?;
+        } else {
+            total += metadata.len();
+        }
+    }
+    Ok(total)
+}
+
+/// Human readable byte count, e.g. "512 B" or "1.5 KB"
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+    }
+
+    #[test]
+    fn test_directory_size_is_recursive() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        std::fs::write(temp_dir.path().join("a.bin"), vec![0u8; 100]).unwrap();
+        std::fs::create_dir(temp_dir.path().join("nested")).unwrap();
+        std::fs::write(temp_dir.path().join("nested/b.bin"), vec![0u8; 50]).unwrap();
+
+        assert_eq!(directory_size(temp_dir.path()).unwrap(), 150);
+    }
+
+    #[test]
+    fn test_storage_section_without_database() {
+        let section = DiagnosticsReport::storage_section(None);
+        assert_eq!(section.lines.len(), 1);
+        assert!(section.lines[0].ok);
+    }
+}
@@ -1,8 +1,9 @@
-use crate::game::Game;
-use crate::models::Card;
+use crate::game::{ForfeitOption, Game, PauseMenuOption};
+use crate::models::{Card, FeltColor, VirtualKeyboard};
 use crate::ui::background_renderer::BackgroundRenderer;
 use crate::ui::card_renderer::CardRenderer;
-use crate::ui::instruction_renderer::InstructionRenderer;
+use crate::ui::instruction_renderer::{HintAction, InstructionRenderer};
+use crate::ui::keyboard_renderer::KeyboardRenderer;
 use crate::ui::menu_renderer::MenuRenderer;
 use crate::ui::text_renderer::TextRenderer;
 
@@ -24,6 +25,16 @@ impl DrawingHelpers {
         CardRenderer::draw_card_inline(d, atlas, card, card_x, card_y, size);
     }
 
+    pub fn draw_value_badge(
+        d: &mut RaylibDrawHandle,
+        card: Card,
+        card_x: i32,
+        card_y: i32,
+        size: i32,
+    ) {
+        CardRenderer::draw_value_badge(d, card, card_x, card_y, size);
+    }
+
     // Re-export background rendering functions
     pub fn draw_gradient_background(d: &mut RaylibDrawHandle) {
         BackgroundRenderer::draw_gradient_background(d);
@@ -34,8 +45,17 @@ impl DrawingHelpers {
         board_width: i32,
         board_height: i32,
         cell_size: i32,
+        mirrored: bool,
+        felt: FeltColor,
     ) {
-        BackgroundRenderer::draw_game_board_background(d, board_width, board_height, cell_size);
+        BackgroundRenderer::draw_game_board_background(
+            d,
+            board_width,
+            board_height,
+            cell_size,
+            mirrored,
+            felt,
+        );
     }
 
     // Re-export text rendering functions
@@ -47,6 +67,10 @@ impl DrawingHelpers {
         TextRenderer::draw_subtitle(d, font);
     }
 
+    pub fn draw_build_info(d: &mut RaylibDrawHandle, font: &Font) {
+        MenuRenderer::draw_build_info(d, font);
+    }
+
     pub fn draw_main_menu(
         d: &mut RaylibDrawHandle,
         font: &Font,
@@ -73,6 +97,7 @@ impl DrawingHelpers {
         info_panel_x: i32,
         board_offset_y: i32,
         has_controller: bool,
+        text_scale: f32,
     ) {
         InstructionRenderer::draw_controls(
             d,
@@ -81,9 +106,20 @@ impl DrawingHelpers {
             info_panel_x,
             board_offset_y,
             has_controller,
+            text_scale,
         );
     }
 
+    pub fn draw_hints(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        actions: &[HintAction],
+        has_controller: bool,
+        y: f32,
+    ) {
+        InstructionRenderer::draw_hints(d, font, actions, has_controller, y);
+    }
+
     pub fn draw_game_over_instructions(
         d: &mut RaylibDrawHandle,
         font: &Font,
@@ -96,7 +132,33 @@ impl DrawingHelpers {
         InstructionRenderer::draw_quit_confirmation(d, font, has_controller);
     }
 
-    pub fn draw_pause_instructions(d: &mut RaylibDrawHandle, font: &Font, has_controller: bool) {
-        InstructionRenderer::draw_pause_instructions(d, font, has_controller);
+    pub fn draw_pause_menu(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        game: &Game,
+        selected: PauseMenuOption,
+        has_controller: bool,
+    ) {
+        MenuRenderer::draw_pause_menu(d, font, game, selected, has_controller);
+    }
+
+    pub fn draw_forfeit_menu(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        game: &Game,
+        selected: ForfeitOption,
+        has_controller: bool,
+    ) {
+        MenuRenderer::draw_forfeit_menu(d, font, game, selected, has_controller);
+    }
+
+    // Re-export on-screen keyboard rendering
+    pub fn draw_virtual_keyboard(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        keyboard: &VirtualKeyboard,
+        y: i32,
+    ) {
+        KeyboardRenderer::draw_virtual_keyboard(d, font, keyboard, y);
     }
 }
@@ -1,89 +1,97 @@
-use crate::game::{Game, Settings};
+use super::config::ControllerConfig;
+use super::screens;
+use super::touch_input::TouchTracker;
+use crate::game::Game;
+use crate::game::input::{InputSnapshot, Key, PadButton, TouchGesture};
 use raylib::prelude::*;
+use std::time::{Duration, Instant};
 
 pub struct InputHandler {
-    last_move_time: std::time::Instant,
-    move_delay: std::time::Duration,
+    touch: TouchTracker,
 }
 
-/// Input mapping for different controllers and keyboards
-struct InputMapping;
-
-impl InputMapping {
-    /// Check if any "left" input is held down
-    fn is_left_down(rl: &RaylibHandle, has_controller: bool) -> bool {
-        rl.is_key_down(KeyboardKey::KEY_LEFT)
-            || (has_controller
-                && (rl.is_gamepad_button_down(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_LEFT)
-                    || rl.get_gamepad_axis_movement(0, GamepadAxis::GAMEPAD_AXIS_LEFT_X) < -0.3))
-    }
-
-    /// Check if any "right" input is held down
-    fn is_right_down(rl: &RaylibHandle, has_controller: bool) -> bool {
-        rl.is_key_down(KeyboardKey::KEY_RIGHT)
-            || (has_controller
-                && (rl.is_gamepad_button_down(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_RIGHT)
-                    || rl.get_gamepad_axis_movement(0, GamepadAxis::GAMEPAD_AXIS_LEFT_X) > 0.3))
-    }
-
-    /// Check if any "up" input is pressed
-    fn is_up_pressed(rl: &RaylibHandle, has_controller: bool) -> bool {
-        rl.is_key_pressed(KeyboardKey::KEY_UP)
-            || (has_controller
-                && (rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_UP)
-                    || rl.get_gamepad_axis_movement(0, GamepadAxis::GAMEPAD_AXIS_LEFT_Y) < -0.3))
-    }
-
-    /// Check if any "down" input is pressed
-    fn is_down_pressed(rl: &RaylibHandle, has_controller: bool) -> bool {
-        rl.is_key_pressed(KeyboardKey::KEY_DOWN)
-            || (has_controller
-                && (rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN)
-                    || rl.get_gamepad_axis_movement(0, GamepadAxis::GAMEPAD_AXIS_LEFT_Y) > 0.3))
-    }
-
-    /// Check if any "down" input is held down
-    fn is_down_down(rl: &RaylibHandle, has_controller: bool) -> bool {
-        rl.is_key_down(KeyboardKey::KEY_DOWN)
-            || (has_controller
-                && (rl.is_gamepad_button_down(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN)
-                    || rl.get_gamepad_axis_movement(0, GamepadAxis::GAMEPAD_AXIS_LEFT_Y) > 0.3))
-    }
-
-    /// Check if any "action/space" input is pressed
-    fn is_action_pressed(rl: &RaylibHandle, has_controller: bool) -> bool {
-        rl.is_key_pressed(KeyboardKey::KEY_SPACE)
-            || rl.is_key_pressed(KeyboardKey::KEY_ENTER)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN))
-    }
-
-    /// Check if any "escape/menu" input is pressed
-    fn is_escape_pressed(rl: &RaylibHandle, has_controller: bool) -> bool {
-        rl.is_key_pressed(KeyboardKey::KEY_ESCAPE)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_MIDDLE_LEFT))
-    }
-
-    /// Check if any "settings" input is pressed
-    fn is_settings_pressed(rl: &RaylibHandle, has_controller: bool) -> bool {
-        rl.is_key_pressed(KeyboardKey::KEY_ESCAPE)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_MIDDLE_LEFT))
-    }
-
-    fn is_pause_pressed(rl: &RaylibHandle, has_controller: bool) -> bool {
-        rl.is_key_pressed(KeyboardKey::KEY_ESCAPE)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_MIDDLE_RIGHT))
-    }
-}
+// Keys any screen listens for, and what the game calls them
+const WATCHED_KEYS: [(KeyboardKey, Key); 40] = [
+    (KeyboardKey::KEY_A, Key::Letter('A')),
+    (KeyboardKey::KEY_B, Key::Letter('B')),
+    (KeyboardKey::KEY_C, Key::Letter('C')),
+    (KeyboardKey::KEY_D, Key::Letter('D')),
+    (KeyboardKey::KEY_E, Key::Letter('E')),
+    (KeyboardKey::KEY_F, Key::Letter('F')),
+    (KeyboardKey::KEY_G, Key::Letter('G')),
+    (KeyboardKey::KEY_H, Key::Letter('H')),
+    (KeyboardKey::KEY_I, Key::Letter('I')),
+    (KeyboardKey::KEY_J, Key::Letter('J')),
+    (KeyboardKey::KEY_K, Key::Letter('K')),
+    (KeyboardKey::KEY_L, Key::Letter('L')),
+    (KeyboardKey::KEY_M, Key::Letter('M')),
+    (KeyboardKey::KEY_N, Key::Letter('N')),
+    (KeyboardKey::KEY_O, Key::Letter('O')),
+    (KeyboardKey::KEY_P, Key::Letter('P')),
+    (KeyboardKey::KEY_Q, Key::Letter('Q')),
+    (KeyboardKey::KEY_R, Key::Letter('R')),
+    (KeyboardKey::KEY_S, Key::Letter('S')),
+    (KeyboardKey::KEY_T, Key::Letter('T')),
+    (KeyboardKey::KEY_U, Key::Letter('U')),
+    (KeyboardKey::KEY_V, Key::Letter('V')),
+    (KeyboardKey::KEY_W, Key::Letter('W')),
+    (KeyboardKey::KEY_X, Key::Letter('X')),
+    (KeyboardKey::KEY_Y, Key::Letter('Y')),
+    (KeyboardKey::KEY_Z, Key::Letter('Z')),
+    (KeyboardKey::KEY_UP, Key::Up),
+    (KeyboardKey::KEY_DOWN, Key::Down),
+    (KeyboardKey::KEY_LEFT, Key::Left),
+    (KeyboardKey::KEY_RIGHT, Key::Right),
+    (KeyboardKey::KEY_SPACE, Key::Space),
+    (KeyboardKey::KEY_ENTER, Key::Enter),
+    (KeyboardKey::KEY_ESCAPE, Key::Escape),
+    (KeyboardKey::KEY_BACKSPACE, Key::Backspace),
+    (KeyboardKey::KEY_TAB, Key::Tab),
+    (KeyboardKey::KEY_MINUS, Key::Minus),
+    (KeyboardKey::KEY_EQUAL, Key::Equal),
+    (KeyboardKey::KEY_F5, Key::F5),
+    (KeyboardKey::KEY_F7, Key::F7),
+    (KeyboardKey::KEY_F9, Key::F9),
+];
+
+// Controller buttons any screen listens for
+const WATCHED_BUTTONS: [(GamepadButton, PadButton); 12] = [
+    (
+        GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_UP,
+        PadButton::DPadUp,
+    ),
+    (
+        GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN,
+        PadButton::DPadDown,
+    ),
+    (
+        GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_LEFT,
+        PadButton::DPadLeft,
+    ),
+    (
+        GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_RIGHT,
+        PadButton::DPadRight,
+    ),
+    (GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN, PadButton::A),
+    (GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_RIGHT, PadButton::B),
+    (GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_LEFT, PadButton::X),
+    (GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_UP, PadButton::Y),
+    (
+        GamepadButton::GAMEPAD_BUTTON_LEFT_TRIGGER_1,
+        PadButton::LeftShoulder,
+    ),
+    (
+        GamepadButton::GAMEPAD_BUTTON_RIGHT_TRIGGER_1,
+        PadButton::RightShoulder,
+    ),
+    (GamepadButton::GAMEPAD_BUTTON_MIDDLE_LEFT, PadButton::Select),
+    (GamepadButton::GAMEPAD_BUTTON_MIDDLE_RIGHT, PadButton::Start),
+];
 
 impl InputHandler {
     pub fn new() -> Self {
         InputHandler {
-            last_move_time: std::time::Instant::now(),
-            move_delay: std::time::Duration::from_millis(150), // 150ms delay between moves
+            touch: TouchTracker::new(),
         }
     }
 
@@ -91,450 +99,211 @@ impl InputHandler {
         rl.is_gamepad_available(0)
     }
 
-    /// Check if a game session is currently active (difficulty should be locked)
-    fn is_game_session_active(game: &Game) -> bool {
-        game.game_session_active
-    }
-
-    pub fn handle_input(&mut self, rl: &mut RaylibHandle, game: &mut Game) {
+    /// Read every device once for this frame, into the game's terms
+    fn capture(rl: &RaylibHandle, game: &Game, gesture: Option<TouchGesture>) -> InputSnapshot {
         let has_controller = Self::is_controller_connected(rl);
-
-        if game.is_start_screen() {
-            self.handle_start_screen_input(rl, game, has_controller);
-        } else if game.is_playing() {
-            self.handle_playing_input(rl, game, has_controller);
-        } else if game.is_paused() {
-            self.handle_paused_input(rl, game, has_controller);
-        } else if game.is_game_over() {
-            self.handle_game_over_input(rl, game, has_controller);
-        } else if game.is_quit_confirm() {
-            self.handle_quit_confirm_input(rl, game, has_controller);
-        } else if game.is_settings() {
-            self.handle_settings_input(rl, game, has_controller);
-        }
-    }
-
-    fn handle_start_screen_input(
-        &self,
-        rl: &mut RaylibHandle,
-        game: &mut Game,
-        has_controller: bool,
-    ) {
-        // Handle navigation in main menu
-        if InputMapping::is_up_pressed(rl, has_controller) {
-            if game.selected_main_option > 0 {
-                game.selected_main_option -= 1;
-            } else {
-                game.selected_main_option = 2;
-            }
-            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
-        }
-
-        if InputMapping::is_down_pressed(rl, has_controller) {
-            if game.selected_main_option < 2 {
-                game.selected_main_option += 1;
-            } else {
-                game.selected_main_option = 0;
-            }
-            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
-        }
-
-        // Handle selection
-        if InputMapping::is_action_pressed(rl, has_controller) {
-            match game.selected_main_option {
-                0 => {
-                    // Start New Game
-                    game.start_game(game.settings.difficulty);
-                }
-                1 => {
-                    // Settings
-                    game.transition_to_settings("StartScreen".to_string());
-                }
-                2 => {
-                    // Quit
-                    game.transition_to_quit_confirm();
-                }
-                _ => {}
-            }
-        }
-
-        // Handle quit confirmation directly with ESC
-        if InputMapping::is_escape_pressed(rl, has_controller) {
-            game.transition_to_quit_confirm();
-        }
-    }
-
-    fn handle_playing_input(
-        &mut self,
-        rl: &mut RaylibHandle,
-        game: &mut Game,
-        has_controller: bool,
-    ) {
-        let now = std::time::Instant::now();
-        let can_move = now.duration_since(self.last_move_time) >= self.move_delay;
-
-        // Handle movement (left/right)
-        if can_move {
-            if InputMapping::is_left_down(rl, has_controller) {
-                game.move_current_card_left();
-                self.last_move_time = now;
-            } else if InputMapping::is_right_down(rl, has_controller) {
-                game.move_current_card_right();
-                self.last_move_time = now;
-            }
-        }
-
-        // Handle soft drop (down key)
-        if InputMapping::is_down_down(rl, has_controller) {
-            game.move_current_card_down();
-        }
-
-        // Handle hard drop (space key)
-        if InputMapping::is_action_pressed(rl, has_controller) {
-            game.hard_drop();
-        }
-
-        // Handle settings (escape/menu button)
-        if InputMapping::is_settings_pressed(rl, has_controller) {
-            game.transition_to_settings("Playing".to_string());
-        }
-
-        // Handle traditional pause (P key or start button)
-        if rl.is_key_pressed(KeyboardKey::KEY_P)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_MIDDLE_RIGHT))
-        {
-            game.transition_to_paused();
-        }
-    }
-
-    fn handle_paused_input(&self, rl: &mut RaylibHandle, game: &mut Game, has_controller: bool) {
-        // Resume game
-        if rl.is_key_pressed(KeyboardKey::KEY_ESCAPE)
-            || rl.is_key_pressed(KeyboardKey::KEY_N)
-            || rl.is_key_pressed(KeyboardKey::KEY_P)
-            || (has_controller
-                && (rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_MIDDLE_RIGHT)
-                    || rl.is_gamepad_button_pressed(
-                        0,
-                        GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_RIGHT,
-                    )))
-        {
-            game.transition_to_playing();
-        }
-
-        // Quit to menu
-        if rl.is_key_pressed(KeyboardKey::KEY_Y)
-            || (has_controller
-                && (rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN)))
-        {
-            game.add_audio_event(crate::game::AudioEvent::ForfeitGame);
-            game.transition_to_start_screen();
-        }
-    }
-
-    fn handle_game_over_input(&self, rl: &mut RaylibHandle, game: &mut Game, has_controller: bool) {
-        // Handle initial input
-        if let Some(key_pressed) = rl.get_key_pressed() {
-            if let Some(c) = Self::key_to_char(key_pressed) {
-                game.add_initial(c);
-            }
-        }
-
-        // Handle controller input for initials
+        let mut input = InputSnapshot::new(Instant::now());
+        input.keys = WATCHED_KEYS
+            .into_iter()
+            .filter(|&(raylib_key, _)| rl.is_key_pressed(raylib_key))
+            .map(|(_, key)| key)
+            .collect();
         if has_controller {
-            if rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_RIGHT)
-                || rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_UP)
-            {
-                Self::add_next_letter(game);
-            }
-
-            if rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_LEFT)
-                || rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN)
-            {
-                Self::add_prev_letter(game);
-            }
-
-            if rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN) {
-                game.add_initial(game.player_initials.chars().last().unwrap_or('@'));
-            }
+            input.buttons = WATCHED_BUTTONS
+                .into_iter()
+                .filter(|&(raylib_button, _)| rl.is_gamepad_button_pressed(0, raylib_button))
+                .map(|(_, button)| button)
+                .collect();
         }
-
-        // Handle backspace
-        if rl.is_key_pressed(KeyboardKey::KEY_BACKSPACE)
+        input.any_held = WATCHED_KEYS
+            .into_iter()
+            .any(|(raylib_key, _)| rl.is_key_down(raylib_key))
             || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_RIGHT))
+                && WATCHED_BUTTONS
+                    .into_iter()
+                    .any(|(raylib_button, _)| rl.is_gamepad_button_down(0, raylib_button)));
+        input.modifier_held = [
+            KeyboardKey::KEY_LEFT_CONTROL,
+            KeyboardKey::KEY_RIGHT_CONTROL,
+            KeyboardKey::KEY_LEFT_SUPER,
+            KeyboardKey::KEY_RIGHT_SUPER,
+        ]
+        .into_iter()
+        .any(|modifier| rl.is_key_down(modifier));
+
+        let (stick_x, stick_y) = if has_controller {
+            (
+                rl.get_gamepad_axis_movement(0, GamepadAxis::GAMEPAD_AXIS_LEFT_X),
+                rl.get_gamepad_axis_movement(0, GamepadAxis::GAMEPAD_AXIS_LEFT_Y),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let pad_down = |button| has_controller && rl.is_gamepad_button_down(0, button);
+        input.up = input.key(Key::Up) || input.button(PadButton::DPadUp) || stick_y < -0.3;
+        input.down = input.key(Key::Down) || input.button(PadButton::DPadDown) || stick_y > 0.3;
+        input.left_held = rl.is_key_down(KeyboardKey::KEY_LEFT)
+            || pad_down(GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_LEFT)
+            || stick_x < -0.3;
+        input.right_held = rl.is_key_down(KeyboardKey::KEY_RIGHT)
+            || pad_down(GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_RIGHT)
+            || stick_x > 0.3;
+
+        // The key and D-Pad are all or nothing; the stick scales with how far it's pushed
+        input.soft_drop = if rl.is_key_down(KeyboardKey::KEY_DOWN)
+            || pad_down(GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN)
         {
-            game.remove_initial();
-        }
-
-        // Submit and return to menu
-        if rl.is_key_pressed(KeyboardKey::KEY_ENTER)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_MIDDLE_RIGHT))
-        {
-            if !game.player_initials.is_empty() {
-                game.save_high_score();
+            1.0
+        } else {
+            let dead_zone = game
+                .settings
+                .stick_dead_zone
+                .unwrap_or(ControllerConfig::DEFAULT_STICK_DEAD_ZONE);
+            stick_soft_drop_strength(stick_y, dead_zone)
+        };
+
+        let mouse = rl.get_mouse_position();
+        input.mouse = (mouse.x, mouse.y);
+        input.mouse_moved = rl.get_mouse_delta() != Vector2::zero();
+        input.mouse_clicked = rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT);
+        input.gesture = gesture;
+
+        if input.shortcut(Key::Letter('V')) {
+            match rl.get_clipboard_text() {
+                Ok(text) => input.pasted = Some(text),
+                Err(e) => eprintln!("Failed to paste: {}", e),
             }
-            game.transition_to_start_screen();
         }
+        input
     }
 
-    fn handle_quit_confirm_input(
-        &self,
-        rl: &mut RaylibHandle,
-        game: &mut Game,
-        has_controller: bool,
-    ) {
-        // Cancel quit (go back to start screen)
-        if rl.is_key_pressed(KeyboardKey::KEY_ESCAPE)
-            || rl.is_key_pressed(KeyboardKey::KEY_N)
-            || (has_controller
-                && (rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_MIDDLE_LEFT)
-                    || rl.is_gamepad_button_pressed(
-                        0,
-                        GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_RIGHT,
-                    )))
-        {
-            game.transition_to_start_screen();
+    pub fn handle_input(&mut self, rl: &mut RaylibHandle, game: &mut Game) {
+        let gesture = if game.settings.touch_controls {
+            self.touch.update(rl)
+        } else {
+            None
+        };
+        let input = Self::capture(rl, game, gesture);
+
+        // Debug snapshots of the board, for reproducing board bugs: F5 saves the run in
+        // progress and F9 puts it back, from the start screen or mid-run
+        if input.key(Key::F5) && (game.is_playing() || game.is_paused()) {
+            game.save_debug_snapshot();
         }
-
-        // Confirm quit - actually exit the application
-        if rl.is_key_pressed(KeyboardKey::KEY_Y)
-            || rl.is_key_pressed(KeyboardKey::KEY_ENTER)
-            || (has_controller
-                && (rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN)))
-        {
-            game.add_audio_event(crate::game::AudioEvent::QuitGame);
-            std::process::exit(0);
+        if input.key(Key::F9) && (game.is_start_screen() || game.is_playing() || game.is_paused()) {
+            game.load_debug_snapshot();
+            return;
         }
-    }
 
-    // Helper functions for gamepad support
-    fn add_next_letter(game: &mut Game) {
-        if game.player_initials.len() <= 3 {
-            // Get the last character or start with 'A'
-            let last_char = game.player_initials.chars().last().unwrap_or('@');
-            let next_char = if last_char == 'Z' {
-                'A'
-            } else {
-                (last_char as u8 + 1) as char
-            };
-
-            // Remove last character if exists and add the new one
-            if !game.player_initials.is_empty() {
-                game.remove_initial();
-            }
-            game.add_initial(next_char);
+        // F7 shows how the combination search explores the board from each card placed
+        if input.key(Key::F7) {
+            game.toggle_search_overlay();
         }
-    }
-
-    fn add_prev_letter(game: &mut Game) {
-        if game.player_initials.len() <= 3 {
-            // Get the last character or start with 'B'
-            let last_char = game.player_initials.chars().last().unwrap_or('B');
-            let prev_char = if last_char == 'A' {
-                'Z'
-            } else {
-                (last_char as u8 - 1) as char
-            };
 
-            // Remove last character if exists and add the new one
-            if !game.player_initials.is_empty() {
-                game.remove_initial();
-            }
-            game.add_initial(prev_char);
-        }
+        screens::handle_state_input(game, &input);
     }
+}
 
-    fn key_to_char(key: KeyboardKey) -> Option<char> {
-        match key {
-            KeyboardKey::KEY_A => Some('A'),
-            KeyboardKey::KEY_B => Some('B'),
-            KeyboardKey::KEY_C => Some('C'),
-            KeyboardKey::KEY_D => Some('D'),
-            KeyboardKey::KEY_E => Some('E'),
-            KeyboardKey::KEY_F => Some('F'),
-            KeyboardKey::KEY_G => Some('G'),
-            KeyboardKey::KEY_H => Some('H'),
-            KeyboardKey::KEY_I => Some('I'),
-            KeyboardKey::KEY_J => Some('J'),
-            KeyboardKey::KEY_K => Some('K'),
-            KeyboardKey::KEY_L => Some('L'),
-            KeyboardKey::KEY_M => Some('M'),
-            KeyboardKey::KEY_N => Some('N'),
-            KeyboardKey::KEY_O => Some('O'),
-            KeyboardKey::KEY_P => Some('P'),
-            KeyboardKey::KEY_Q => Some('Q'),
-            KeyboardKey::KEY_R => Some('R'),
-            KeyboardKey::KEY_S => Some('S'),
-            KeyboardKey::KEY_T => Some('T'),
-            KeyboardKey::KEY_U => Some('U'),
-            KeyboardKey::KEY_V => Some('V'),
-            KeyboardKey::KEY_W => Some('W'),
-            KeyboardKey::KEY_X => Some('X'),
-            KeyboardKey::KEY_Y => Some('Y'),
-            KeyboardKey::KEY_Z => Some('Z'),
-            _ => None,
-        }
+/// Stick deflection past the dead zone, rescaled so the edge of the dead zone is 0.0 and
+/// full deflection is 1.0. Only downward deflection (positive Y) counts.
+fn stick_soft_drop_strength(axis_y: f32, dead_zone: f32) -> f32 {
+    let dead_zone = dead_zone.clamp(0.0, ControllerConfig::MAX_STICK_DEAD_ZONE);
+    if axis_y <= dead_zone {
+        return 0.0;
     }
+    ((axis_y - dead_zone) / (1.0 - dead_zone)).min(1.0)
+}
 
-    fn handle_settings_input(&self, rl: &mut RaylibHandle, game: &mut Game, has_controller: bool) {
-        const TOTAL_OPTIONS: usize = 4; // Music, SFX, VSync, Difficulty
+/// Time between soft drop steps: every frame at full strength, slowing toward
+/// SOFT_DROP_SLOWEST_MS as the stick eases off
+pub fn soft_drop_interval(strength: f32) -> Duration {
+    let slowest = ControllerConfig::SOFT_DROP_SLOWEST_MS as f32;
+    Duration::from_millis((slowest * (1.0 - strength.clamp(0.0, 1.0))) as u64)
+}
 
-        // Back to previous screen
-        if rl.is_key_pressed(KeyboardKey::KEY_ESCAPE)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_RIGHT))
-        {
-            // Get the previous state from the current Settings state
-            if let Some(settings_state) = game.state.as_any().downcast_ref::<Settings>() {
-                match settings_state.previous_state_name.as_str() {
-                    "Playing" => game.transition_to_playing(),
-                    "StartScreen" => game.transition_to_start_screen(),
-                    _ => game.transition_to_start_screen(), // Default fallback
-                }
-            } else {
-                game.transition_to_start_screen(); // Fallback if we can't determine previous state
-            }
-            return;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameState;
+    use crate::game::tests::test_fixtures::create_test_game;
+    use crate::models::Difficulty;
+
+    #[test]
+    fn test_states_handle_their_own_input() {
+        let (mut game, _temp_dir) = create_test_game();
+        game.start_game(Difficulty::Easy);
+        let now = Instant::now();
+
+        screens::handle_state_input(
+            &mut game,
+            &InputSnapshot::pressing(now, &[Key::Letter('P')]),
+        );
+        assert!(game.is_paused());
+
+        // The same key means something else on the pause menu
+        screens::handle_state_input(
+            &mut game,
+            &InputSnapshot::pressing(now, &[Key::Letter('P')]),
+        );
+        assert!(game.is_playing());
+
+        screens::handle_state_input(&mut game, &InputSnapshot::pressing(now, &[Key::Escape]));
+        assert!(game.is_settings());
+    }
 
-        // Navigation (Up/Down)
-        if rl.is_key_pressed(KeyboardKey::KEY_UP)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_UP))
-        {
-            if game.settings.selected_option > 0 {
-                game.settings.selected_option -= 1;
-            } else {
-                game.settings.selected_option = TOTAL_OPTIONS - 1; // Wrap to bottom
-            }
-            if !game.settings.sound_effects_muted {
-                game.add_audio_event(crate::game::AudioEvent::MoveLeft);
-            }
-        }
+    #[test]
+    fn test_held_moves_wait_for_the_repeat_delay() {
+        let (mut game, _temp_dir) = create_test_game();
+        game.start_game(Difficulty::Easy);
+        let now = Instant::now();
+        let held_left = |now| InputSnapshot {
+            left_held: true,
+            ..InputSnapshot::new(now)
+        };
+
+        screens::handle_state_input(&mut game, &held_left(now));
+        let GameState::Playing(playing) = &game.state else {
+            panic!("Should still be playing");
+        };
+        assert_eq!(playing.last_move, Some(now));
+
+        // Too soon for another step, then far enough apart for one
+        let soon = now + Duration::from_millis(ControllerConfig::MOVE_REPEAT_MS / 2);
+        screens::handle_state_input(&mut game, &held_left(soon));
+        let GameState::Playing(playing) = &game.state else {
+            panic!("Should still be playing");
+        };
+        assert_eq!(playing.last_move, Some(now));
+
+        let later = now + Duration::from_millis(ControllerConfig::MOVE_REPEAT_MS);
+        screens::handle_state_input(&mut game, &held_left(later));
+        let GameState::Playing(playing) = &game.state else {
+            panic!("Should still be playing");
+        };
+        assert_eq!(playing.last_move, Some(later));
+    }
 
-        if rl.is_key_pressed(KeyboardKey::KEY_DOWN)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN))
-        {
-            game.settings.selected_option = (game.settings.selected_option + 1) % TOTAL_OPTIONS;
-            if !game.settings.sound_effects_muted {
-                game.add_audio_event(crate::game::AudioEvent::MoveRight);
-            }
-        }
+    #[test]
+    fn test_stick_soft_drop_strength() {
+        // Inside the dead zone, or pushed up, does nothing
+        assert_eq!(stick_soft_drop_strength(0.2, 0.3), 0.0);
+        assert_eq!(stick_soft_drop_strength(-1.0, 0.3), 0.0);
 
-        // Adjust values based on current selection (Left/Right)
-        let left_pressed = rl.is_key_pressed(KeyboardKey::KEY_LEFT)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_LEFT));
-        let right_pressed = rl.is_key_pressed(KeyboardKey::KEY_RIGHT)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_RIGHT));
+        // Past the dead zone the strength scales up to full deflection
+        assert!((stick_soft_drop_strength(0.65, 0.3) - 0.5).abs() < 1e-6);
+        assert_eq!(stick_soft_drop_strength(1.0, 0.3), 1.0);
 
-        match game.settings.selected_option {
-            0 => {
-                // Music Volume
-                if left_pressed {
-                    game.settings.music_volume = (game.settings.music_volume - 0.1).max(0.0);
-                    if !game.settings.sound_effects_muted {
-                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
-                    }
-                    game.save_settings();
-                }
-                if right_pressed {
-                    game.settings.music_volume = (game.settings.music_volume + 0.1).min(1.0);
-                    if !game.settings.sound_effects_muted {
-                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
-                    }
-                    game.save_settings();
-                }
-            }
-            1 => {
-                // Sound Effects Volume
-                if left_pressed {
-                    game.settings.sound_effects_volume =
-                        (game.settings.sound_effects_volume - 0.1).max(0.0);
-                    game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
-                    game.save_settings();
-                }
-                if right_pressed {
-                    game.settings.sound_effects_volume =
-                        (game.settings.sound_effects_volume + 0.1).min(1.0);
-                    game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
-                    game.save_settings();
-                }
-            }
-            2 => { // VSync - no left/right adjustment, only toggle
-                // VSync doesn't have adjustable values, only toggle
-            }
-            3 => {
-                // Difficulty - only allow changes when no game session is active
-                if (left_pressed || right_pressed) && !Self::is_game_session_active(game) {
-                    game.settings.difficulty = match game.settings.difficulty {
-                        crate::models::Difficulty::Easy => crate::models::Difficulty::Hard,
-                        crate::models::Difficulty::Hard => crate::models::Difficulty::Easy,
-                    };
-                    // Also update the main game difficulty for consistency
-                    game.difficulty = game.settings.difficulty;
-                    if !game.settings.sound_effects_muted {
-                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
-                    }
-                    game.save_settings();
-                }
-            }
-            _ => {}
-        }
+        // A dead zone too large to get past is clamped
+        assert!(stick_soft_drop_strength(1.0, 5.0) > 0.0);
+    }
 
-        // Toggle actions (Space/A button)
-        if rl.is_key_pressed(KeyboardKey::KEY_SPACE)
-            || (has_controller
-                && rl.is_gamepad_button_pressed(0, GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN))
-        {
-            match game.settings.selected_option {
-                0 => {
-                    // Music Mute Toggle
-                    game.settings.music_muted = !game.settings.music_muted;
-                    if !game.settings.sound_effects_muted {
-                        game.add_audio_event(crate::game::AudioEvent::PauseGame);
-                    }
-                    game.save_settings();
-                }
-                1 => {
-                    // Sound Effects Mute Toggle
-                    let was_muted = game.settings.sound_effects_muted;
-                    game.settings.sound_effects_muted = !game.settings.sound_effects_muted;
-                    if was_muted && !game.settings.sound_effects_muted {
-                        game.add_audio_event(crate::game::AudioEvent::ResumeGame);
-                    }
-                    game.save_settings();
-                }
-                2 => {
-                    // VSync Toggle
-                    game.settings.vsync_enabled = !game.settings.vsync_enabled;
-                    if !game.settings.sound_effects_muted {
-                        game.add_audio_event(crate::game::AudioEvent::StartGame);
-                    }
-                    game.save_settings();
-                }
-                3 => {
-                    // Difficulty Toggle (same as left/right) - only when no game session is active
-                    if !Self::is_game_session_active(game) {
-                        game.settings.difficulty = match game.settings.difficulty {
-                            crate::models::Difficulty::Easy => crate::models::Difficulty::Hard,
-                            crate::models::Difficulty::Hard => crate::models::Difficulty::Easy,
-                        };
-                        // Also update the main game difficulty for consistency
-                        game.difficulty = game.settings.difficulty;
-                        if !game.settings.sound_effects_muted {
-                            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
-                        }
-                        game.save_settings();
-                    }
-                }
-                _ => {}
-            }
-        }
+    #[test]
+    fn test_soft_drop_interval_speeds_up_with_strength() {
+        assert_eq!(soft_drop_interval(1.0), Duration::ZERO);
+        assert_eq!(
+            soft_drop_interval(0.0),
+            Duration::from_millis(ControllerConfig::SOFT_DROP_SLOWEST_MS)
+        );
+        assert!(soft_drop_interval(0.8) < soft_drop_interval(0.2));
     }
 }
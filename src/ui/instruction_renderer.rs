@@ -1,9 +1,90 @@
 use crate::ui::config::InstructionsConfig;
+use crate::ui::sdf_text::SdfText;
+use crate::ui::text_layout::TextLayout;
 use raylib::color::Color;
-use raylib::drawing::{RaylibDraw, RaylibDrawHandle};
+use raylib::drawing::RaylibDrawHandle;
 use raylib::math::Vector2;
 use raylib::prelude::Font;
 
+// Something a screen's hint bar tells the player how to do. Each knows its key or button
+// under either control scheme, so screens list actions rather than spelling out both.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HintAction {
+    Navigate,
+    Select,
+    ContinueSavedRun,
+    LeaderboardPeriod,
+    Resume,
+    Restart,
+    CombinationLog,
+    Change,
+    Adjust,
+    Toggle,
+    ToggleMod,
+    Next,
+    Scroll,
+    ToggleHeatmap,
+    ExportStatistics,
+    Back,
+    CloseLog,
+    BackToSettings,
+    ReplayBack,
+    ReplayForward,
+}
+
+impl HintAction {
+    fn key(&self, has_controller: bool) -> &'static str {
+        let (controller, keyboard) = match self {
+            HintAction::Navigate => ("D-Pad Up/Down", "Up/Down"),
+            HintAction::Select => ("A", "ENTER"),
+            HintAction::ContinueSavedRun => ("Y", "C"),
+            HintAction::LeaderboardPeriod => ("LB/RB", "Tab"),
+            HintAction::Resume => ("B", "ESC"),
+            HintAction::Restart => ("X", "R"),
+            HintAction::CombinationLog => ("Y", "H"),
+            HintAction::Change | HintAction::Adjust => ("Left/Right", "Left/Right"),
+            HintAction::Toggle | HintAction::Next => ("A", "Space"),
+            HintAction::ToggleMod => ("A", "ENTER"),
+            HintAction::Scroll => ("D-Pad", "Up/Down"),
+            HintAction::ToggleHeatmap => ("Y", "H"),
+            HintAction::ExportStatistics => ("X", "E"),
+            HintAction::Back | HintAction::BackToSettings => ("B", "ESC"),
+            HintAction::CloseLog => ("B", "ESC/H"),
+            HintAction::ReplayBack => ("LB", "Left"),
+            HintAction::ReplayForward => ("RB", "Right"),
+        };
+        if has_controller { controller } else { keyboard }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            HintAction::Navigate => "Navigate",
+            HintAction::Select => "Select",
+            HintAction::ContinueSavedRun => "Continue Saved Run",
+            HintAction::LeaderboardPeriod => "Scores",
+            HintAction::Resume => "Resume",
+            HintAction::Restart => "Restart",
+            HintAction::CombinationLog => "Combination Log",
+            HintAction::Change => "Change",
+            HintAction::Adjust => "Adjust/Change",
+            HintAction::Toggle | HintAction::ToggleMod => "Toggle",
+            HintAction::Next => "Next",
+            HintAction::Scroll => "Scroll",
+            HintAction::ToggleHeatmap => "Toggle Heatmap",
+            HintAction::ExportStatistics => "Export",
+            HintAction::Back | HintAction::CloseLog => "Back",
+            HintAction::BackToSettings => "Back to Settings",
+            HintAction::ReplayBack => "Further back",
+            HintAction::ReplayForward => "Forward, past the end to return to initials",
+        }
+    }
+
+    /// The hint as shown, e.g. "ESC: Back"
+    pub fn prompt(&self, has_controller: bool) -> String {
+        format!("{}: {}", self.key(has_controller), self.description())
+    }
+}
+
 pub struct InstructionRenderer;
 
 impl InstructionRenderer {
@@ -14,6 +95,7 @@ impl InstructionRenderer {
         info_panel_x: i32,
         board_offset_y: i32,
         has_controller: bool,
+        text_scale: f32,
     ) {
         // Enhanced controls title with glow effect
         let controls_x = info_panel_x + InstructionsConfig::X_OFFSET;
@@ -22,7 +104,8 @@ impl InstructionRenderer {
         // Glow effect for the title
         for glow in 1..=InstructionsConfig::GLOW_LAYERS {
             let glow_alpha = 40 / glow;
-            d.draw_text_ex(
+            SdfText::draw(
+                d,
                 title_font,
                 "Controls:",
                 Vector2::new((controls_x + glow) as f32, (controls_y + glow) as f32),
@@ -33,7 +116,8 @@ impl InstructionRenderer {
         }
 
         // Shadow
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             title_font,
             "Controls:",
             Vector2::new(
@@ -46,7 +130,8 @@ impl InstructionRenderer {
         );
 
         // Main title
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             title_font,
             "Controls:",
             Vector2::new(controls_x as f32, controls_y as f32),
@@ -78,169 +163,163 @@ impl InstructionRenderer {
                 ),
                 ("Down Arrow: Soft drop", InstructionsConfig::SOFT_DROP_COLOR),
                 ("Space: Hard drop", InstructionsConfig::HARD_DROP_COLOR),
-                ("ESC: Settings | P: Pause", InstructionsConfig::PAUSE_COLOR),
+                (
+                    "ESC: Settings | P: Pause | R: Restart",
+                    InstructionsConfig::PAUSE_COLOR,
+                ),
             ],
         };
 
+        let text_size = InstructionsConfig::TEXT_SIZE * text_scale;
+        let line_spacing = (InstructionsConfig::LINE_SPACING as f32 * text_scale) as i32;
         for (i, (text, color)) in instructions.iter().enumerate() {
-            let y_pos = controls_y
-                + InstructionsConfig::Y_START_OFFSET
-                + i as i32 * InstructionsConfig::LINE_SPACING;
+            let y_pos = controls_y + InstructionsConfig::Y_START_OFFSET + i as i32 * line_spacing;
 
             // Subtle shadow for each instruction
-            d.draw_text_ex(
+            SdfText::draw(
+                d,
                 font,
                 text,
                 Vector2::new(
                     (controls_x + InstructionsConfig::TEXT_X_OFFSET) as f32,
                     (y_pos + InstructionsConfig::TEXT_Y_OFFSET) as f32,
                 ),
-                InstructionsConfig::TEXT_SIZE,
+                text_size,
                 1.0,
                 InstructionsConfig::TEXT_SHADOW_COLOR,
             );
 
             // Main text with color coding
-            d.draw_text_ex(
+            SdfText::draw(
+                d,
                 font,
                 text,
                 Vector2::new(controls_x as f32, y_pos as f32),
-                InstructionsConfig::TEXT_SIZE,
+                text_size,
                 1.0,
                 *color,
             );
         }
     }
 
-    pub fn draw_game_over_instructions(
+    /// All of `actions` on one line, for places with their own text style
+    pub fn hint_line(actions: &[HintAction], has_controller: bool) -> String {
+        actions
+            .iter()
+            .map(|action| action.prompt(has_controller))
+            .collect::<Vec<_>>()
+            .join(InstructionsConfig::HINT_SEPARATOR)
+    }
+
+    /// A screen's hint bar: the prompts for `actions` centered across the screen from `y`,
+    /// wrapping onto more lines between prompts when they don't fit on one
+    pub fn draw_hints(
         d: &mut RaylibDrawHandle,
         font: &Font,
+        actions: &[HintAction],
         has_controller: bool,
+        y: f32,
     ) {
-        if has_controller {
-            d.draw_text_ex(
+        let prompts: Vec<_> = actions
+            .iter()
+            .map(|action| action.prompt(has_controller))
+            .collect();
+        let lines = TextLayout::wrap_joined(
+            font,
+            &prompts,
+            InstructionsConfig::HINT_SEPARATOR,
+            InstructionsConfig::HINT_SIZE,
+            InstructionsConfig::HINT_SPACING,
+            InstructionsConfig::HINT_MAX_WIDTH,
+        );
+        for (i, line) in lines.iter().enumerate() {
+            TextLayout::draw_centered_line(
+                d,
                 font,
-                "D-Pad: Cycle letters, A: Next/Accept, B: Backspace",
-                Vector2::new(
-                    InstructionsConfig::GAME_OVER_X,
-                    InstructionsConfig::GAME_OVER_Y,
-                ),
-                InstructionsConfig::GAME_OVER_SIZE,
-                1.0,
-                InstructionsConfig::CONTROLLER_COLOR,
+                line,
+                y + i as f32 * InstructionsConfig::HINT_LINE_HEIGHT,
+                InstructionsConfig::HINT_SIZE,
+                InstructionsConfig::HINT_SPACING,
+                InstructionsConfig::HINT_COLOR,
             );
+        }
+    }
+
+    pub fn draw_game_over_instructions(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        has_controller: bool,
+    ) {
+        let (text, y, color) = if has_controller {
+            (
+                "D-Pad: Choose key, A: Type, B: Backspace, Start: Done",
+                InstructionsConfig::GAME_OVER_CONTROLLER_Y,
+                InstructionsConfig::CONTROLLER_COLOR,
+            )
         } else {
-            d.draw_text_ex(
-                font,
-                "Type your initials, then press ENTER when done",
-                Vector2::new(
-                    InstructionsConfig::GAME_OVER_X_ALT,
-                    InstructionsConfig::GAME_OVER_Y,
-                ),
-                InstructionsConfig::GAME_OVER_SIZE,
-                1.0,
+            (
+                "Type your name or initials, then press ENTER when done",
+                InstructionsConfig::GAME_OVER_Y,
                 Color::LIGHTGRAY,
-            );
-        }
+            )
+        };
+        TextLayout::draw_centered_line(
+            d,
+            font,
+            text,
+            y,
+            InstructionsConfig::GAME_OVER_SIZE,
+            1.0,
+            color,
+        );
     }
 
     pub fn draw_quit_confirmation(d: &mut RaylibDrawHandle, font: &Font, has_controller: bool) {
-        if has_controller {
-            d.draw_text_ex(
-                font,
-                "Press A to Quit",
-                Vector2::new(
-                    InstructionsConfig::QUIT_CONFIRM_QUIT_X,
-                    InstructionsConfig::QUIT_CONFIRM_QUIT_Y,
-                ),
-                InstructionsConfig::QUIT_CONFIRM_SIZE,
-                InstructionsConfig::QUIT_CONFIRM_SPACING,
-                InstructionsConfig::QUIT_COLOR,
-            );
-            d.draw_text_ex(
-                font,
-                "Press B to Cancel",
-                Vector2::new(
-                    InstructionsConfig::QUIT_CONFIRM_CANCEL_X,
-                    InstructionsConfig::QUIT_CONFIRM_CANCEL_Y,
-                ),
-                InstructionsConfig::QUIT_CONFIRM_SIZE,
-                InstructionsConfig::QUIT_CONFIRM_SPACING,
-                InstructionsConfig::RESUME_COLOR,
-            );
+        let (quit_text, cancel_text) = if has_controller {
+            ("Press A to Quit", "Press B to Cancel")
         } else {
-            d.draw_text_ex(
-                font,
-                "Press Y to Quit",
-                Vector2::new(
-                    InstructionsConfig::QUIT_CONFIRM_QUIT_X,
-                    InstructionsConfig::QUIT_CONFIRM_QUIT_Y,
-                ),
-                InstructionsConfig::QUIT_CONFIRM_SIZE,
-                InstructionsConfig::QUIT_CONFIRM_SPACING,
+            ("Press Y to Quit", "Press N or ESC to Cancel")
+        };
+        for (text, y, color) in [
+            (
+                quit_text,
+                InstructionsConfig::QUIT_CONFIRM_QUIT_Y,
                 InstructionsConfig::QUIT_COLOR,
-            );
-            d.draw_text_ex(
+            ),
+            (
+                cancel_text,
+                InstructionsConfig::QUIT_CONFIRM_CANCEL_Y,
+                InstructionsConfig::RESUME_COLOR,
+            ),
+        ] {
+            TextLayout::draw_centered_line(
+                d,
                 font,
-                "Press N or ESC to Cancel",
-                Vector2::new(
-                    InstructionsConfig::QUIT_CONFIRM_CANCEL_X_ALT,
-                    InstructionsConfig::QUIT_CONFIRM_CANCEL_Y,
-                ),
+                text,
+                y,
                 InstructionsConfig::QUIT_CONFIRM_SIZE,
                 InstructionsConfig::QUIT_CONFIRM_SPACING,
-                InstructionsConfig::RESUME_COLOR,
+                color,
             );
         }
     }
+}
 
-    pub fn draw_pause_instructions(d: &mut RaylibDrawHandle, font: &Font, has_controller: bool) {
-        if has_controller {
-            d.draw_text_ex(
-                font,
-                "Press A to Forfeit",
-                Vector2::new(
-                    InstructionsConfig::PAUSE_FORFEIT_X,
-                    InstructionsConfig::PAUSE_FORFEIT_Y,
-                ),
-                InstructionsConfig::QUIT_CONFIRM_SIZE,
-                InstructionsConfig::QUIT_CONFIRM_SPACING,
-                InstructionsConfig::QUIT_COLOR,
-            );
-            d.draw_text_ex(
-                font,
-                "Press B to Resume",
-                Vector2::new(
-                    InstructionsConfig::PAUSE_RESUME_X,
-                    InstructionsConfig::PAUSE_RESUME_Y,
-                ),
-                InstructionsConfig::QUIT_CONFIRM_SIZE,
-                InstructionsConfig::QUIT_CONFIRM_SPACING,
-                InstructionsConfig::RESUME_COLOR,
-            );
-        } else {
-            d.draw_text_ex(
-                font,
-                "Press N/ESC/P to Resume",
-                Vector2::new(
-                    InstructionsConfig::PAUSE_RESUME_X_ALT,
-                    InstructionsConfig::PAUSE_FORFEIT_Y,
-                ),
-                InstructionsConfig::QUIT_CONFIRM_SIZE,
-                InstructionsConfig::QUIT_CONFIRM_SPACING,
-                InstructionsConfig::RESUME_COLOR,
-            );
-            d.draw_text_ex(
-                font,
-                "Press Y to Quit to Menu",
-                Vector2::new(
-                    InstructionsConfig::PAUSE_QUIT_X,
-                    InstructionsConfig::PAUSE_RESUME_Y,
-                ),
-                InstructionsConfig::QUIT_CONFIRM_SIZE,
-                InstructionsConfig::QUIT_CONFIRM_SPACING,
-                InstructionsConfig::QUIT_COLOR,
-            );
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hints_follow_control_scheme() {
+        let actions = [HintAction::Navigate, HintAction::Select, HintAction::Back];
+        assert_eq!(
+            InstructionRenderer::hint_line(&actions, false),
+            "Up/Down: Navigate  |  ENTER: Select  |  ESC: Back"
+        );
+        assert_eq!(
+            InstructionRenderer::hint_line(&actions, true),
+            "D-Pad Up/Down: Navigate  |  A: Select  |  B: Back"
+        );
+        assert_eq!(HintAction::CloseLog.prompt(false), "ESC/H: Back");
     }
 }
@@ -0,0 +1,96 @@
+use crate::models::{VirtualKey, VirtualKeyboard};
+use crate::ui::config::{ScreenConfig, VirtualKeyboardConfig};
+use crate::ui::sdf_text::SdfText;
+use raylib::color::Color;
+use raylib::drawing::{RaylibDraw, RaylibDrawHandle};
+use raylib::math::Vector2;
+use raylib::prelude::Font;
+
+pub struct KeyboardRenderer;
+
+impl KeyboardRenderer {
+    /// Draw the on-screen keyboard grid, horizontally centered, with the selected key highlighted
+    pub fn draw_virtual_keyboard(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        keyboard: &VirtualKeyboard,
+        y: i32,
+    ) {
+        let columns = VirtualKeyboard::COLUMNS as i32;
+        let grid_width = columns * VirtualKeyboardConfig::KEY_WIDTH
+            + (columns - 1) * VirtualKeyboardConfig::KEY_GAP;
+        let start_x = (ScreenConfig::WIDTH - grid_width) / 2;
+
+        for row in 0..VirtualKeyboard::ROWS {
+            for col in 0..VirtualKeyboard::COLUMNS {
+                let key = VirtualKeyboard::key_at(row, col);
+                let selected = keyboard.row == row && keyboard.col == col;
+
+                let key_x = start_x
+                    + col as i32
+                        * (VirtualKeyboardConfig::KEY_WIDTH + VirtualKeyboardConfig::KEY_GAP);
+                let key_y = y + row as i32
+                    * (VirtualKeyboardConfig::KEY_HEIGHT + VirtualKeyboardConfig::KEY_GAP);
+
+                let (fill, text_color) = if selected {
+                    (
+                        VirtualKeyboardConfig::SELECTED_COLOR,
+                        VirtualKeyboardConfig::SELECTED_TEXT_COLOR,
+                    )
+                } else {
+                    (
+                        VirtualKeyboardConfig::KEY_COLOR,
+                        VirtualKeyboardConfig::TEXT_COLOR,
+                    )
+                };
+
+                d.draw_rectangle(
+                    key_x,
+                    key_y,
+                    VirtualKeyboardConfig::KEY_WIDTH,
+                    VirtualKeyboardConfig::KEY_HEIGHT,
+                    fill,
+                );
+                d.draw_rectangle_lines(
+                    key_x,
+                    key_y,
+                    VirtualKeyboardConfig::KEY_WIDTH,
+                    VirtualKeyboardConfig::KEY_HEIGHT,
+                    VirtualKeyboardConfig::KEY_BORDER_COLOR,
+                );
+
+                Self::draw_key_label(d, font, key, key_x, key_y, text_color);
+            }
+        }
+    }
+
+    fn draw_key_label(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        key: VirtualKey,
+        key_x: i32,
+        key_y: i32,
+        color: Color,
+    ) {
+        let label = key.label();
+        let size = match key {
+            VirtualKey::Letter(_) => VirtualKeyboardConfig::TEXT_SIZE,
+            _ => VirtualKeyboardConfig::SMALL_TEXT_SIZE,
+        };
+
+        // Rough centering based on approximate character width
+        let text_width = label.len() as f32 * size * 0.5;
+        let text_x = key_x as f32 + (VirtualKeyboardConfig::KEY_WIDTH as f32 - text_width) / 2.0;
+        let text_y = key_y as f32 + (VirtualKeyboardConfig::KEY_HEIGHT as f32 - size) / 2.0;
+
+        SdfText::draw(
+            d,
+            font,
+            &label,
+            Vector2::new(text_x, text_y),
+            size,
+            1.0,
+            color,
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::game::AudioEvent;
+use crate::ui::config::{LoadingConfig, ScreenConfig};
+use raylib::prelude::*;
+
+// One piece of startup loading. Each runs between two frames of the loading screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoadStep {
+    DefaultFonts,
+    TitleFonts,
+    CardAtlas,
+    FallbackSound,
+    EventSound(AudioEvent),
+}
+
+impl LoadStep {
+    fn label(&self) -> &'static str {
+        match self {
+            LoadStep::DefaultFonts | LoadStep::TitleFonts => "Loading fonts",
+            LoadStep::CardAtlas => "Loading card art",
+            LoadStep::FallbackSound | LoadStep::EventSound(_) => "Loading sounds",
+        }
+    }
+}
+
+/// Progress through the startup assets, drawn with raylib's built-in font since the game's
+/// own fonts are among the things being loaded
+pub struct LoadingScreen {
+    steps: Vec<LoadStep>,
+    done: usize,
+    missing: Vec<String>,   // Assets that didn't load, with what's used instead
+    fallback_sounds: usize, // Events without a sound of their own, which play the click
+}
+
+impl LoadingScreen {
+    pub fn new(sound_events: impl IntoIterator<Item = AudioEvent>) -> Self {
+        let mut steps = vec![
+            LoadStep::DefaultFonts,
+            LoadStep::TitleFonts,
+            LoadStep::CardAtlas,
+            LoadStep::FallbackSound,
+        ];
+        steps.extend(sound_events.into_iter().map(LoadStep::EventSound));
+
+        LoadingScreen {
+            steps,
+            done: 0,
+            missing: Vec::new(),
+            fallback_sounds: 0,
+        }
+    }
+
+    /// The step to run next, or None once everything is loaded
+    pub fn next_step(&self) -> Option<LoadStep> {
+        self.steps.get(self.done).copied()
+    }
+
+    pub fn step_done(&mut self) {
+        self.done = (self.done + 1).min(self.steps.len());
+    }
+
+    pub fn report_missing(&mut self, message: impl Into<String>) {
+        self.missing.push(message.into());
+    }
+
+    pub fn count_fallback_sound(&mut self) {
+        self.fallback_sounds += 1;
+    }
+
+    pub fn missing(&self) -> &[String] {
+        &self.missing
+    }
+
+    /// Fraction of the steps finished, from 0 to 1
+    pub fn progress(&self) -> f32 {
+        if self.steps.is_empty() {
+            1.0
+        } else {
+            self.done as f32 / self.steps.len() as f32
+        }
+    }
+
+    pub fn draw(&self, d: &mut RaylibDrawHandle) {
+        d.clear_background(LoadingConfig::BACKGROUND_COLOR);
+
+        let bar_x = (ScreenConfig::WIDTH - LoadingConfig::BAR_WIDTH) / 2;
+        let label = self.next_step().map_or("Ready", |step| step.label());
+        d.draw_text(
+            label,
+            bar_x,
+            LoadingConfig::BAR_Y - LoadingConfig::LABEL_GAP,
+            LoadingConfig::TEXT_SIZE,
+            LoadingConfig::TEXT_COLOR,
+        );
+        let percent = format!("{}%", (self.progress() * 100.0).round() as i32);
+        let percent_width = d.measure_text(&percent, LoadingConfig::TEXT_SIZE);
+        d.draw_text(
+            &percent,
+            bar_x + LoadingConfig::BAR_WIDTH - percent_width,
+            LoadingConfig::BAR_Y - LoadingConfig::LABEL_GAP,
+            LoadingConfig::TEXT_SIZE,
+            LoadingConfig::TEXT_COLOR,
+        );
+
+        d.draw_rectangle(
+            bar_x,
+            LoadingConfig::BAR_Y,
+            LoadingConfig::BAR_WIDTH,
+            LoadingConfig::BAR_HEIGHT,
+            LoadingConfig::BAR_BACKGROUND_COLOR,
+        );
+        d.draw_rectangle(
+            bar_x,
+            LoadingConfig::BAR_Y,
+            (LoadingConfig::BAR_WIDTH as f32 * self.progress()) as i32,
+            LoadingConfig::BAR_HEIGHT,
+            LoadingConfig::BAR_FILL_COLOR,
+        );
+        d.draw_rectangle_lines(
+            bar_x,
+            LoadingConfig::BAR_Y,
+            LoadingConfig::BAR_WIDTH,
+            LoadingConfig::BAR_HEIGHT,
+            LoadingConfig::BAR_BORDER_COLOR,
+        );
+
+        let mut y = LoadingConfig::BAR_Y + LoadingConfig::BAR_HEIGHT + LoadingConfig::LIST_GAP;
+        for message in &self.missing {
+            d.draw_text(
+                &format!("Missing {}", message),
+                bar_x,
+                y,
+                LoadingConfig::TEXT_SIZE,
+                LoadingConfig::MISSING_COLOR,
+            );
+            y += LoadingConfig::LINE_HEIGHT;
+        }
+        if self.fallback_sounds > 0 {
+            d.draw_text(
+                &format!("{} sound(s) use the click fallback", self.fallback_sounds),
+                bar_x,
+                y,
+                LoadingConfig::TEXT_SIZE,
+                LoadingConfig::NOTE_COLOR,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_run_in_order() {
+        let mut loading = LoadingScreen::new([AudioEvent::DropCard, AudioEvent::GameOver]);
+        assert_eq!(loading.next_step(), Some(LoadStep::DefaultFonts));
+        assert_eq!(loading.progress(), 0.0);
+
+        let mut steps = Vec::new();
+        while let Some(step) = loading.next_step() {
+            steps.push(step);
+            loading.step_done();
+        }
+        assert_eq!(steps.len(), 6);
+        assert_eq!(steps[5], LoadStep::EventSound(AudioEvent::GameOver));
+        assert_eq!(loading.progress(), 1.0);
+
+        // Extra calls don't run past the end
+        loading.step_done();
+        assert_eq!(loading.progress(), 1.0);
+    }
+}
@@ -1,5 +1,13 @@
-use crate::game::Game;
-use crate::ui::config::{HighScoreConfig, MainMenuConfig, ScreenConfig};
+use crate::build_info::BuildInfo;
+use crate::game::{ForfeitOption, Game, PauseMenuOption};
+use crate::models::{Difficulty, LeaderboardPeriod};
+use crate::ui::config::{
+    HighScoreConfig, MainMenuConfig, PauseMenuConfig, ScreenConfig, StreamerConfig,
+};
+use crate::ui::instruction_renderer::{HintAction, InstructionRenderer};
+use crate::ui::sdf_text::SdfText;
+use crate::ui::text_layout::TextLayout;
+use crate::ui::widgets::{MenuLayout, WidgetStyle, Widgets};
 use raylib::color::Color;
 use raylib::drawing::{RaylibDraw, RaylibDrawHandle};
 use raylib::math::Vector2;
@@ -63,52 +71,8 @@ impl HighScoreLayout {
     }
 }
 
-// Pre-computed main menu layout
-struct MainMenuLayout {
-    base_x: i32,
-    base_y: i32,
-    option_spacing: i32,
-    option_width: i32,
-    option_height: i32,
-    text_x_offset: i32,
-    text_y_offset: i32,
-    instruction_y: i32,
-    // Pre-computed colors
-    selected_bg: Color,
-    unselected_bg: Color,
-    selected_text_color: Color,
-    unselected_text_color: Color,
-    border_color: Color,
-    instruction_color: Color,
-}
-
-impl MainMenuLayout {
-    fn new() -> Self {
-        let base_x = MainMenuConfig::BASE_X;
-        let base_y = MainMenuConfig::BASE_Y;
-
-        Self {
-            base_x,
-            base_y,
-            option_spacing: MainMenuConfig::OPTION_SPACING,
-            option_width: MainMenuConfig::OPTION_WIDTH,
-            option_height: MainMenuConfig::OPTION_HEIGHT,
-            text_x_offset: MainMenuConfig::TEXT_X_OFFSET,
-            text_y_offset: MainMenuConfig::TEXT_Y_OFFSET,
-            instruction_y: base_y + MainMenuConfig::INSTRUCTION_Y_OFFSET,
-            selected_bg: MainMenuConfig::SELECTED_BG,
-            unselected_bg: MainMenuConfig::UNSELECTED_BG,
-            selected_text_color: MainMenuConfig::SELECTED_TEXT_COLOR,
-            unselected_text_color: MainMenuConfig::UNSELECTED_TEXT_COLOR,
-            border_color: MainMenuConfig::BORDER_COLOR,
-            instruction_color: MainMenuConfig::INSTRUCTION_COLOR,
-        }
-    }
-}
-
 // Thread-safe lazy static initialization
 static HIGH_SCORE_LAYOUT: LazyLock<HighScoreLayout> = LazyLock::new(HighScoreLayout::new);
-static MAIN_MENU_LAYOUT: LazyLock<MainMenuLayout> = LazyLock::new(MainMenuLayout::new);
 
 impl MenuRenderer {
     pub fn draw_main_menu(
@@ -117,78 +81,124 @@ impl MenuRenderer {
         game: &Game,
         has_controller: bool,
     ) {
-        let layout = &*MAIN_MENU_LAYOUT;
-        let options = ["Start New Game", "Settings", "Quit"];
-
-        for (i, &option_text) in options.iter().enumerate() {
-            let option_y = layout.base_y + i as i32 * layout.option_spacing;
-            let is_selected = game.selected_main_option == i;
-
-            // Draw selection background
-            let bg_color = if is_selected {
-                layout.selected_bg
-            } else {
-                layout.unselected_bg
-            };
-
-            d.draw_rectangle(
-                layout.base_x,
-                option_y,
-                layout.option_width,
-                layout.option_height,
-                bg_color,
-            );
+        let model = game.main_menu();
+        let options: Vec<&str> = model.options.iter().map(|option| option.label()).collect();
+        Widgets::list(
+            d,
+            font,
+            &MenuLayout::main_menu().packed(model.len()),
+            &options,
+            model.focused(game.selected_main_option),
+            &WidgetStyle::menu(game),
+        );
 
-            // Draw border for selected option
-            if is_selected {
-                d.draw_rectangle_lines(
-                    layout.base_x,
-                    option_y,
-                    layout.option_width,
-                    layout.option_height,
-                    layout.border_color,
-                );
-            }
+        // Draw instructions, with the continue shortcut while a saved run is waiting
+        let hints: &[HintAction] = if game.saved_run.is_some() {
+            &[
+                HintAction::Navigate,
+                HintAction::Select,
+                HintAction::LeaderboardPeriod,
+                HintAction::ContinueSavedRun,
+            ]
+        } else {
+            &[
+                HintAction::Navigate,
+                HintAction::Select,
+                HintAction::LeaderboardPeriod,
+            ]
+        };
+        InstructionRenderer::draw_hints(
+            d,
+            font,
+            hints,
+            has_controller,
+            (MainMenuConfig::BASE_Y + MainMenuConfig::INSTRUCTION_Y_OFFSET) as f32,
+        );
+    }
 
-            // Draw option text
-            let text_color = if is_selected {
-                layout.selected_text_color
-            } else {
-                layout.unselected_text_color
-            };
+    pub fn draw_pause_menu(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        game: &Game,
+        selected: PauseMenuOption,
+        has_controller: bool,
+    ) {
+        let options = PauseMenuOption::ALL.map(|option| option.label());
+        let selected_index = PauseMenuOption::ALL
+            .iter()
+            .position(|&option| option == selected)
+            .unwrap_or(0);
+        Widgets::list(
+            d,
+            font,
+            &MenuLayout::pause_menu(),
+            &options,
+            selected_index,
+            &WidgetStyle::menu(game),
+        );
 
-            d.draw_text_ex(
-                font,
-                option_text,
-                Vector2::new(
-                    (layout.base_x + layout.text_x_offset) as f32,
-                    (option_y + layout.text_y_offset) as f32,
-                ),
-                MainMenuConfig::TEXT_SIZE,
-                MainMenuConfig::TEXT_SPACING,
-                text_color,
-            );
-        }
+        InstructionRenderer::draw_hints(
+            d,
+            font,
+            &[
+                HintAction::Navigate,
+                HintAction::Select,
+                HintAction::Resume,
+                HintAction::Restart,
+                HintAction::CombinationLog,
+            ],
+            has_controller,
+            PauseMenuConfig::INSTRUCTION_Y as f32,
+        );
+    }
 
-        // Draw instructions
-        let instruction_text = if has_controller {
-            "D-Pad Up/Down: Navigate | A: Select"
-        } else {
-            "Up/Down: Navigate | ENTER: Select"
-        };
+    // The quit-to-menu dialog takes the pause menu's place on screen
+    pub fn draw_forfeit_menu(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        game: &Game,
+        selected: ForfeitOption,
+        has_controller: bool,
+    ) {
+        let options = ForfeitOption::ALL.map(|option| option.label());
+        let selected_index = ForfeitOption::ALL
+            .iter()
+            .position(|&option| option == selected)
+            .unwrap_or(0);
+        Widgets::list(
+            d,
+            font,
+            &MenuLayout::pause_menu(),
+            &options,
+            selected_index,
+            &WidgetStyle::menu(game),
+        );
 
-        // measure instruction_text to get offset to center the text
-        let instruction_width =
-            d.measure_text(instruction_text, MainMenuConfig::INSTRUCTION_SIZE as i32);
-        let instruction_x: f32 = 30f32 + (ScreenConfig::WIDTH - instruction_width) as f32 / 2f32;
+        InstructionRenderer::draw_hints(
+            d,
+            font,
+            &[HintAction::Navigate, HintAction::Select, HintAction::Back],
+            has_controller,
+            PauseMenuConfig::INSTRUCTION_Y as f32,
+        );
+    }
 
-        d.draw_text_ex(
+    /// The version, commit and build date in the bottom right corner, for bug reports
+    pub fn draw_build_info(d: &mut RaylibDrawHandle, font: &Font) {
+        let text = BuildInfo::CURRENT.to_string();
+        let size = TextLayout::measure(font, &text, MainMenuConfig::BUILD_INFO_SIZE, 1.0);
+        let position = Vector2::new(
+            ScreenConfig::WIDTH as f32 - MainMenuConfig::BUILD_INFO_MARGIN - size.x,
+            ScreenConfig::HEIGHT as f32 - MainMenuConfig::BUILD_INFO_MARGIN - size.y,
+        );
+        SdfText::draw(
+            d,
             font,
-            instruction_text,
-            Vector2::new(instruction_x, layout.instruction_y as f32),
-            MainMenuConfig::INSTRUCTION_SIZE,
-            MainMenuConfig::INSTRUCTION_SPACING,
-            layout.instruction_color,
+            &text,
+            position,
+            MainMenuConfig::BUILD_INFO_SIZE,
+            1.0,
+            MainMenuConfig::BUILD_INFO_COLOR,
         );
     }
 
@@ -209,38 +219,45 @@ impl MenuRenderer {
             layout.background_color,
         );
 
-        // High scores title - centered above both columns
-        let title_text = "High Scores";
-        let title_width = d.measure_text(title_text, HighScoreConfig::TITLE_SIZE as i32);
-        let title_x = layout.base_x + HighScoreConfig::COLUMN_WIDTH - title_width / 2;
+        // Relax mode keeps its own board, shown while the setting is on
+        let relaxed = game.settings.relax_mode;
 
-        d.draw_text_ex(
+        // High scores title - centered above both columns
+        let title_text = if relaxed {
+            "Relax Scores"
+        } else {
+            "High Scores"
+        };
+        SdfText::draw(
+            d,
             title_font,
             title_text,
-            Vector2::new(title_x as f32, layout.base_y as f32),
+            Vector2::new(layout.base_x as f32, layout.base_y as f32),
             HighScoreConfig::TITLE_SIZE,
             HighScoreConfig::TITLE_SPACING,
             layout.title_color,
         );
+        Self::draw_leaderboard_tabs(d, font, game.leaderboard_period, layout);
+
+        // Streamer mode keeps other players' initials off the stream
+        let hide_initials = game.settings.streamer_mode.is_on();
 
         // Split scores by difficulty
-        let easy_scores: Vec<_> = game
-            .high_scores
-            .iter()
-            .filter(|s| s.difficulty == "Easy")
-            .take(3)
-            .collect();
-        let hard_scores: Vec<_> = game
-            .high_scores
-            .iter()
-            .filter(|s| s.difficulty == "Hard")
-            .take(3)
-            .collect();
+        let top_scores = |difficulty: Difficulty| -> Vec<_> {
+            game.high_scores
+                .iter()
+                .filter(|s| s.difficulty == difficulty && s.relaxed == relaxed)
+                .take(3)
+                .collect()
+        };
+        let easy_scores = top_scores(Difficulty::Easy);
+        let hard_scores = top_scores(Difficulty::Hard);
 
         // Draw Easy column
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             title_font,
-            "Easy",
+            &Difficulty::Easy.to_string(),
             Vector2::new(
                 layout.base_x as f32,
                 (layout.base_y + HighScoreConfig::COLUMN_TITLE_Y_OFFSET) as f32,
@@ -257,13 +274,15 @@ impl MenuRenderer {
             layout.base_x,
             layout.base_y + HighScoreConfig::TITLE_Y_OFFSET,
             layout,
+            hide_initials,
         );
 
         // Draw Hard column
         let hard_column_x = layout.base_x + HighScoreConfig::COLUMN_WIDTH;
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             title_font,
-            "Hard",
+            &Difficulty::Hard.to_string(),
             Vector2::new(
                 hard_column_x as f32,
                 (layout.base_y + HighScoreConfig::COLUMN_TITLE_Y_OFFSET) as f32,
@@ -280,13 +299,20 @@ impl MenuRenderer {
             hard_column_x,
             layout.base_y + HighScoreConfig::TITLE_Y_OFFSET,
             layout,
+            hide_initials,
         );
 
         // Show a message if no scores at all
-        if game.high_scores.is_empty() {
-            d.draw_text_ex(
+        if easy_scores.is_empty() && hard_scores.is_empty() {
+            let message = match game.leaderboard_period {
+                LeaderboardPeriod::Today => "No scores yet today - be the first!",
+                LeaderboardPeriod::ThisWeek => "No scores yet this week - be the first!",
+                LeaderboardPeriod::AllTime => "No high scores yet - be the first!",
+            };
+            SdfText::draw(
+                d,
                 font,
-                "No high scores yet - be the first!",
+                message,
                 Vector2::new(
                     (layout.base_x + HighScoreConfig::COLUMN_WIDTH / 4) as f32,
                     (layout.base_y + HighScoreConfig::TITLE_Y_OFFSET + 30) as f32,
@@ -298,6 +324,47 @@ impl MenuRenderer {
         }
     }
 
+    // The periods the leaderboard can show, laid out right to left from the panel's right
+    // edge, with the one showing underlined
+    fn draw_leaderboard_tabs(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        selected: LeaderboardPeriod,
+        layout: &HighScoreLayout,
+    ) {
+        let mut x =
+            (layout.background_x + layout.background_width - HighScoreConfig::TAB_MARGIN) as f32;
+        let y = (layout.base_y + HighScoreConfig::TAB_Y_OFFSET) as f32;
+        for period in LeaderboardPeriod::ALL.iter().rev() {
+            let label = period.label();
+            let size = TextLayout::measure(
+                font,
+                label,
+                HighScoreConfig::TAB_SIZE,
+                HighScoreConfig::TAB_SPACING,
+            );
+            x -= size.x;
+            let color = if *period == selected {
+                layout.title_color
+            } else {
+                HighScoreConfig::TAB_COLOR
+            };
+            SdfText::draw(
+                d,
+                font,
+                label,
+                Vector2::new(x, y),
+                HighScoreConfig::TAB_SIZE,
+                HighScoreConfig::TAB_SPACING,
+                color,
+            );
+            if *period == selected {
+                d.draw_rectangle(x as i32, (y + size.y) as i32 + 2, size.x as i32, 2, color);
+            }
+            x -= HighScoreConfig::TAB_GAP;
+        }
+    }
+
     fn draw_scores_column(
         d: &mut RaylibDrawHandle,
         font: &Font,
@@ -305,6 +372,7 @@ impl MenuRenderer {
         column_x: i32,
         start_y: i32,
         layout: &HighScoreLayout,
+        hide_initials: bool,
     ) {
         for (i, score) in scores.iter().enumerate() {
             let y_offset = start_y + i as i32 * layout.score_y_spacing;
@@ -328,7 +396,8 @@ impl MenuRenderer {
 
             // Rank number
             let rank_text = &(i + 1).to_string();
-            d.draw_text_ex(
+            SdfText::draw(
+                d,
                 font,
                 rank_text,
                 Vector2::new((circle_center_x - 6) as f32, (circle_center_y - 8) as f32),
@@ -338,8 +407,24 @@ impl MenuRenderer {
             );
 
             // Score details
-            let initials_and_score = format!("{} - {} pts", score.player_initials, score.score);
-            d.draw_text_ex(
+            let initials = if hide_initials {
+                StreamerConfig::HIDDEN_INITIALS
+            } else {
+                score.short_name()
+            };
+            let modded = if score.modded { " [MOD]" } else { "" };
+            let modified = if score.modified { " [EDITED]" } else { "" };
+            let deck = score
+                .deck_template
+                .tag()
+                .map(|tag| format!(" [{}]", tag))
+                .unwrap_or_default();
+            let initials_and_score = format!(
+                "{} - {} pts{}{}{}",
+                initials, score.score, modded, modified, deck
+            );
+            SdfText::draw(
+                d,
                 font,
                 &initials_and_score,
                 Vector2::new((column_x + 45) as f32, (y_offset + 8) as f32),
@@ -351,7 +436,8 @@ impl MenuRenderer {
 
         // Show message if this difficulty has no scores
         if scores.is_empty() {
-            d.draw_text_ex(
+            SdfText::draw(
+                d,
                 font,
                 "No scores yet",
                 Vector2::new((column_x + 45) as f32, (start_y + 10) as f32),
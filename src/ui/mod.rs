@@ -12,87 +12,138 @@
 //! - Performance monitoring with FPS counter
 
 // Sub-modules
-pub mod animated_background;
+mod ambient_glow;
+mod animated_background;
 mod atlas_card_renderer;
 mod background_renderer;
+mod board_snapshot;
+mod card_back_renderer;
 mod card_renderer;
 pub mod config;
+mod diagnostics;
 mod drawing_helpers;
-pub mod input_handler;
+mod input_handler;
 mod instruction_renderer;
+mod keyboard_renderer;
+mod loading_screen;
 mod menu_renderer;
-pub mod particle_system;
+mod next_card_preview;
+mod particle;
+mod particle_system;
+mod score_popups;
+mod screens;
+mod sdf_text;
+mod seasons;
+mod text_layout;
 mod text_renderer;
+mod theme;
+mod toast;
+mod touch_input;
+mod widgets;
 
 // Re-export for easy access
 pub use drawing_helpers::DrawingHelpers;
+pub use screens::handle_state_input;
 
+use self::ambient_glow::AmbientGlow;
 use self::animated_background::AnimatedBackground;
-use self::config::{BoardConfig, FPSConfig, ParticleConfig, PerformanceConfig, ScreenConfig};
+use self::board_snapshot::BoardSnapshot;
+use self::config::{
+    BoardConfig, DiagnosticsConfig, FPSConfig, ParticleConfig, PerformanceConfig, ScorePopupConfig,
+    ScreenConfig, TextConfig, ToastConfig,
+};
+use self::diagnostics::DiagnosticsReport;
 // Board offset constants are now in ScreenConfig
 use self::input_handler::InputHandler;
+use self::loading_screen::{LoadStep, LoadingScreen};
 use self::particle_system::ParticleSystem;
+use self::score_popups::ScorePopups;
+use self::sdf_text::{SDF_BASE_SIZE, SdfText};
+use self::toast::ToastSystem;
 use crate::audio::AudioSystem;
-use crate::game::Game;
+use crate::error::DropJackError;
+use crate::game::{ClearedCombination, Game, GameEvent};
+use crate::mods::catalog::{ModKind, THEME_ATLAS};
+#[cfg(not(target_os = "emscripten"))]
+use crate::presence::LiveFeed;
+use crate::presence::{OverlayFile, Presence};
+use crate::update_check::UpdateCheck;
 use raylib::prelude::*;
 
-/// Font collection for different size ranges
+// Ace of spades from the card atlas, built into the binary so the icon never goes missing
+const WINDOW_ICON_PNG: &[u8] = include_bytes!("../../assets/icon.png");
+
+const DEFAULT_FONT_PATH: &str = "assets/fonts/default.ttf";
+const TITLE_FONT_PATH: &str = "assets/fonts/title.ttf";
+const CARD_ATLAS_PATH: &str = "assets/cards/atlas.png";
+
+/// Make sure the files the window can't do without are where it will look for them. Sounds
+/// have a fallback; the fonts and the card atlas don't.
+pub fn check_assets() -> Result<(), DropJackError> {
+    for path in [DEFAULT_FONT_PATH, TITLE_FONT_PATH, CARD_ATLAS_PATH] {
+        if !std::path::Path::new(path).is_file() {
+            return Err(DropJackError::AssetMissing(path.into()));
+        }
+    }
+    Ok(())
+}
+
+/// The fonts loaded from one font file. Each file is read once and turned into a single
+/// distance field atlas that draws crisply at every size; when distance fields aren't
+/// available it falls back to one bitmap atlas at the body text size.
 #[derive(Debug)]
 pub struct FontCollection {
-    /// Small text (12-24px) - loaded at base size 24
-    small: Font,
-    /// Medium text (24-48px) - loaded at base size 48  
-    medium: Font,
-
-    /// Extra large text (96px+) - loaded at base size 120
-    extra_large: Font,
+    font: Font,
+    /// Font file the collection was loaded from
+    path: String,
+    /// Whether the atlas is a distance field rather than the bitmap fallback
+    sdf: bool,
 }
 
 impl FontCollection {
-    /// Create a new font collection from a single font file
+    /// Base size of the bitmap fallback, the size most body text is drawn at
+    const BITMAP_BASE_SIZE: i32 = 48;
+
+    /// Create a font collection from a single font file
     fn new(
         rl: &mut RaylibHandle,
         thread: &RaylibThread,
         font_path: &str,
         description: &str,
     ) -> Self {
-        println!(
-            "Loading optimized font collection for {}: {}",
-            description, font_path
-        );
-
-        // Load fonts at their optimal base sizes using LoadFontEx for crystal clear rendering
-        let small = Self::load_font_ex(
-            rl,
-            thread,
-            font_path,
-            24,
-            &format!("{} (small)", description),
-        );
-        let medium = Self::load_font_ex(
-            rl,
-            thread,
-            font_path,
-            48,
-            &format!("{} (medium)", description),
-        );
+        println!("Loading font for {}: {}", description, font_path);
 
-        // For title font, load at 120px which is the exact size used (TextConfig::TITLE_SIZE)
-        let extra_large = Self::load_font_ex(
-            rl,
-            thread,
-            font_path,
-            120,
-            &format!("{} (extra large)", description),
-        );
+        let sdf_font = match std::fs::read(font_path) {
+            Ok(data) => SdfText::load_font(&data),
+            Err(e) => {
+                eprintln!("Warning: Could not read font {}: {}", font_path, e);
+                None
+            }
+        };
+        if let Some(font) = sdf_font {
+            println!(
+                "  ✓ Loaded {} as a distance field at {}px",
+                description, SDF_BASE_SIZE
+            );
+            return FontCollection {
+                font,
+                path: font_path.to_string(),
+                sdf: true,
+            };
+        }
 
         FontCollection {
-            small,
-            medium,
-            extra_large,
+            font: Self::load_font_ex(rl, thread, font_path, Self::BITMAP_BASE_SIZE, description),
+            path: font_path.to_string(),
+            sdf: false,
         }
     }
 
+    /// The font to draw text of the given size with. Every size comes from the same atlas
+    fn get_font_for_size(&self, _size: f32) -> &Font {
+        &self.font
+    }
+
     /// Load a font at a specific base size using LoadFontEx for optimal quality
     fn load_font_ex(
         rl: &mut RaylibHandle,
@@ -127,7 +178,7 @@ impl FontCollection {
         Self::apply_font_filtering(&font);
 
         println!(
-            "  ✓ Loaded {} at exact size {}px using LoadFontEx",
+            "  ✓ Loaded {} as a bitmap at {}px using LoadFontEx",
             description, base_size
         );
         font
@@ -171,16 +222,29 @@ impl FontCollection {
 pub struct GameUI {
     rl: RaylibHandle,
     thread: RaylibThread,
-    // Enhanced font system with multiple sizes for optimal rendering
+    // One distance field atlas per font file, drawn at every size
     default_fonts: FontCollection,
     title_fonts: FontCollection,
-    card_atlas: Option<Texture2D>,
+    card_atlas: Texture2D,
     particle_system: ParticleSystem,
     input_handler: InputHandler,
     last_frame_time: std::time::Instant,
     fps_counter: FPSCounter,
     animated_background: AnimatedBackground,
+    board_snapshot: BoardSnapshot,
     audio_system: AudioSystem,
+    toasts: ToastSystem,
+    score_popups: ScorePopups,
+    ambient_glow: AmbientGlow,
+    diagnostics_report: Option<DiagnosticsReport>, // Collected while the diagnostics screen is open
+    window_title: String,                          // Last title set, so it only changes when needed
+    overlay_file: Option<OverlayFile>,             // Only while streamer mode writes the file
+    #[cfg(not(target_os = "emscripten"))]
+    live_feed: Option<LiveFeed>, // Only while streamer mode serves the feed
+    update_check: Option<UpdateCheck>,             // Only when the player has opted in
+    available_update: Option<String>,              // Newer version, held until the start screen
+    #[cfg(feature = "discord")]
+    discord: Option<crate::presence::DiscordPresence>,
 }
 
 struct FPSCounter {
@@ -215,7 +279,8 @@ impl FPSCounter {
 }
 
 impl GameUI {
-    pub fn new() -> Self {
+    #[allow(clippy::new_without_default)] // Opens the window, which a default value shouldn't
+    pub fn new() -> Result<Self, DropJackError> {
         let (mut rl, thread) = raylib::init()
             .size(ScreenConfig::WIDTH, ScreenConfig::HEIGHT)
             .title("DropJack")
@@ -224,33 +289,87 @@ impl GameUI {
         rl.set_target_fps(PerformanceConfig::TARGET_FPS);
         rl.set_exit_key(None); // Disable ESC from closing the window
 
-        // Load enhanced font collections with multiple sizes for optimal rendering
-        println!("Initializing enhanced font system...");
-        let default_fonts =
-            FontCollection::new(&mut rl, &thread, "assets/fonts/default.ttf", "default");
-        let title_fonts = FontCollection::new(&mut rl, &thread, "assets/fonts/title.ttf", "title");
-        println!("✓ Font system initialized with bilinear filtering");
-
-        // Load the card atlas
-        let card_atlas = rl.load_texture(&thread, "assets/cards/atlas.png").ok();
-        if card_atlas.is_none() {
-            eprintln!(
-                "Warning: Could not load card atlas assets/cards/atlas.png, using fallback rendering"
-            );
+        match Image::load_image_from_mem(".png", WINDOW_ICON_PNG) {
+            Ok(icon) => rl.set_window_icon(&icon),
+            Err(e) => eprintln!("Warning: Could not load window icon: {}", e),
         }
 
-        // Initialize audio system
-        let audio_system = AudioSystem::new();
+        // Load assets one step per frame behind a progress bar, so the window paints right
+        // away instead of freezing on a slow disk
+        let mut audio_system = AudioSystem::without_sounds();
+        let mut loading = LoadingScreen::new(
+            AudioSystem::sound_files()
+                .into_iter()
+                .map(|(event, _)| event),
+        );
+        let mut default_fonts = None;
+        let mut title_fonts = None;
+        let mut card_atlas = None;
+
+        println!("Initializing font system...");
+        SdfText::init_shader();
+        while let Some(step) = loading.next_step() {
+            loading.draw(&mut rl.begin_drawing(&thread));
+
+            match step {
+                LoadStep::DefaultFonts => {
+                    default_fonts = Some(FontCollection::new(
+                        &mut rl,
+                        &thread,
+                        DEFAULT_FONT_PATH,
+                        "default",
+                    ));
+                }
+                LoadStep::TitleFonts => {
+                    title_fonts = Some(FontCollection::new(
+                        &mut rl,
+                        &thread,
+                        TITLE_FONT_PATH,
+                        "title",
+                    ));
+                    println!("✓ Font system initialized");
+                }
+                LoadStep::CardAtlas => match rl.load_texture(&thread, CARD_ATLAS_PATH) {
+                    Ok(atlas) => card_atlas = Some(atlas),
+                    Err(e) => {
+                        // Every card is drawn from the atlas, so there's nothing to play without it
+                        eprintln!("Could not load card atlas {}: {}", CARD_ATLAS_PATH, e);
+                        return Err(DropJackError::AssetMissing(CARD_ATLAS_PATH.into()));
+                    }
+                },
+                LoadStep::FallbackSound => {
+                    if !audio_system.load_fallback_sound() {
+                        loading.report_missing(
+                            "assets/audio/click.ogg (events without a sound are silent)",
+                        );
+                    }
+                }
+                LoadStep::EventSound(event) => {
+                    if !audio_system.load_event_sound(event) {
+                        loading.count_fallback_sound();
+                    }
+                }
+            }
+            loading.step_done();
+        }
+        loading.draw(&mut rl.begin_drawing(&thread));
 
         // Print audio status for debugging/information
         audio_system.print_audio_status();
 
-        GameUI {
+        // Missing assets stay on screen after loading as toasts
+        let mut toasts = ToastSystem::new();
+        for message in loading.missing() {
+            toasts.show(format!("Missing {}", message));
+        }
+
+        Ok(GameUI {
             rl,
             thread,
-            default_fonts,
-            title_fonts,
-            card_atlas,
+            default_fonts: default_fonts.expect("Fonts load before the loading screen finishes"),
+            title_fonts: title_fonts.expect("Fonts load before the loading screen finishes"),
+            card_atlas: card_atlas
+                .expect("The card atlas loads before the loading screen finishes"),
             particle_system: ParticleSystem::builder()
                 .particle_capacity(ParticleConfig::SYSTEM_CAPACITY)
                 .explosion_particle_count(ParticleConfig::EXPLOSION_COUNT)
@@ -259,15 +378,35 @@ impl GameUI {
             last_frame_time: std::time::Instant::now(),
             fps_counter: FPSCounter::new(),
             animated_background: AnimatedBackground::new(),
+            board_snapshot: BoardSnapshot::new(),
             audio_system,
-        }
+            toasts,
+            score_popups: ScorePopups::new(),
+            ambient_glow: AmbientGlow::new(),
+            diagnostics_report: None,
+            window_title: "DropJack".to_string(),
+            overlay_file: None,
+            #[cfg(not(target_os = "emscripten"))]
+            live_feed: None,
+            update_check: None,
+            available_update: None,
+            #[cfg(feature = "discord")]
+            discord: crate::presence::DiscordPresence::from_env(),
+        })
     }
 
     pub fn run(&mut self, game: &mut Game) {
+        self.apply_packs(game);
+        if game.settings.check_for_updates {
+            self.update_check = Some(UpdateCheck::start());
+        }
         while !self.rl.window_should_close() {
             self.update_frame(game);
             self.render_frame(game);
         }
+
+        // Don't lose a settings change made just before closing the window
+        game.flush_settings();
     }
 
     /// Separated update logic for better organization
@@ -280,63 +419,167 @@ impl GameUI {
         // Update FPS counter
         self.fps_counter.update(delta_time);
 
+        // Alt-tabbing away (or hiding the browser tab) should never cost the player a run
+        if !self.rl.is_window_focused() {
+            game.pause_for_focus_loss();
+        }
+
         // Handle input
         self.input_handler.handle_input(&mut self.rl, game);
 
         // Apply VSync setting if it changed
         self.apply_vsync_setting(game);
 
+        // Handheld runs fullscreen, desktop in a window
+        self.apply_display_profile(game);
+
+        // Mode and score in the title bar, the streamer overlay file and on Discord, when enabled
+        self.update_presence(game);
+
+        // Follow the chosen output device, and devices being plugged in or removed
+        self.audio_system
+            .update_output_device(game.settings.audio_device.as_deref());
+        if game.audio_devices != self.audio_system.known_devices() {
+            game.audio_devices = self.audio_system.known_devices().to_vec();
+        }
+
         // Apply music settings
         self.apply_music_settings(game);
 
+        // Music intensity follows the board during play and settles back everywhere else
+        let danger = if game.is_playing() {
+            game.board.danger()
+        } else {
+            0.0
+        };
+        self.audio_system.update_music(delta_time, danger);
+
         // Update game state (only when not paused and not in settings)
         if !game.is_paused() && !game.is_settings() {
             game.update();
         }
 
+        // Motion-sensitive players get fades instead of bursts and drifting cards
+        self.particle_system
+            .set_reduced_motion(game.settings.reduced_motion);
+        self.animated_background
+            .set_reduced_motion(game.settings.reduced_motion);
+        self.animated_background
+            .set_card_back(game.settings.card_back);
+        self.animated_background.set_season(game.active_season());
+
         // Update animated background for title and quit screens
-        if game.is_start_screen() || game.is_quit_confirm() {
+        if game.is_start_screen() || game.is_quit_confirm() || game.is_setup_wizard() {
             self.animated_background.update(delta_time);
         }
+        if game.is_playing() {
+            self.ambient_glow.update(delta_time);
+        }
+
+        // Collect diagnostics once when the screen opens, and drop them when it closes
+        if game.is_diagnostics() {
+            if self.diagnostics_report.is_none() {
+                self.diagnostics_report = Some(DiagnosticsReport::collect(
+                    game,
+                    &self.audio_system,
+                    &[&self.default_fonts, &self.title_fonts],
+                ));
+            }
+        } else {
+            self.diagnostics_report = None;
+        }
+
+        // Write settings once the player stops changing them
+        game.update_settings_save();
 
-        // Process explosions
-        self.process_explosions(game);
+        // Pick up finished score saves and rank lookups from the worker
+        game.poll_score_worker();
 
-        // Process audio events
-        self.process_audio_events(game);
+        // Hand queued game events to audio and effects
+        self.process_game_events(game);
 
         // Update particle system
         self.particle_system.update(delta_time);
+        self.toasts.update();
+        self.score_popups.update();
+
+        // A newer release is mentioned once, on the start screen rather than mid-run
+        if let Some(version) = self.update_check.as_ref().and_then(UpdateCheck::poll) {
+            self.available_update = Some(version);
+        }
+        if game.is_start_screen()
+            && let Some(version) = self.available_update.take()
+        {
+            self.toasts.show(format!("v{} available", version));
+        }
     }
 
     /// Separated render logic for better organization
     fn render_frame(&mut self, game: &Game) {
-        let has_controller = InputHandler::is_controller_connected(&self.rl);
+        // Texture rendering has to happen before the frame's drawing begins
+        self.board_snapshot
+            .update(&mut self.rl, &self.thread, game, &self.card_atlas);
+
+        // Button prompts follow the player's control scheme choice
+        let has_controller = game
+            .settings
+            .control_scheme
+            .shows_controller_prompts(InputHandler::is_controller_connected(&self.rl));
 
         let mut d = self.rl.begin_drawing(&self.thread);
 
         // Use elegant gradient background instead of flat DARKGREEN
         DrawingHelpers::draw_gradient_background(&mut d);
 
-        // Render game state with optimized font selection
-        // Use the extra large title font (120px) for crystal clear title rendering
-        game.state.render(
+        // Render game state; titles and body text each come from their font's single atlas
+        if let Some(report) = &self.diagnostics_report {
+            // Diagnostics draws from data only the UI has (audio, fonts), not from game state
+            screens::render_diagnostics(
+                &mut d,
+                report,
+                has_controller,
+                self.title_fonts.get_font_for_size(TextConfig::TITLE_SIZE),
+                self.default_fonts
+                    .get_font_for_size(DiagnosticsConfig::TEXT_SIZE),
+            );
+        } else {
+            screens::render_state(
+                &mut d,
+                game,
+                has_controller,
+                self.title_fonts.get_font_for_size(TextConfig::TITLE_SIZE),
+                self.default_fonts
+                    .get_font_for_size(TextConfig::SUBTITLE_SIZE * game.settings.text_scale()),
+                &self.card_atlas,
+                &mut self.particle_system,
+                &mut self.animated_background,
+                &self.board_snapshot,
+            );
+        }
+
+        // Score popups float over the board while it's in play, above its ambient light
+        if game.is_playing() {
+            self.ambient_glow.draw(&mut d, game);
+            self.score_popups.draw(
+                &mut d,
+                self.default_fonts
+                    .get_font_for_size(ScorePopupConfig::POINTS_SIZE),
+                game.settings.text_scale(),
+                game.settings.reduced_motion,
+            );
+        }
+
+        // Toasts sit above every screen
+        self.toasts.draw(
             &mut d,
-            game,
-            has_controller,
-            &self.title_fonts.extra_large, // Use 120px font for title
-            &self.default_fonts.medium,    // Use 48px font for default text
-            self.card_atlas
-                .as_ref()
-                .expect("Card atlas must be loaded!"),
-            &mut self.particle_system,
-            &mut self.animated_background,
+            self.default_fonts.get_font_for_size(ToastConfig::FONT_SIZE),
+            game.settings.text_scale(),
         );
 
-        // Render FPS counter with small font (20px) using 24px base
+        // Render FPS counter with small font
         Self::render_fps_counter_static(
             &mut d,
-            &self.default_fonts.small,
+            self.default_fonts.get_font_for_size(FPSConfig::FONT_SIZE),
             self.fps_counter.get_fps(),
         );
     }
@@ -372,7 +615,8 @@ impl GameUI {
         );
 
         // Draw shadow
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             font,
             &fps_text,
             Vector2::new((fps_panel_x + 1) as f32, (FPSConfig::PANEL_Y + 1) as f32),
@@ -382,7 +626,8 @@ impl GameUI {
         );
 
         // Draw main text
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             font,
             &fps_text,
             Vector2::new(fps_panel_x as f32, FPSConfig::PANEL_Y as f32),
@@ -392,34 +637,132 @@ impl GameUI {
         );
     }
 
-    /// Process game explosions and create particle effects
-    fn process_explosions(&mut self, game: &mut Game) {
-        let explosions = game.take_pending_explosions();
-        for (x, y, card) in explosions {
-            let position = Vector2::new(
-                (BoardConfig::OFFSET_X + x * game.board.cell_size + game.board.cell_size / 2)
-                    as f32,
-                (BoardConfig::OFFSET_Y + y * game.board.cell_size + game.board.cell_size / 2)
-                    as f32,
-            );
+    /// Drain the game's event queue and let each subsystem react to the events it cares about
+    fn process_game_events(&mut self, game: &mut Game) {
+        for event in game.take_events() {
+            self.play_event_sound(&event, game);
+            self.spawn_event_particles(&event, game);
 
-            self.particle_system.add_card_explosion(
-                card,
-                position,
-                game.board.cell_size as f32,
-                &self.card_atlas,
-            );
+            if let GameEvent::ChainAdvanced { .. } = &event {
+                self.audio_system.boost_music_intensity();
+            }
+            if let Some(hold) = event.music_duck() {
+                self.audio_system.duck_music(hold);
+            }
+
+            if let GameEvent::CombinationCleared {
+                positions,
+                points,
+                combinations,
+            } = &event
+            {
+                self.show_score_popup(positions, *points, combinations, game);
+            }
+
+            if let GameEvent::ScoreSaveFailed { message } = &event {
+                self.toasts
+                    .show(format!("Couldn't save high score: {}", message));
+            }
+            if let GameEvent::RunSaveFailed { message } = &event {
+                self.toasts.show(format!("Couldn't save run: {}", message));
+            }
+            if let GameEvent::StatisticsExported { key } = &event {
+                self.toasts.show(format!("Statistics exported to {}", key));
+            }
+            if let GameEvent::StatisticsExportFailed { message } = &event {
+                self.toasts
+                    .show(format!("Couldn't export statistics: {}", message));
+            }
+            if let GameEvent::SnapshotSaved { key } = &event {
+                self.toasts.show(format!("Board snapshot saved to {}", key));
+            }
+            if let GameEvent::SnapshotRestored { key } = &event {
+                self.toasts.show(format!("Board restored from {}", key));
+            }
+            if let GameEvent::SnapshotFailed { message } = &event {
+                self.toasts.show(format!("Snapshot failed: {}", message));
+            }
+            if let GameEvent::PuzzleSolved { name, stars } = &event {
+                let plural = if *stars == 1 { "" } else { "s" };
+                self.toasts
+                    .show(format!("{} cleared: {} star{}", name, stars, plural));
+            }
+            if let GameEvent::PuzzleFailed { name } = &event {
+                self.toasts.show(format!("{} not cleared, try again", name));
+            }
+            if let GameEvent::PuzzleUnavailable { message } = &event {
+                self.toasts
+                    .show(format!("Couldn't start puzzle: {}", message));
+            }
+            if let GameEvent::ColumnCleared { penalty, .. } = &event {
+                self.toasts
+                    .show(format!("Board full: column cleared, -{} points", penalty));
+            }
+            if let GameEvent::LevelSaved { path } = &event {
+                self.toasts.show(format!("Level saved to {}", path));
+            }
+            if let GameEvent::LevelSaveFailed { message } = &event {
+                self.toasts
+                    .show(format!("Couldn't save level: {}", message));
+            }
+            if let GameEvent::SeedCopied { code } = &event {
+                match self.rl.set_clipboard_text(code) {
+                    Ok(()) => self.toasts.show(format!("Seed {} copied", code)),
+                    Err(e) => eprintln!("Failed to copy seed: {}", e),
+                }
+            }
+        }
+    }
+
+    /// Show a clear's points over the middle of its cards, with how each combination scored
+    fn show_score_popup(
+        &mut self,
+        positions: &[(i32, i32)],
+        points: i32,
+        combinations: &[ClearedCombination],
+        game: &Game,
+    ) {
+        let Some(center) = Self::board_center(positions, game) else {
+            return;
+        };
+        let breakdown = combinations
+            .iter()
+            .map(ClearedCombination::breakdown)
+            .collect::<Vec<_>>()
+            .join(" + ");
+        self.score_popups.show(points, breakdown, center);
+    }
+
+    /// Screen position of the middle of a set of board cells
+    fn board_center(positions: &[(i32, i32)], game: &Game) -> Option<Vector2> {
+        if positions.is_empty() {
+            return None;
         }
+        let cell_size = game.board.cell_size;
+        let (origin_x, origin_y) = BoardConfig::origin(
+            game.board.width,
+            game.board.height,
+            cell_size,
+            game.settings.mirrored_layout,
+        );
+        let count = positions.len() as f32;
+        let (sum_x, sum_y) = positions
+            .iter()
+            .fold((0.0, 0.0), |(sum_x, sum_y), &(x, y)| {
+                (sum_x + x as f32, sum_y + y as f32)
+            });
+        Some(Vector2::new(
+            origin_x as f32 + (sum_x / count + 0.5) * cell_size as f32,
+            origin_y as f32 + (sum_y / count + 0.5) * cell_size as f32,
+        ))
     }
 
-    /// Process audio events from the game
-    fn process_audio_events(&mut self, game: &mut Game) {
-        let audio_events = game.take_pending_audio_events();
-        for event in audio_events {
-            // Play the appropriate sound for each specific event with volume settings
+    /// Play the sound for an event with the current volume settings
+    fn play_event_sound(&mut self, event: &GameEvent, game: &Game) {
+        if let Some(audio_event) = event.audio_event() {
             let settings = &game.settings;
             self.audio_system.play_event(
-                event,
+                audio_event,
                 settings.sound_effects_volume,
                 settings.sound_effects_muted,
                 &mut self.rl,
@@ -427,6 +770,92 @@ impl GameUI {
         }
     }
 
+    /// Create particle effects for card explosions, landings and new records
+    fn spawn_event_particles(&mut self, event: &GameEvent, game: &Game) {
+        match event {
+            GameEvent::CardExploded { x, y, card } => {
+                let cell_size = game.board.cell_size;
+                let (origin_x, origin_y) = BoardConfig::origin(
+                    game.board.width,
+                    game.board.height,
+                    cell_size,
+                    game.settings.mirrored_layout,
+                );
+                let position = Vector2::new(
+                    (origin_x + x * cell_size + cell_size / 2) as f32,
+                    (origin_y + y * cell_size + cell_size / 2) as f32,
+                );
+
+                self.particle_system.add_card_explosion(
+                    *card,
+                    position,
+                    game.board.cell_size as f32,
+                );
+            }
+            GameEvent::CardLanded { x, y, .. } => {
+                if let Some(center) = Self::board_center(&[(*x, *y)], game) {
+                    let half_cell = game.board.cell_size as f32 / 2.0;
+                    self.particle_system.add_landing_dust(
+                        center + Vector2::new(0.0, half_cell),
+                        game.board.cell_size as f32,
+                    );
+                }
+            }
+            GameEvent::NewRecord { .. } => {
+                self.particle_system
+                    .add_confetti_burst(Vector2::new(ScreenConfig::WIDTH as f32 / 2.0, 220.0));
+            }
+            GameEvent::NaturalBlackjack { positions, bonus } => {
+                if let Some(center) = Self::board_center(positions, game) {
+                    self.particle_system.add_golden_burst(center);
+                    // Above the clear's own popup so the two don't overlap
+                    let above = Vector2::new(0.0, game.board.cell_size as f32);
+                    self.score_popups.show(*bonus, "Blackjack!", center - above);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn update_presence(&mut self, game: &Game) {
+        let presence = Presence::of(game);
+
+        let title = presence.window_title();
+        if title != self.window_title {
+            self.rl.set_window_title(&self.thread, &title);
+            self.window_title = title;
+        }
+
+        if game.settings.streamer_mode.writes_overlay_file() {
+            if self.overlay_file.is_none() {
+                match crate::storage::FileStorage::app_data() {
+                    Ok(storage) => self.overlay_file = Some(OverlayFile::new(Box::new(storage))),
+                    Err(e) => eprintln!("Stream overlay file unavailable: {}", e),
+                }
+            }
+            if let Some(overlay_file) = &mut self.overlay_file {
+                overlay_file.update(&presence);
+            }
+        } else {
+            self.overlay_file = None;
+        }
+
+        // Dropping the feed closes its port and lets every viewer go
+        #[cfg(not(target_os = "emscripten"))]
+        if game.settings.streamer_mode.serves_live_feed() {
+            self.live_feed
+                .get_or_insert_with(LiveFeed::start)
+                .update(game);
+        } else {
+            self.live_feed = None;
+        }
+
+        #[cfg(feature = "discord")]
+        if let Some(discord) = &mut self.discord {
+            discord.update(&presence);
+        }
+    }
+
     /// Apply VSync setting changes
     fn apply_vsync_setting(&mut self, game: &Game) {
         // Note: Raylib doesn't provide runtime VSync control, so we'll just track the setting
@@ -439,6 +868,51 @@ impl GameUI {
         }
     }
 
+    /// Enter or leave fullscreen to match the display profile. Browser builds leave this to
+    /// the page, since fullscreen there needs a user gesture
+    fn apply_display_profile(&mut self, game: &Game) {
+        if cfg!(target_os = "emscripten") {
+            return;
+        }
+        if game.settings.display_profile.fullscreen() != self.rl.is_window_fullscreen() {
+            self.rl.toggle_fullscreen();
+        }
+    }
+
+    /// Swap in the card art and sounds of the enabled theme and audio packs. Only the first
+    /// enabled theme is used, since each one replaces the whole card atlas.
+    fn apply_packs(&mut self, game: &Game) {
+        let enabled = game
+            .mod_catalog
+            .iter()
+            .filter(|item| game.settings.is_mod_enabled(&item.id));
+        let mut theme_applied = false;
+
+        for item in enabled {
+            match item.kind {
+                ModKind::Theme if !theme_applied => {
+                    let atlas_path = item.path.join(THEME_ATLAS);
+                    match self
+                        .rl
+                        .load_texture(&self.thread, &atlas_path.to_string_lossy())
+                    {
+                        Ok(atlas) => {
+                            println!("Using theme {}", item.name);
+                            self.card_atlas = atlas;
+                            theme_applied = true;
+                        }
+                        Err(e) => eprintln!("Failed to load theme {}: {}", item.name, e),
+                    }
+                }
+                ModKind::AudioPack => {
+                    println!("Using audio pack {}", item.name);
+                    self.audio_system.load_sound_pack(&item.path);
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Apply music settings changes
     fn apply_music_settings(&mut self, game: &Game) {
         let settings = &game.settings;
@@ -0,0 +1,108 @@
+use crate::game::Game;
+use crate::models::Card;
+use crate::ui::DrawingHelpers;
+use crate::ui::atlas_card_renderer::{AtlasCardRenderer, CardRenderOptions};
+use crate::ui::card_back_renderer::CardBackRenderer;
+use crate::ui::config::NextCardRevealConfig;
+use raylib::prelude::*;
+
+// The card in the info panel's next card frame. A newly drawn card turns over from its back:
+// it narrows to an edge, widens again face up, and settles from a small tilt and scale as it
+// goes. Reduced motion fades the face in where it sits instead.
+pub struct NextCardPreview;
+
+impl NextCardPreview {
+    pub fn draw(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        atlas: &Texture2D,
+        card: Card,
+        x: i32,
+        y: i32,
+        size: i32,
+    ) {
+        let Some(progress) = game.next_card_reveal_progress() else {
+            DrawingHelpers::draw_card_inline(d, atlas, card, x, y, size);
+            return;
+        };
+
+        if game.settings.reduced_motion {
+            let options = CardRenderOptions::new(x, y, size).with_tint(Color::WHITE.fade(progress));
+            AtlasCardRenderer::draw_card_with_options(d, atlas, card, options);
+            return;
+        }
+
+        let pose = FlipPose::at(progress);
+        let card_size = size as f32 * pose.scale;
+
+        // Both renderers turn the card about its top-left corner, so find the corner that
+        // keeps it turning about the middle of the frame
+        let half = Vector2::new(card_size * pose.width_scale / 2.0, card_size / 2.0);
+        let (sin, cos) = pose.tilt.to_radians().sin_cos();
+        let center = Vector2::new(x as f32 + size as f32 / 2.0, y as f32 + size as f32 / 2.0);
+        let corner = Vector2::new(
+            center.x - (half.x * cos - half.y * sin),
+            center.y - (half.x * sin + half.y * cos),
+        );
+
+        let options = CardRenderOptions::new(corner.x as i32, corner.y as i32, card_size as i32)
+            .with_rotation(pose.tilt)
+            .with_width_scale(pose.width_scale);
+        if pose.face_up {
+            AtlasCardRenderer::draw_card_with_options(d, atlas, card, options);
+        } else {
+            CardBackRenderer::draw(d, game.settings.card_back, options);
+        }
+    }
+}
+
+/// How the card sits part of the way through turning over
+#[derive(Debug, PartialEq)]
+struct FlipPose {
+    width_scale: f32, // 1.0 flat on, 0.0 edge on
+    scale: f32,
+    tilt: f32, // Degrees
+    face_up: bool,
+}
+
+impl FlipPose {
+    fn at(progress: f32) -> Self {
+        let settled = 1.0 - (1.0 - progress).powi(2); // Ease out
+        FlipPose {
+            width_scale: (1.0 - 2.0 * progress).abs(),
+            scale: NextCardRevealConfig::START_SCALE
+                + (1.0 - NextCardRevealConfig::START_SCALE) * settled,
+            tilt: NextCardRevealConfig::START_TILT * (1.0 - settled),
+            face_up: progress >= 0.5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flip_turns_over_halfway_and_settles() {
+        let start = FlipPose::at(0.0);
+        assert_eq!(start.width_scale, 1.0);
+        assert_eq!(start.scale, NextCardRevealConfig::START_SCALE);
+        assert_eq!(start.tilt, NextCardRevealConfig::START_TILT);
+        assert!(!start.face_up);
+
+        // Edge on as the face comes round
+        let halfway = FlipPose::at(0.5);
+        assert_eq!(halfway.width_scale, 0.0);
+        assert!(halfway.face_up);
+
+        assert_eq!(
+            FlipPose::at(1.0),
+            FlipPose {
+                width_scale: 1.0,
+                scale: 1.0,
+                tilt: 0.0,
+                face_up: true,
+            }
+        );
+    }
+}
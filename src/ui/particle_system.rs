@@ -1,5 +1,6 @@
-use crate::models::{Card, CardColor, Particle};
+use crate::models::{Card, CardColor};
 use crate::ui::config::ParticleConfig;
+use crate::ui::particle::Particle;
 use raylib::prelude::*;
 
 pub struct ParticleSystem {
@@ -10,6 +11,7 @@ pub struct ParticleSystem {
     explosion_velocities: Vec<Vector2>,
     explosion_colors: [Color; 4],
     sparkle_velocities: Vec<Vector2>,
+    reduced_motion: bool, // Bursts become a single glow fading in place
 }
 
 pub struct ParticleSystemBuilder {
@@ -83,6 +85,7 @@ impl ParticleSystemBuilder {
             explosion_velocities,
             explosion_colors: self.explosion_colors,
             sparkle_velocities,
+            reduced_motion: false,
         }
     }
 }
@@ -92,19 +95,26 @@ impl ParticleSystem {
         ParticleSystemBuilder::new()
     }
 
-    pub fn add_card_explosion(
-        &mut self,
-        card: Card,
-        position: Vector2,
-        size: f32,
-        _atlas: &Option<Texture2D>,
-    ) {
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        self.reduced_motion = reduced_motion;
+    }
+
+    pub fn add_card_explosion(&mut self, card: Card, position: Vector2, size: f32) {
         // Create explosion effect based on card colors
         let primary_color = match card.suit.color() {
             CardColor::Red => Color::RED,
             CardColor::Black => ParticleConfig::COLOR_BLACK,
         };
 
+        if self.reduced_motion {
+            self.add_fade(
+                position,
+                primary_color,
+                size * ParticleConfig::FADE_SIZE_RATIO,
+            );
+            return;
+        }
+
         // Generate particles using pre-computed patterns
         let total_particles = self.explosion_velocities.len();
 
@@ -209,6 +219,115 @@ impl ParticleSystem {
         }
     }
 
+    /// Burst of colorful confetti fanning upward from a point (used for new records)
+    pub fn add_confetti_burst(&mut self, origin: Vector2) {
+        if self.reduced_motion {
+            self.add_fade(
+                origin,
+                ParticleConfig::CONFETTI_COLORS[0],
+                ParticleConfig::CONFETTI_FADE_SIZE,
+            );
+            return;
+        }
+
+        let count = ParticleConfig::CONFETTI_COUNT;
+
+        for i in 0..count {
+            // Fan the particles upward, centered on straight up
+            let t = i as f32 / (count - 1) as f32;
+            let angle = -std::f32::consts::FRAC_PI_2 + (t - 0.5) * ParticleConfig::CONFETTI_SPREAD;
+            let speed = ParticleConfig::CONFETTI_MIN_SPEED
+                + ((i * 37) % 100) as f32 / 100.0 * ParticleConfig::CONFETTI_SPEED_RANGE; // Deterministic variation
+            let velocity = Vector2::new(angle.cos() * speed, angle.sin() * speed);
+
+            let color = ParticleConfig::CONFETTI_COLORS[i % ParticleConfig::CONFETTI_COLORS.len()];
+            let life_time = ParticleConfig::CONFETTI_LIFE
+                + (i % 10) as f32 * ParticleConfig::CONFETTI_LIFE_VARIATION;
+
+            let particle = Particle::builder(origin, velocity, color, life_time)
+                .size(ParticleConfig::CONFETTI_SIZE)
+                .acceleration(Vector2::new(0.0, ParticleConfig::CONFETTI_ACCELERATION_Y))
+                .angular_velocity(((i % 9) as f32 - 4.0) * ParticleConfig::ANGULAR_VELOCITY_RANGE)
+                .build();
+
+            self.particles.push(particle);
+        }
+    }
+
+    /// Ring of gold sparks spreading out from a natural blackjack
+    pub fn add_golden_burst(&mut self, origin: Vector2) {
+        if self.reduced_motion {
+            self.add_fade(
+                origin,
+                ParticleConfig::GOLDEN_COLORS[0],
+                ParticleConfig::GOLDEN_FADE_SIZE,
+            );
+            return;
+        }
+
+        let count = ParticleConfig::GOLDEN_COUNT;
+
+        for i in 0..count {
+            let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+            let speed = ParticleConfig::GOLDEN_MIN_SPEED
+                + ((i * 53) % 100) as f32 / 100.0 * ParticleConfig::GOLDEN_SPEED_RANGE; // Deterministic variation
+            let velocity = Vector2::new(angle.cos() * speed, angle.sin() * speed);
+
+            let color = ParticleConfig::GOLDEN_COLORS[i % ParticleConfig::GOLDEN_COLORS.len()];
+            let particle = Particle::builder(origin, velocity, color, ParticleConfig::GOLDEN_LIFE)
+                .size(ParticleConfig::GOLDEN_SIZE)
+                .acceleration(Vector2::new(0.0, ParticleConfig::GOLDEN_ACCELERATION_Y))
+                .angular_velocity(((i % 7) as f32 - 3.0) * ParticleConfig::ANGULAR_VELOCITY_RANGE)
+                .build();
+
+            self.particles.push(particle);
+        }
+    }
+
+    /// Puff of dust kicked out sideways along the bottom edge of a card that just landed.
+    /// Too small to be worth a glow, so reduced motion leaves it out.
+    pub fn add_landing_dust(&mut self, position: Vector2, width: f32) {
+        if self.reduced_motion {
+            return;
+        }
+
+        let count = ParticleConfig::DUST_COUNT;
+
+        for i in 0..count {
+            // Half the puff goes left and half right, spread along the card's edge
+            let side = if i % 2 == 0 { -1.0 } else { 1.0 };
+            let offset = ((i / 2) as f32 + 0.5) / (count / 2) as f32 * width / 2.0;
+            let speed = ParticleConfig::DUST_MIN_SPEED
+                + ((i * 29) % 100) as f32 / 100.0 * ParticleConfig::DUST_SPEED_RANGE; // Deterministic variation
+            let velocity = Vector2::new(side * speed, ParticleConfig::DUST_UPWARD_SPEED);
+
+            let particle = Particle::builder(
+                Vector2::new(position.x + side * offset, position.y),
+                velocity,
+                ParticleConfig::DUST_COLOR,
+                ParticleConfig::DUST_LIFE,
+            )
+            .size(ParticleConfig::DUST_SIZE)
+            .acceleration(Vector2::new(0.0, ParticleConfig::DUST_ACCELERATION_Y))
+            .angular_velocity(0.0)
+            .build();
+
+            self.particles.push(particle);
+        }
+    }
+
+    /// A still glow that fades out where a burst would have been
+    fn add_fade(&mut self, position: Vector2, color: Color, size: f32) {
+        let particle =
+            Particle::builder(position, Vector2::zero(), color, ParticleConfig::FADE_LIFE)
+                .size(size)
+                .acceleration(Vector2::zero())
+                .angular_velocity(0.0)
+                .build();
+
+        self.particles.push(particle);
+    }
+
     pub fn update(&mut self, delta_time: f32) {
         // Update all particles and collect dead ones for reuse
         let mut i = 0;
@@ -232,3 +351,41 @@ impl ParticleSystem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Suit, Value};
+
+    #[test]
+    fn test_reduced_motion_explosion_fades_in_place() {
+        let mut system = ParticleSystem::builder().build();
+        system.set_reduced_motion(true);
+
+        let position = Vector2::new(100.0, 200.0);
+        system.add_card_explosion(Card::new(Suit::Hearts, Value::Ace), position, 50.0);
+        system.add_confetti_burst(position);
+        system.add_golden_burst(position);
+        system.add_landing_dust(position, 50.0);
+        assert_eq!(system.particles.len(), 3);
+
+        system.update(0.1);
+        assert!(system.particles.iter().all(|particle| {
+            particle.position.x == position.x && particle.position.y == position.y
+        }));
+    }
+
+    #[test]
+    fn test_full_motion_explosion_bursts() {
+        let mut system = ParticleSystem::builder().build();
+        system.add_card_explosion(
+            Card::new(Suit::Spades, Value::King),
+            Vector2::new(100.0, 200.0),
+            50.0,
+        );
+        assert_eq!(
+            system.particles.len(),
+            ParticleConfig::EXPLOSION_COUNT + ParticleConfig::SPARKLE_COUNT
+        );
+    }
+}
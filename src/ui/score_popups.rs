@@ -0,0 +1,103 @@
+use crate::ui::config::ScorePopupConfig;
+use crate::ui::sdf_text::SdfText;
+use raylib::prelude::*;
+use std::time::{Duration, Instant};
+
+// Points for a cleared combination, rising from where its cards were and fading away
+struct ScorePopup {
+    points: String,
+    breakdown: String, // How the points were made, e.g. "21 x4"
+    position: Vector2,
+    created: Instant,
+}
+
+pub struct ScorePopups {
+    popups: Vec<ScorePopup>,
+}
+
+impl ScorePopups {
+    pub fn new() -> Self {
+        ScorePopups { popups: Vec::new() }
+    }
+
+    pub fn show(&mut self, points: i32, breakdown: impl Into<String>, position: Vector2) {
+        self.popups.push(ScorePopup {
+            points: format!("+{}", points),
+            breakdown: breakdown.into(),
+            position,
+            created: Instant::now(),
+        });
+
+        if self.popups.len() > ScorePopupConfig::MAX_VISIBLE {
+            let excess = self.popups.len() - ScorePopupConfig::MAX_VISIBLE;
+            self.popups.drain(..excess);
+        }
+    }
+
+    pub fn update(&mut self) {
+        let lifetime = Duration::from_millis(ScorePopupConfig::LIFETIME_MS);
+        self.popups
+            .retain(|popup| popup.created.elapsed() < lifetime);
+    }
+
+    /// With reduced motion the popups fade in place instead of rising
+    pub fn draw(
+        &self,
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        text_scale: f32,
+        reduced_motion: bool,
+    ) {
+        let points_size = ScorePopupConfig::POINTS_SIZE * text_scale;
+        let breakdown_size = ScorePopupConfig::BREAKDOWN_SIZE * text_scale;
+        for popup in &self.popups {
+            let age = popup.created.elapsed();
+            let progress = (age.as_millis() as f32 / ScorePopupConfig::LIFETIME_MS as f32).min(1.0);
+            let alpha = Self::fade_alpha(age);
+            let rise = if reduced_motion {
+                0.0
+            } else {
+                ScorePopupConfig::RISE * progress
+            };
+
+            let lines = [
+                (&popup.points, points_size, ScorePopupConfig::POINTS_COLOR),
+                (
+                    &popup.breakdown,
+                    breakdown_size,
+                    ScorePopupConfig::BREAKDOWN_COLOR,
+                ),
+            ];
+            let mut y = popup.position.y - points_size / 2.0 - rise;
+            for (text, size, color) in lines {
+                if text.is_empty() {
+                    continue;
+                }
+                let text_width = d.measure_text(text, size as i32) as f32;
+                let position = Vector2::new(popup.position.x - text_width / 2.0, y);
+                SdfText::draw(
+                    d,
+                    font,
+                    text,
+                    position + Vector2::new(2.0, 2.0),
+                    size,
+                    1.0,
+                    ScorePopupConfig::SHADOW_COLOR.fade(alpha),
+                );
+                SdfText::draw(d, font, text, position, size, 1.0, color.fade(alpha));
+                y += size + ScorePopupConfig::LINE_GAP;
+            }
+        }
+    }
+
+    // Fully visible until the last part of the popup's life, then fade out
+    fn fade_alpha(age: Duration) -> f32 {
+        let fade_start = ScorePopupConfig::LIFETIME_MS - ScorePopupConfig::FADE_MS;
+        let age_ms = age.as_millis() as u64;
+        if age_ms <= fade_start {
+            1.0
+        } else {
+            1.0 - (age_ms - fade_start) as f32 / ScorePopupConfig::FADE_MS as f32
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::game::input::InputSnapshot;
+use crate::game::{Appearance, AppearanceOption, Game};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::atlas_card_renderer::CardRenderOptions;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::card_back_renderer::CardBackRenderer;
+use crate::ui::config::{FeltConfig, ScreenConfig};
+use crate::ui::instruction_renderer::HintAction;
+use crate::ui::particle_system::ParticleSystem;
+use crate::ui::theme::Theme;
+use crate::ui::widgets::{MenuLayout, WidgetState, WidgetStyle, Widgets};
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl Appearance {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "APPEARANCE",
+            200.0,
+            60.0,
+            2.5,
+            Color::WHITE,
+        );
+
+        let theme = Theme::of(game);
+        let panel_width = 400;
+        let option_spacing = 40;
+        let panel_x = ScreenConfig::WIDTH / 2 - panel_width / 2;
+        let panel_y = 290;
+        let panel_height = 30 + AppearanceOption::ALL.len() as i32 * option_spacing;
+
+        d.draw_rectangle(
+            panel_x - 10,
+            panel_y - 10,
+            panel_width + 20,
+            panel_height + 20,
+            Color::new(0, 0, 0, 150),
+        );
+        theme.draw_panel(d, panel_x, panel_y, panel_width, panel_height);
+
+        let layout = MenuLayout::panel(panel_x, panel_y, panel_width, 20);
+        let style = WidgetStyle::panel(game);
+        let focused = AppearanceOption::ALL
+            .iter()
+            .position(|option| *option == self.selected)
+            .unwrap_or(0);
+        Widgets::focus_highlight(d, &layout, focused, &style);
+        for (index, option) in AppearanceOption::ALL.iter().enumerate() {
+            let text = format!("{}: {}", option.label(), Self::option_value(game, *option));
+            let state = WidgetState::focused_if(*option == self.selected);
+            Widgets::button(d, font, layout.row(index), &text, state, &style);
+        }
+
+        DrawingHelpers::draw_hints(
+            d,
+            font,
+            &[
+                HintAction::Navigate,
+                HintAction::Change,
+                HintAction::Next,
+                HintAction::Back,
+            ],
+            has_controller,
+            (panel_y + panel_height + 30) as f32,
+        );
+
+        Self::draw_preview(d, game, panel_y + panel_height + 80);
+    }
+
+    // A patch of the chosen felt with a face-down card on it
+    fn draw_preview(d: &mut RaylibDrawHandle, game: &Game, y: i32) {
+        const WIDTH: i32 = 240;
+        const HEIGHT: i32 = 130;
+        const CARD_SIZE: i32 = 90;
+
+        let shade = FeltConfig::shade(game.settings.felt_color);
+        let channel = |i: usize| {
+            (shade.base[i] + (shade.x_range[i] + shade.y_range[i]) / 2.0 + shade.glow[i]) as u8
+        };
+        let x = (ScreenConfig::WIDTH - WIDTH) / 2;
+        d.draw_rectangle(
+            x,
+            y,
+            WIDTH,
+            HEIGHT,
+            Color::new(channel(0), channel(1), channel(2), 255),
+        );
+        d.draw_rectangle_lines(x, y, WIDTH, HEIGHT, Color::new(0, 0, 0, 150));
+
+        CardBackRenderer::draw(
+            d,
+            game.settings.card_back,
+            CardRenderOptions::new(
+                (ScreenConfig::WIDTH - CARD_SIZE) / 2,
+                y + (HEIGHT - CARD_SIZE) / 2,
+                CARD_SIZE,
+            ),
+        );
+    }
+
+    fn option_value(game: &Game, option: AppearanceOption) -> &'static str {
+        match option {
+            AppearanceOption::FeltColor => game.settings.felt_color.name(),
+            AppearanceOption::CardBack => game.settings.card_back.name(),
+            AppearanceOption::SeasonalEvents if game.settings.seasonal_events => "ON",
+            AppearanceOption::SeasonalEvents => "OFF",
+        }
+    }
+}
+
+impl OverlayState for Appearance {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        self.render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        // Not used; render_overlay picks the same background as the settings screen
+        BackgroundRenderer::render_start_screen
+    }
+
+    /// Keep whatever was behind the settings screen behind this one too
+    fn render_overlay(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        let background_renderer = if self.return_to.is_over_run() {
+            BackgroundRenderer::render_game_view
+        } else {
+            BackgroundRenderer::render_start_screen
+        };
+
+        SharedRenderer::render_with_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+            background_renderer,
+            self.get_overlay_alpha(),
+            |d, game, has_controller, title_font, font| {
+                self.render_overlay_content(d, game, has_controller, title_font, font)
+            },
+        );
+    }
+}
+
+impl ScreenRenderer for Appearance {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for Appearance {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.up {
+            game.select_appearance_option(-1);
+            if !game.settings.sound_effects_muted {
+                game.add_audio_event(crate::game::AudioEvent::MoveLeft);
+            }
+        }
+        if input.down {
+            game.select_appearance_option(1);
+            if !game.settings.sound_effects_muted {
+                game.add_audio_event(crate::game::AudioEvent::MoveRight);
+            }
+        }
+
+        if input.left() {
+            game.change_appearance_option(-1);
+        } else if input.action() || input.right() {
+            game.change_appearance_option(1);
+        }
+
+        if input.back() {
+            game.flush_settings();
+            game.return_from_appearance();
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use crate::game::input::{InputSnapshot, Key};
+use crate::game::{ClearedCombination, CombinationLog, Game};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{CombinationLogConfig, ScreenConfig};
+use crate::ui::instruction_renderer::HintAction;
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, SharedRenderer};
+
+impl CombinationLog {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "COMBINATIONS",
+            CombinationLogConfig::TITLE_Y,
+            CombinationLogConfig::TITLE_SIZE,
+            2.5,
+            Color::WHITE,
+        );
+
+        let history = &game.combination_history;
+        let summary = if history.is_empty() {
+            "No combinations yet this run".to_string()
+        } else {
+            let points: i32 = history.iter().map(|combination| combination.points).sum();
+            format!("{} this run, {} points", history.len(), points)
+        };
+        Self::draw_centered_text(d, font, &summary, CombinationLogConfig::SUMMARY_Y);
+
+        // Newest first; row numbers keep the order they were made in
+        let mut row_y = CombinationLogConfig::LIST_Y;
+        for (index, combination) in history
+            .iter()
+            .enumerate()
+            .rev()
+            .skip(self.scroll)
+            .take(CombinationLogConfig::VISIBLE_ROWS)
+        {
+            Self::draw_row(d, font, card_atlas, index + 1, combination, row_y);
+            row_y += CombinationLogConfig::ROW_HEIGHT;
+        }
+
+        let hidden_below = history
+            .len()
+            .saturating_sub(self.scroll + CombinationLogConfig::VISIBLE_ROWS);
+        if self.scroll > 0 || hidden_below > 0 {
+            let scroll_text = format!("{} newer  /  {} older", self.scroll, hidden_below);
+            Self::draw_centered_text(
+                d,
+                font,
+                &scroll_text,
+                CombinationLogConfig::INSTRUCTIONS_Y - 40,
+            );
+        }
+
+        DrawingHelpers::draw_hints(
+            d,
+            font,
+            &[HintAction::Scroll, HintAction::CloseLog],
+            has_controller,
+            CombinationLogConfig::INSTRUCTIONS_Y as f32,
+        );
+    }
+
+    fn draw_row(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        card_atlas: &Texture2D,
+        number: usize,
+        combination: &ClearedCombination,
+        y: i32,
+    ) {
+        let x = CombinationLogConfig::LIST_X;
+        let row_width = CombinationLogConfig::CHAIN_X + 160;
+        d.draw_rectangle(
+            x - 10,
+            y - 4,
+            row_width,
+            CombinationLogConfig::ROW_HEIGHT - 4,
+            CombinationLogConfig::ROW_COLOR,
+        );
+
+        let text_y = (y
+            + (CombinationLogConfig::CARD_SIZE - CombinationLogConfig::TEXT_SIZE as i32) / 2)
+            as f32;
+        SharedRenderer::draw_text(
+            d,
+            font,
+            &format!("{}.", number),
+            x as f32,
+            text_y,
+            CombinationLogConfig::TEXT_SIZE,
+            1.0,
+            CombinationLogConfig::NUMBER_COLOR,
+        );
+
+        // Long chains of low cards shrink to fit before the value column
+        let cards_x = x + CombinationLogConfig::NUMBER_WIDTH;
+        let gap = CombinationLogConfig::CARD_GAP;
+        let available = CombinationLogConfig::VALUE_X - CombinationLogConfig::NUMBER_WIDTH - 20;
+        let count = combination.cards.len().max(1) as i32;
+        let card_size = CombinationLogConfig::CARD_SIZE.min((available + gap) / count - gap);
+        for (index, &card) in combination.cards.iter().enumerate() {
+            DrawingHelpers::draw_card_inline(
+                d,
+                card_atlas,
+                card,
+                cards_x + index as i32 * (card_size + gap),
+                y,
+                card_size,
+            );
+        }
+
+        SharedRenderer::draw_text(
+            d,
+            font,
+            &format!("= {}", combination.breakdown()),
+            (x + CombinationLogConfig::VALUE_X) as f32,
+            text_y,
+            CombinationLogConfig::TEXT_SIZE,
+            1.0,
+            CombinationLogConfig::VALUE_COLOR,
+        );
+        SharedRenderer::draw_text(
+            d,
+            font,
+            &format!("+{}", combination.points),
+            (x + CombinationLogConfig::POINTS_X) as f32,
+            text_y,
+            CombinationLogConfig::TEXT_SIZE,
+            1.0,
+            CombinationLogConfig::POINTS_COLOR,
+        );
+        if combination.chain > 1 {
+            SharedRenderer::draw_text(
+                d,
+                font,
+                &format!("Chain x{}", combination.chain),
+                (x + CombinationLogConfig::CHAIN_X) as f32,
+                text_y,
+                CombinationLogConfig::TEXT_SIZE,
+                1.0,
+                CombinationLogConfig::CHAIN_COLOR,
+            );
+        }
+    }
+
+    fn draw_centered_text(d: &mut RaylibDrawHandle, font: &Font, text: &str, y: i32) {
+        let text_width = d.measure_text(text, CombinationLogConfig::TEXT_SIZE as i32);
+        SharedRenderer::draw_text(
+            d,
+            font,
+            text,
+            ((ScreenConfig::WIDTH - text_width) / 2) as f32,
+            y as f32,
+            CombinationLogConfig::TEXT_SIZE,
+            1.0,
+            Color::LIGHTGRAY,
+        );
+    }
+}
+
+impl ScreenRenderer for CombinationLog {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        // Same game view as the pause screen, with a darker overlay for the list
+        BackgroundRenderer::render_game_view(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+        SharedRenderer::draw_overlay(d, CombinationLogConfig::OVERLAY_ALPHA);
+        self.render_content(d, game, has_controller, title_font, font, card_atlas);
+    }
+}
+
+impl StateInput for CombinationLog {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.up {
+            game.scroll_combination_log(-1);
+        }
+        if input.down {
+            game.scroll_combination_log(1);
+        }
+
+        if input.back() || input.key(Key::Letter('H')) {
+            game.return_from_combination_log();
+        }
+    }
+}
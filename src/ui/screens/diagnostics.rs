@@ -0,0 +1,122 @@
+use crate::game::input::InputSnapshot;
+use crate::game::{Diagnostics, Game};
+use crate::ui::DrawingHelpers;
+use crate::ui::config::DiagnosticsConfig;
+use crate::ui::diagnostics::{DiagnosticsReport, DiagnosticsSection};
+use crate::ui::instruction_renderer::HintAction;
+use raylib::prelude::*;
+
+use super::StateInput;
+use super::shared_renderer::SharedRenderer;
+
+/// Draw the diagnostics report: audio on the left, graphics and storage on the right
+pub fn render_diagnostics(
+    d: &mut RaylibDrawHandle,
+    report: &DiagnosticsReport,
+    has_controller: bool,
+    title_font: &Font,
+    font: &Font,
+) {
+    SharedRenderer::draw_overlay(d, DiagnosticsConfig::PANEL_COLOR.a);
+    SharedRenderer::draw_centered_title(
+        d,
+        title_font,
+        "DIAGNOSTICS",
+        DiagnosticsConfig::TITLE_Y,
+        DiagnosticsConfig::TITLE_SIZE,
+        2.5,
+        Color::WHITE,
+    );
+
+    let mut left_y = DiagnosticsConfig::CONTENT_Y;
+    let mut right_y = DiagnosticsConfig::CONTENT_Y;
+    for section in &report.sections {
+        // Audio has the longest list, so it gets the left column to itself
+        if section.title == "Audio" {
+            left_y = draw_section(d, font, section, DiagnosticsConfig::LEFT_COLUMN_X, left_y);
+        } else {
+            right_y = draw_section(d, font, section, DiagnosticsConfig::RIGHT_COLUMN_X, right_y);
+        }
+    }
+
+    DrawingHelpers::draw_hints(
+        d,
+        font,
+        &[HintAction::BackToSettings],
+        has_controller,
+        DiagnosticsConfig::INSTRUCTIONS_Y as f32,
+    );
+}
+
+// Draws one section and returns the y position below it
+fn draw_section(
+    d: &mut RaylibDrawHandle,
+    font: &Font,
+    section: &DiagnosticsSection,
+    x: i32,
+    y: i32,
+) -> i32 {
+    SharedRenderer::draw_text(
+        d,
+        font,
+        section.title,
+        x as f32,
+        y as f32,
+        DiagnosticsConfig::SECTION_SIZE,
+        1.2,
+        DiagnosticsConfig::SECTION_COLOR,
+    );
+
+    let mut line_y = y + DiagnosticsConfig::SECTION_SIZE as i32 + 10;
+    for line in &section.lines {
+        let value_color = if line.ok {
+            DiagnosticsConfig::OK_COLOR
+        } else {
+            DiagnosticsConfig::PROBLEM_COLOR
+        };
+
+        SharedRenderer::draw_text(
+            d,
+            font,
+            &line.label,
+            x as f32,
+            line_y as f32,
+            DiagnosticsConfig::TEXT_SIZE,
+            1.0,
+            DiagnosticsConfig::LABEL_COLOR,
+        );
+        SharedRenderer::draw_text(
+            d,
+            font,
+            &fit_value(&line.value),
+            (x + DiagnosticsConfig::VALUE_OFFSET_X) as f32,
+            line_y as f32,
+            DiagnosticsConfig::TEXT_SIZE,
+            1.0,
+            value_color,
+        );
+        line_y += DiagnosticsConfig::LINE_HEIGHT;
+    }
+
+    line_y + DiagnosticsConfig::SECTION_GAP
+}
+
+// Keep the end of long values (file paths), which is the informative part
+fn fit_value(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() <= DiagnosticsConfig::MAX_VALUE_CHARS {
+        return value.to_string();
+    }
+    let tail: String = chars[chars.len() - (DiagnosticsConfig::MAX_VALUE_CHARS - 3)..]
+        .iter()
+        .collect();
+    format!("...{}", tail)
+}
+
+impl StateInput for Diagnostics {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.back() {
+            game.return_from_diagnostics();
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::game::input::InputSnapshot;
+use crate::game::{ForfeitConfirm, ForfeitOption, Game};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{InstructionsConfig, ScreenConfig};
+use crate::ui::particle_system::ParticleSystem;
+use crate::ui::widgets::MenuLayout;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl ForfeitConfirm {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "QUIT TO MENU?",
+            250.0,
+            60.0,
+            2.5,
+            Color::WHITE,
+        );
+
+        let score_text = format!(
+            "Forfeiting loses your score of {}. Save & Quit lets you continue it from the menu.",
+            game.score
+        );
+        let text_width = d.measure_text(&score_text, InstructionsConfig::QUIT_CONFIRM_SIZE as i32);
+        SharedRenderer::draw_text(
+            d,
+            font,
+            &score_text,
+            ((ScreenConfig::WIDTH - text_width) / 2) as f32,
+            340.0,
+            InstructionsConfig::QUIT_CONFIRM_SIZE,
+            InstructionsConfig::QUIT_CONFIRM_SPACING,
+            Color::LIGHTGRAY,
+        );
+
+        DrawingHelpers::draw_forfeit_menu(d, font, game, self.selected, has_controller);
+    }
+}
+
+impl OverlayState for ForfeitConfirm {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        self.render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_game_view
+    }
+}
+
+impl ScreenRenderer for ForfeitConfirm {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for ForfeitConfirm {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        let count = ForfeitOption::ALL.len();
+        let menu = MenuLayout::pause_menu().read(input, count);
+        let current = game
+            .forfeit_selection()
+            .and_then(|selected| ForfeitOption::ALL.iter().position(|&o| o == selected))
+            .unwrap_or(0);
+        let step = menu.focus_step(current, count);
+        if step != 0 {
+            game.select_forfeit_option(step);
+            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+        }
+
+        if menu.activate {
+            game.choose_forfeit_option();
+            return;
+        }
+
+        if input.back() {
+            game.cancel_forfeit();
+        }
+    }
+}
@@ -0,0 +1,550 @@
+use crate::game::board_history::BoardFrame;
+use crate::game::input::{InputSnapshot, Key, PadButton};
+use crate::game::{Game, GameEvent, GameOver, Playing};
+use crate::models::{MAX_NAME_LEN, VirtualKey};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{
+    BoardConfig, GameOverZoomConfig, InitialsRejectedConfig, NewRecordConfig, ReplayConfig,
+    ScreenConfig, SeedConfig, VirtualKeyboardConfig,
+};
+use crate::ui::instruction_renderer::{HintAction, InstructionRenderer};
+use crate::ui::particle_system::ParticleSystem;
+use crate::ui::text_layout::TextLayout;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::playing::BoardView;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl GameOver {
+    fn render_content(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        // Draw game over text using title font - centered
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "GAME OVER",
+            250.0,
+            60.0,
+            2.5,
+            Color::WHITE,
+        );
+
+        // Draw final score
+        let score_text = format!("Final Score: {}", game.score);
+        SharedRenderer::draw_centered_title(d, font, &score_text, 330.0, 36.0, 1.5, Color::WHITE);
+
+        // How the run compares with the saved scores for its difficulty and mode
+        if let Some(percentile) = game.run_percentile.filter(|percentile| *percentile > 0) {
+            let percentile_text = format!("Better than {}% of your runs", percentile);
+            SharedRenderer::draw_centered_title(
+                d,
+                font,
+                &percentile_text,
+                366.0,
+                20.0,
+                1.0,
+                Color::LIGHTGRAY,
+            );
+        }
+
+        if let Some(rank) = game.new_record_rank {
+            Self::draw_new_record_banner(d, game, title_font, font, rank);
+
+            // Initials entry waits until the celebration has played out
+            if game.is_celebrating_new_record() {
+                return;
+            }
+        }
+
+        // Draw initials input heading using title font
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "Enter your name:",
+            390.0,
+            32.0,
+            1.25,
+            Color::WHITE,
+        );
+
+        // Draw initials box, shaking with a red border when the initials were turned away
+        // (reduced motion keeps just the border)
+        let rejected = game.initials_rejected_progress();
+        let shake = match rejected {
+            Some(progress) if !game.settings.reduced_motion => {
+                (progress * InitialsRejectedConfig::SHAKE_CYCLES * std::f32::consts::TAU).sin()
+                    * InitialsRejectedConfig::SHAKE_DISTANCE
+                    * (1.0 - progress)
+            }
+            _ => 0.0,
+        };
+        let border = if rejected.is_some() {
+            InitialsRejectedConfig::BORDER_COLOR
+        } else {
+            Color::WHITE
+        };
+        // Wide enough for the longest name, in the widest letter
+        let widest = "W".repeat(MAX_NAME_LEN);
+        let box_width = TextLayout::measure(font, &widest, 36.0, 1.5).x as i32 + 40;
+        let box_height = 60;
+        let box_x = ScreenConfig::WIDTH / 2 - box_width / 2 + shake as i32;
+        let box_y = 440;
+
+        SharedRenderer::draw_input_box(
+            d,
+            box_x,
+            box_y,
+            box_width,
+            box_height,
+            Color::DARKGRAY,
+            border,
+        );
+
+        // Draw the name so far, with blanks for initials until there are three letters
+        let initials_text = if game.player_initials.is_empty() {
+            "___".to_string()
+        } else {
+            format!("{:_<3}", game.player_initials)
+        };
+
+        TextLayout::draw_centered(
+            d,
+            font,
+            &initials_text,
+            Rectangle::new(
+                box_x as f32,
+                box_y as f32,
+                box_width as f32,
+                box_height as f32,
+            ),
+            36.0,
+            1.5,
+            Color::WHITE,
+        );
+
+        // Gamepad players pick letters from the on-screen keyboard
+        if has_controller {
+            DrawingHelpers::draw_virtual_keyboard(
+                d,
+                font,
+                &game.initials_keyboard,
+                VirtualKeyboardConfig::Y,
+            );
+        }
+
+        // Draw conditional instructions based on controller availability
+        DrawingHelpers::draw_game_over_instructions(d, font, has_controller);
+
+        // The seed, for a friend to play the same deck
+        if let Some(seed) = game.run_seed {
+            let copy = if has_controller { "Y" } else { "Ctrl+C" };
+            SharedRenderer::draw_text(
+                d,
+                font,
+                &format!("Seed {}   {}: Copy", seed, copy),
+                SeedConfig::GAME_OVER_MARGIN,
+                SeedConfig::GAME_OVER_MARGIN,
+                SeedConfig::GAME_OVER_SIZE,
+                1.0,
+                SeedConfig::GAME_OVER_COLOR,
+            );
+        }
+
+        if game.board_history.len() > 1 {
+            let (hint, y) = if has_controller {
+                (
+                    "LB: Replay the final moments",
+                    ReplayConfig::HINT_CONTROLLER_Y,
+                )
+            } else {
+                ("Left: Replay the final moments", ReplayConfig::HINT_Y)
+            };
+            SharedRenderer::draw_centered_title(
+                d,
+                font,
+                hint,
+                y,
+                ReplayConfig::HINT_SIZE,
+                1.0,
+                ReplayConfig::HINT_COLOR,
+            );
+        }
+    }
+
+    // The board as it was a few moves back, with how far back and how to step
+    #[allow(clippy::too_many_arguments)]
+    fn render_replay(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        frame: &BoardFrame,
+    ) {
+        Playing::draw_game_view(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            BoardView::Replay(frame),
+        );
+
+        d.draw_rectangle(
+            0,
+            ReplayConfig::BAR_Y,
+            ScreenConfig::WIDTH,
+            ReplayConfig::BAR_HEIGHT,
+            ReplayConfig::BAR_COLOR,
+        );
+
+        let step = game.replay_step();
+        let title = format!(
+            "REPLAY  -{:.1}s  ({} of {})",
+            game.board_history.age(step).as_secs_f32(),
+            step,
+            game.board_history.len() - 1
+        );
+        SharedRenderer::draw_centered_title(
+            d,
+            font,
+            &title,
+            (ReplayConfig::BAR_Y + 8) as f32,
+            ReplayConfig::TITLE_SIZE,
+            1.2,
+            ReplayConfig::TITLE_COLOR,
+        );
+
+        let hint = InstructionRenderer::hint_line(
+            &[HintAction::ReplayBack, HintAction::ReplayForward],
+            has_controller,
+        );
+        SharedRenderer::draw_centered_title(
+            d,
+            font,
+            &hint,
+            (ReplayConfig::BAR_Y + 42) as f32,
+            ReplayConfig::HINT_SIZE,
+            1.0,
+            ReplayConfig::HINT_COLOR,
+        );
+    }
+
+    // The camera closes in on the column that reached the top and tints it red, then the
+    // screen darkens to where the game over overlay picks up
+    #[allow(clippy::too_many_arguments)]
+    fn render_losing_zoom(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        column: i32,
+        progress: f32,
+    ) {
+        let cell_size = game.board.cell_size;
+        let (origin_x, origin_y) = BoardConfig::origin(
+            game.board.width,
+            game.board.height,
+            cell_size,
+            game.settings.mirrored_layout,
+        );
+        let column_x = origin_x + column * cell_size;
+        let focus_x = (column_x + cell_size / 2) as f32;
+        let focus_y = (origin_y + cell_size / 2) as f32;
+
+        let zoom_progress = (progress / GameOverZoomConfig::ZOOM_PORTION).min(1.0);
+        let eased = 1.0 - (1.0 - zoom_progress).powi(3);
+        let center_x = (ScreenConfig::WIDTH / 2) as f32;
+        let center_y = (ScreenConfig::HEIGHT / 2) as f32;
+        let camera = Camera2D {
+            offset: Vector2::new(
+                focus_x + (center_x - focus_x) * eased,
+                focus_y + (center_y - focus_y) * eased,
+            ),
+            target: Vector2::new(focus_x, focus_y),
+            rotation: 0.0,
+            zoom: 1.0 + (GameOverZoomConfig::MAX_ZOOM - 1.0) * eased,
+        };
+
+        {
+            let mut zoomed = d.begin_mode2D(camera);
+            Playing::draw_game_view(
+                &mut zoomed,
+                game,
+                has_controller,
+                title_font,
+                font,
+                card_atlas,
+                particle_system,
+                BoardView::Live,
+            );
+            zoomed.draw_rectangle(
+                column_x,
+                origin_y,
+                cell_size,
+                game.board.height * cell_size,
+                GameOverZoomConfig::TINT_COLOR.fade(eased),
+            );
+        }
+
+        let fade = ((progress - GameOverZoomConfig::ZOOM_PORTION)
+            / (1.0 - GameOverZoomConfig::ZOOM_PORTION))
+            .clamp(0.0, 1.0);
+        SharedRenderer::draw_overlay(d, (GameOverZoomConfig::FADE_ALPHA as f32 * fade) as u8);
+    }
+
+    fn draw_new_record_banner(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        title_font: &Font,
+        font: &Font,
+        rank: usize,
+    ) {
+        let elapsed = game
+            .celebration_started
+            .map(|started| started.elapsed().as_secs_f32())
+            .unwrap_or(f32::MAX);
+
+        // Slide the banner in from above, then pulse while the celebration plays.
+        // Reduced motion fades it in where it ends up instead.
+        let slide_progress = (elapsed / NewRecordConfig::SLIDE_IN_SECONDS).min(1.0);
+        let reduced_motion = game.settings.reduced_motion;
+        let eased = if reduced_motion {
+            1.0
+        } else {
+            1.0 - (1.0 - slide_progress).powi(3)
+        };
+        let banner_y = NewRecordConfig::START_Y
+            + (NewRecordConfig::BANNER_Y - NewRecordConfig::START_Y) * eased;
+        let alpha = if reduced_motion { slide_progress } else { 1.0 };
+
+        let pulse = if game.is_celebrating_new_record() && !reduced_motion {
+            (elapsed * NewRecordConfig::PULSE_SPEED).sin() * NewRecordConfig::PULSE_AMOUNT
+        } else {
+            0.0
+        };
+
+        d.draw_rectangle(
+            0,
+            banner_y as i32,
+            ScreenConfig::WIDTH,
+            NewRecordConfig::BANNER_HEIGHT,
+            NewRecordConfig::BANNER_COLOR.fade(alpha),
+        );
+        d.draw_rectangle(
+            0,
+            banner_y as i32,
+            ScreenConfig::WIDTH,
+            NewRecordConfig::BORDER_THICKNESS,
+            NewRecordConfig::BORDER_COLOR.fade(alpha),
+        );
+        d.draw_rectangle(
+            0,
+            banner_y as i32 + NewRecordConfig::BANNER_HEIGHT - NewRecordConfig::BORDER_THICKNESS,
+            ScreenConfig::WIDTH,
+            NewRecordConfig::BORDER_THICKNESS,
+            NewRecordConfig::BORDER_COLOR.fade(alpha),
+        );
+
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "NEW RECORD!",
+            banner_y + 6.0 - pulse / 2.0,
+            NewRecordConfig::TITLE_SIZE + pulse,
+            2.0,
+            NewRecordConfig::TEXT_COLOR.fade(alpha),
+        );
+
+        let rank_text = format!("#{} on {}", rank, game.difficulty);
+        SharedRenderer::draw_centered_title(
+            d,
+            font,
+            &rank_text,
+            banner_y + NewRecordConfig::TITLE_SIZE + 6.0,
+            NewRecordConfig::RANK_SIZE,
+            1.5,
+            NewRecordConfig::TEXT_COLOR.fade(alpha),
+        );
+    }
+}
+
+impl OverlayState for GameOver {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        Self::render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_game_view
+    }
+}
+
+impl ScreenRenderer for GameOver {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        if let Some(frame) = game.replay_frame() {
+            Self::render_replay(
+                d,
+                game,
+                has_controller,
+                title_font,
+                font,
+                card_atlas,
+                particle_system,
+                frame,
+            );
+            return;
+        }
+
+        if let (Some(column), Some(progress)) = (game.losing_column, game.game_over_zoom_progress())
+        {
+            Self::render_losing_zoom(
+                d,
+                game,
+                has_controller,
+                title_font,
+                font,
+                card_atlas,
+                particle_system,
+                column,
+                progress,
+            );
+            return;
+        }
+
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+
+        // Keep the confetti visible above the overlay
+        if game.new_record_rank.is_some() {
+            particle_system.draw(d);
+        }
+    }
+}
+
+impl StateInput for GameOver {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        // Keys still down from play wait to be let go
+        if !game.game_over_accepts_input(input) {
+            return;
+        }
+
+        // Let the zoom toward the losing column and the new record celebration finish
+        // before taking initials
+        if game.game_over_zoom_progress().is_some() || game.is_celebrating_new_record() {
+            return;
+        }
+
+        // Step back through the final boards; the D-pad is busy with the on-screen keyboard,
+        // so controllers use the shoulder buttons
+        if input.key(Key::Left) || input.button(PadButton::LeftShoulder) {
+            game.step_replay(1);
+        }
+        if input.key(Key::Right) || input.button(PadButton::RightShoulder) {
+            game.step_replay(-1);
+        }
+
+        // Share the run's seed; checked first so Ctrl+C doesn't type a C. The front end puts
+        // it on the clipboard when it sees the event.
+        if let Some(seed) = game.run_seed
+            && (input.shortcut(Key::Letter('C')) || input.button(PadButton::Y))
+        {
+            game.emit(GameEvent::SeedCopied {
+                code: seed.to_string(),
+            });
+            return;
+        }
+
+        // Handle initial input
+        if let Some(c) = input.typed() {
+            game.add_initial(c);
+        }
+
+        // Handle controller input for initials via the on-screen keyboard
+        if input.button(PadButton::DPadLeft) {
+            game.initials_keyboard.move_left();
+        }
+        if input.button(PadButton::DPadRight) {
+            game.initials_keyboard.move_right();
+        }
+        if input.button(PadButton::DPadUp) {
+            game.initials_keyboard.move_up();
+        }
+        if input.button(PadButton::DPadDown) {
+            game.initials_keyboard.move_down();
+        }
+
+        if input.button(PadButton::A) {
+            match game.initials_keyboard.selected_key() {
+                VirtualKey::Letter(c) => game.add_initial(c),
+                VirtualKey::Backspace => game.remove_initial(),
+                VirtualKey::Done => {
+                    game.submit_initials();
+                    return;
+                }
+            }
+        }
+
+        // Handle backspace
+        if input.key(Key::Backspace) || input.button(PadButton::B) {
+            game.remove_initial();
+        }
+
+        // Submit and return to menu
+        if input.key(Key::Enter) || input.button(PadButton::Start) {
+            game.submit_initials();
+        }
+    }
+}
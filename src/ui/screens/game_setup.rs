@@ -0,0 +1,183 @@
+use crate::game::input::InputSnapshot;
+use crate::game::{Game, GameSetup, GameSetupOption};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::ScreenConfig;
+use crate::ui::instruction_renderer::HintAction;
+use crate::ui::particle_system::ParticleSystem;
+use crate::ui::theme::Theme;
+use crate::ui::widgets::{MenuLayout, WidgetState, WidgetStyle, Widgets};
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl GameSetup {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "GAME SETUP",
+            150.0,
+            60.0,
+            2.5,
+            Color::WHITE,
+        );
+
+        let theme = Theme::of(game);
+        let panel_width = 400;
+        let option_spacing = 40;
+        let panel_x = ScreenConfig::WIDTH / 2 - panel_width / 2;
+        let panel_y = 240;
+        let panel_height = 30 + GameSetupOption::ALL.len() as i32 * option_spacing;
+
+        d.draw_rectangle(
+            panel_x - 10,
+            panel_y - 10,
+            panel_width + 20,
+            panel_height + 20,
+            Color::new(0, 0, 0, 150),
+        );
+        theme.draw_panel(d, panel_x, panel_y, panel_width, panel_height);
+
+        let layout = MenuLayout::panel(panel_x, panel_y, panel_width, 20);
+        let style = WidgetStyle::panel(game);
+        let focused = GameSetupOption::ALL
+            .iter()
+            .position(|option| *option == self.selected)
+            .unwrap_or(0);
+        Widgets::focus_highlight(d, &layout, focused, &style);
+        for (index, option) in GameSetupOption::ALL.iter().enumerate() {
+            let text = format!("{}: {}", option.label(), Self::option_value(game, *option));
+            let state = WidgetState::focused_if(*option == self.selected);
+            Widgets::button(d, font, layout.row(index), &text, state, &style);
+        }
+
+        DrawingHelpers::draw_hints(
+            d,
+            font,
+            &[
+                HintAction::Navigate,
+                HintAction::Change,
+                HintAction::Toggle,
+                HintAction::Back,
+            ],
+            has_controller,
+            (panel_y + panel_height + 30) as f32,
+        );
+    }
+
+    fn option_value(game: &Game, option: GameSetupOption) -> String {
+        let on = match option {
+            GameSetupOption::HardDropSteer => game.settings.hard_drop_steer,
+            GameSetupOption::SlideDelay => game.settings.slide_delay,
+            GameSetupOption::BustRule => game.settings.bust_rule,
+            GameSetupOption::RelaxMode => game.settings.relax_mode,
+            GameSetupOption::AdaptiveSpeed => game.settings.adaptive_speed,
+            GameSetupOption::DrawOdds => game.settings.draw_odds,
+            GameSetupOption::DeckTemplate => {
+                return game.settings.deck_template.name().to_string();
+            }
+            GameSetupOption::Handicap => {
+                return match game.settings.handicap_rows {
+                    0 => "OFF".to_string(),
+                    1 => "1 row".to_string(),
+                    rows => format!("{} rows", rows),
+                };
+            }
+        };
+        if on { "ON" } else { "OFF" }.to_string()
+    }
+}
+
+impl OverlayState for GameSetup {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        self.render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_start_screen
+    }
+}
+
+impl ScreenRenderer for GameSetup {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for GameSetup {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.up {
+            game.select_game_setup_option(-1);
+            if !game.settings.sound_effects_muted {
+                game.add_audio_event(crate::game::AudioEvent::MoveLeft);
+            }
+        }
+        if input.down {
+            game.select_game_setup_option(1);
+            if !game.settings.sound_effects_muted {
+                game.add_audio_event(crate::game::AudioEvent::MoveRight);
+            }
+        }
+
+        // Choices step back on left and forward otherwise; on/off rows flip either way
+        if input.left() {
+            game.change_game_setup_option(-1);
+        } else if input.action() || input.right() {
+            game.change_game_setup_option(1);
+        }
+
+        if input.escape() || input.back() {
+            game.flush_settings();
+            game.return_from_game_setup();
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::game::input::InputSnapshot;
+use crate::game::{Game, HowToPlay};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::HowToPlayConfig;
+use crate::ui::instruction_renderer::HintAction;
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, SharedRenderer};
+
+const RULES: [&str; 5] = [
+    "Steer each falling card into place on the board.",
+    "Line up adjacent cards whose values add up to exactly 21.",
+    "Face cards count 10; an ace counts 1 or 11, whichever fits.",
+    "Longer combinations score more, and clears that set off more clears chain for a bonus.",
+    "The run ends when the cards stack up to the top of the board.",
+];
+
+const KEYBOARD_CONTROLS: [&str; 4] = [
+    "Left/Right Arrow: Move card",
+    "Down Arrow: Soft drop",
+    "Space: Hard drop",
+    "ESC: Settings | P: Pause | R: Restart",
+];
+
+const CONTROLLER_CONTROLS: [&str; 4] = [
+    "D-Pad/Left Stick: Move card",
+    "D-Pad Down/Stick Down: Soft drop",
+    "A Button: Hard drop",
+    "Menu: Settings | Start: Pause",
+];
+
+impl HowToPlay {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "HOW TO PLAY",
+            HowToPlayConfig::TITLE_Y,
+            HowToPlayConfig::TITLE_SIZE,
+            2.5,
+            Color::WHITE,
+        );
+
+        let mut y = HowToPlayConfig::RULES_Y;
+        for rule in RULES {
+            SharedRenderer::draw_text(
+                d,
+                font,
+                &format!("- {}", rule),
+                HowToPlayConfig::TEXT_X,
+                y as f32,
+                HowToPlayConfig::TEXT_SIZE,
+                1.0,
+                Color::WHITE,
+            );
+            y += HowToPlayConfig::LINE_SPACING;
+        }
+
+        y += HowToPlayConfig::SECTION_GAP;
+        SharedRenderer::draw_text(
+            d,
+            font,
+            "Controls",
+            HowToPlayConfig::TEXT_X,
+            y as f32,
+            HowToPlayConfig::HEADING_SIZE,
+            1.0,
+            HowToPlayConfig::HEADING_COLOR,
+        );
+        y += HowToPlayConfig::LINE_SPACING + 10;
+
+        let controls = if has_controller {
+            CONTROLLER_CONTROLS
+        } else {
+            KEYBOARD_CONTROLS
+        };
+        for control in controls {
+            SharedRenderer::draw_text(
+                d,
+                font,
+                control,
+                HowToPlayConfig::TEXT_X,
+                y as f32,
+                HowToPlayConfig::TEXT_SIZE,
+                1.0,
+                Color::LIGHTGRAY,
+            );
+            y += HowToPlayConfig::LINE_SPACING;
+        }
+
+        DrawingHelpers::draw_hints(
+            d,
+            font,
+            &[HintAction::Back],
+            has_controller,
+            HowToPlayConfig::INSTRUCTIONS_Y as f32,
+        );
+    }
+}
+
+impl ScreenRenderer for HowToPlay {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        // Same game view as the pause screen, darkened so the text reads clearly
+        BackgroundRenderer::render_game_view(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+        SharedRenderer::draw_overlay(d, HowToPlayConfig::OVERLAY_ALPHA);
+        self.render_content(d, has_controller, title_font, font);
+    }
+}
+
+impl StateInput for HowToPlay {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.back() || input.action() {
+            game.return_from_how_to_play();
+        }
+    }
+}
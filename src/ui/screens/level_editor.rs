@@ -0,0 +1,246 @@
+use crate::game::input::{InputSnapshot, Key};
+use crate::game::{Game, LevelEditor};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::LevelEditorConfig;
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, SharedRenderer};
+
+// Keys the editor listens for, shown down the side panel
+const CONTROLS: [&str; 9] = [
+    "Left/Right  Column",
+    "Up/Down  Card value",
+    "Tab  Suit",
+    "Enter  Stack card    Backspace  Take off",
+    "Q  Queue card    W  Drop last queued",
+    "-/+  Par    H  Easy/Hard",
+    "T  Test play",
+    "S  Save level",
+    "Esc  Back to puzzles",
+];
+
+impl LevelEditor {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        font: &Font,
+        atlas: &Texture2D,
+    ) {
+        let Some(draft) = game.level_draft.as_ref() else {
+            return;
+        };
+        let cell = LevelEditorConfig::CELL_SIZE;
+        let board_x = LevelEditorConfig::BOARD_X;
+        let board_y = LevelEditorConfig::BOARD_Y;
+
+        d.draw_rectangle(
+            board_x,
+            board_y,
+            draft.width() * cell,
+            draft.height() * cell,
+            LevelEditorConfig::BOARD_COLOR,
+        );
+        d.draw_rectangle(
+            board_x + self.column * cell,
+            board_y,
+            cell,
+            draft.height() * cell,
+            LevelEditorConfig::COLUMN_COLOR,
+        );
+        for (y, row) in draft.grid.iter().enumerate() {
+            for (x, card) in row.iter().enumerate() {
+                if let Some(card) = *card {
+                    DrawingHelpers::draw_card_inline(
+                        d,
+                        atlas,
+                        card,
+                        board_x + x as i32 * cell,
+                        board_y + y as i32 * cell,
+                        cell,
+                    );
+                }
+            }
+        }
+        d.draw_rectangle_lines(
+            board_x,
+            board_y,
+            draft.width() * cell,
+            draft.height() * cell,
+            LevelEditorConfig::BORDER_COLOR,
+        );
+
+        // Side panel: the draft's settings, the brush and the queue
+        let panel_x = LevelEditorConfig::PANEL_X as f32;
+        let mut y = LevelEditorConfig::BOARD_Y as f32;
+        let mut line = |d: &mut RaylibDrawHandle, text: &str, color: Color| {
+            SharedRenderer::draw_text(
+                d,
+                font,
+                text,
+                panel_x,
+                y,
+                LevelEditorConfig::TEXT_SIZE,
+                1.0,
+                color,
+            );
+            y += LevelEditorConfig::LINE_HEIGHT;
+        };
+        line(d, "LEVEL EDITOR", Color::WHITE);
+        line(
+            d,
+            &format!("{}  ({})", draft.name, draft.id),
+            LevelEditorConfig::DETAILS_COLOR,
+        );
+        line(
+            d,
+            &format!(
+                "{}  Par {}  Cards queued {}",
+                draft.difficulty,
+                draft.par,
+                draft.cards.len()
+            ),
+            LevelEditorConfig::DETAILS_COLOR,
+        );
+
+        let brush_y = LevelEditorConfig::BRUSH_Y;
+        SharedRenderer::draw_text(
+            d,
+            font,
+            "Brush",
+            panel_x,
+            (brush_y + LevelEditorConfig::BRUSH_SIZE / 3) as f32,
+            LevelEditorConfig::TEXT_SIZE,
+            1.0,
+            Color::WHITE,
+        );
+        DrawingHelpers::draw_card_inline(
+            d,
+            atlas,
+            self.brush,
+            LevelEditorConfig::PANEL_X + LevelEditorConfig::BRUSH_X_OFFSET,
+            brush_y,
+            LevelEditorConfig::BRUSH_SIZE,
+        );
+
+        let queue_size = LevelEditorConfig::QUEUE_CARD_SIZE;
+        let step = queue_size + LevelEditorConfig::QUEUE_GAP;
+        for (index, &card) in draft.cards.iter().enumerate() {
+            let column = (index % LevelEditorConfig::QUEUE_PER_ROW) as i32;
+            let row = (index / LevelEditorConfig::QUEUE_PER_ROW) as i32;
+            DrawingHelpers::draw_card_inline(
+                d,
+                atlas,
+                card,
+                LevelEditorConfig::PANEL_X + column * step,
+                LevelEditorConfig::QUEUE_Y + row * step,
+                queue_size,
+            );
+        }
+
+        let mut y = LevelEditorConfig::CONTROLS_Y as f32;
+        for text in CONTROLS {
+            SharedRenderer::draw_text(
+                d,
+                font,
+                text,
+                panel_x,
+                y,
+                LevelEditorConfig::CONTROLS_SIZE,
+                1.0,
+                LevelEditorConfig::CONTROLS_COLOR,
+            );
+            y += LevelEditorConfig::CONTROLS_LINE_HEIGHT;
+        }
+    }
+}
+
+// Drawn over the start screen like the level list, but it needs the card atlas, so it skips
+// OverlayState and lays the overlay down itself
+impl ScreenRenderer for LevelEditor {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        SharedRenderer::render_with_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+            BackgroundRenderer::render_start_screen,
+            LevelEditorConfig::OVERLAY_ALPHA,
+            |d, game, _, _, font| self.render_content(d, game, font, card_atlas),
+        );
+    }
+}
+
+// Keyboard only, apart from moving around: the editor needs more keys than a pad has
+impl StateInput for LevelEditor {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.left() {
+            game.move_editor_column(-1);
+        }
+        if input.right() {
+            game.move_editor_column(1);
+        }
+        if input.up {
+            game.change_editor_brush_value(1);
+        }
+        if input.down {
+            game.change_editor_brush_value(-1);
+        }
+        if input.key(Key::Tab) {
+            game.cycle_editor_brush_suit();
+        }
+        if input.key(Key::Enter) {
+            game.stack_editor_card();
+        }
+        if input.key(Key::Backspace) {
+            game.unstack_editor_card();
+        }
+        if input.key(Key::Letter('Q')) {
+            game.queue_editor_card();
+        }
+        if input.key(Key::Letter('W')) {
+            game.unqueue_editor_card();
+        }
+        if input.key(Key::Minus) {
+            game.adjust_editor_par(-1);
+        }
+        if input.key(Key::Equal) {
+            game.adjust_editor_par(1);
+        }
+        if input.key(Key::Letter('H')) {
+            game.toggle_editor_difficulty();
+        }
+        if input.key(Key::Letter('S')) {
+            game.save_level_draft();
+        }
+        if input.key(Key::Letter('T')) {
+            game.test_play_draft();
+            return;
+        }
+
+        if input.back() {
+            game.return_from_level_editor();
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// Screen rendering and input for each game state. The states themselves live in game::states
+// and stay free of raylib; this module maps each one to the code that draws it and the code
+// that reads the player's input on it.
+mod accessibility;
+mod appearance;
+mod combination_log;
+mod diagnostics;
+mod forfeit_confirm;
+mod game_over;
+mod game_setup;
+mod how_to_play;
+mod level_editor;
+mod mods_screen;
+mod paused;
+mod playing;
+mod puzzle_select;
+mod quit_confirm;
+mod restart_confirm;
+mod seed_entry;
+mod settings;
+mod setup_wizard;
+mod shared_renderer;
+mod start_screen;
+mod statistics_screen;
+
+pub use diagnostics::render_diagnostics;
+
+use crate::game::input::InputSnapshot;
+use crate::game::{
+    Accessibility, Appearance, CombinationLog, Diagnostics, ForfeitConfirm, Game, GameOver,
+    GameSetup, GameState, HowToPlay, LevelEditor, ModsScreen, Paused, Playing, PuzzleSelect,
+    QuitConfirm, RestartConfirm, SeedEntry, Settings, SetupWizard, StartScreen, StatisticsScreen,
+};
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+// Trait implemented by every game state that can be drawn
+pub trait ScreenRenderer {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    );
+}
+
+// Trait implemented by every game state that takes input. Handlers act on the game through
+// its methods, so they take no state data of their own; anything a state keeps between frames
+// lives in its GameState variant.
+pub trait StateInput {
+    fn handle_input(game: &mut Game, input: &InputSnapshot);
+}
+
+/// Hand this frame's input to the current game state
+pub fn handle_state_input(game: &mut Game, input: &InputSnapshot) {
+    match game.state {
+        GameState::StartScreen(_) => StartScreen::handle_input(game, input),
+        GameState::Playing(_) => Playing::handle_input(game, input),
+        GameState::Paused(_) => Paused::handle_input(game, input),
+        GameState::GameOver(_) => GameOver::handle_input(game, input),
+        GameState::QuitConfirm(_) => QuitConfirm::handle_input(game, input),
+        GameState::Settings(_) => Settings::handle_input(game, input),
+        GameState::SetupWizard(_) => SetupWizard::handle_input(game, input),
+        GameState::CombinationLog(_) => CombinationLog::handle_input(game, input),
+        GameState::HowToPlay(_) => HowToPlay::handle_input(game, input),
+        GameState::RestartConfirm(_) => RestartConfirm::handle_input(game, input),
+        GameState::ForfeitConfirm(_) => ForfeitConfirm::handle_input(game, input),
+        GameState::Accessibility(_) => Accessibility::handle_input(game, input),
+        GameState::Appearance(_) => Appearance::handle_input(game, input),
+        GameState::GameSetup(_) => GameSetup::handle_input(game, input),
+        GameState::StatisticsScreen(_) => StatisticsScreen::handle_input(game, input),
+        GameState::ModsScreen(_) => ModsScreen::handle_input(game, input),
+        GameState::PuzzleSelect(_) => PuzzleSelect::handle_input(game, input),
+        GameState::LevelEditor(_) => LevelEditor::handle_input(game, input),
+        GameState::SeedEntry(_) => SeedEntry::handle_input(game, input),
+        GameState::Diagnostics(_) => Diagnostics::handle_input(game, input),
+    }
+}
+
+/// Render the current game state with the screen renderer for its data
+#[allow(clippy::too_many_arguments)]
+pub fn render_state(
+    d: &mut RaylibDrawHandle,
+    game: &Game,
+    has_controller: bool,
+    title_font: &Font,
+    font: &Font,
+    card_atlas: &Texture2D,
+    particle_system: &mut ParticleSystem,
+    animated_background: &mut AnimatedBackground,
+    board_snapshot: &BoardSnapshot,
+) {
+    let renderer: &dyn ScreenRenderer = match &game.state {
+        GameState::StartScreen(s) => s,
+        GameState::Playing(s) => s,
+        GameState::Paused(s) => s,
+        GameState::GameOver(s) => s,
+        GameState::QuitConfirm(s) => s,
+        GameState::Settings(s) => s,
+        GameState::SetupWizard(s) => s,
+        GameState::CombinationLog(s) => s,
+        GameState::HowToPlay(s) => s,
+        GameState::RestartConfirm(s) => s,
+        GameState::ForfeitConfirm(s) => s,
+        GameState::Accessibility(s) => s,
+        GameState::Appearance(s) => s,
+        GameState::GameSetup(s) => s,
+        GameState::StatisticsScreen(s) => s,
+        GameState::ModsScreen(s) => s,
+        GameState::PuzzleSelect(s) => s,
+        GameState::LevelEditor(s) => s,
+        GameState::SeedEntry(s) => s,
+        // Drawn by render_diagnostics from the report the UI collects
+        GameState::Diagnostics(_) => return,
+    };
+
+    renderer.render(
+        d,
+        game,
+        has_controller,
+        title_font,
+        font,
+        card_atlas,
+        particle_system,
+        animated_background,
+        board_snapshot,
+    );
+}
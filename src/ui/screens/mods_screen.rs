@@ -0,0 +1,255 @@
+use crate::game::input::InputSnapshot;
+use crate::game::{Game, ModsScreen};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{ModsScreenConfig, ScreenConfig};
+use crate::ui::instruction_renderer::HintAction;
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl ModsScreen {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "MODS & THEMES",
+            ModsScreenConfig::TITLE_Y,
+            ModsScreenConfig::TITLE_SIZE,
+            2.5,
+            Color::WHITE,
+        );
+
+        // The scripts switch first, then one row per installed script or pack
+        let scripts_row = (
+            game.settings.mods_enabled,
+            "Run mod scripts".to_string(),
+            "All Scripts",
+        );
+        let rows = std::iter::once(scripts_row).chain(game.mod_catalog.iter().map(|item| {
+            let mut label = item.name.clone();
+            if !item.version.is_empty() {
+                label.push_str(&format!(" v{}", item.version));
+            }
+            label.push_str(&format!(" by {}", item.author));
+            (
+                game.settings.is_mod_enabled(&item.id),
+                label,
+                item.kind.label(),
+            )
+        }));
+
+        // Keep the selected row in view
+        let first_row = self
+            .selected
+            .saturating_sub(ModsScreenConfig::VISIBLE_ROWS - 1);
+        let mut row_y = ModsScreenConfig::LIST_Y;
+        for (index, (enabled, label, kind)) in rows
+            .enumerate()
+            .skip(first_row)
+            .take(ModsScreenConfig::VISIBLE_ROWS)
+        {
+            Self::draw_row(
+                d,
+                font,
+                index == self.selected,
+                enabled,
+                &label,
+                kind,
+                row_y,
+            );
+            row_y += ModsScreenConfig::ROW_HEIGHT;
+        }
+
+        let hint = if game.mod_catalog.is_empty() {
+            "Put scripts in mods/, theme packs in themes/ and audio packs in audio/ in the data folder"
+        } else {
+            "Changes apply next time DropJack starts"
+        };
+        Self::draw_centered_text(
+            d,
+            font,
+            hint,
+            ModsScreenConfig::HINT_Y,
+            ModsScreenConfig::HINT_COLOR,
+        );
+
+        DrawingHelpers::draw_hints(
+            d,
+            font,
+            &[
+                HintAction::Navigate,
+                HintAction::ToggleMod,
+                HintAction::Back,
+            ],
+            has_controller,
+            ModsScreenConfig::INSTRUCTIONS_Y as f32,
+        );
+    }
+
+    fn draw_row(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        selected: bool,
+        enabled: bool,
+        label: &str,
+        kind: &str,
+        y: i32,
+    ) {
+        let x = ModsScreenConfig::LIST_X;
+        let background = if selected {
+            ModsScreenConfig::SELECTED_COLOR
+        } else {
+            ModsScreenConfig::ROW_COLOR
+        };
+        d.draw_rectangle(
+            x,
+            y,
+            ModsScreenConfig::LIST_WIDTH,
+            ModsScreenConfig::ROW_HEIGHT - 4,
+            background,
+        );
+
+        let text_y = (y + ModsScreenConfig::TEXT_Y_OFFSET) as f32;
+        let (switch, switch_color) = if enabled {
+            ("[ON]", ModsScreenConfig::ON_COLOR)
+        } else {
+            ("[OFF]", ModsScreenConfig::OFF_COLOR)
+        };
+        SharedRenderer::draw_text(
+            d,
+            font,
+            switch,
+            (x + ModsScreenConfig::TEXT_X_OFFSET) as f32,
+            text_y,
+            ModsScreenConfig::TEXT_SIZE,
+            1.0,
+            switch_color,
+        );
+        SharedRenderer::draw_text(
+            d,
+            font,
+            label,
+            (x + ModsScreenConfig::TEXT_X_OFFSET + 80) as f32,
+            text_y,
+            ModsScreenConfig::TEXT_SIZE,
+            1.0,
+            Color::WHITE,
+        );
+        SharedRenderer::draw_text(
+            d,
+            font,
+            kind,
+            (x + ModsScreenConfig::KIND_X) as f32,
+            text_y,
+            ModsScreenConfig::TEXT_SIZE,
+            1.0,
+            ModsScreenConfig::KIND_COLOR,
+        );
+    }
+
+    fn draw_centered_text(d: &mut RaylibDrawHandle, font: &Font, text: &str, y: i32, color: Color) {
+        let text_width = d.measure_text(text, ModsScreenConfig::TEXT_SIZE as i32);
+        SharedRenderer::draw_text(
+            d,
+            font,
+            text,
+            ((ScreenConfig::WIDTH - text_width) / 2) as f32,
+            y as f32,
+            ModsScreenConfig::TEXT_SIZE,
+            1.0,
+            color,
+        );
+    }
+}
+
+impl OverlayState for ModsScreen {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        self.render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_start_screen
+    }
+
+    fn get_overlay_alpha(&self) -> u8 {
+        ModsScreenConfig::OVERLAY_ALPHA
+    }
+}
+
+impl ScreenRenderer for ModsScreen {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for ModsScreen {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.up {
+            game.select_mods_row(-1);
+            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+        }
+
+        if input.down {
+            game.select_mods_row(1);
+            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+        }
+
+        if input.action() {
+            game.toggle_mods_row();
+        }
+
+        if input.escape() || input.back() {
+            game.return_from_mods_screen();
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::game::input::{InputSnapshot, Key, PadButton, TouchGesture};
+use crate::game::{Game, PauseMenuOption, PauseReason, Paused};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{PauseMenuConfig, ScreenConfig};
+use crate::ui::particle_system::ParticleSystem;
+use crate::ui::text_layout::TextLayout;
+use crate::ui::widgets::MenuLayout;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl Paused {
+    // Explains pauses the player didn't ask for
+    fn reason_text(&self, game: &Game) -> Option<String> {
+        match self.reason {
+            PauseReason::Manual => None,
+            PauseReason::FocusLost => Some("Paused automatically: window lost focus".to_string()),
+            PauseReason::Idle => Some(match game.idle_pause_after {
+                Some(timeout) => format!(
+                    "Paused automatically after {}s without input",
+                    timeout.as_secs()
+                ),
+                None => "Paused automatically: no input".to_string(),
+            }),
+        }
+    }
+
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        // Draw paused text using title font
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "GAME PAUSED",
+            250.0,
+            60.0,
+            2.5,
+            Color::WHITE,
+        );
+
+        // Draw current score (not final score)
+        let score_text = format!("Current Score: {}", game.score);
+        SharedRenderer::draw_centered_title(d, font, &score_text, 330.0, 36.0, 1.5, Color::WHITE);
+
+        // Wraps rather than running under the menu when the text size is large
+        if let Some(reason) = self.reason_text(game) {
+            let bounds = Rectangle::new(
+                (ScreenConfig::WIDTH as f32 - PauseMenuConfig::REASON_WIDTH) / 2.0,
+                PauseMenuConfig::REASON_Y,
+                PauseMenuConfig::REASON_WIDTH,
+                PauseMenuConfig::REASON_HEIGHT,
+            );
+            TextLayout::draw_wrapped(
+                d,
+                font,
+                &reason,
+                bounds,
+                PauseMenuConfig::REASON_SIZE,
+                1.0,
+                Color::LIGHTGRAY,
+            );
+        }
+
+        DrawingHelpers::draw_pause_menu(d, font, game, self.selected, has_controller);
+    }
+}
+
+impl OverlayState for Paused {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        self.render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_game_view
+    }
+}
+
+impl ScreenRenderer for Paused {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for Paused {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        // Menu navigation
+        let count = PauseMenuOption::ALL.len();
+        let menu = MenuLayout::pause_menu().read(input, count);
+        let current = game
+            .pause_menu_selection()
+            .and_then(|selected| PauseMenuOption::ALL.iter().position(|&o| o == selected))
+            .unwrap_or(0);
+        let step = menu.focus_step(current, count);
+        if step != 0 {
+            game.select_pause_option(step);
+            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+        }
+
+        if menu.activate {
+            game.choose_pause_option();
+            return;
+        }
+
+        // Resume shortcuts
+        if input.back() || input.key(Key::Letter('P')) || input.button(PadButton::Start) {
+            game.transition_to_playing();
+            return;
+        }
+
+        // Restart shortcut (asks first)
+        if input.key(Key::Letter('R')) || input.button(PadButton::X) {
+            game.transition_to_restart_confirm();
+            return;
+        }
+
+        // Combinations made so far this run
+        if input.key(Key::Letter('H')) || input.button(PadButton::Y) {
+            game.transition_to_combination_log();
+            return;
+        }
+
+        // Tapping anywhere resumes, mirroring the on-screen pause button
+        if matches!(input.gesture, Some(TouchGesture::Tap { .. })) {
+            game.transition_to_playing();
+        }
+    }
+}
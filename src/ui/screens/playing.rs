@@ -0,0 +1,1236 @@
+use crate::game::board_history::BoardFrame;
+use crate::game::input::{InputSnapshot, Key, PadButton, TouchGesture};
+use crate::game::search_trace::SearchTrace;
+use crate::game::{Game, GameState, Playing, SettingsOrigin};
+use crate::models::Card;
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::ControllerConfig;
+use crate::ui::config::ScreenConfig;
+use crate::ui::config::{
+    BoardConfig, ColumnSumConfig, ExplosionFlashConfig, InfoPanelConfig, LockedCardConfig,
+    SearchTraceConfig, StreamerConfig,
+};
+use crate::ui::input_handler::soft_drop_interval;
+use crate::ui::next_card_preview::NextCardPreview;
+use crate::ui::particle_system::ParticleSystem;
+use crate::ui::sdf_text::SdfText;
+use crate::ui::seasons::SeasonalDecorations;
+use crate::ui::text_layout::TextLayout;
+use crate::ui::text_renderer::TextRenderer;
+use crate::ui::theme::Theme;
+use crate::ui::touch_input;
+use raylib::prelude::*;
+use std::time::Duration;
+
+use super::ScreenRenderer;
+use super::StateInput;
+
+impl ScreenRenderer for Playing {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        _animated_background: &mut AnimatedBackground,
+        _board_snapshot: &BoardSnapshot,
+    ) {
+        Self::draw_game_view(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            BoardView::Live,
+        );
+
+        if game.settings.touch_controls {
+            touch_input::draw_pause_button(d, game, font);
+        }
+    }
+}
+
+/// What the board shows when drawing the game view
+pub enum BoardView<'a> {
+    Live,                        // Every card, as in play
+    Hidden,                      // No placed cards, so the layout can't be studied
+    Snapshot(&'a BoardSnapshot), // A blurred capture of the placed cards
+    Replay(&'a BoardFrame),      // A board recorded earlier in the run, with its falling card
+}
+
+impl Playing {
+    /// Draws the complete game view (board + info panel + particles)
+    /// This is used by both Playing and Paused states to avoid duplication
+    ///
+    /// # Parameters
+    /// * `board_view` - How placed and falling cards are shown (overlays hide or blur them)
+    pub fn draw_game_view(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        board_view: BoardView,
+    ) {
+        Self::draw_game_board(d, game, card_atlas, board_view);
+        Self::draw_info_panel(d, game, has_controller, title_font, font, card_atlas);
+
+        // Draw particle effects on top of everything
+        particle_system.draw(d);
+    }
+
+    fn draw_game_board(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        card_atlas: &Texture2D,
+        board_view: BoardView,
+    ) {
+        // Draw the beautiful game board background with the chosen felt and grid
+        DrawingHelpers::draw_game_board_background(
+            d,
+            game.board.width,
+            game.board.height,
+            game.board.cell_size,
+            game.settings.mirrored_layout,
+            game.settings.felt_color,
+        );
+        let (origin_x, origin_y) = BoardConfig::origin(
+            game.board.width,
+            game.board.height,
+            game.board.cell_size,
+            game.settings.mirrored_layout,
+        );
+        SeasonalDecorations::draw_board_frame(
+            d,
+            game.active_season(),
+            origin_x,
+            origin_y,
+            game.board.width * game.board.cell_size,
+            game.board.height * game.board.cell_size,
+        );
+
+        // Only draw static cards on the board when in playing mode
+        // In pause mode, hide or blur them so players can't analyze board patterns
+        match board_view {
+            BoardView::Live => Self::draw_board_cards(d, game, card_atlas, origin_x, origin_y),
+            BoardView::Snapshot(snapshot) => snapshot.draw(d, game),
+            BoardView::Hidden => {}
+            BoardView::Replay(frame) => {
+                // The recorded falling card stands in for the live ones
+                Self::draw_replay_frame(d, game, card_atlas, frame, origin_x, origin_y);
+                return;
+            }
+        }
+
+        // The sum only helps while the stack underneath is visible
+        if matches!(board_view, BoardView::Live) {
+            Self::draw_column_sum(d, game, origin_x, origin_y);
+            if let Some(trace) = &game.search_trace {
+                Self::draw_search_trace(d, game, trace, origin_x, origin_y);
+            }
+        }
+
+        // Always draw the current falling card (even in pause mode, as requested)
+        if let Some(ref playing_card) = game.current_card {
+            let card_x = origin_x + playing_card.visual_position.x as i32;
+            let card_y = origin_y + playing_card.visual_position.y as i32;
+            Self::draw_card_in_play(d, game, card_atlas, playing_card.card, card_x, card_y);
+        }
+
+        // Draw hard-dropping cards that are still animating
+        for hard_drop_card in &game.hard_dropping_cards {
+            if hard_drop_card.is_falling {
+                let card_x = origin_x + hard_drop_card.visual_position.x as i32;
+                let card_y = origin_y + hard_drop_card.visual_position.y as i32;
+                Self::draw_card_in_play(d, game, card_atlas, hard_drop_card.card, card_x, card_y);
+            }
+        }
+    }
+
+    // Every card in a recorded frame, drawn where it sat at the time
+    fn draw_replay_frame(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        card_atlas: &Texture2D,
+        frame: &BoardFrame,
+        origin_x: i32,
+        origin_y: i32,
+    ) {
+        let cell_size = game.board.cell_size;
+        for (y, row) in frame.grid.iter().enumerate() {
+            for (x, card) in row.iter().enumerate() {
+                if let Some(card) = card {
+                    let card_x = origin_x + x as i32 * cell_size;
+                    let card_y = origin_y + y as i32 * cell_size;
+                    Self::draw_card_in_play(d, game, card_atlas, *card, card_x, card_y);
+                }
+            }
+        }
+
+        if let Some((card, position)) = frame.current_card {
+            let card_x = origin_x + position.x * cell_size;
+            let card_y = origin_y + position.y * cell_size;
+            Self::draw_card_in_play(d, game, card_atlas, card, card_x, card_y);
+        }
+    }
+
+    // What the current card would add up to with the stack it lands on, above its column
+    fn draw_column_sum(d: &mut RaylibDrawHandle, game: &Game, origin_x: i32, origin_y: i32) {
+        let Some(playing_card) = &game.current_card else {
+            return;
+        };
+        let column = playing_card.position.x;
+        let Some(sum) =
+            game.board
+                .contiguous_sum_preview(column, playing_card.card, game.difficulty)
+        else {
+            return;
+        };
+
+        let text = sum.to_string();
+        let text_color = match sum {
+            21 => ColumnSumConfig::TWENTY_ONE_COLOR,
+            s if s > 21 => ColumnSumConfig::BUST_COLOR,
+            _ => ColumnSumConfig::TEXT_COLOR,
+        };
+        let text_width = d.measure_text(&text, ColumnSumConfig::FONT_SIZE);
+        let width = text_width + ColumnSumConfig::PADDING * 2;
+        let x = origin_x + column * game.board.cell_size + (game.board.cell_size - width) / 2;
+        let y = origin_y - ColumnSumConfig::BOTTOM_GAP - ColumnSumConfig::HEIGHT;
+
+        d.draw_rectangle_rounded(
+            Rectangle::new(
+                x as f32,
+                y as f32,
+                width as f32,
+                ColumnSumConfig::HEIGHT as f32,
+            ),
+            0.5,
+            6,
+            ColumnSumConfig::BACKGROUND_COLOR,
+        );
+        d.draw_text(
+            &text,
+            x + ColumnSumConfig::PADDING,
+            y + (ColumnSumConfig::HEIGHT - ColumnSumConfig::FONT_SIZE) / 2,
+            ColumnSumConfig::FONT_SIZE,
+            text_color,
+        );
+    }
+
+    // The combination search from the last card placed, as far as playback has got: cards
+    // it has stepped on shaded, 21s it has found outlined, and the path it's exploring drawn
+    // through the cards with a dot where it's reached
+    fn draw_search_trace(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        trace: &SearchTrace,
+        origin_x: i32,
+        origin_y: i32,
+    ) {
+        let size = game.board.cell_size;
+        let center = |&(x, y): &(i32, i32)| {
+            Vector2::new(
+                (origin_x + x * size + size / 2) as f32,
+                (origin_y + y * size + size / 2) as f32,
+            )
+        };
+        let shown = trace.steps_shown(std::time::Instant::now());
+        let view = trace.view(shown);
+
+        for &(x, y) in &view.visited {
+            d.draw_rectangle(
+                origin_x + x * size,
+                origin_y + y * size,
+                size,
+                size,
+                SearchTraceConfig::VISITED_COLOR,
+            );
+        }
+        for path in &view.found {
+            for &(x, y) in path {
+                d.draw_rectangle_lines_ex(
+                    Rectangle::new(
+                        (origin_x + x * size) as f32,
+                        (origin_y + y * size) as f32,
+                        size as f32,
+                        size as f32,
+                    ),
+                    SearchTraceConfig::FOUND_THICKNESS,
+                    SearchTraceConfig::FOUND_COLOR,
+                );
+            }
+            for pair in path.windows(2) {
+                d.draw_line_ex(
+                    center(&pair[0]),
+                    center(&pair[1]),
+                    SearchTraceConfig::FOUND_THICKNESS,
+                    SearchTraceConfig::FOUND_COLOR,
+                );
+            }
+        }
+        for pair in view.path.windows(2) {
+            d.draw_line_ex(
+                center(&pair[0]),
+                center(&pair[1]),
+                SearchTraceConfig::PATH_THICKNESS,
+                SearchTraceConfig::PATH_COLOR,
+            );
+        }
+        if let Some(head) = view.path.last() {
+            let head = center(head);
+            d.draw_circle(
+                head.x as i32,
+                head.y as i32,
+                SearchTraceConfig::HEAD_RADIUS,
+                SearchTraceConfig::PATH_COLOR,
+            );
+        }
+
+        let caption = format!(
+            "Search: step {} of {}   21s found: {}",
+            shown,
+            trace.steps.len(),
+            view.found.len()
+        );
+        d.draw_text(
+            &caption,
+            origin_x,
+            origin_y - SearchTraceConfig::CAPTION_GAP,
+            SearchTraceConfig::CAPTION_SIZE,
+            SearchTraceConfig::CAPTION_COLOR,
+        );
+    }
+
+    // A card on the board or falling: the card, the theme's outline and, when turned on,
+    // its value badge
+    fn draw_card_in_play(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        card_atlas: &Texture2D,
+        card: Card,
+        card_x: i32,
+        card_y: i32,
+    ) {
+        let size = game.board.cell_size;
+        DrawingHelpers::draw_card_inline(d, card_atlas, card, card_x, card_y, size);
+        Theme::of(game).draw_card_outline(d, card_x, card_y, size);
+        if game.settings.card_values {
+            DrawingHelpers::draw_value_badge(d, card, card_x, card_y, size);
+        }
+    }
+
+    // Flashes a card whose combination explodes next. Cards of one combination share a
+    // removal time, so they flash in step; combinations further back in the queue wait
+    // their turn unlit.
+    fn draw_explosion_flash(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        x: i32,
+        y: i32,
+        card_x: i32,
+        card_y: i32,
+    ) {
+        let Some(removal_time) = game.board.marked_for_removal[y as usize][x as usize] else {
+            return;
+        };
+        let window = game.balance.combination_delay();
+        let remaining = removal_time.saturating_duration_since(std::time::Instant::now());
+        if window.is_zero() || remaining > window {
+            return;
+        }
+
+        let alpha = if game.settings.reduced_motion {
+            ExplosionFlashConfig::STEADY_ALPHA
+        } else {
+            let progress = 1.0 - remaining.as_secs_f32() / window.as_secs_f32();
+            let phase = std::f32::consts::TAU * ExplosionFlashConfig::CYCLES * progress;
+            ExplosionFlashConfig::MAX_ALPHA * (0.5 - 0.5 * phase.cos())
+        };
+        let size = game.board.cell_size;
+        d.draw_rectangle(
+            card_x,
+            card_y,
+            size,
+            size,
+            ExplosionFlashConfig::COLOR.fade(alpha),
+        );
+    }
+
+    // Grays out a card the bust rule has locked and counts down to its unlock
+    fn draw_lock_cover(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        x: i32,
+        y: i32,
+        card_x: i32,
+        card_y: i32,
+    ) {
+        let Some(unlock_time) = game.board.locked[y as usize][x as usize] else {
+            return;
+        };
+        let size = game.board.cell_size;
+        d.draw_rectangle(card_x, card_y, size, size, LockedCardConfig::COVER_COLOR);
+        d.draw_rectangle_lines(card_x, card_y, size, size, LockedCardConfig::BORDER_COLOR);
+
+        let seconds_left = unlock_time
+            .saturating_duration_since(std::time::Instant::now())
+            .as_secs_f32()
+            .ceil() as i32;
+        let text = seconds_left.to_string();
+        let text_width = d.measure_text(&text, LockedCardConfig::FONT_SIZE);
+        d.draw_text(
+            &text,
+            card_x + (size - text_width) / 2,
+            card_y + (size - LockedCardConfig::FONT_SIZE) / 2,
+            LockedCardConfig::FONT_SIZE,
+            LockedCardConfig::TEXT_COLOR,
+        );
+    }
+
+    // Placed cards plus the ones still animating into place
+    fn draw_board_cards(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        card_atlas: &Texture2D,
+        origin_x: i32,
+        origin_y: i32,
+    ) {
+        // Draw cards on the board
+        for y in 0..game.board.height {
+            for x in 0..game.board.width {
+                if let Some(card) = game.board.grid[y as usize][x as usize] {
+                    // Check if this position has a falling card animation
+                    let has_falling =
+                        game.board.falling_cards.iter().any(|falling| {
+                            falling.x == x && falling.to_y == y && falling.is_animating
+                        });
+
+                    // Only draw static cards if there's no falling animation
+                    if !has_falling {
+                        let card_x = origin_x + x * game.board.cell_size;
+                        let card_y = origin_y + y * game.board.cell_size;
+                        Self::draw_card_in_play(d, game, card_atlas, card, card_x, card_y);
+                        Self::draw_lock_cover(d, game, x, y, card_x, card_y);
+                        Self::draw_explosion_flash(d, game, x, y, card_x, card_y);
+                    }
+                }
+            }
+        }
+
+        // Draw falling cards with smooth animation
+        for falling_card in &game.board.falling_cards {
+            if falling_card.is_animating {
+                let card_x = origin_x + falling_card.x * game.board.cell_size;
+                let card_y = origin_y + falling_card.visual_y as i32;
+                Self::draw_card_in_play(d, game, card_atlas, falling_card.card, card_x, card_y);
+                Self::draw_lock_cover(d, game, falling_card.x, falling_card.to_y, card_x, card_y);
+            }
+        }
+    }
+
+    fn draw_info_panel(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+    ) {
+        // Enhanced panel background with sophisticated styling and depth
+        let panel_x = InfoPanelConfig::x(game.settings.mirrored_layout);
+        let panel_height = ScreenConfig::HEIGHT - 2 * BoardConfig::OFFSET_Y;
+
+        let theme = Theme::of(game);
+        if theme.solid_panels {
+            theme.draw_panel(
+                d,
+                panel_x,
+                BoardConfig::OFFSET_Y,
+                InfoPanelConfig::WIDTH,
+                panel_height,
+            );
+        } else {
+            Self::draw_info_panel_background(d, panel_x, panel_height);
+        }
+
+        // Enhanced panel title with multiple shadow layers and glow effect
+        let title_text = "DropJack";
+        let title_x = panel_x + 30;
+        let title_y = BoardConfig::OFFSET_Y + 30;
+
+        // Outer glow effect
+        for glow_layer in 1..=4 {
+            let glow_alpha = 40 / glow_layer;
+            SdfText::draw(
+                d,
+                title_font,
+                title_text,
+                Vector2::new((title_x + glow_layer) as f32, (title_y + glow_layer) as f32),
+                40.0,
+                1.5,
+                Color::new(255, 215, 0, glow_alpha as u8),
+            );
+        }
+
+        // Deep shadow
+        SdfText::draw(
+            d,
+            title_font,
+            title_text,
+            Vector2::new((title_x + 3) as f32, (title_y + 3) as f32),
+            40.0,
+            1.5,
+            Color::new(0, 0, 0, 180),
+        );
+
+        // Medium shadow
+        SdfText::draw(
+            d,
+            title_font,
+            title_text,
+            Vector2::new((title_x + 2) as f32, (title_y + 2) as f32),
+            40.0,
+            1.5,
+            Color::new(0, 0, 0, 120),
+        );
+
+        // Close shadow
+        SdfText::draw(
+            d,
+            title_font,
+            title_text,
+            Vector2::new((title_x + 1) as f32, (title_y + 1) as f32),
+            40.0,
+            1.5,
+            Color::new(0, 0, 0, 80),
+        );
+
+        // Main title with gradient effect
+        theme.draw_text_ex(
+            d,
+            title_font,
+            title_text,
+            Vector2::new(title_x as f32, title_y as f32),
+            40.0,
+            1.5,
+            theme.highlight_text_color,
+        );
+
+        // Top highlight for 3D effect
+        SdfText::draw(
+            d,
+            title_font,
+            title_text,
+            Vector2::new(title_x as f32, (title_y - 1) as f32),
+            40.0,
+            1.5,
+            Color::new(255, 255, 200, 100),
+        );
+
+        // Enhanced difficulty display with styling; a puzzle shows its level instead
+        let difficulty_text = match game.current_puzzle() {
+            Some(level) => format!("Puzzle: {}", level.name),
+            None if game.run_relaxed => format!("Difficulty: {} (Relax)", game.difficulty),
+            None => format!("Difficulty: {}", game.difficulty),
+        };
+        let diff_x = panel_x + 30;
+        let diff_y = BoardConfig::OFFSET_Y + 90;
+        let text_scale = game.settings.text_scale();
+        let diff_size = TextRenderer::scaled_size(
+            font,
+            &difficulty_text,
+            24.0,
+            text_scale,
+            1.0,
+            InfoPanelConfig::DIFFICULTY_MAX_WIDTH,
+        );
+
+        // Multiple shadow layers
+        SdfText::draw(
+            d,
+            font,
+            &difficulty_text,
+            Vector2::new((diff_x + 2) as f32, (diff_y + 2) as f32),
+            diff_size,
+            1.0,
+            Color::new(0, 0, 0, 150),
+        );
+        SdfText::draw(
+            d,
+            font,
+            &difficulty_text,
+            Vector2::new((diff_x + 1) as f32, (diff_y + 1) as f32),
+            diff_size,
+            1.0,
+            Color::new(0, 0, 0, 100),
+        );
+        theme.draw_text_ex(
+            d,
+            font,
+            &difficulty_text,
+            Vector2::new(diff_x as f32, diff_y as f32),
+            diff_size,
+            1.0,
+            theme.text_color,
+        );
+
+        // Speed level on the same line, right aligned in the panel
+        let speed_text = format!("Speed: {}", game.speed_level + 1);
+        let speed_size = TextRenderer::scaled_size(
+            font,
+            &speed_text,
+            24.0,
+            text_scale,
+            1.0,
+            InfoPanelConfig::SPEED_MAX_WIDTH,
+        );
+        let speed_x =
+            panel_x + InfoPanelConfig::WIDTH - 40 - d.measure_text(&speed_text, speed_size as i32);
+        SdfText::draw(
+            d,
+            font,
+            &speed_text,
+            Vector2::new((speed_x + 2) as f32, (diff_y + 2) as f32),
+            speed_size,
+            1.0,
+            Color::new(0, 0, 0, 150),
+        );
+        theme.draw_text_ex(
+            d,
+            font,
+            &speed_text,
+            Vector2::new(speed_x as f32, diff_y as f32),
+            speed_size,
+            1.0,
+            theme.text_color,
+        );
+
+        // Adaptive speed's nudge, as a small arrow just past the speed
+        let adjustment = game.difficulty_controller.adjustment();
+        if adjustment != 0 {
+            let arrow_x = (panel_x + InfoPanelConfig::WIDTH - 28) as f32;
+            let middle_y = diff_y as f32 + speed_size / 2.0;
+            let half = InfoPanelConfig::PACE_ARROW_SIZE / 2.0;
+            let (tip, left, right) = if adjustment > 0 {
+                (
+                    Vector2::new(arrow_x, middle_y - half),
+                    Vector2::new(arrow_x - half, middle_y + half),
+                    Vector2::new(arrow_x + half, middle_y + half),
+                )
+            } else {
+                (
+                    Vector2::new(arrow_x, middle_y + half),
+                    Vector2::new(arrow_x + half, middle_y - half),
+                    Vector2::new(arrow_x - half, middle_y - half),
+                )
+            };
+            d.draw_triangle(tip, left, right, InfoPanelConfig::PACE_ARROW_COLOR);
+        }
+
+        // Enhanced score display with a glow effect
+        let score_text = format!("Score: {}", game.score);
+        let score_x = panel_x + 30;
+        let score_y = BoardConfig::OFFSET_Y + 130;
+        let streamer_mode = game.settings.streamer_mode.is_on();
+        let base_score_size = if streamer_mode {
+            StreamerConfig::SCORE_SIZE
+        } else {
+            30.0
+        };
+        let score_size = TextRenderer::scaled_size(
+            font,
+            &score_text,
+            base_score_size,
+            text_scale,
+            1.25,
+            InfoPanelConfig::SCORE_MAX_WIDTH,
+        );
+
+        // Glow effect for the score
+        for glow in 1..=3 {
+            let glow_alpha = 60 / glow;
+            SdfText::draw(
+                d,
+                font,
+                &score_text,
+                Vector2::new((score_x + glow) as f32, (score_y + glow) as f32),
+                score_size,
+                1.25,
+                Color::new(255, 215, 0, glow_alpha as u8),
+            );
+        }
+
+        // Main score shadow
+        SdfText::draw(
+            d,
+            font,
+            &score_text,
+            Vector2::new((score_x + 2) as f32, (score_y + 2) as f32),
+            score_size,
+            1.25,
+            Color::new(0, 0, 0, 150),
+        );
+
+        // Main score text
+        theme.draw_text_ex(
+            d,
+            font,
+            &score_text,
+            Vector2::new(score_x as f32, score_y as f32),
+            score_size,
+            1.25,
+            theme.highlight_text_color,
+        );
+
+        // Personal best to beat for this difficulty and mode
+        if let Some(best) = game.best_to_show() {
+            let best_text = format!("Best: {}", best);
+            let best_y = score_y + score_size as i32 + 4;
+            let best_size = TextRenderer::scaled_size(
+                font,
+                &best_text,
+                InfoPanelConfig::BEST_SIZE,
+                text_scale,
+                1.0,
+                InfoPanelConfig::BEST_MAX_WIDTH,
+            );
+            theme.draw_text_ex(
+                d,
+                font,
+                &best_text,
+                Vector2::new(score_x as f32, best_y as f32),
+                best_size,
+                1.0,
+                theme.text_color,
+            );
+
+            if let Some(progress) = game.personal_best_banner_progress() {
+                let banner_x =
+                    score_x as f32 + TextLayout::measure(font, &best_text, best_size, 1.0).x + 12.0;
+                Self::draw_personal_best_banner(
+                    d,
+                    game,
+                    font,
+                    Vector2::new(banner_x, best_y as f32),
+                    best_size,
+                    progress,
+                );
+            }
+        }
+
+        // Enhanced next card preview with a sophisticated frame
+        let next_card_text = "Next Card:";
+        let next_x = panel_x + 30;
+        let next_y = BoardConfig::OFFSET_Y + 190;
+        let next_size = TextRenderer::scaled_size(
+            title_font,
+            next_card_text,
+            28.0,
+            text_scale,
+            1.0,
+            InfoPanelConfig::NEXT_LABEL_MAX_WIDTH,
+        );
+
+        // Shadow and text
+        SdfText::draw(
+            d,
+            title_font,
+            next_card_text,
+            Vector2::new((next_x + 2) as f32, (next_y + 2) as f32),
+            next_size,
+            1.0,
+            Color::new(0, 0, 0, 120),
+        );
+        theme.draw_text_ex(
+            d,
+            title_font,
+            next_card_text,
+            Vector2::new(next_x as f32, next_y as f32),
+            next_size,
+            1.0,
+            theme.text_color,
+        );
+
+        if let Some(card) = game.next_card {
+            // Enhanced decorative frame around the next card with lighting effects
+            let card_x = panel_x + 60;
+            let card_y = BoardConfig::OFFSET_Y + 230;
+            let card_size = if streamer_mode {
+                (game.board.cell_size as f32 * StreamerConfig::NEXT_CARD_SCALE) as i32
+            } else {
+                game.board.cell_size
+            };
+            let frame_size = card_size + 16;
+
+            // Outer shadow
+            d.draw_rectangle(
+                card_x - 10,
+                card_y - 8,
+                frame_size + 4,
+                frame_size + 4,
+                Color::new(0, 0, 0, 100),
+            );
+
+            // Multiple frame layers for depth
+            d.draw_rectangle(
+                card_x - 8,
+                card_y - 8,
+                frame_size,
+                frame_size,
+                Color::new(80, 40, 20, 255),
+            );
+            d.draw_rectangle(
+                card_x - 6,
+                card_y - 6,
+                frame_size - 4,
+                frame_size - 4,
+                Color::new(139, 69, 19, 255),
+            );
+            d.draw_rectangle(
+                card_x - 4,
+                card_y - 4,
+                frame_size - 8,
+                frame_size - 8,
+                Color::new(210, 180, 140, 255),
+            );
+
+            // Inner highlight
+            d.draw_rectangle(
+                card_x - 2,
+                card_y - 2,
+                frame_size - 12,
+                frame_size - 12,
+                Color::new(255, 255, 200, 60),
+            );
+
+            NextCardPreview::draw(d, game, card_atlas, card, card_x, card_y, card_size);
+        }
+
+        // Streamer mode's larger preview needs this space
+        if !streamer_mode {
+            let deck_text = match game.current_puzzle() {
+                Some(level) => format!(
+                    "Cards: {} of {} used, par {}",
+                    game.puzzle_cards_used(),
+                    level.cards.len(),
+                    level.par
+                ),
+                None => format!(
+                    "Deck: {} left, {} cleared",
+                    game.deck.remaining(),
+                    game.deck.discards().len()
+                ),
+            };
+            let mut lines = vec![(deck_text, InfoPanelConfig::DECK_COUNT_Y_OFFSET)];
+            if game.settings.draw_odds
+                && let Some(chance) = game.next_draw_chance()
+            {
+                let odds_text = format!("Next draw makes 21: {}%", (chance * 100.0).round());
+                lines.push((odds_text, InfoPanelConfig::DRAW_ODDS_Y_OFFSET));
+            }
+
+            for (text, y_offset) in lines {
+                let size = TextRenderer::scaled_size(
+                    font,
+                    &text,
+                    InfoPanelConfig::DECK_COUNT_SIZE,
+                    text_scale,
+                    1.0,
+                    InfoPanelConfig::DECK_COUNT_MAX_WIDTH,
+                );
+                theme.draw_text_ex(
+                    d,
+                    font,
+                    &text,
+                    Vector2::new(next_x as f32, (BoardConfig::OFFSET_Y + y_offset) as f32),
+                    size,
+                    1.0,
+                    theme.text_color,
+                );
+            }
+        }
+
+        Self::draw_last_combination(d, game, title_font, font, card_atlas);
+
+        // Draw conditional controls based on controller availability
+        DrawingHelpers::draw_controls(
+            d,
+            title_font,
+            font,
+            panel_x,
+            BoardConfig::OFFSET_Y,
+            has_controller,
+            text_scale,
+        );
+    }
+
+    // Wood frame, gradient and fabric texture behind the standard info panel
+    // "New PB!" on a gold tag, blinking until it goes (steady with reduced motion)
+    fn draw_personal_best_banner(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        font: &Font,
+        position: Vector2,
+        size: f32,
+        progress: f32,
+    ) {
+        let blink_on = game.settings.reduced_motion
+            || (progress * InfoPanelConfig::PB_BANNER_FLASHES).fract() < 0.6;
+        if !blink_on {
+            return;
+        }
+
+        let text = "New PB!";
+        let padding = InfoPanelConfig::PB_BANNER_PADDING;
+        let text_size = TextLayout::measure(font, text, size, 1.0);
+        d.draw_rectangle_rounded(
+            Rectangle::new(
+                position.x - padding,
+                position.y - padding / 2.0,
+                text_size.x + padding * 2.0,
+                text_size.y + padding,
+            ),
+            0.4,
+            6,
+            InfoPanelConfig::PB_BANNER_COLOR,
+        );
+        SdfText::draw(
+            d,
+            font,
+            text,
+            position,
+            size,
+            1.0,
+            InfoPanelConfig::PB_BANNER_TEXT_COLOR,
+        );
+    }
+
+    fn draw_info_panel_background(d: &mut RaylibDrawHandle, panel_x: i32, panel_height: i32) {
+        let panel_center_y = BoardConfig::OFFSET_Y + panel_height / 2;
+
+        // Outermost shadow for dramatic depth
+        d.draw_rectangle(
+            panel_x - 8,
+            BoardConfig::OFFSET_Y - 8,
+            InfoPanelConfig::WIDTH + 16,
+            panel_height + 16,
+            Color::new(0, 0, 0, 120),
+        );
+
+        // Multiple frame layers for rich depth
+        // Outer dark wood frame matching the board
+        d.draw_rectangle(
+            panel_x - 6,
+            BoardConfig::OFFSET_Y - 6,
+            InfoPanelConfig::WIDTH + 12,
+            panel_height + 12,
+            Color::new(80, 40, 20, 255),
+        );
+
+        // Middle wood frame with grain effect
+        d.draw_rectangle(
+            panel_x - 4,
+            BoardConfig::OFFSET_Y - 4,
+            InfoPanelConfig::WIDTH + 8,
+            panel_height + 8,
+            Color::new(139, 69, 19, 255),
+        );
+
+        // Add wood grain lines for consistency with the board frame
+        for i in 0..6 {
+            let grain_offset = i * 2;
+            d.draw_line(
+                panel_x - 4 + grain_offset,
+                BoardConfig::OFFSET_Y - 4,
+                panel_x - 4 + grain_offset,
+                BoardConfig::OFFSET_Y + panel_height + 4,
+                Color::new(110, 55, 15, 80),
+            );
+        }
+
+        // Inner decorative border
+        d.draw_rectangle(
+            panel_x - 2,
+            BoardConfig::OFFSET_Y - 2,
+            InfoPanelConfig::WIDTH + 4,
+            panel_height + 4,
+            Color::new(210, 180, 140, 255),
+        );
+
+        // Create a sophisticated radial gradient background for the panel - OPTIMIZED
+        let panel_center_x = panel_x + InfoPanelConfig::WIDTH / 2;
+        let max_distance = ((InfoPanelConfig::WIDTH * InfoPanelConfig::WIDTH
+            + panel_height * panel_height) as f32)
+            .sqrt()
+            / 2.0;
+
+        // Use efficient overlapping rectangles for smooth gradient - NO GAPS
+        let gradient_steps = 20; // Reduced for performance but still smooth
+        let step_width = (InfoPanelConfig::WIDTH as f32 / gradient_steps as f32).ceil() as i32;
+        let step_height = (panel_height as f32 / gradient_steps as f32).ceil() as i32;
+
+        for y in 0..gradient_steps {
+            for x in 0..gradient_steps {
+                let rect_x = panel_x + x * step_width;
+                let rect_y = BoardConfig::OFFSET_Y + y * step_height;
+
+                // Make rectangles overlap slightly to eliminate gaps
+                let rect_width = if x == gradient_steps - 1 {
+                    InfoPanelConfig::WIDTH - x * step_width + 2
+                } else {
+                    step_width + 2
+                };
+                let rect_height = if y == gradient_steps - 1 {
+                    panel_height - y * step_height + 2
+                } else {
+                    step_height + 2
+                };
+
+                // Calculate the center of this rectangle for distance calculation
+                let center_x_offset = (rect_x + rect_width / 2) - panel_center_x;
+                let center_y_offset =
+                    (rect_y + rect_height / 2) - (BoardConfig::OFFSET_Y + panel_height / 2);
+                let distance = ((center_x_offset * center_x_offset
+                    + center_y_offset * center_y_offset) as f32)
+                    .sqrt();
+                let distance_ratio = (distance / max_distance).min(1.0);
+
+                // Create sophisticated color transitions
+                let light_factor = 1.0 - (distance_ratio * distance_ratio * 0.5);
+                let x_factor = x as f32 / gradient_steps as f32;
+                let y_factor = y as f32 / gradient_steps as f32;
+
+                // Rich blue gradient with subtle variations
+                let base_r = 25.0 + y_factor * 20.0;
+                let base_g = 25.0 + x_factor * 25.0 + y_factor * 15.0;
+                let base_b = 80.0 + x_factor * 30.0 + y_factor * 25.0;
+
+                let r = (base_r * light_factor) as u8;
+                let g = (base_g * light_factor) as u8;
+                let b = (base_b * light_factor + 10.0) as u8;
+
+                let color = Color::new(r, g, b, 255);
+                d.draw_rectangle(rect_x, rect_y, rect_width, rect_height, color);
+            }
+        }
+
+        // Add subtle fabric-like texture to match the board
+        for i in 0..80 {
+            let x = panel_x + (i * 61) % InfoPanelConfig::WIDTH;
+            let y = BoardConfig::OFFSET_Y + (i * 97) % panel_height;
+
+            // Distance from the center affects texture visibility
+            let dx = x - panel_center_x;
+            let dy = y - panel_center_y;
+            let distance_from_center = ((dx * dx + dy * dy) as f32).sqrt();
+            let distance_ratio = (distance_from_center / max_distance).min(1.0);
+
+            // Texture is more visible in lit areas
+            let base_alpha = 20.0 * (1.0 - distance_ratio * 0.6);
+            let alpha = ((i * 23) % 12 + base_alpha as i32) as u8;
+
+            let size = 0.2 + ((i * 7) % 4) as f32 * 0.1;
+            d.draw_circle(x, y, size, Color::new(255, 255, 255, alpha));
+        }
+    }
+
+    /// The most recent 21 as mini cards with the sum written out underneath
+    fn draw_last_combination(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+    ) {
+        let Some(combination) = game.last_combination() else {
+            return;
+        };
+
+        let combo_x = InfoPanelConfig::x(game.settings.mirrored_layout)
+            + InfoPanelConfig::LAST_COMBO_X_OFFSET;
+        let label_y = BoardConfig::OFFSET_Y + 190;
+
+        SdfText::draw(
+            d,
+            title_font,
+            "Last 21:",
+            Vector2::new((combo_x + 2) as f32, (label_y + 2) as f32),
+            28.0,
+            1.0,
+            Color::new(0, 0, 0, 120),
+        );
+        SdfText::draw(
+            d,
+            title_font,
+            "Last 21:",
+            Vector2::new(combo_x as f32, label_y as f32),
+            28.0,
+            1.0,
+            Color::new(255, 255, 255, 255),
+        );
+
+        // Long chains of low cards shrink to stay inside the panel
+        let count = combination.cards.len().max(1) as i32;
+        let gap = InfoPanelConfig::LAST_COMBO_CARD_GAP;
+        let card_size = InfoPanelConfig::LAST_COMBO_CARD_SIZE
+            .min((InfoPanelConfig::LAST_COMBO_MAX_WIDTH + gap) / count - gap);
+        let cards_y = BoardConfig::OFFSET_Y + 232;
+
+        for (index, &card) in combination.cards.iter().enumerate() {
+            let card_x = combo_x + index as i32 * (card_size + gap);
+            DrawingHelpers::draw_card_inline(d, card_atlas, card, card_x, cards_y, card_size);
+        }
+
+        let equation = combination.equation();
+        let equation_y = cards_y + card_size + 10;
+        SdfText::draw(
+            d,
+            font,
+            &equation,
+            Vector2::new((combo_x + 2) as f32, (equation_y + 2) as f32),
+            22.0,
+            1.0,
+            Color::new(0, 0, 0, 150),
+        );
+        SdfText::draw(
+            d,
+            font,
+            &equation,
+            Vector2::new(combo_x as f32, equation_y as f32),
+            22.0,
+            1.0,
+            Color::new(255, 215, 0, 255),
+        );
+    }
+}
+
+impl Playing {
+    fn handle_keys(game: &mut Game, input: &InputSnapshot, pacing: &mut Playing) {
+        // Handle movement (left/right), repeating while held
+        let move_delay = Duration::from_millis(ControllerConfig::MOVE_REPEAT_MS);
+        let can_move = pacing
+            .last_move
+            .is_none_or(|last| input.now.duration_since(last) >= move_delay);
+        if can_move {
+            if input.left_held {
+                game.note_input();
+                game.move_current_card_left();
+                pacing.last_move = Some(input.now);
+            } else if input.right_held {
+                game.note_input();
+                game.move_current_card_right();
+                pacing.last_move = Some(input.now);
+            }
+        }
+
+        // Handle soft drop (down key, or the stick at a speed set by how far it's pushed)
+        if input.soft_drop > 0.0 {
+            game.note_input();
+            let interval = soft_drop_interval(input.soft_drop);
+            if pacing
+                .last_soft_drop
+                .is_none_or(|last| input.now.duration_since(last) >= interval)
+            {
+                game.move_current_card_down();
+                pacing.last_soft_drop = Some(input.now);
+            }
+        }
+
+        // Handle hard drop (space key)
+        if input.action() {
+            game.note_input();
+            game.hard_drop();
+        }
+
+        // Handle settings (escape/menu button)
+        if input.escape() {
+            game.transition_to_settings(SettingsOrigin::Playing);
+        }
+
+        // Restart the run (asks first)
+        if input.key(Key::Letter('R')) {
+            game.transition_to_restart_confirm();
+            return;
+        }
+
+        // Handle traditional pause (P key or start button)
+        if input.key(Key::Letter('P')) || input.button(PadButton::Start) {
+            game.transition_to_paused();
+        }
+    }
+
+    fn handle_touch(game: &mut Game, input: &InputSnapshot, pacing: &mut Playing) {
+        match input.gesture {
+            Some(TouchGesture::Tap { x, y }) if touch_input::is_on_pause_button(game, x, y) => {
+                pacing.touch_target = None;
+                game.transition_to_paused();
+                return;
+            }
+            Some(TouchGesture::Tap { x, .. }) => {
+                if let Some(column) = touch_input::board_column_at(game, x) {
+                    game.note_input();
+                    pacing.touch_target = Some(column);
+                }
+            }
+            Some(TouchGesture::SwipeLeft) => {
+                game.note_input();
+                pacing.touch_target = None;
+                game.move_current_card_left();
+            }
+            Some(TouchGesture::SwipeRight) => {
+                game.note_input();
+                pacing.touch_target = None;
+                game.move_current_card_right();
+            }
+            Some(TouchGesture::SwipeDown) => {
+                game.note_input();
+                pacing.touch_target = None;
+                game.hard_drop();
+            }
+            None => {}
+        }
+
+        // Walk the card to a tapped column one step at a time, like holding an arrow key
+        let move_delay = Duration::from_millis(ControllerConfig::MOVE_REPEAT_MS);
+        if let Some(column) = pacing.touch_target
+            && pacing
+                .last_move
+                .is_none_or(|last| input.now.duration_since(last) >= move_delay)
+        {
+            if game.step_current_card_toward(column) {
+                pacing.last_move = Some(input.now);
+            } else {
+                pacing.touch_target = None;
+            }
+        }
+    }
+}
+
+impl StateInput for Playing {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        let GameState::Playing(pacing) = &game.state else {
+            return;
+        };
+        let mut pacing = pacing.clone();
+
+        Self::handle_keys(game, input, &mut pacing);
+        if game.is_playing() {
+            Self::handle_touch(game, input, &mut pacing);
+        }
+
+        // Keep the timers for next frame, unless play was left
+        if let GameState::Playing(playing) = &mut game.state {
+            *playing = pacing;
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use crate::game::input::{InputSnapshot, Key};
+use crate::game::{Game, PuzzleSelect};
+use crate::models::MAX_STARS;
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{PuzzleSelectConfig, ScreenConfig};
+use crate::ui::instruction_renderer::HintAction;
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+// Inner corners of a five-pointed star, as a share of its outer radius
+const STAR_INNER_RATIO: f32 = 0.382;
+
+impl PuzzleSelect {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "PUZZLES",
+            PuzzleSelectConfig::TITLE_Y,
+            PuzzleSelectConfig::TITLE_SIZE,
+            2.5,
+            Color::WHITE,
+        );
+
+        // Keep the selected level in view
+        let first_row = self
+            .selected
+            .saturating_sub(PuzzleSelectConfig::VISIBLE_ROWS - 1);
+        let mut row_y = PuzzleSelectConfig::LIST_Y;
+        for (index, level) in game
+            .puzzle_levels
+            .iter()
+            .enumerate()
+            .skip(first_row)
+            .take(PuzzleSelectConfig::VISIBLE_ROWS)
+        {
+            let stars = game.puzzle_stars.get(&level.id).copied().unwrap_or(0);
+            Self::draw_row(
+                d,
+                font,
+                index == self.selected,
+                &format!("{}. {}", index + 1, level.name),
+                &format!("{}  Par {}", level.difficulty, level.par),
+                stars,
+                row_y,
+            );
+            row_y += PuzzleSelectConfig::ROW_HEIGHT;
+        }
+
+        let hint = if game.puzzle_levels.is_empty() {
+            "No puzzles could be loaded"
+        } else {
+            "Clear the board with the cards you're dealt  -  E to make your own"
+        };
+        let text_width = d.measure_text(hint, PuzzleSelectConfig::TEXT_SIZE as i32);
+        SharedRenderer::draw_text(
+            d,
+            font,
+            hint,
+            ((ScreenConfig::WIDTH - text_width) / 2) as f32,
+            PuzzleSelectConfig::HINT_Y as f32,
+            PuzzleSelectConfig::TEXT_SIZE,
+            1.0,
+            PuzzleSelectConfig::HINT_COLOR,
+        );
+
+        DrawingHelpers::draw_hints(
+            d,
+            font,
+            &[HintAction::Navigate, HintAction::Select, HintAction::Back],
+            has_controller,
+            PuzzleSelectConfig::INSTRUCTIONS_Y as f32,
+        );
+    }
+
+    fn draw_row(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        selected: bool,
+        name: &str,
+        details: &str,
+        stars: u8,
+        y: i32,
+    ) {
+        let x = PuzzleSelectConfig::LIST_X;
+        let background = if selected {
+            PuzzleSelectConfig::SELECTED_COLOR
+        } else {
+            PuzzleSelectConfig::ROW_COLOR
+        };
+        d.draw_rectangle(
+            x,
+            y,
+            PuzzleSelectConfig::LIST_WIDTH,
+            PuzzleSelectConfig::ROW_HEIGHT - 4,
+            background,
+        );
+
+        let text_y = (y + PuzzleSelectConfig::TEXT_Y_OFFSET) as f32;
+        SharedRenderer::draw_text(
+            d,
+            font,
+            name,
+            (x + PuzzleSelectConfig::TEXT_X_OFFSET) as f32,
+            text_y,
+            PuzzleSelectConfig::TEXT_SIZE,
+            1.0,
+            Color::WHITE,
+        );
+        SharedRenderer::draw_text(
+            d,
+            font,
+            details,
+            (x + PuzzleSelectConfig::DETAILS_X) as f32,
+            text_y,
+            PuzzleSelectConfig::TEXT_SIZE,
+            1.0,
+            PuzzleSelectConfig::DETAILS_COLOR,
+        );
+
+        let star_y = (y + (PuzzleSelectConfig::ROW_HEIGHT - 4) / 2) as f32;
+        for star in 0..MAX_STARS {
+            let center = Vector2::new(
+                (x + PuzzleSelectConfig::STARS_X) as f32
+                    + star as f32 * PuzzleSelectConfig::STAR_SPACING,
+                star_y,
+            );
+            let color = if star < stars {
+                PuzzleSelectConfig::STAR_COLOR
+            } else {
+                PuzzleSelectConfig::EMPTY_STAR_COLOR
+            };
+            Self::draw_star(d, center, PuzzleSelectConfig::STAR_RADIUS, color);
+        }
+    }
+
+    // The fonts only carry ASCII, so stars are drawn: a pentagon of the inner corners with a
+    // triangle for each point. Triangles go counter-clockwise on screen, as raylib expects.
+    fn draw_star(d: &mut RaylibDrawHandle, center: Vector2, radius: f32, color: Color) {
+        let point = |degrees: f32, distance: f32| {
+            let angle = degrees.to_radians();
+            Vector2::new(
+                center.x + angle.sin() * distance,
+                center.y - angle.cos() * distance,
+            )
+        };
+        let inner = radius * STAR_INNER_RATIO;
+        for corner in 0..5 {
+            let tip = corner as f32 * 72.0;
+            let left = point(tip - 36.0, inner);
+            let right = point(tip + 36.0, inner);
+            d.draw_triangle(point(tip, radius), left, right, color);
+            d.draw_triangle(center, point(tip + 108.0, inner), right, color);
+        }
+    }
+}
+
+impl OverlayState for PuzzleSelect {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        self.render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_start_screen
+    }
+
+    fn get_overlay_alpha(&self) -> u8 {
+        PuzzleSelectConfig::OVERLAY_ALPHA
+    }
+}
+
+impl ScreenRenderer for PuzzleSelect {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for PuzzleSelect {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.up {
+            game.select_puzzle(-1);
+            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+        }
+
+        if input.down {
+            game.select_puzzle(1);
+            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+        }
+
+        if input.action() {
+            game.start_selected_puzzle();
+            return;
+        }
+
+        if input.key(Key::Letter('E')) {
+            game.transition_to_level_editor();
+            return;
+        }
+
+        if input.escape() || input.back() {
+            game.return_from_puzzle_select();
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::game::input::{InputSnapshot, Key, PadButton};
+use crate::game::{Game, QuitConfirm};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl QuitConfirm {
+    fn render_content(
+        d: &mut RaylibDrawHandle,
+        _game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        // Draw quit confirmation dialog
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "QUIT GAME?",
+            300.0,
+            60.0,
+            2.5,
+            Color::WHITE,
+        );
+
+        // Draw conditional quit confirmation based on controller availability
+        DrawingHelpers::draw_quit_confirmation(d, font, has_controller);
+    }
+}
+
+impl OverlayState for QuitConfirm {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        Self::render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_start_screen
+    }
+}
+
+impl ScreenRenderer for QuitConfirm {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for QuitConfirm {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        // Cancel quit (go back to start screen)
+        if input.back() || input.key(Key::Letter('N')) || input.button(PadButton::Select) {
+            game.transition_to_start_screen();
+        }
+
+        // Confirm quit - actually exit the application
+        if input.key(Key::Letter('Y')) || input.key(Key::Enter) || input.button(PadButton::A) {
+            game.add_audio_event(crate::game::AudioEvent::QuitGame);
+            std::process::exit(0);
+        }
+    }
+}
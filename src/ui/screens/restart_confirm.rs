@@ -0,0 +1,132 @@
+use crate::game::input::{InputSnapshot, Key, PadButton};
+use crate::game::{Game, RestartConfirm};
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{InstructionsConfig, ScreenConfig};
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl RestartConfirm {
+    fn render_content(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "RESTART RUN?",
+            300.0,
+            60.0,
+            2.5,
+            Color::WHITE,
+        );
+
+        let score_text = format!(
+            "Your current score of {} will be lost. A new {} run starts right away.",
+            game.score, game.difficulty
+        );
+        Self::draw_centered_text(d, font, &score_text, 380.0, Color::LIGHTGRAY);
+
+        let (confirm_text, cancel_text) = if has_controller {
+            ("Press A to Restart", "Press B to Cancel")
+        } else {
+            ("Press Y/ENTER to Restart", "Press N or ESC to Cancel")
+        };
+        Self::draw_centered_text(d, font, confirm_text, 430.0, InstructionsConfig::QUIT_COLOR);
+        Self::draw_centered_text(
+            d,
+            font,
+            cancel_text,
+            470.0,
+            InstructionsConfig::RESUME_COLOR,
+        );
+    }
+
+    fn draw_centered_text(d: &mut RaylibDrawHandle, font: &Font, text: &str, y: f32, color: Color) {
+        let text_width = d.measure_text(text, InstructionsConfig::QUIT_CONFIRM_SIZE as i32);
+        SharedRenderer::draw_text(
+            d,
+            font,
+            text,
+            ((ScreenConfig::WIDTH - text_width) / 2) as f32,
+            y,
+            InstructionsConfig::QUIT_CONFIRM_SIZE,
+            InstructionsConfig::QUIT_CONFIRM_SPACING,
+            color,
+        );
+    }
+}
+
+impl OverlayState for RestartConfirm {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        Self::render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_game_view
+    }
+}
+
+impl ScreenRenderer for RestartConfirm {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for RestartConfirm {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.back() || input.key(Key::Letter('N')) {
+            game.cancel_restart();
+            return;
+        }
+
+        if input.key(Key::Letter('Y')) || input.key(Key::Enter) || input.button(PadButton::A) {
+            game.restart_game();
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::game::input::{InputSnapshot, Key, PadButton};
+use crate::game::{Game, SeedEntry};
+use crate::models::RunSeed;
+use crate::models::{VirtualKey, VirtualKeyboard};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{ScreenConfig, SeedConfig, VirtualKeyboardConfig};
+use crate::ui::particle_system::ParticleSystem;
+use crate::ui::text_layout::TextLayout;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl SeedEntry {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "PLAY FROM SEED",
+            SeedConfig::TITLE_Y,
+            SeedConfig::TITLE_SIZE,
+            2.5,
+            Color::WHITE,
+        );
+        SharedRenderer::draw_centered_title(
+            d,
+            font,
+            "Enter the seed a friend shared to play the same deck",
+            SeedConfig::PROMPT_Y,
+            SeedConfig::PROMPT_SIZE,
+            1.0,
+            SeedConfig::PROMPT_COLOR,
+        );
+
+        let box_x = ScreenConfig::WIDTH / 2 - SeedConfig::BOX_WIDTH / 2;
+        SharedRenderer::draw_input_box(
+            d,
+            box_x,
+            SeedConfig::BOX_Y,
+            SeedConfig::BOX_WIDTH,
+            SeedConfig::BOX_HEIGHT,
+            Color::DARKGRAY,
+            Color::WHITE,
+        );
+        TextLayout::draw_centered(
+            d,
+            font,
+            &format!("{:_<width$}", self.code, width = RunSeed::LENGTH),
+            Rectangle::new(
+                box_x as f32,
+                SeedConfig::BOX_Y as f32,
+                SeedConfig::BOX_WIDTH as f32,
+                SeedConfig::BOX_HEIGHT as f32,
+            ),
+            SeedConfig::CODE_SIZE,
+            4.0,
+            Color::WHITE,
+        );
+
+        // The seed picks the cards; the difficulty and deck still come from settings
+        SharedRenderer::draw_centered_title(
+            d,
+            font,
+            &format!(
+                "{} difficulty, {} deck",
+                game.settings.difficulty,
+                game.settings.deck_template.name()
+            ),
+            SeedConfig::DIFFICULTY_Y,
+            SeedConfig::PROMPT_SIZE,
+            1.0,
+            SeedConfig::PROMPT_COLOR,
+        );
+
+        let (hint, y) = if has_controller {
+            DrawingHelpers::draw_virtual_keyboard(
+                d,
+                font,
+                &self.keyboard,
+                VirtualKeyboardConfig::Y,
+            );
+            (
+                "D-Pad: Choose key, A: Type, B: Backspace, Start: Play, Back: Cancel",
+                SeedConfig::HINT_CONTROLLER_Y,
+            )
+        } else {
+            (
+                "Type or paste (Ctrl+V) the seed, ENTER to play, ESC to go back",
+                SeedConfig::HINT_Y,
+            )
+        };
+        SharedRenderer::draw_centered_title(
+            d,
+            font,
+            hint,
+            y,
+            SeedConfig::HINT_SIZE,
+            1.0,
+            SeedConfig::HINT_COLOR,
+        );
+    }
+}
+
+impl OverlayState for SeedEntry {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        self.render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_start_screen
+    }
+}
+
+impl ScreenRenderer for SeedEntry {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+// Typed like initials on the game over screen, with paste for a code copied from a message
+impl StateInput for SeedEntry {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.shortcut(Key::Letter('V')) {
+            if let Some(text) = &input.pasted {
+                game.set_seed_code(text);
+            }
+        } else if let Some(c) = input.typed() {
+            game.add_seed_char(c);
+        }
+
+        if input.button(PadButton::DPadLeft) {
+            game.move_seed_keyboard(VirtualKeyboard::move_left);
+        }
+        if input.button(PadButton::DPadRight) {
+            game.move_seed_keyboard(VirtualKeyboard::move_right);
+        }
+        if input.button(PadButton::DPadUp) {
+            game.move_seed_keyboard(VirtualKeyboard::move_up);
+        }
+        if input.button(PadButton::DPadDown) {
+            game.move_seed_keyboard(VirtualKeyboard::move_down);
+        }
+
+        if input.button(PadButton::A)
+            && let Some(entry) = game.seed_entry()
+        {
+            match entry.keyboard.selected_key() {
+                VirtualKey::Letter(c) => game.add_seed_char(c),
+                VirtualKey::Backspace => game.remove_seed_char(),
+                VirtualKey::Done => {
+                    game.play_from_seed();
+                    return;
+                }
+            }
+        }
+
+        if input.key(Key::Backspace) || input.button(PadButton::B) {
+            game.remove_seed_char();
+        }
+
+        if (input.key(Key::Enter) || input.button(PadButton::Start)) && game.play_from_seed() {
+            return;
+        }
+
+        if input.escape() {
+            game.return_from_seed_entry();
+        }
+    }
+}
@@ -0,0 +1,560 @@
+use crate::game::input::InputSnapshot;
+use crate::game::{Game, Settings};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::SettingsConfig;
+use crate::ui::instruction_renderer::HintAction;
+use crate::ui::particle_system::ParticleSystem;
+use crate::ui::theme::Theme;
+use crate::ui::widgets::{MenuLayout, WidgetState, WidgetStyle, Widgets};
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl Settings {
+    fn render_content(
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        selected_option: usize,
+    ) {
+        // Draw settings title
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "SETTINGS",
+            120.0,
+            60.0,
+            2.5,
+            Color::WHITE,
+        );
+
+        // Draw settings panel background
+        let theme = Theme::of(game);
+        let panel_x = MenuLayout::settings_panel_x();
+        let panel_y = SettingsConfig::PANEL_Y;
+        let panel_width = SettingsConfig::PANEL_WIDTH;
+        let panel_height = SettingsConfig::PANEL_HEIGHT;
+
+        // Semi-transparent background for settings panel
+        d.draw_rectangle(
+            panel_x - 10,
+            panel_y - 10,
+            panel_width + 20,
+            panel_height + 20,
+            Color::new(0, 0, 0, 150),
+        );
+        theme.draw_panel(d, panel_x, panel_y, panel_width, panel_height);
+
+        let settings = &game.settings;
+        let layout = MenuLayout::settings();
+        let style = WidgetStyle::panel(game);
+        let state = |index: usize| WidgetState::focused_if(selected_option == index);
+        Widgets::focus_highlight(d, &layout, selected_option, &style);
+
+        // Volumes, dimmed while muted
+        let music_text = if settings.music_muted {
+            "Music: MUTED".to_string()
+        } else {
+            format!("Music: {}%", (settings.music_volume * 100.0) as i32)
+        };
+        Widgets::slider(
+            d,
+            font,
+            layout.row(0),
+            &music_text,
+            settings.music_volume,
+            state(0).dimmed(settings.music_muted),
+            &style,
+        );
+        let sfx_text = if settings.sound_effects_muted {
+            "Sound FX: MUTED".to_string()
+        } else {
+            format!(
+                "Sound FX: {}%",
+                (settings.sound_effects_volume * 100.0) as i32
+            )
+        };
+        Widgets::slider(
+            d,
+            font,
+            layout.row(1),
+            &sfx_text,
+            settings.sound_effects_volume,
+            state(1).dimmed(settings.sound_effects_muted),
+            &style,
+        );
+
+        Widgets::toggle(
+            d,
+            font,
+            layout.row(2),
+            "VSync",
+            settings.vsync_enabled,
+            state(2),
+            &style,
+        );
+        Widgets::toggle(
+            d,
+            font,
+            layout.row(6),
+            "Touch Controls",
+            settings.touch_controls,
+            state(6),
+            &style,
+        );
+
+        // Difficulty is locked while a game session is active
+        let difficulty_locked = game.game_session_active;
+        let difficulty_text = if difficulty_locked {
+            format!("Difficulty: {} (LOCKED)", settings.difficulty)
+        } else {
+            format!("Difficulty: {}", settings.difficulty)
+        };
+        Widgets::button(
+            d,
+            font,
+            layout.row(3),
+            &difficulty_text,
+            state(3).dimmed(difficulty_locked),
+            &style,
+        );
+
+        let device_name = settings.audio_device.as_deref().unwrap_or("System Default");
+        let layout_name = if settings.mirrored_layout {
+            "Mirrored"
+        } else {
+            "Standard"
+        };
+
+        // The rest cycle through values, or open a screen of their own
+        let rows = [
+            (4, format!("Paused Board: {}", settings.paused_board.name())),
+            (5, format!("Audio: {}", Self::fit_device_name(device_name))),
+            (7, format!("Profile: {}", settings.display_profile.name())),
+            (
+                8,
+                format!("Streamer Mode: {}", settings.streamer_mode.name()),
+            ),
+            (9, format!("Layout: {}", layout_name)),
+            (10, "Accessibility...".to_string()),
+            (11, "Appearance...".to_string()),
+            (12, "Diagnostics...".to_string()),
+        ];
+        for (index, text) in &rows {
+            Widgets::button(d, font, layout.row(*index), text, state(*index), &style);
+        }
+
+        // Instructions
+        Self::draw_settings_instructions(d, font, has_controller, panel_y + panel_height + 30);
+    }
+
+    // Device names can be long ("Speakers (Realtek High Definition Audio)"), so keep the start
+    fn fit_device_name(name: &str) -> String {
+        const MAX_CHARS: usize = 20;
+        if name.chars().count() <= MAX_CHARS {
+            return name.to_string();
+        }
+        let head: String = name.chars().take(MAX_CHARS - 3).collect();
+        format!("{}...", head)
+    }
+
+    fn draw_settings_instructions(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        has_controller: bool,
+        y: i32,
+    ) {
+        DrawingHelpers::draw_hints(
+            d,
+            font,
+            &[
+                HintAction::Navigate,
+                HintAction::Adjust,
+                HintAction::Toggle,
+                HintAction::Back,
+            ],
+            has_controller,
+            y as f32,
+        );
+    }
+}
+
+impl OverlayState for Settings {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        Self::render_content(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            game.settings.selected_option,
+        );
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        // This static method is not used since we override render_overlay
+        BackgroundRenderer::render_start_screen
+    }
+
+    /// Custom overlay rendering that chooses background based on previous state
+    fn render_overlay(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        // Choose background renderer based on previous state
+        let background_renderer = if self.is_over_run() {
+            BackgroundRenderer::render_game_view
+        } else {
+            BackgroundRenderer::render_start_screen
+        };
+
+        SharedRenderer::render_with_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+            background_renderer,
+            self.get_overlay_alpha(),
+            |d, game, has_controller, title_font, font| {
+                self.render_overlay_content(d, game, has_controller, title_font, font)
+            },
+        );
+    }
+}
+
+impl ScreenRenderer for Settings {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for Settings {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        // Music, SFX, VSync, Difficulty, Paused Board, Audio Device, Touch Controls, Display
+        // Profile, Streamer Mode, Layout, Accessibility, Appearance, Diagnostics
+        const TOTAL_OPTIONS: usize = SettingsConfig::OPTION_COUNT;
+
+        // Back to previous screen
+        if input.back() {
+            // Don't wait for the debounce when the player is done with settings
+            game.flush_settings();
+
+            game.return_from_settings();
+            return;
+        }
+
+        // Navigation (Up/Down, or the mouse)
+        let menu = MenuLayout::settings().read(input, TOTAL_OPTIONS);
+        let focus = menu.focus(game.settings.selected_option, TOTAL_OPTIONS);
+        if focus != game.settings.selected_option {
+            let moved_up = focus < game.settings.selected_option;
+            game.settings.selected_option = focus;
+            if !game.settings.sound_effects_muted {
+                game.add_audio_event(if moved_up {
+                    crate::game::AudioEvent::MoveLeft
+                } else {
+                    crate::game::AudioEvent::MoveRight
+                });
+            }
+        }
+
+        // Adjust values based on current selection (Left/Right)
+        let left_pressed = menu.adjust < 0;
+        let right_pressed = menu.adjust > 0;
+
+        match game.settings.selected_option {
+            0 => {
+                // Music Volume
+                if left_pressed {
+                    game.settings.music_volume = (game.settings.music_volume - 0.1).max(0.0);
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+                if right_pressed {
+                    game.settings.music_volume = (game.settings.music_volume + 0.1).min(1.0);
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+            }
+            1 => {
+                // Sound Effects Volume
+                if left_pressed {
+                    game.settings.sound_effects_volume =
+                        (game.settings.sound_effects_volume - 0.1).max(0.0);
+                    game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    game.request_settings_save();
+                }
+                if right_pressed {
+                    game.settings.sound_effects_volume =
+                        (game.settings.sound_effects_volume + 0.1).min(1.0);
+                    game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    game.request_settings_save();
+                }
+            }
+            2 => { // VSync - no left/right adjustment, only toggle
+                // VSync doesn't have adjustable values, only toggle
+            }
+            3 => {
+                // Difficulty - only allow changes when no game session is active
+                if (left_pressed || right_pressed) && !game.game_session_active {
+                    game.settings.difficulty = match game.settings.difficulty {
+                        crate::models::Difficulty::Easy => crate::models::Difficulty::Hard,
+                        crate::models::Difficulty::Hard => crate::models::Difficulty::Easy,
+                    };
+                    // Also update the main game difficulty for consistency
+                    game.difficulty = game.settings.difficulty;
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+            }
+            4 => {
+                // Paused board view
+                let step = if left_pressed {
+                    -1
+                } else if right_pressed {
+                    1
+                } else {
+                    0
+                };
+                if step != 0 {
+                    game.settings.paused_board = game.settings.paused_board.cycle(step);
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+            }
+            5 => {
+                // Audio output device
+                if left_pressed {
+                    game.cycle_audio_device(-1);
+                }
+                if right_pressed {
+                    game.cycle_audio_device(1);
+                }
+            }
+            7 => {
+                // Display profile preset
+                let step = if left_pressed {
+                    -1
+                } else if right_pressed {
+                    1
+                } else {
+                    0
+                };
+                if step != 0 {
+                    let profile = game.settings.display_profile.cycle(step);
+                    game.settings.apply_display_profile(profile);
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+            }
+            8 => {
+                // Streamer mode
+                let step = if left_pressed {
+                    -1
+                } else if right_pressed {
+                    1
+                } else {
+                    0
+                };
+                if step != 0 {
+                    game.settings.streamer_mode = game.settings.streamer_mode.cycle(step);
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+            }
+            9 => {
+                // Layout - only two sides, so either direction swaps them
+                if left_pressed || right_pressed {
+                    game.settings.mirrored_layout = !game.settings.mirrored_layout;
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+            }
+            _ => {}
+        }
+
+        // Toggle actions (Space/A button, or a click)
+        if menu.activate {
+            match game.settings.selected_option {
+                0 => {
+                    // Music Mute Toggle
+                    game.settings.music_muted = !game.settings.music_muted;
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::PauseGame);
+                    }
+                    game.request_settings_save();
+                }
+                1 => {
+                    // Sound Effects Mute Toggle
+                    let was_muted = game.settings.sound_effects_muted;
+                    game.settings.sound_effects_muted = !game.settings.sound_effects_muted;
+                    if was_muted && !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::ResumeGame);
+                    }
+                    game.request_settings_save();
+                }
+                2 => {
+                    // VSync Toggle
+                    game.settings.vsync_enabled = !game.settings.vsync_enabled;
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::StartGame);
+                    }
+                    game.request_settings_save();
+                }
+                3 => {
+                    // Difficulty Toggle (same as left/right) - only when no game session is active
+                    if !game.game_session_active {
+                        game.settings.difficulty = match game.settings.difficulty {
+                            crate::models::Difficulty::Easy => crate::models::Difficulty::Hard,
+                            crate::models::Difficulty::Hard => crate::models::Difficulty::Easy,
+                        };
+                        // Also update the main game difficulty for consistency
+                        game.difficulty = game.settings.difficulty;
+                        if !game.settings.sound_effects_muted {
+                            game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                        }
+                        game.request_settings_save();
+                    }
+                }
+                4 => {
+                    // Paused board view (same as right)
+                    game.settings.paused_board = game.settings.paused_board.cycle(1);
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+                5 => {
+                    // Audio output device (same as right)
+                    game.cycle_audio_device(1);
+                }
+                6 => {
+                    // Touch Controls Toggle
+                    game.settings.touch_controls = !game.settings.touch_controls;
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::StartGame);
+                    }
+                    game.request_settings_save();
+                }
+                7 => {
+                    // Display profile preset (same as right)
+                    let profile = game.settings.display_profile.cycle(1);
+                    game.settings.apply_display_profile(profile);
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+                8 => {
+                    // Streamer mode (same as right)
+                    game.settings.streamer_mode = game.settings.streamer_mode.cycle(1);
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+                9 => {
+                    // Layout Toggle (same as left/right)
+                    game.settings.mirrored_layout = !game.settings.mirrored_layout;
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::DifficultyChange);
+                    }
+                    game.request_settings_save();
+                }
+                10 => {
+                    // Open the accessibility screen
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::StartGame);
+                    }
+                    game.transition_to_accessibility();
+                }
+                11 => {
+                    // Open the appearance screen
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::StartGame);
+                    }
+                    game.transition_to_appearance();
+                }
+                12 => {
+                    // Open the diagnostics screen
+                    if !game.settings.sound_effects_muted {
+                        game.add_audio_event(crate::game::AudioEvent::StartGame);
+                    }
+                    game.transition_to_diagnostics();
+                }
+                _ => {}
+            }
+        }
+    }
+}
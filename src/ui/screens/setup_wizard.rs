@@ -0,0 +1,240 @@
+use crate::game::input::InputSnapshot;
+use crate::game::{Game, SetupStep, SetupWizard};
+use crate::models::ControlScheme;
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{ScreenConfig, SetupWizardConfig};
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::SharedRenderer;
+
+impl SetupWizard {
+    // One row per value the current step lets the player change
+    fn rows(&self, game: &Game) -> Vec<String> {
+        let settings = &game.settings;
+        match self.step {
+            SetupStep::Controls => vec![format!("< {} >", settings.control_scheme.name())],
+            SetupStep::Volume => vec![
+                format!("Music: {}%", (settings.music_volume * 100.0).round() as i32),
+                format!(
+                    "Sound FX: {}%",
+                    (settings.sound_effects_volume * 100.0).round() as i32
+                ),
+            ],
+            SetupStep::Difficulty => vec![format!("< {} >", settings.difficulty)],
+        }
+    }
+
+    fn hint(&self, game: &Game) -> &'static str {
+        match self.step {
+            SetupStep::Controls => match game.settings.control_scheme {
+                ControlScheme::Auto => "Show gamepad buttons whenever a controller is connected",
+                ControlScheme::Keyboard => "Always show keyboard keys",
+                ControlScheme::Controller => "Always show gamepad buttons",
+            },
+            SetupStep::Volume => "You can change these any time in Settings",
+            SetupStep::Difficulty => "On Hard, cards must share a suit to make 21",
+        }
+    }
+
+    fn draw_rows(&self, d: &mut RaylibDrawHandle, font: &Font, game: &Game) {
+        let panel_x = (ScreenConfig::WIDTH - SetupWizardConfig::PANEL_WIDTH) / 2;
+        let rows = self.rows(game);
+        let has_multiple_rows = rows.len() > 1;
+
+        for (index, text) in rows.iter().enumerate() {
+            let row_y = SetupWizardConfig::ROW_Y + index as i32 * SetupWizardConfig::ROW_SPACING;
+            let selected = !has_multiple_rows || index == self.volume_row;
+
+            if selected {
+                d.draw_rectangle(
+                    panel_x + 10,
+                    row_y - 8,
+                    SetupWizardConfig::PANEL_WIDTH - 20,
+                    SetupWizardConfig::ROW_HEIGHT,
+                    SetupWizardConfig::SELECTED_COLOR,
+                );
+            }
+
+            let text_color = if selected {
+                Color::YELLOW
+            } else {
+                Color::WHITE
+            };
+            SharedRenderer::draw_text(
+                d,
+                font,
+                text,
+                (panel_x + 30) as f32,
+                row_y as f32,
+                SetupWizardConfig::VALUE_SIZE,
+                1.2,
+                text_color,
+            );
+
+            // Volume rows get a slider on the right
+            if self.step == SetupStep::Volume {
+                let volume = if index == 0 {
+                    game.settings.music_volume
+                } else {
+                    game.settings.sound_effects_volume
+                };
+                let slider_x =
+                    panel_x + SetupWizardConfig::PANEL_WIDTH - SetupWizardConfig::SLIDER_WIDTH - 30;
+                let slider_y = row_y + 12;
+                d.draw_rectangle(
+                    slider_x,
+                    slider_y,
+                    SetupWizardConfig::SLIDER_WIDTH,
+                    SetupWizardConfig::SLIDER_HEIGHT,
+                    Color::DARKGRAY,
+                );
+                d.draw_rectangle(
+                    slider_x,
+                    slider_y,
+                    (SetupWizardConfig::SLIDER_WIDTH as f32 * volume) as i32,
+                    SetupWizardConfig::SLIDER_HEIGHT,
+                    Color::GREEN,
+                );
+                d.draw_rectangle_lines(
+                    slider_x,
+                    slider_y,
+                    SetupWizardConfig::SLIDER_WIDTH,
+                    SetupWizardConfig::SLIDER_HEIGHT,
+                    Color::WHITE,
+                );
+            }
+        }
+    }
+
+    fn draw_centered(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        text: &str,
+        y: i32,
+        size: f32,
+        color: Color,
+    ) {
+        let text_width = d.measure_text(text, size as i32);
+        SharedRenderer::draw_text(
+            d,
+            font,
+            text,
+            ((ScreenConfig::WIDTH - text_width) / 2) as f32,
+            y as f32,
+            size,
+            1.0,
+            color,
+        );
+    }
+}
+
+impl ScreenRenderer for SetupWizard {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        _particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        _board_snapshot: &BoardSnapshot,
+    ) {
+        // Same backdrop as the start screen, dimmed so the wizard reads clearly
+        DrawingHelpers::draw_gradient_background(d);
+        animated_background.draw(d, card_atlas);
+        SharedRenderer::draw_overlay(d, SetupWizardConfig::OVERLAY_ALPHA);
+
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "WELCOME TO DROPJACK",
+            SetupWizardConfig::TITLE_Y,
+            SetupWizardConfig::TITLE_SIZE,
+            2.5,
+            Color::WHITE,
+        );
+
+        let step_text = format!(
+            "Step {} of {}: {}",
+            self.step.index() + 1,
+            SetupStep::ALL.len(),
+            self.step.title()
+        );
+        Self::draw_centered(
+            d,
+            font,
+            &step_text,
+            SetupWizardConfig::STEP_Y as i32,
+            SetupWizardConfig::STEP_SIZE,
+            SetupWizardConfig::STEP_COLOR,
+        );
+
+        let panel_x = (ScreenConfig::WIDTH - SetupWizardConfig::PANEL_WIDTH) / 2;
+        SharedRenderer::draw_input_box(
+            d,
+            panel_x,
+            SetupWizardConfig::PANEL_Y,
+            SetupWizardConfig::PANEL_WIDTH,
+            SetupWizardConfig::PANEL_HEIGHT,
+            SetupWizardConfig::PANEL_COLOR,
+            Color::WHITE,
+        );
+        self.draw_rows(d, font, game);
+
+        Self::draw_centered(
+            d,
+            font,
+            self.hint(game),
+            SetupWizardConfig::HINT_Y,
+            SetupWizardConfig::HINT_SIZE,
+            SetupWizardConfig::HINT_COLOR,
+        );
+
+        let is_last_step = self.step.next().is_none();
+        let instructions = match (has_controller, is_last_step) {
+            (true, false) => "D-Pad: Change  |  A: Next  |  B: Back",
+            (true, true) => "D-Pad: Change  |  A: Finish  |  B: Back",
+            (false, false) => "Arrows: Change  |  Enter: Next  |  ESC: Back",
+            (false, true) => "Arrows: Change  |  Enter: Finish  |  ESC: Back",
+        };
+        Self::draw_centered(
+            d,
+            font,
+            instructions,
+            SetupWizardConfig::INSTRUCTIONS_Y,
+            SetupWizardConfig::HINT_SIZE,
+            Color::LIGHTGRAY,
+        );
+    }
+}
+
+impl StateInput for SetupWizard {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.up {
+            game.setup_wizard_select_row(-1);
+        }
+        if input.down {
+            game.setup_wizard_select_row(1);
+        }
+
+        if input.left() {
+            game.setup_wizard_adjust(-1);
+        }
+        if input.right() {
+            game.setup_wizard_adjust(1);
+        }
+
+        if input.action() {
+            game.setup_wizard_next();
+        } else if input.back() {
+            game.setup_wizard_back();
+        }
+    }
+}
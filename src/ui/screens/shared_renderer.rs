@@ -1,10 +1,15 @@
 use crate::game::Game;
+use crate::models::PausedBoard;
 use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
 use crate::ui::config::ScreenConfig;
 use crate::ui::particle_system::ParticleSystem;
+use crate::ui::sdf_text::SdfText;
+use crate::ui::text_layout::TextLayout;
 use raylib::prelude::*;
 
-use super::game_state::GameState;
+use super::ScreenRenderer;
+use super::playing::BoardView;
 
 // Shared rendering functionality
 pub struct SharedRenderer;
@@ -31,12 +36,7 @@ impl SharedRenderer {
         spacing: f32,
         color: Color,
     ) {
-        // Manual centering based on approximate character width
-        let approx_char_width = size * 0.5; // Approximation for most fonts
-        let text_width = text.len() as f32 * approx_char_width;
-        let x = (ScreenConfig::WIDTH as f32 - text_width) / 2.0;
-
-        d.draw_text_ex(font, text, Vector2::new(x, y), size, spacing, color);
+        TextLayout::draw_centered_line(d, font, text, y, size, spacing, color);
     }
 
     /// Draw text with consistent positioning (not centered)
@@ -50,7 +50,7 @@ impl SharedRenderer {
         spacing: f32,
         color: Color,
     ) {
-        d.draw_text_ex(font, text, Vector2::new(x, y), size, spacing, color);
+        SdfText::draw(d, font, text, Vector2::new(x, y), size, spacing, color);
     }
 
     /// Draw a styled input box for text entry
@@ -77,6 +77,7 @@ impl SharedRenderer {
         card_atlas: &Texture2D,
         particle_system: &mut ParticleSystem,
         animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
         render_background: F,
         overlay_alpha: u8,
         render_content: C,
@@ -90,6 +91,7 @@ impl SharedRenderer {
             &Texture2D,
             &mut ParticleSystem,
             &mut AnimatedBackground,
+            &BoardSnapshot,
         ),
         C: FnOnce(&mut RaylibDrawHandle, &Game, bool, &Font, &Font),
     {
@@ -103,6 +105,7 @@ impl SharedRenderer {
             card_atlas,
             particle_system,
             animated_background,
+            board_snapshot,
         );
 
         // Draw overlay
@@ -126,8 +129,14 @@ impl BackgroundRenderer {
         card_atlas: &Texture2D,
         particle_system: &mut ParticleSystem,
         _animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
     ) {
-        use super::playing::Playing;
+        use crate::game::Playing;
+        let board_view = match game.settings.paused_board {
+            PausedBoard::Hidden => BoardView::Hidden,
+            PausedBoard::Blurred => BoardView::Snapshot(board_snapshot),
+            PausedBoard::Shown => BoardView::Live,
+        };
         Playing::draw_game_view(
             d,
             game,
@@ -136,7 +145,7 @@ impl BackgroundRenderer {
             font,
             card_atlas,
             particle_system,
-            false,
+            board_view,
         );
     }
 
@@ -149,8 +158,9 @@ impl BackgroundRenderer {
         card_atlas: &Texture2D,
         particle_system: &mut ParticleSystem,
         animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
     ) {
-        use super::start_screen::StartScreen;
+        use crate::game::StartScreen;
         let start_screen = StartScreen;
         start_screen.render(
             d,
@@ -161,6 +171,7 @@ impl BackgroundRenderer {
             card_atlas,
             particle_system,
             animated_background,
+            board_snapshot,
         );
     }
 }
@@ -187,6 +198,7 @@ pub trait OverlayState {
         &Texture2D,
         &mut ParticleSystem,
         &mut AnimatedBackground,
+        &BoardSnapshot,
     );
 
     /// Get the overlay alpha value (default 200)
@@ -205,6 +217,7 @@ pub trait OverlayState {
         card_atlas: &Texture2D,
         particle_system: &mut ParticleSystem,
         animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
     ) {
         SharedRenderer::render_with_overlay(
             d,
@@ -215,6 +228,7 @@ pub trait OverlayState {
             card_atlas,
             particle_system,
             animated_background,
+            board_snapshot,
             Self::get_background_renderer(),
             self.get_overlay_alpha(),
             |d, game, has_controller, title_font, font| {
@@ -0,0 +1,86 @@
+use crate::game::input::{InputSnapshot, Key, PadButton};
+use crate::game::{AudioEvent, Game, StartScreen};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::particle_system::ParticleSystem;
+use crate::ui::widgets::MenuLayout;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+
+impl ScreenRenderer for StartScreen {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        _particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        _board_snapshot: &BoardSnapshot,
+    ) {
+        // Draw a sophisticated gradient background
+        DrawingHelpers::draw_gradient_background(d);
+
+        // Draw animated background cards instead of static ones
+        animated_background.draw(d, card_atlas);
+
+        // Main title with shadow effect
+        DrawingHelpers::draw_title_with_shadow(d, title_font);
+
+        // Subtitle with elegant styling
+        DrawingHelpers::draw_subtitle(d, font);
+
+        // Main menu, with Continue while a saved run is waiting
+        DrawingHelpers::draw_main_menu(d, font, game, has_controller);
+
+        // High scores in two columns (Easy/Hard)
+        DrawingHelpers::draw_high_scores_panel(d, title_font, font, game);
+
+        DrawingHelpers::draw_build_info(d, font);
+    }
+}
+
+impl StateInput for StartScreen {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        // Handle navigation in main menu
+        let model = game.main_menu();
+        let menu = MenuLayout::main_menu()
+            .packed(model.len())
+            .read(input, model.len());
+        let step = menu.focus_step(model.focused(game.selected_main_option), model.len());
+        if step != 0 {
+            game.select_main_option(step);
+            game.add_audio_event(AudioEvent::DifficultyChange);
+        }
+
+        // Handle selection
+        if menu.activate {
+            game.choose_main_option();
+            return;
+        }
+
+        // Switch the leaderboard between today, this week and all time
+        if input.key(Key::Tab) || input.button(PadButton::RightShoulder) {
+            game.cycle_leaderboard_period(1);
+        }
+        if input.button(PadButton::LeftShoulder) {
+            game.cycle_leaderboard_period(-1);
+        }
+
+        // Pick up a run left with Save & Quit
+        if input.key(Key::Letter('C')) || input.button(PadButton::Y) {
+            game.continue_saved_run();
+            return;
+        }
+
+        // Handle quit confirmation directly with ESC
+        if input.escape() {
+            game.transition_to_quit_confirm();
+        }
+    }
+}
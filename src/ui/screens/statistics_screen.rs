@@ -0,0 +1,269 @@
+use crate::game::input::{InputSnapshot, Key, PadButton};
+use crate::game::{Game, StatisticsScreen};
+use crate::ui::DrawingHelpers;
+use crate::ui::animated_background::AnimatedBackground;
+use crate::ui::board_snapshot::BoardSnapshot;
+use crate::ui::config::{ScreenConfig, StatisticsConfig};
+use crate::ui::instruction_renderer::HintAction;
+use crate::ui::particle_system::ParticleSystem;
+use raylib::prelude::*;
+
+use super::ScreenRenderer;
+use super::StateInput;
+use super::shared_renderer::{BackgroundRenderer, OverlayState, SharedRenderer};
+
+impl StatisticsScreen {
+    fn render_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        SharedRenderer::draw_centered_title(
+            d,
+            title_font,
+            "STATISTICS",
+            StatisticsConfig::TITLE_Y,
+            StatisticsConfig::TITLE_SIZE,
+            2.5,
+            Color::WHITE,
+        );
+
+        let statistics = &game.statistics;
+        let counters = format!(
+            "Finished: {}   Saved: {}   Abandoned: {}   Cards dropped: {}",
+            statistics.runs_finished,
+            statistics.runs_saved,
+            statistics.runs_abandoned,
+            statistics.total_drops()
+        );
+        Self::draw_centered_text(
+            d,
+            font,
+            &counters,
+            StatisticsConfig::COUNTERS_Y,
+            StatisticsConfig::TEXT_SIZE,
+            Color::WHITE,
+        );
+
+        self.draw_column_chart(d, game, font);
+
+        DrawingHelpers::draw_hints(
+            d,
+            font,
+            &[
+                HintAction::ToggleHeatmap,
+                HintAction::ExportStatistics,
+                HintAction::Back,
+            ],
+            has_controller,
+            StatisticsConfig::INSTRUCTIONS_Y as f32,
+        );
+    }
+
+    // A board-shaped chart with one column per board column: bars by default, or each
+    // column tinted from cold to hot with the heatmap on
+    fn draw_column_chart(&self, d: &mut RaylibDrawHandle, game: &Game, font: &Font) {
+        let statistics = &game.statistics;
+        let columns = (game.board.width.max(0) as usize).max(statistics.column_drops.len());
+        if columns == 0 {
+            return;
+        }
+
+        let column_width = StatisticsConfig::CHART_WIDTH / columns as i32;
+        let chart_width = column_width * columns as i32;
+        let chart_x = (ScreenConfig::WIDTH - chart_width) / 2;
+        let chart_y = StatisticsConfig::CHART_Y;
+        let chart_height = StatisticsConfig::CHART_HEIGHT;
+        d.draw_rectangle(
+            chart_x,
+            chart_y,
+            chart_width,
+            chart_height,
+            StatisticsConfig::CHART_BG,
+        );
+
+        let max_share = (0..columns)
+            .map(|column| statistics.column_share(column))
+            .fold(0.0, f32::max);
+
+        for column in 0..columns {
+            let share = statistics.column_share(column);
+            let x = chart_x + column as i32 * column_width;
+
+            if self.show_heatmap {
+                if max_share > 0.0 {
+                    d.draw_rectangle(
+                        x,
+                        chart_y,
+                        column_width,
+                        chart_height,
+                        heat_color(share / max_share),
+                    );
+                }
+            } else {
+                let bar_height = (chart_height as f32 * share) as i32;
+                d.draw_rectangle(
+                    x + StatisticsConfig::BAR_INSET,
+                    chart_y + chart_height - bar_height,
+                    column_width - StatisticsConfig::BAR_INSET * 2,
+                    bar_height,
+                    StatisticsConfig::BAR_COLOR,
+                );
+            }
+
+            // Board rows, so the chart reads as the board seen from the front
+            for row in 1..game.board.height.max(1) {
+                let row_y = chart_y + chart_height * row / game.board.height;
+                d.draw_line(
+                    x,
+                    row_y,
+                    x + column_width,
+                    row_y,
+                    StatisticsConfig::GRID_COLOR,
+                );
+            }
+            d.draw_rectangle_lines(
+                x,
+                chart_y,
+                column_width,
+                chart_height,
+                StatisticsConfig::GRID_COLOR,
+            );
+
+            let count = statistics.column_drops.get(column).copied().unwrap_or(0);
+            let labels = [
+                count.to_string(),
+                format!("{}%", (share * 100.0).round() as i32),
+            ];
+            for (line, label) in labels.iter().enumerate() {
+                let label_width = d.measure_text(label, StatisticsConfig::LABEL_SIZE as i32);
+                SharedRenderer::draw_text(
+                    d,
+                    font,
+                    label,
+                    (x + (column_width - label_width) / 2) as f32,
+                    (chart_y
+                        + chart_height
+                        + StatisticsConfig::LABEL_GAP
+                        + line as i32 * StatisticsConfig::LABEL_LINE_HEIGHT)
+                        as f32,
+                    StatisticsConfig::LABEL_SIZE,
+                    1.0,
+                    Color::LIGHTGRAY,
+                );
+            }
+        }
+    }
+
+    fn draw_centered_text(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        text: &str,
+        y: i32,
+        size: f32,
+        color: Color,
+    ) {
+        let text_width = d.measure_text(text, size as i32);
+        SharedRenderer::draw_text(
+            d,
+            font,
+            text,
+            ((ScreenConfig::WIDTH - text_width) / 2) as f32,
+            y as f32,
+            size,
+            1.0,
+            color,
+        );
+    }
+}
+
+// Cold for the least used columns through to hot for the most used; `heat` is 0.0 to 1.0
+fn heat_color(heat: f32) -> Color {
+    let heat = heat.clamp(0.0, 1.0);
+    let cold = StatisticsConfig::HEAT_COLD;
+    let hot = StatisticsConfig::HEAT_HOT;
+    let mix = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * heat).round() as u8;
+    Color::new(
+        mix(cold.r, hot.r),
+        mix(cold.g, hot.g),
+        mix(cold.b, hot.b),
+        mix(cold.a, hot.a),
+    )
+}
+
+impl OverlayState for StatisticsScreen {
+    fn render_overlay_content(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+    ) {
+        self.render_content(d, game, has_controller, title_font, font);
+    }
+
+    fn get_background_renderer() -> fn(
+        &mut RaylibDrawHandle,
+        &Game,
+        bool,
+        &Font,
+        &Font,
+        &Texture2D,
+        &mut ParticleSystem,
+        &mut AnimatedBackground,
+        &BoardSnapshot,
+    ) {
+        BackgroundRenderer::render_start_screen
+    }
+
+    fn get_overlay_alpha(&self) -> u8 {
+        StatisticsConfig::OVERLAY_ALPHA
+    }
+}
+
+impl ScreenRenderer for StatisticsScreen {
+    fn render(
+        &self,
+        d: &mut RaylibDrawHandle,
+        game: &Game,
+        has_controller: bool,
+        title_font: &Font,
+        font: &Font,
+        card_atlas: &Texture2D,
+        particle_system: &mut ParticleSystem,
+        animated_background: &mut AnimatedBackground,
+        board_snapshot: &BoardSnapshot,
+    ) {
+        self.render_overlay(
+            d,
+            game,
+            has_controller,
+            title_font,
+            font,
+            card_atlas,
+            particle_system,
+            animated_background,
+            board_snapshot,
+        );
+    }
+}
+
+impl StateInput for StatisticsScreen {
+    fn handle_input(game: &mut Game, input: &InputSnapshot) {
+        if input.key(Key::Letter('H')) || input.button(PadButton::Y) {
+            game.toggle_heatmap();
+        }
+
+        if input.key(Key::Letter('E')) || input.button(PadButton::X) {
+            game.export_statistics();
+        }
+
+        if input.escape() || input.back() {
+            game.return_from_statistics_screen();
+        }
+    }
+}
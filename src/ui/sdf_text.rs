@@ -0,0 +1,165 @@
+// Fonts load once per file as signed distance fields: each glyph pixel stores how far it is
+// from the outline instead of how much of it is covered, so one atlas draws crisply at any
+// size. Distance field atlases only look right through the SDF shader, so text goes
+// through `SdfText::draw` instead of raylib's draw_text_ex. When the shader can't be
+// compiled, fonts load as plain bitmap atlases and draw without it.
+
+use raylib::ffi;
+use raylib::prelude::*;
+use std::cell::RefCell;
+use std::ffi::CString;
+
+pub const SDF_BASE_SIZE: i32 = 64; // Glyph size in the atlas; draws scale from this
+const SDF_PADDING: i32 = 4; // Room around each glyph for the distance falloff
+const GLYPH_COUNT: i32 = 95; // Printable ASCII, raylib's default set
+
+// Smooths the outline over about one screen pixel, whatever the size the text is drawn at
+#[cfg(not(target_os = "emscripten"))]
+const SDF_FRAGMENT_SHADER: &str = r#"#version 330
+in vec2 fragTexCoord;
+in vec4 fragColor;
+uniform sampler2D texture0;
+out vec4 finalColor;
+
+void main()
+{
+    float distanceFromOutline = texture(texture0, fragTexCoord).a - 0.5;
+    float distanceChangePerFragment = length(vec2(dFdx(distanceFromOutline), dFdy(distanceFromOutline)));
+    float alpha = smoothstep(-distanceChangePerFragment, distanceChangePerFragment, distanceFromOutline);
+    finalColor = vec4(fragColor.rgb, fragColor.a*alpha);
+}
+"#;
+
+#[cfg(target_os = "emscripten")]
+const SDF_FRAGMENT_SHADER: &str = r#"#version 100
+#extension GL_OES_standard_derivatives : enable
+precision mediump float;
+varying vec2 fragTexCoord;
+varying vec4 fragColor;
+uniform sampler2D texture0;
+
+void main()
+{
+    float distanceFromOutline = texture2D(texture0, fragTexCoord).a - 0.5;
+    float distanceChangePerFragment = length(vec2(dFdx(distanceFromOutline), dFdy(distanceFromOutline)));
+    float alpha = smoothstep(-distanceChangePerFragment, distanceChangePerFragment, distanceFromOutline);
+    gl_FragColor = vec4(fragColor.rgb, fragColor.a*alpha);
+}
+"#;
+
+#[derive(Default)]
+struct SdfState {
+    shader: Option<ffi::Shader>,
+    atlases: Vec<u32>, // Texture ids of the fonts loaded as distance fields
+}
+
+thread_local! {
+    // Raylib draws from the main thread only, so that's where the shader lives
+    static STATE: RefCell<SdfState> = RefCell::new(SdfState::default());
+}
+
+pub struct SdfText;
+
+impl SdfText {
+    /// Compile the SDF shader; needs the window to be open. Returns whether distance field
+    /// fonts can be used.
+    pub fn init_shader() -> bool {
+        let source =
+            CString::new(SDF_FRAGMENT_SHADER).expect("Shader source has no interior nul bytes");
+        let shader = unsafe { ffi::LoadShaderFromMemory(std::ptr::null(), source.as_ptr()) };
+        let valid = unsafe { ffi::IsShaderValid(shader) };
+        if !valid {
+            eprintln!("Warning: Could not compile the SDF text shader, using bitmap fonts");
+        }
+        STATE.with(|state| state.borrow_mut().shader = valid.then_some(shader));
+        valid
+    }
+
+    pub fn is_available() -> bool {
+        STATE.with(|state| state.borrow().shader.is_some())
+    }
+
+    /// Build a distance field font from a font file already read into memory
+    pub fn load_font(data: &[u8]) -> Option<Font> {
+        if !Self::is_available() {
+            return None;
+        }
+
+        let glyphs = unsafe {
+            ffi::LoadFontData(
+                data.as_ptr(),
+                data.len() as i32,
+                SDF_BASE_SIZE,
+                std::ptr::null_mut(),
+                0,
+                ffi::FontType::FONT_SDF as i32,
+            )
+        };
+        if glyphs.is_null() {
+            return None;
+        }
+
+        let mut recs = std::ptr::null_mut();
+        let atlas = unsafe {
+            ffi::GenImageFontAtlas(
+                glyphs,
+                &mut recs,
+                GLYPH_COUNT,
+                SDF_BASE_SIZE,
+                SDF_PADDING,
+                0,
+            )
+        };
+        let texture = unsafe { ffi::LoadTextureFromImage(atlas) };
+        unsafe { ffi::UnloadImage(atlas) };
+        if texture.id == 0 {
+            unsafe { ffi::UnloadFontData(glyphs, GLYPH_COUNT) };
+            return None;
+        }
+
+        // Distance fields need smooth sampling between texels to find the outline
+        unsafe {
+            ffi::SetTextureFilter(texture, ffi::TextureFilter::TEXTURE_FILTER_BILINEAR as i32)
+        };
+        STATE.with(|state| state.borrow_mut().atlases.push(texture.id));
+
+        // The font owns the glyphs, rectangles and texture from here and frees them on drop
+        Some(unsafe {
+            Font::from_raw(ffi::Font {
+                baseSize: SDF_BASE_SIZE,
+                glyphCount: GLYPH_COUNT,
+                glyphPadding: SDF_PADDING,
+                texture,
+                recs,
+                glyphs,
+            })
+        })
+    }
+
+    /// draw_text_ex, through the SDF shader when the font is a distance field
+    pub fn draw<D: RaylibDraw>(
+        d: &mut D,
+        font: &Font,
+        text: &str,
+        position: impl Into<ffi::Vector2>,
+        size: f32,
+        spacing: f32,
+        color: impl Into<ffi::Color>,
+    ) {
+        let shader = STATE.with(|state| {
+            let state = state.borrow();
+            state
+                .shader
+                .filter(|_| state.atlases.contains(&font.texture().id))
+        });
+
+        match shader {
+            Some(shader) => {
+                unsafe { ffi::BeginShaderMode(shader) };
+                d.draw_text_ex(font, text, position, size, spacing, color);
+                unsafe { ffi::EndShaderMode() };
+            }
+            None => d.draw_text_ex(font, text, position, size, spacing, color),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::ui::config::{AnimationConfig, BoardConfig, HalloweenConfig, ScreenConfig};
+use raylib::prelude::*;
+
+struct Pumpkin {
+    position: Vector2,
+    size: f32,
+    bob_phase: f32, // Offsets each pumpkin's bobbing
+}
+
+pub struct PumpkinPatch {
+    pumpkins: Vec<Pumpkin>,
+    time: f32,
+}
+
+impl PumpkinPatch {
+    pub fn new() -> Self {
+        let pumpkins = (0..HalloweenConfig::PUMPKIN_COUNT)
+            .map(|_| {
+                let size = HalloweenConfig::PUMPKIN_SIZE * (0.7 + rand::random::<f32>() * 0.6);
+                Pumpkin {
+                    position: Vector2::new(
+                        size + rand::random::<f32>() * (ScreenConfig::WIDTH as f32 - size * 2.0),
+                        size + rand::random::<f32>() * (ScreenConfig::HEIGHT as f32 - size * 2.0),
+                    ),
+                    size,
+                    bob_phase: rand::random::<f32>() * std::f32::consts::TAU,
+                }
+            })
+            .collect();
+
+        Self {
+            pumpkins,
+            time: 0.0,
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.time += delta_time;
+    }
+
+    pub fn draw(&self, d: &mut RaylibDrawHandle) {
+        for pumpkin in &self.pumpkins {
+            let bob = (self.time * HalloweenConfig::BOB_SPEED + pumpkin.bob_phase).sin()
+                * HalloweenConfig::BOB_DISTANCE;
+            draw_pumpkin(
+                d,
+                Vector2::new(pumpkin.position.x, pumpkin.position.y + bob),
+                pumpkin.size,
+                AnimationConfig::ALPHA * 2,
+            );
+        }
+    }
+}
+
+/// Pumpkins sitting on the two bottom corners of the board frame
+pub fn draw_frame_pumpkins(d: &mut RaylibDrawHandle, x: i32, y: i32, width: i32, height: i32) {
+    let size = HalloweenConfig::FRAME_PUMPKIN_SIZE;
+    let bottom = (y + height + BoardConfig::OUTER_FRAME_OFFSET) as f32 - size * 0.35;
+    let left = (x - BoardConfig::OUTER_FRAME_OFFSET) as f32;
+    let right = (x + width + BoardConfig::OUTER_FRAME_OFFSET) as f32;
+    draw_pumpkin(d, Vector2::new(left, bottom), size, 255);
+    draw_pumpkin(d, Vector2::new(right, bottom), size * 0.8, 255);
+}
+
+// A pumpkin centred on `center`, `size` pixels wide
+fn draw_pumpkin(d: &mut RaylibDrawHandle, center: Vector2, size: f32, alpha: u8) {
+    let with_alpha = |color: Color| Color::new(color.r, color.g, color.b, alpha);
+    let half_width = size / 2.0;
+    let half_height = size * 0.4;
+
+    d.draw_rectangle_v(
+        Vector2::new(center.x - size * 0.06, center.y - half_height - size * 0.18),
+        Vector2::new(size * 0.12, size * 0.22),
+        with_alpha(HalloweenConfig::STEM_COLOR),
+    );
+    // Three overlapping lobes, the middle one in front
+    for offset in [-0.45, 0.45] {
+        d.draw_ellipse(
+            (center.x + half_width * offset) as i32,
+            center.y as i32,
+            half_width * 0.55,
+            half_height,
+            with_alpha(HalloweenConfig::RIB_COLOR),
+        );
+    }
+    d.draw_ellipse(
+        center.x as i32,
+        center.y as i32,
+        half_width * 0.6,
+        half_height,
+        with_alpha(HalloweenConfig::PUMPKIN_COLOR),
+    );
+}
@@ -0,0 +1,65 @@
+// Seasonal decorations, one module per season. Something drifts among the cards behind the
+// menus, and the board frame gets a touch of the season during play.
+
+mod halloween;
+mod winter;
+
+use crate::models::Season;
+use raylib::prelude::*;
+
+use self::halloween::PumpkinPatch;
+use self::winter::Snowfall;
+
+pub struct SeasonalDecorations {
+    season: Option<Season>,
+    snowfall: Snowfall,
+    pumpkins: PumpkinPatch,
+}
+
+impl SeasonalDecorations {
+    pub fn new() -> Self {
+        Self {
+            season: None,
+            snowfall: Snowfall::new(),
+            pumpkins: PumpkinPatch::new(),
+        }
+    }
+
+    pub fn set_season(&mut self, season: Option<Season>) {
+        self.season = season;
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        match self.season {
+            Some(Season::Winter) => self.snowfall.update(delta_time),
+            Some(Season::Halloween) => self.pumpkins.update(delta_time),
+            None => {}
+        }
+    }
+
+    /// Background decorations, drawn over the drifting cards
+    pub fn draw(&self, d: &mut RaylibDrawHandle) {
+        match self.season {
+            Some(Season::Winter) => self.snowfall.draw(d),
+            Some(Season::Halloween) => self.pumpkins.draw(d),
+            None => {}
+        }
+    }
+
+    /// Decorate the outside of the board frame; `x`, `y`, `width` and `height` are the board
+    /// itself, inside the frame
+    pub fn draw_board_frame(
+        d: &mut RaylibDrawHandle,
+        season: Option<Season>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        match season {
+            Some(Season::Winter) => winter::draw_snow_cap(d, x, y, width),
+            Some(Season::Halloween) => halloween::draw_frame_pumpkins(d, x, y, width, height),
+            None => {}
+        }
+    }
+}
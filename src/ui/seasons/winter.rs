@@ -0,0 +1,87 @@
+use crate::ui::config::{BoardConfig, ScreenConfig, WinterConfig};
+use raylib::prelude::*;
+
+struct Snowflake {
+    position: Vector2,
+    fall_speed: f32,
+    radius: f32,
+    sway_phase: f32, // Offsets each flake's side-to-side drift
+}
+
+pub struct Snowfall {
+    flakes: Vec<Snowflake>,
+    time: f32,
+}
+
+impl Snowfall {
+    pub fn new() -> Self {
+        let flakes = (0..WinterConfig::SNOWFLAKE_COUNT)
+            .map(|_| Snowflake {
+                position: Vector2::new(
+                    rand::random::<f32>() * ScreenConfig::WIDTH as f32,
+                    rand::random::<f32>() * ScreenConfig::HEIGHT as f32,
+                ),
+                fall_speed: WinterConfig::MIN_FALL_SPEED
+                    + rand::random::<f32>()
+                        * (WinterConfig::MAX_FALL_SPEED - WinterConfig::MIN_FALL_SPEED),
+                radius: WinterConfig::MIN_RADIUS
+                    + rand::random::<f32>() * (WinterConfig::MAX_RADIUS - WinterConfig::MIN_RADIUS),
+                sway_phase: rand::random::<f32>() * std::f32::consts::TAU,
+            })
+            .collect();
+
+        Self { flakes, time: 0.0 }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.time += delta_time;
+        for flake in &mut self.flakes {
+            flake.position.y += flake.fall_speed * delta_time;
+            // Back to the top once it has fallen out of sight
+            if flake.position.y - flake.radius > ScreenConfig::HEIGHT as f32 {
+                flake.position.y = -flake.radius;
+                flake.position.x = rand::random::<f32>() * ScreenConfig::WIDTH as f32;
+            }
+        }
+    }
+
+    pub fn draw(&self, d: &mut RaylibDrawHandle) {
+        for flake in &self.flakes {
+            let sway = (self.time * WinterConfig::SWAY_SPEED + flake.sway_phase).sin()
+                * WinterConfig::SWAY_DISTANCE;
+            d.draw_circle_v(
+                Vector2::new(flake.position.x + sway, flake.position.y),
+                flake.radius,
+                WinterConfig::SNOW_COLOR,
+            );
+        }
+    }
+}
+
+/// A drift of snow resting along the top of the board frame
+pub fn draw_snow_cap(d: &mut RaylibDrawHandle, x: i32, y: i32, width: i32) {
+    let frame_left = x - BoardConfig::OUTER_FRAME_OFFSET;
+    let frame_top = y - BoardConfig::OUTER_FRAME_OFFSET;
+    let frame_width = width + BoardConfig::OUTER_FRAME_SIZE;
+
+    let mut cap_x = frame_left;
+    let mut index = 0;
+    while cap_x <= frame_left + frame_width {
+        // Alternate sizes so the drift looks lumpy rather than scalloped
+        let radius = if index % 2 == 0 {
+            WinterConfig::CAP_RADIUS
+        } else {
+            WinterConfig::CAP_RADIUS * 0.7
+        };
+        d.draw_circle(cap_x, frame_top, radius, WinterConfig::CAP_COLOR);
+        cap_x += WinterConfig::CAP_SPACING;
+        index += 1;
+    }
+    d.draw_rectangle(
+        frame_left,
+        frame_top,
+        frame_width,
+        WinterConfig::CAP_DEPTH,
+        WinterConfig::CAP_COLOR,
+    );
+}
@@ -0,0 +1,192 @@
+// Places text by measuring it with the font it's drawn in, so centered text stays centered
+// whatever the font, size or string, and long lines wrap to the space they're given.
+
+use crate::ui::config::ScreenConfig;
+use crate::ui::sdf_text::SdfText;
+use raylib::prelude::*;
+
+const LINE_HEIGHT: f32 = 1.2; // Wrapped lines are this many times the text size apart
+
+pub struct TextLayout;
+
+impl TextLayout {
+    /// Width and height of `text` drawn with `font`
+    pub fn measure(font: &Font, text: &str, size: f32, spacing: f32) -> Vector2 {
+        font.measure_text(text, size, spacing)
+    }
+
+    /// Top left corner that puts a block of `text_size` in the middle of `bounds`
+    pub fn center_in(text_size: Vector2, bounds: Rectangle) -> Vector2 {
+        Vector2::new(
+            bounds.x + (bounds.width - text_size.x) / 2.0,
+            bounds.y + (bounds.height - text_size.y) / 2.0,
+        )
+    }
+
+    /// Break `text` into lines no wider than `max_width`. A word too long for a line of its
+    /// own gets one anyway rather than being cut.
+    pub fn wrap(font: &Font, text: &str, size: f32, spacing: f32, max_width: f32) -> Vec<String> {
+        wrap_with(text, max_width, |line| {
+            Self::measure(font, line, size, spacing).x
+        })
+    }
+
+    /// Join `items` with `separator` into lines no wider than `max_width`, breaking only
+    /// between items
+    pub fn wrap_joined(
+        font: &Font,
+        items: &[String],
+        separator: &str,
+        size: f32,
+        spacing: f32,
+        max_width: f32,
+    ) -> Vec<String> {
+        join_within(items, separator, max_width, |line| {
+            Self::measure(font, line, size, spacing).x
+        })
+    }
+
+    /// Draw one line in the middle of `bounds`
+    pub fn draw_centered<D: RaylibDraw>(
+        d: &mut D,
+        font: &Font,
+        text: &str,
+        bounds: Rectangle,
+        size: f32,
+        spacing: f32,
+        color: Color,
+    ) {
+        let text_size = Self::measure(font, text, size, spacing);
+        let position = Self::center_in(text_size, bounds);
+        SdfText::draw(d, font, text, position, size, spacing, color);
+    }
+
+    /// Draw one line centered across the screen with its top at `y`
+    pub fn draw_centered_line<D: RaylibDraw>(
+        d: &mut D,
+        font: &Font,
+        text: &str,
+        y: f32,
+        size: f32,
+        spacing: f32,
+        color: Color,
+    ) {
+        let width = Self::measure(font, text, size, spacing).x;
+        let x = (ScreenConfig::WIDTH as f32 - width) / 2.0;
+        SdfText::draw(d, font, text, Vector2::new(x, y), size, spacing, color);
+    }
+
+    /// Wrap `text` to the width of `bounds` and draw the lines centered in it
+    pub fn draw_wrapped<D: RaylibDraw>(
+        d: &mut D,
+        font: &Font,
+        text: &str,
+        bounds: Rectangle,
+        size: f32,
+        spacing: f32,
+        color: Color,
+    ) {
+        let lines = Self::wrap(font, text, size, spacing, bounds.width);
+        let line_height = size * LINE_HEIGHT;
+        let block_height = line_height * (lines.len() as f32 - 1.0) + size;
+        let mut y = bounds.y + (bounds.height - block_height) / 2.0;
+        for line in &lines {
+            let row = Rectangle::new(bounds.x, y, bounds.width, size);
+            Self::draw_centered(d, font, line, row, size, spacing, color);
+            y += line_height;
+        }
+    }
+}
+
+// Greedy word wrap against any width measure. Line breaks already in the text are kept.
+fn wrap_with(text: &str, max_width: f32, measure: impl Fn(&str) -> f32) -> Vec<String> {
+    text.lines()
+        .flat_map(|paragraph| {
+            let words: Vec<_> = paragraph.split_whitespace().collect();
+            join_within(&words, " ", max_width, &measure)
+        })
+        .collect()
+}
+
+// Fill each line with as many items as fit, always at least one. Always returns a line,
+// even when there are no items.
+fn join_within(
+    items: &[impl AsRef<str>],
+    separator: &str,
+    max_width: f32,
+    measure: impl Fn(&str) -> f32,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for item in items {
+        let item = item.as_ref();
+        if line.is_empty() {
+            line.push_str(item);
+            continue;
+        }
+        let candidate = format!("{}{}{}", line, separator, item);
+        if measure(&candidate) <= max_width {
+            line = candidate;
+        } else {
+            lines.push(std::mem::replace(&mut line, item.to_string()));
+        }
+    }
+    lines.push(line);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ten pixels a character
+    fn measure(text: &str) -> f32 {
+        text.chars().count() as f32 * 10.0
+    }
+
+    #[test]
+    fn test_wrap_breaks_between_words() {
+        let lines = wrap_with(
+            "Paused automatically after 30s without input",
+            200.0,
+            measure,
+        );
+        assert_eq!(
+            lines,
+            vec!["Paused automatically", "after 30s without", "input"]
+        );
+        assert!(lines.iter().all(|line| measure(line) <= 200.0));
+
+        // Short text stays on one line, and a word wider than the limit isn't split
+        assert_eq!(wrap_with("GAME OVER", 200.0, measure), vec!["GAME OVER"]);
+        assert_eq!(wrap_with("Unbreakable", 50.0, measure), vec!["Unbreakable"]);
+    }
+
+    #[test]
+    fn test_wrap_keeps_line_breaks() {
+        assert_eq!(
+            wrap_with("First line\nSecond", 500.0, measure),
+            vec!["First line", "Second"]
+        );
+    }
+
+    #[test]
+    fn test_joined_items_break_only_between_items() {
+        let items = ["Up/Down: Navigate", "ENTER: Select", "ESC: Back"];
+        assert_eq!(
+            join_within(&items, " | ", 350.0, measure),
+            vec!["Up/Down: Navigate | ENTER: Select", "ESC: Back"]
+        );
+        assert_eq!(
+            join_within(&items, " | ", 1000.0, measure),
+            vec!["Up/Down: Navigate | ENTER: Select | ESC: Back"]
+        );
+    }
+
+    #[test]
+    fn test_center_in() {
+        let bounds = Rectangle::new(100.0, 50.0, 400.0, 60.0);
+        let position = TextLayout::center_in(Vector2::new(200.0, 20.0), bounds);
+        assert_eq!(position, Vector2::new(200.0, 70.0));
+    }
+}
@@ -1,8 +1,9 @@
 use crate::ui::config::TextConfig;
+use crate::ui::sdf_text::SdfText;
 use raylib::color::Color;
-use raylib::drawing::{RaylibDraw, RaylibDrawHandle};
+use raylib::drawing::RaylibDrawHandle;
 use raylib::math::Vector2;
-use raylib::prelude::Font;
+use raylib::prelude::{Font, RaylibFont};
 use std::sync::LazyLock;
 
 pub struct TextRenderer;
@@ -68,6 +69,21 @@ impl TextCache {
 static TEXT_CACHE: LazyLock<TextCache> = LazyLock::new(TextCache::new);
 
 impl TextRenderer {
+    /// `base_size` scaled by the text size setting, then shrunk back (never below the
+    /// unscaled size) if the text would run wider than `max_width`
+    pub fn scaled_size(
+        font: &Font,
+        text: &str,
+        base_size: f32,
+        text_scale: f32,
+        spacing: f32,
+        max_width: f32,
+    ) -> f32 {
+        let size = base_size * text_scale;
+        let width = font.measure_text(text, size, spacing).x;
+        fit_size(size, width, max_width).max(base_size.min(size))
+    }
+
     pub fn draw_title_with_shadow(d: &mut RaylibDrawHandle, title_font: &Font) {
         let cache = &*TEXT_CACHE;
         let title = "DropJack";
@@ -79,7 +95,8 @@ impl TextRenderer {
             .iter()
             .zip(cache.shadow_config.colors.iter())
         {
-            d.draw_text_ex(
+            SdfText::draw(
+                d,
                 title_font,
                 title,
                 Vector2::new(
@@ -93,7 +110,8 @@ impl TextRenderer {
         }
 
         // Main title with gradient effect
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             title_font,
             title,
             cache.title_position,
@@ -102,7 +120,8 @@ impl TextRenderer {
             cache.title_main_color,
         );
 
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             title_font,
             title,
             Vector2::new(cache.title_position.x, cache.title_position.y - 1.0),
@@ -117,7 +136,8 @@ impl TextRenderer {
         let subtitle = "A Strategic Card-Falling Puzzle";
 
         // Shadow
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             font,
             subtitle,
             Vector2::new(
@@ -130,7 +150,8 @@ impl TextRenderer {
         );
 
         // Main text
-        d.draw_text_ex(
+        SdfText::draw(
+            d,
             font,
             subtitle,
             cache.subtitle_position,
@@ -140,3 +161,24 @@ impl TextRenderer {
         );
     }
 }
+
+// Text width grows with the font size, so shrink the size by however much the text overflows
+fn fit_size(size: f32, text_width: f32, max_width: f32) -> f32 {
+    if text_width <= max_width || text_width <= 0.0 {
+        size
+    } else {
+        size * max_width / text_width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_size_shrinks_overflowing_text() {
+        assert_eq!(fit_size(30.0, 200.0, 300.0), 30.0);
+        assert_eq!(fit_size(30.0, 400.0, 200.0), 15.0);
+        assert_eq!(fit_size(30.0, 0.0, 200.0), 30.0);
+    }
+}
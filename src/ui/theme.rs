@@ -0,0 +1,70 @@
+// Colors and outlines that change between the standard look and the high-contrast look.
+// The tables themselves live in ThemeConfig; renderers ask for the one the settings pick.
+
+use super::config::ThemeConfig;
+use super::sdf_text::SdfText;
+use crate::game::Game;
+use raylib::prelude::*;
+
+pub struct Theme {
+    pub panel_color: Color,
+    pub panel_border_color: Color,
+    pub panel_border_thickness: f32,
+    pub solid_panels: bool, // Flat panels instead of the wood frames and gradients
+    pub text_color: Color,
+    pub highlight_text_color: Color, // Titles and the score
+    pub bold_text: bool,
+    pub card_outline_color: Color,
+    pub card_outline_thickness: f32, // 0 draws cards without an outline
+}
+
+impl Theme {
+    pub fn of(game: &Game) -> &'static Theme {
+        if game.settings.high_contrast {
+            &ThemeConfig::HIGH_CONTRAST
+        } else {
+            &ThemeConfig::STANDARD
+        }
+    }
+
+    /// draw_text_ex, drawn a second time a pixel to the right when the theme wants bold text
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_ex(
+        &self,
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        text: &str,
+        position: Vector2,
+        size: f32,
+        spacing: f32,
+        color: Color,
+    ) {
+        SdfText::draw(d, font, text, position, size, spacing, color);
+        if self.bold_text {
+            let offset = Vector2::new(position.x + 1.0, position.y);
+            SdfText::draw(d, font, text, offset, size, spacing, color);
+        }
+    }
+
+    /// A panel in the theme's colors
+    pub fn draw_panel(&self, d: &mut RaylibDrawHandle, x: i32, y: i32, width: i32, height: i32) {
+        d.draw_rectangle(x, y, width, height, self.panel_color);
+        d.draw_rectangle_lines_ex(
+            Rectangle::new(x as f32, y as f32, width as f32, height as f32),
+            self.panel_border_thickness,
+            self.panel_border_color,
+        );
+    }
+
+    /// Outline a card cell so it stands out from its neighbours and the felt
+    pub fn draw_card_outline(&self, d: &mut RaylibDrawHandle, x: i32, y: i32, size: i32) {
+        if self.card_outline_thickness <= 0.0 {
+            return;
+        }
+        d.draw_rectangle_lines_ex(
+            Rectangle::new(x as f32, y as f32, size as f32, size as f32),
+            self.card_outline_thickness,
+            self.card_outline_color,
+        );
+    }
+}
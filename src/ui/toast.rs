@@ -0,0 +1,83 @@
+use crate::ui::config::{ScreenConfig, ToastConfig};
+use crate::ui::sdf_text::SdfText;
+use raylib::prelude::*;
+use std::time::{Duration, Instant};
+
+// A short message shown at the top of the screen that fades away on its own
+struct Toast {
+    message: String,
+    created: Instant,
+}
+
+pub struct ToastSystem {
+    toasts: Vec<Toast>,
+}
+
+impl ToastSystem {
+    pub fn new() -> Self {
+        ToastSystem { toasts: Vec::new() }
+    }
+
+    pub fn show(&mut self, message: impl Into<String>) {
+        self.toasts.push(Toast {
+            message: message.into(),
+            created: Instant::now(),
+        });
+
+        // Drop the oldest toasts so a burst of errors can't fill the screen
+        if self.toasts.len() > ToastConfig::MAX_VISIBLE {
+            let excess = self.toasts.len() - ToastConfig::MAX_VISIBLE;
+            self.toasts.drain(..excess);
+        }
+    }
+
+    pub fn update(&mut self) {
+        let lifetime = Duration::from_millis(ToastConfig::LIFETIME_MS);
+        self.toasts
+            .retain(|toast| toast.created.elapsed() < lifetime);
+    }
+
+    pub fn draw(&self, d: &mut RaylibDrawHandle, font: &Font, text_scale: f32) {
+        let font_size = ToastConfig::FONT_SIZE * text_scale;
+        for (index, toast) in self.toasts.iter().enumerate() {
+            let alpha = Self::fade_alpha(toast.created.elapsed());
+            let text_width = d.measure_text(&toast.message, font_size as i32);
+            let width = text_width + ToastConfig::PADDING * 2;
+            let height = font_size as i32 + ToastConfig::PADDING * 2;
+            let x = (ScreenConfig::WIDTH - width) / 2;
+            let y = ToastConfig::Y + index as i32 * (height + ToastConfig::SPACING);
+
+            d.draw_rectangle(
+                x,
+                y,
+                width,
+                height,
+                ToastConfig::BACKGROUND_COLOR.fade(alpha),
+            );
+            d.draw_rectangle_lines(x, y, width, height, ToastConfig::BORDER_COLOR.fade(alpha));
+            SdfText::draw(
+                d,
+                font,
+                &toast.message,
+                Vector2::new(
+                    (x + ToastConfig::PADDING) as f32,
+                    (y + ToastConfig::PADDING) as f32,
+                ),
+                font_size,
+                1.0,
+                ToastConfig::TEXT_COLOR.fade(alpha),
+            );
+        }
+    }
+
+    // Fully visible until the last part of the toast's life, then fade out
+    fn fade_alpha(age: Duration) -> f32 {
+        let fade_start = ToastConfig::LIFETIME_MS - ToastConfig::FADE_MS;
+        let age_ms = age.as_millis() as u64;
+        if age_ms <= fade_start {
+            1.0
+        } else {
+            1.0 - (age_ms - fade_start) as f32 / ToastConfig::FADE_MS as f32
+        }
+    }
+}
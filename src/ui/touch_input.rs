@@ -0,0 +1,170 @@
+// Touchscreen play for touch laptops and handhelds: tap a column to move the card there,
+// swipe sideways to move one column, swipe down to hard drop, and tap the on-screen
+// button to pause. Only real touch points are read, so the mouse never triggers these.
+
+use super::config::{BoardConfig, TouchConfig};
+use super::sdf_text::SdfText;
+use crate::game::Game;
+use crate::game::input::TouchGesture;
+use raylib::prelude::*;
+use std::time::{Duration, Instant};
+
+/// Follows the first touch point from finger down to finger up
+pub struct TouchTracker {
+    start: Option<(Vector2, Instant)>,
+    last: Vector2,
+}
+
+impl TouchTracker {
+    pub fn new() -> Self {
+        TouchTracker {
+            start: None,
+            last: Vector2::zero(),
+        }
+    }
+
+    /// The gesture made by a touch that just lifted, if any
+    pub fn update(&mut self, rl: &RaylibHandle) -> Option<TouchGesture> {
+        if rl.get_touch_point_count() > 0 {
+            let position = rl.get_touch_position(0);
+            if self.start.is_none() {
+                self.start = Some((position, Instant::now()));
+            }
+            self.last = position;
+            return None;
+        }
+
+        let (start, started_at) = self.start.take()?;
+        classify_touch(
+            (start.x, start.y),
+            (self.last.x, self.last.y),
+            started_at.elapsed(),
+        )
+    }
+}
+
+/// Turn a touch's start and end points into a gesture. Short, still touches are taps;
+/// long moves are swipes in their dominant direction. Upward swipes mean nothing.
+pub fn classify_touch(
+    start: (f32, f32),
+    end: (f32, f32),
+    duration: Duration,
+) -> Option<TouchGesture> {
+    let dx = end.0 - start.0;
+    let dy = end.1 - start.1;
+    let distance = (dx * dx + dy * dy).sqrt();
+
+    if distance <= TouchConfig::TAP_MAX_DISTANCE {
+        let quick = duration <= Duration::from_millis(TouchConfig::TAP_MAX_MS);
+        return quick.then_some(TouchGesture::Tap { x: end.0, y: end.1 });
+    }
+    if distance < TouchConfig::SWIPE_MIN_DISTANCE {
+        return None;
+    }
+
+    if dy > dx.abs() {
+        Some(TouchGesture::SwipeDown)
+    } else if dx.abs() > dy.abs() {
+        Some(if dx < 0.0 {
+            TouchGesture::SwipeLeft
+        } else {
+            TouchGesture::SwipeRight
+        })
+    } else {
+        None
+    }
+}
+
+/// Whether a point is on the on-screen pause button
+pub fn is_on_pause_button(game: &Game, x: f32, y: f32) -> bool {
+    let (bx, by, bw, bh) = TouchConfig::pause_button(game.settings.mirrored_layout);
+    x >= bx as f32 && x < (bx + bw) as f32 && y >= by as f32 && y < (by + bh) as f32
+}
+
+/// The board column under a screen x position, if it's over the board
+pub fn board_column_at(game: &Game, x: f32) -> Option<i32> {
+    let cell_size = game.board.cell_size;
+    let (origin_x, _) = BoardConfig::origin(
+        game.board.width,
+        game.board.height,
+        cell_size,
+        game.settings.mirrored_layout,
+    );
+    let column = ((x - origin_x as f32) / cell_size as f32).floor() as i32;
+    (0..game.board.width).contains(&column).then_some(column)
+}
+
+/// On-screen pause button, drawn while touch controls are on
+pub fn draw_pause_button(d: &mut RaylibDrawHandle, game: &Game, font: &Font) {
+    let (x, y, width, height) = TouchConfig::pause_button(game.settings.mirrored_layout);
+    d.draw_rectangle(x, y, width, height, TouchConfig::BUTTON_COLOR);
+    d.draw_rectangle_lines(x, y, width, height, TouchConfig::BUTTON_BORDER_COLOR);
+
+    let text_width = d.measure_text("PAUSE", TouchConfig::BUTTON_TEXT_SIZE as i32);
+    SdfText::draw(
+        d,
+        font,
+        "PAUSE",
+        Vector2::new(
+            (x + (width - text_width) / 2) as f32,
+            (y + (height - TouchConfig::BUTTON_TEXT_SIZE as i32) / 2) as f32,
+        ),
+        TouchConfig::BUTTON_TEXT_SIZE,
+        1.0,
+        Color::WHITE,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::config::InfoPanelConfig;
+
+    #[test]
+    fn test_quick_still_touch_is_a_tap() {
+        assert_eq!(
+            classify_touch((100.0, 100.0), (105.0, 102.0), Duration::from_millis(120)),
+            Some(TouchGesture::Tap { x: 105.0, y: 102.0 })
+        );
+
+        // Holding a finger still isn't a tap
+        assert_eq!(
+            classify_touch((100.0, 100.0), (100.0, 100.0), Duration::from_secs(2)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_swipes_follow_dominant_direction() {
+        let quick = Duration::from_millis(150);
+        assert_eq!(
+            classify_touch((300.0, 100.0), (150.0, 130.0), quick),
+            Some(TouchGesture::SwipeLeft)
+        );
+        assert_eq!(
+            classify_touch((300.0, 100.0), (450.0, 80.0), quick),
+            Some(TouchGesture::SwipeRight)
+        );
+        assert_eq!(
+            classify_touch((300.0, 100.0), (320.0, 400.0), quick),
+            Some(TouchGesture::SwipeDown)
+        );
+
+        // Upward swipes and short drags do nothing
+        assert_eq!(classify_touch((300.0, 400.0), (300.0, 100.0), quick), None);
+        assert_eq!(classify_touch((300.0, 100.0), (330.0, 100.0), quick), None);
+    }
+
+    #[test]
+    fn test_pause_button_follows_the_info_panel() {
+        for mirrored in [false, true] {
+            let (x, _, width, _) = TouchConfig::pause_button(mirrored);
+            let panel_x = InfoPanelConfig::x(mirrored);
+            assert!(x >= panel_x && x + width <= panel_x + InfoPanelConfig::WIDTH);
+
+            // The default 10x15 board never sits under the panel
+            let (origin_x, _) = BoardConfig::origin(10, 15, 50, mirrored);
+            assert!(origin_x + 500 <= panel_x || origin_x >= panel_x + InfoPanelConfig::WIDTH);
+        }
+    }
+}
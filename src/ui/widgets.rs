@@ -0,0 +1,519 @@
+// A small immediate-mode widget layer for the menus. Widgets keep no state of their own:
+// each frame a screen draws them from the game's state into rectangles handed out by a
+// MenuLayout. Input reads the same layouts (see MenuLayout::read), so the mouse
+// points at the row that was drawn there. The one thing remembered between frames is where
+// the focus highlight was, so it can slide to the newly focused row instead of jumping.
+
+use crate::game::Game;
+use crate::game::input::InputSnapshot;
+use crate::ui::config::{
+    MainMenuConfig, PauseMenuConfig, ScreenConfig, SettingsConfig, TextConfig, WidgetConfig,
+};
+use crate::ui::sdf_text::SdfText;
+use crate::ui::text_renderer::TextRenderer;
+use crate::ui::theme::Theme;
+use raylib::prelude::*;
+use std::cell::RefCell;
+use std::time::Instant;
+
+// Where the focus highlight was last drawn
+struct FocusSlide {
+    layout: MenuLayout,
+    y: f32,
+    started: Instant, // When this menu's highlight first appeared, for the pulse
+    updated: Instant,
+}
+
+thread_local! {
+    // Only one menu has focus at a time, so one highlight is all there is to remember
+    static FOCUS_SLIDE: RefCell<Option<FocusSlide>> = const { RefCell::new(None) };
+}
+
+/// Rows of the same size stacked down the screen at a fixed pitch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MenuLayout {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub row_height: f32,
+    pub pitch: f32, // From the top of one row to the top of the next
+}
+
+impl MenuLayout {
+    pub fn main_menu() -> Self {
+        MenuLayout {
+            x: MainMenuConfig::BASE_X as f32,
+            y: MainMenuConfig::BASE_Y as f32,
+            width: MainMenuConfig::OPTION_WIDTH as f32,
+            row_height: MainMenuConfig::OPTION_HEIGHT as f32,
+            pitch: MainMenuConfig::OPTION_SPACING as f32,
+        }
+    }
+
+    /// These rows squeezed together so `count` of them fit where the main menu has room for
+    /// its usual number, keeping the gap between rows in proportion
+    pub fn packed(self, count: usize) -> Self {
+        let room = MainMenuConfig::ROOM_FOR_OPTIONS as f32;
+        let pitch = self.pitch.min(self.pitch * room / count.max(1) as f32);
+        MenuLayout {
+            row_height: self.row_height * pitch / self.pitch,
+            pitch,
+            ..self
+        }
+    }
+
+    /// The pause menu and the quit-to-menu dialog, which share the main menu's look
+    pub fn pause_menu() -> Self {
+        MenuLayout {
+            y: PauseMenuConfig::BASE_Y as f32,
+            ..Self::main_menu()
+        }
+    }
+
+    /// Rows inside a themed panel whose top left corner is at (`panel_x`, `panel_y`). The
+    /// first row's text sits `first_row_y` below the panel's top.
+    pub fn panel(panel_x: i32, panel_y: i32, panel_width: i32, first_row_y: i32) -> Self {
+        MenuLayout {
+            x: (panel_x + WidgetConfig::ROW_INSET) as f32,
+            y: (panel_y + first_row_y - 8) as f32,
+            width: (panel_width - WidgetConfig::ROW_INSET * 2) as f32,
+            row_height: WidgetConfig::ROW_HEIGHT as f32,
+            pitch: SettingsConfig::ROW_PITCH as f32,
+        }
+    }
+
+    pub fn settings() -> Self {
+        Self::panel(
+            Self::settings_panel_x(),
+            SettingsConfig::PANEL_Y,
+            SettingsConfig::PANEL_WIDTH,
+            30,
+        )
+    }
+
+    pub fn settings_panel_x() -> i32 {
+        (ScreenConfig::WIDTH - SettingsConfig::PANEL_WIDTH) / 2
+    }
+
+    pub fn row(&self, index: usize) -> Rectangle {
+        Rectangle::new(
+            self.x,
+            self.y + index as f32 * self.pitch,
+            self.width,
+            self.row_height,
+        )
+    }
+
+    /// Which of the first `count` rows `point` is over, if any
+    pub fn row_at(&self, point: Vector2, count: usize) -> Option<usize> {
+        if point.x < self.x || point.x >= self.x + self.width || point.y < self.y {
+            return None;
+        }
+        let index = ((point.y - self.y) / self.pitch) as usize;
+        let within_row = point.y - self.row(index).y < self.row_height;
+        (index < count && within_row).then_some(index)
+    }
+
+    /// Navigation for a menu drawn with this layout: up/down and left/right from the keys,
+    /// D-Pad or stick, and the mouse focusing whichever of the `count` rows it moves over.
+    /// Clicking a row chooses it, like ENTER or A.
+    pub fn read(&self, input: &InputSnapshot, count: usize) -> MenuInput {
+        let mut menu = MenuInput::default();
+        if input.up {
+            menu.step = -1;
+        } else if input.down {
+            menu.step = 1;
+        }
+
+        if input.left() {
+            menu.adjust = -1;
+        } else if input.right() {
+            menu.adjust = 1;
+        }
+
+        // A cursor left resting over a row doesn't take focus back from the keys
+        let (x, y) = input.mouse;
+        let hovered = self.row_at(Vector2::new(x, y), count);
+        menu.pointed = hovered.filter(|_| input.mouse_moved || input.mouse_clicked);
+        menu.activate = input.action() || (input.mouse_clicked && hovered.is_some());
+        menu
+    }
+}
+
+/// What the player did to a menu this frame, from whichever device they used
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MenuInput {
+    pub step: i32,              // Focus moves up (-1) or down (1)
+    pub adjust: i32,            // Left (-1) or right (1) on the focused row
+    pub activate: bool,         // Choose the focused row
+    pub pointed: Option<usize>, // Row the mouse moved onto or clicked
+}
+
+impl MenuInput {
+    /// Where focus goes from `current` among `count` rows. The mouse wins over the keys,
+    /// and keys wrap around at either end.
+    pub fn focus(&self, current: usize, count: usize) -> usize {
+        if let Some(index) = self.pointed {
+            return index;
+        }
+        if count == 0 {
+            return current;
+        }
+        (current as i32 + self.step).rem_euclid(count as i32) as usize
+    }
+
+    /// Focus moves as a number of rows, for menus that cycle their own selection
+    pub fn focus_step(&self, current: usize, count: usize) -> i32 {
+        self.focus(current, count) as i32 - current as i32
+    }
+}
+
+/// How widgets look: the big main menu buttons, or rows on a settings panel
+pub struct WidgetStyle {
+    pub background: Option<Color>, // Behind every row, under the focus highlight
+    pub focus_background: Color,
+    pub focus_border: Color,
+    pub text_color: Color,
+    pub focus_text_color: Color,
+    pub text_size: f32,
+    pub max_text_size: f32,
+    pub text_spacing: f32,
+    pub text_scale: f32, // The player's text size setting; 1.0 leaves text as designed
+    pub text_inset: f32, // Space between the row's left edge and its text
+    pub bold: bool,
+    pub reduced_motion: bool, // The focus highlight jumps between rows and doesn't pulse
+}
+
+impl WidgetStyle {
+    pub fn menu(game: &Game) -> Self {
+        WidgetStyle {
+            background: Some(MainMenuConfig::UNSELECTED_BG),
+            focus_background: MainMenuConfig::SELECTED_BG,
+            focus_border: MainMenuConfig::BORDER_COLOR,
+            text_color: MainMenuConfig::UNSELECTED_TEXT_COLOR,
+            focus_text_color: MainMenuConfig::SELECTED_TEXT_COLOR,
+            text_size: MainMenuConfig::TEXT_SIZE,
+            max_text_size: MainMenuConfig::TEXT_SIZE,
+            text_spacing: MainMenuConfig::TEXT_SPACING,
+            text_scale: 1.0,
+            text_inset: MainMenuConfig::TEXT_X_OFFSET as f32,
+            bold: false,
+            reduced_motion: game.settings.reduced_motion,
+        }
+    }
+
+    /// Rows on a themed panel, following the text size and high contrast settings
+    pub fn panel(game: &Game) -> Self {
+        let theme = Theme::of(game);
+        WidgetStyle {
+            background: None,
+            focus_background: WidgetConfig::FOCUS_BACKGROUND,
+            focus_border: WidgetConfig::FOCUS_BORDER,
+            text_color: theme.text_color,
+            focus_text_color: WidgetConfig::FOCUS_TEXT_COLOR,
+            text_size: TextConfig::OPTION_LABEL_SIZE,
+            max_text_size: TextConfig::OPTION_LABEL_MAX_SIZE,
+            text_spacing: 1.2,
+            text_scale: game.settings.text_scale(),
+            text_inset: WidgetConfig::TEXT_INSET,
+            bold: theme.bold_text,
+            reduced_motion: game.settings.reduced_motion,
+        }
+    }
+}
+
+/// Whether a widget has focus, and whether it's dimmed. Dimmed widgets still take focus so
+/// the player can land on them and see they can't be changed right now.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WidgetState {
+    pub focused: bool,
+    pub dimmed: bool,
+}
+
+impl WidgetState {
+    pub fn focused_if(focused: bool) -> Self {
+        WidgetState {
+            focused,
+            dimmed: false,
+        }
+    }
+
+    pub fn dimmed(mut self, dimmed: bool) -> Self {
+        self.dimmed = dimmed;
+        self
+    }
+}
+
+pub struct Widgets;
+
+impl Widgets {
+    /// A row with a label that does something when chosen
+    pub fn button(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        bounds: Rectangle,
+        label: &str,
+        state: WidgetState,
+        style: &WidgetStyle,
+    ) {
+        Self::draw_frame(d, bounds, style);
+        Self::draw_label(d, font, bounds, label, bounds.width, state, style);
+    }
+
+    /// A row showing whether something is on
+    pub fn toggle(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        bounds: Rectangle,
+        label: &str,
+        on: bool,
+        state: WidgetState,
+        style: &WidgetStyle,
+    ) {
+        let text = format!("{}: {}", label, if on { "ON" } else { "OFF" });
+        Self::button(d, font, bounds, &text, state, style);
+    }
+
+    /// A row with a bar filled to `value` (0 to 1) at its right end. Dimmed sliders show an
+    /// empty bar.
+    pub fn slider(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        bounds: Rectangle,
+        label: &str,
+        value: f32,
+        state: WidgetState,
+        style: &WidgetStyle,
+    ) {
+        Self::draw_frame(d, bounds, style);
+        let bar_x = bounds.x + bounds.width - WidgetConfig::SLIDER_WIDTH - style.text_inset;
+        Self::draw_label(d, font, bounds, label, bar_x - bounds.x, state, style);
+
+        let bar = Rectangle::new(
+            bar_x,
+            bounds.y + (bounds.height - WidgetConfig::SLIDER_HEIGHT) / 2.0,
+            WidgetConfig::SLIDER_WIDTH,
+            WidgetConfig::SLIDER_HEIGHT,
+        );
+        d.draw_rectangle_rec(bar, WidgetConfig::SLIDER_TRACK_COLOR);
+        let fill = if state.dimmed {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+        if fill > 0.0 {
+            let filled = Rectangle::new(bar.x, bar.y, bar.width * fill, bar.height);
+            d.draw_rectangle_rec(filled, WidgetConfig::SLIDER_FILL_COLOR);
+        }
+        d.draw_rectangle_lines_ex(bar, 1.0, WidgetConfig::SLIDER_BORDER_COLOR);
+    }
+
+    /// Buttons down the rows of `layout`, with the one at `focused` highlighted
+    pub fn list(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        layout: &MenuLayout,
+        items: &[&str],
+        focused: usize,
+        style: &WidgetStyle,
+    ) {
+        // Backgrounds first so the highlight slides over them rather than under
+        for index in 0..items.len() {
+            Self::draw_frame(d, layout.row(index), style);
+        }
+        Self::focus_highlight(d, layout, focused, style);
+        for (index, item) in items.iter().enumerate() {
+            let bounds = layout.row(index);
+            let state = WidgetState::focused_if(index == focused);
+            Self::draw_label(d, font, bounds, item, bounds.width, state, style);
+        }
+    }
+
+    /// The highlight behind the focused row of `layout`. Draw it before the row's widgets.
+    /// When focus moves it slides to the new row and pulses gently while it rests, or with
+    /// reduced motion just appears there.
+    pub fn focus_highlight(
+        d: &mut RaylibDrawHandle,
+        layout: &MenuLayout,
+        focused: usize,
+        style: &WidgetStyle,
+    ) {
+        let target = layout.row(focused);
+        let now = Instant::now();
+        let (y, pulse_time) = FOCUS_SLIDE.with(|slide| {
+            let mut slide = slide.borrow_mut();
+            // A different menu, or one coming back after a while, starts on its focused row
+            let slide = match slide.as_mut() {
+                Some(current)
+                    if current.layout == *layout
+                        && now.duration_since(current.updated).as_secs_f32()
+                            < WidgetConfig::FOCUS_STALE_SECONDS =>
+                {
+                    current
+                }
+                _ => slide.insert(FocusSlide {
+                    layout: *layout,
+                    y: target.y,
+                    started: now,
+                    updated: now,
+                }),
+            };
+            let elapsed = now.duration_since(slide.updated).as_secs_f32();
+            slide.y = if style.reduced_motion {
+                target.y
+            } else {
+                slide_towards(slide.y, target.y, elapsed)
+            };
+            slide.updated = now;
+            (slide.y, now.duration_since(slide.started).as_secs_f32())
+        });
+
+        let bounds = Rectangle::new(target.x, y, target.width, target.height);
+        let pulse = if style.reduced_motion {
+            1.0
+        } else {
+            1.0 - WidgetConfig::FOCUS_PULSE_AMOUNT
+                * (0.5 + 0.5 * (pulse_time * WidgetConfig::FOCUS_PULSE_SPEED).sin())
+        };
+        d.draw_rectangle_rec(bounds, style.focus_background.fade(pulse));
+        d.draw_rectangle_lines_ex(bounds, 1.0, style.focus_border);
+    }
+
+    fn draw_frame(d: &mut RaylibDrawHandle, bounds: Rectangle, style: &WidgetStyle) {
+        if let Some(background) = style.background {
+            d.draw_rectangle_rec(bounds, background);
+        }
+    }
+
+    // Label text shrunk to fit inside `width` from the row's left edge, and centered on the
+    // row's height
+    fn draw_label(
+        d: &mut RaylibDrawHandle,
+        font: &Font,
+        bounds: Rectangle,
+        text: &str,
+        width: f32,
+        state: WidgetState,
+        style: &WidgetStyle,
+    ) {
+        let size = TextRenderer::scaled_size(
+            font,
+            text,
+            style.text_size,
+            style.text_scale,
+            style.text_spacing,
+            width - style.text_inset * 2.0,
+        )
+        .min(style.max_text_size);
+        let color = match (state.focused, state.dimmed) {
+            (true, true) => WidgetConfig::FOCUS_DISABLED_TEXT_COLOR,
+            (true, false) => style.focus_text_color,
+            (false, true) => WidgetConfig::DISABLED_TEXT_COLOR,
+            (false, false) => style.text_color,
+        };
+        let position = Vector2::new(
+            bounds.x + style.text_inset,
+            bounds.y + (bounds.height - size) / 2.0,
+        );
+        SdfText::draw(d, font, text, position, size, style.text_spacing, color);
+        if style.bold {
+            let offset = Vector2::new(position.x + 1.0, position.y);
+            SdfText::draw(d, font, text, offset, size, style.text_spacing, color);
+        }
+    }
+}
+
+// Ease `y` towards `target`, covering most of the distance in the first few frames and
+// settling onto it once it's within a pixel
+fn slide_towards(y: f32, target: f32, elapsed_seconds: f32) -> f32 {
+    let eased = 1.0 - (-WidgetConfig::FOCUS_SLIDE_RATE * elapsed_seconds).exp();
+    let next = y + (target - y) * eased;
+    if (target - next).abs() < 1.0 {
+        target
+    } else {
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows_step_down_by_the_pitch() {
+        let layout = MenuLayout {
+            x: 100.0,
+            y: 200.0,
+            width: 240.0,
+            row_height: 45.0,
+            pitch: 55.0,
+        };
+        assert_eq!(layout.row(0), Rectangle::new(100.0, 200.0, 240.0, 45.0));
+        assert_eq!(layout.row(2).y, 310.0);
+    }
+
+    #[test]
+    fn test_menu_focus_wraps_and_follows_the_mouse() {
+        let up = MenuInput {
+            step: -1,
+            ..MenuInput::default()
+        };
+        assert_eq!(up.focus(0, 5), 4);
+        assert_eq!(up.focus_step(0, 5), 4);
+        let down = MenuInput {
+            step: 1,
+            ..MenuInput::default()
+        };
+        assert_eq!(down.focus(4, 5), 0);
+        assert_eq!(down.focus(2, 5), 3);
+
+        // Pointing at a row takes focus there, whatever the keys did
+        let pointed = MenuInput {
+            step: 1,
+            pointed: Some(1),
+            ..MenuInput::default()
+        };
+        assert_eq!(pointed.focus(3, 5), 1);
+        assert_eq!(pointed.focus_step(3, 5), -2);
+        assert_eq!(MenuInput::default().focus(2, 5), 2);
+    }
+
+    #[test]
+    fn test_highlight_slides_onto_the_focused_row() {
+        let frame = 1.0 / 60.0;
+        let mut y = 100.0;
+        y = slide_towards(y, 200.0, frame);
+        assert!(y > 100.0 && y < 200.0);
+
+        let mut frames = 1;
+        while y != 200.0 {
+            y = slide_towards(y, 200.0, frame);
+            frames += 1;
+            assert!(frames < 60, "the highlight should settle within a second");
+        }
+
+        // Going back up works the same way, and a long pause lands it right away
+        assert!(slide_towards(200.0, 100.0, frame) < 200.0);
+        assert_eq!(slide_towards(200.0, 100.0, 5.0), 100.0);
+    }
+
+    #[test]
+    fn test_row_at_finds_the_row_under_a_point() {
+        let layout = MenuLayout {
+            x: 100.0,
+            y: 200.0,
+            width: 240.0,
+            row_height: 45.0,
+            pitch: 55.0,
+        };
+        assert_eq!(layout.row_at(Vector2::new(150.0, 210.0), 3), Some(0));
+        assert_eq!(layout.row_at(Vector2::new(339.0, 299.0), 3), Some(1));
+
+        // The gap between rows, past the last row, and either side miss
+        assert_eq!(layout.row_at(Vector2::new(150.0, 250.0), 3), None);
+        assert_eq!(layout.row_at(Vector2::new(150.0, 370.0), 3), None);
+        assert_eq!(layout.row_at(Vector2::new(99.0, 210.0), 3), None);
+        assert_eq!(layout.row_at(Vector2::new(340.0, 210.0), 3), None);
+        assert_eq!(layout.row_at(Vector2::new(150.0, 190.0), 3), None);
+    }
+}
@@ -0,0 +1,104 @@
+// Optional check for a newer release, off unless `check_for_updates` is set in
+// settings.json. The feed is fetched on a thread of its own so a slow or missing network
+// never holds up startup; the UI polls for the answer once per frame.
+
+use crate::build_info::BuildInfo;
+use std::error::Error;
+use std::sync::mpsc::{self, Receiver};
+
+// Latest published release, whose tag_name is the version, e.g. "v1.2.0"
+#[cfg_attr(target_os = "emscripten", allow(dead_code))]
+const RELEASE_FEED_URL: &str = "https://api.github.com/repos/sdeming/dropjack/releases/latest";
+#[cfg_attr(target_os = "emscripten", allow(dead_code))]
+const TIMEOUT_SECONDS: u64 = 10;
+
+pub struct UpdateCheck {
+    result: Receiver<Option<String>>,
+}
+
+impl UpdateCheck {
+    /// Start asking the release feed whether there's anything newer than this build
+    pub fn start() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let check = move || {
+            let newer = match fetch_latest_version() {
+                Ok(latest) => newer_version(BuildInfo::CURRENT.version, &latest),
+                Err(e) => {
+                    eprintln!("Update check failed: {}", e);
+                    None
+                }
+            };
+            // The UI may have gone away already, in which case nobody needs the answer
+            let _ = sender.send(newer);
+        };
+
+        // Browser builds have no threads, and nothing to fetch, so they answer right away
+        #[cfg(target_os = "emscripten")]
+        check();
+        #[cfg(not(target_os = "emscripten"))]
+        std::thread::spawn(check);
+
+        UpdateCheck { result: receiver }
+    }
+
+    /// The newer version, on the one call after the check finds it. None otherwise,
+    /// including while the check is still running.
+    pub fn poll(&self) -> Option<String> {
+        self.result.try_recv().unwrap_or_default()
+    }
+}
+
+#[cfg(not(target_os = "emscripten"))]
+fn fetch_latest_version() -> Result<String, Box<dyn Error>> {
+    let release: serde_json::Value = ureq::get(RELEASE_FEED_URL)
+        .timeout(std::time::Duration::from_secs(TIMEOUT_SECONDS))
+        .set("User-Agent", "DropJack")
+        .call()?
+        .into_json()?;
+    let tag = release["tag_name"]
+        .as_str()
+        .ok_or("Release feed has no tag_name")?;
+    Ok(tag.to_string())
+}
+
+// Browser builds are updated by reloading the page, so there's nothing to check
+#[cfg(target_os = "emscripten")]
+fn fetch_latest_version() -> Result<String, Box<dyn Error>> {
+    Err("Update checks aren't available in the browser".into())
+}
+
+/// `latest` without its "v" prefix, if it's a later version than `current`
+fn newer_version(current: &str, latest: &str) -> Option<String> {
+    let latest = latest.trim().trim_start_matches('v');
+    let (current_parts, latest_parts) = (version_parts(current)?, version_parts(latest)?);
+    (latest_parts > current_parts).then(|| latest.to_string())
+}
+
+// "1.2.0" -> [1, 2, 0], padded so "1.2" and "1.2.0" compare equal. Pre-release and build
+// suffixes ("-beta", "+abc") are ignored.
+fn version_parts(version: &str) -> Option<[u64; 3]> {
+    let core = version.split(['-', '+']).next()?;
+    let mut parts = [0; 3];
+    for (index, part) in core.split('.').enumerate() {
+        *parts.get_mut(index)? = part.parse().ok()?;
+    }
+    Some(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newer_version() {
+        assert_eq!(newer_version("0.1.0", "v1.2"), Some("1.2".to_string()));
+        assert_eq!(newer_version("0.1.0", "0.1.1"), Some("0.1.1".to_string()));
+        assert_eq!(newer_version("0.10.0", "v0.9.5"), None);
+        assert_eq!(newer_version("1.2.0", "v1.2"), None);
+        assert_eq!(newer_version("1.2.0", "v1.2.0-beta"), None);
+
+        // A tag that isn't a version never counts as an update
+        assert_eq!(newer_version("0.1.0", "nightly"), None);
+        assert_eq!(newer_version("0.1.0", "1.2.3.4"), None);
+    }
+}